@@ -13,11 +13,11 @@ fn main() {
     let mut world = worldgen::generate_world(config);
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
         Box::new(ActionSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
         Box::new(ConflictSystem),
         Box::new(DiseaseSystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
     run(&mut world, &mut systems, SimConfig::new(1, 50, 42));
 
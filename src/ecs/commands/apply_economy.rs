@@ -46,6 +46,7 @@ pub(crate) fn apply_establish_trade_route(
                 path: vec![],
                 distance: 1,
                 resource: String::new(),
+                security: 0.0,
             });
         }
     }
@@ -57,6 +58,7 @@ pub(crate) fn apply_establish_trade_route(
                 path: vec![],
                 distance: 1,
                 resource: String::new(),
+                security: 0.0,
             });
         }
     }
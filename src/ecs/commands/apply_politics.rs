@@ -308,6 +308,7 @@ pub(crate) fn apply_betray_alliance(
                 sources: Vec::new(),
                 peak: 0.0,
                 updated: now,
+                atrocity_at: None,
             });
         grievance.severity = (grievance.severity + BETRAYAL_GRIEVANCE_INCREMENT).min(1.0);
         grievance.updated = now;
@@ -95,7 +95,17 @@ pub(crate) fn apply_add_relationship(
         }
 
         // AdjacentTo and TradeRoute are handled elsewhere (adjacency resource, trade data)
-        RelationshipKind::AdjacentTo | RelationshipKind::TradeRoute => {
+        RelationshipKind::AdjacentTo
+        | RelationshipKind::TradeRoute
+        | RelationshipKind::Rival
+        | RelationshipKind::Friend
+        | RelationshipKind::CaptiveOf
+        | RelationshipKind::SpymasterOf
+        | RelationshipKind::GovernorOf
+        | RelationshipKind::KnownTo
+        | RelationshipKind::Embargo
+        | RelationshipKind::StrategicEmbargo
+        | RelationshipKind::CapitalOf => {
             tracing::warn!("AddRelationship for {:?} not handled via applicator", kind);
         }
         RelationshipKind::Custom(_) => {}
@@ -174,7 +184,16 @@ pub(crate) fn apply_end_relationship(
         RelationshipKind::Parent
         | RelationshipKind::Child
         | RelationshipKind::AdjacentTo
-        | RelationshipKind::TradeRoute => {
+        | RelationshipKind::TradeRoute
+        | RelationshipKind::Rival
+        | RelationshipKind::Friend
+        | RelationshipKind::CaptiveOf
+        | RelationshipKind::SpymasterOf
+        | RelationshipKind::GovernorOf
+        | RelationshipKind::KnownTo
+        | RelationshipKind::Embargo
+        | RelationshipKind::StrategicEmbargo
+        | RelationshipKind::CapitalOf => {
             tracing::warn!("EndRelationship for {:?} not handled via applicator", kind);
         }
         RelationshipKind::Custom(_) => {}
@@ -247,6 +247,9 @@ fn process_actions(
                 &clock,
                 &mut commands,
             ),
+            ActionKind::FoundSettlement { .. } => ActionOutcome::Failed {
+                reason: "frontier founding is not supported by the ECS port".to_string(),
+            },
         };
         results.0.push(ActionResult {
             actor_id: action.actor_id,
@@ -1653,6 +1653,7 @@ fn add_faction_grievance(
                     sources: Vec::new(),
                     peak: 0.0,
                     updated: now,
+                    atrocity_at: None,
                 });
         grievance.severity = (grievance.severity + delta).min(1.0);
         grievance.updated = now;
@@ -1893,6 +1894,7 @@ mod tests {
                     sources: vec!["test".to_string()],
                     peak: 0.5,
                     updated: crate::model::SimTimestamp::default(),
+                    atrocity_at: None,
                 },
             );
         }
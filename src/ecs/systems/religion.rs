@@ -782,6 +782,7 @@ mod tests {
                     path: vec![],
                     distance: 1,
                     resource: String::new(),
+                    security: 0.0,
                 });
         }
 
@@ -0,0 +1,73 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+
+use super::checkpoint::{CheckpointState, CheckpointStateRef, assemble_world};
+use crate::model::{Entity, Event, EventEffect, EventParticipant, Relationship, World};
+
+/// Everything [`write_checkpoint`](super::write_checkpoint) writes across its
+/// JSONL files and `state.json`, as one document.
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    state: CheckpointStateRef<'a>,
+    entities: Vec<&'a Entity>,
+    relationships: Vec<&'a Relationship>,
+    events: Vec<&'a Event>,
+    event_participants: &'a [EventParticipant],
+    event_effects: &'a [EventEffect],
+}
+
+/// Owned counterpart of [`SnapshotRef`].
+#[derive(Deserialize)]
+struct Snapshot {
+    state: CheckpointState,
+    entities: Vec<Entity>,
+    relationships: Vec<Relationship>,
+    events: Vec<Event>,
+    event_participants: Vec<EventParticipant>,
+    event_effects: Vec<EventEffect>,
+}
+
+fn invalid_data(err: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+impl World {
+    /// Save the whole world, resume state included, as a single gzipped
+    /// MessagePack file. Far smaller than a JSON checkpoint; use
+    /// [`write_checkpoint`](super::write_checkpoint) when the output should be
+    /// human-readable.
+    pub fn save_binary(&self, path: &Path) -> io::Result<()> {
+        let snapshot = SnapshotRef {
+            state: CheckpointStateRef::of(self),
+            entities: self.entities.values().collect(),
+            relationships: self.collect_relationships().collect(),
+            events: self.events.values().collect(),
+            event_participants: &self.event_participants,
+            event_effects: &self.event_effects,
+        };
+        let mut writer = GzEncoder::new(BufWriter::new(File::create(path)?), Compression::fast());
+        rmp_serde::encode::write_named(&mut writer, &snapshot).map_err(invalid_data)?;
+        writer.finish()?.flush()
+    }
+
+    /// Load a world saved by [`World::save_binary`].
+    pub fn load_binary(path: &Path) -> io::Result<World> {
+        let snapshot: Snapshot =
+            rmp_serde::from_read(GzDecoder::new(BufReader::new(File::open(path)?)))
+                .map_err(invalid_data)?;
+        assemble_world(
+            snapshot.state,
+            snapshot.entities,
+            snapshot.relationships,
+            snapshot.events,
+            snapshot.event_participants,
+            snapshot.event_effects,
+        )
+    }
+}
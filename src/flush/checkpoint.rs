@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::jsonl::flush_to_jsonl;
+use crate::id::IdGenerator;
+use crate::model::action::{Action, ActionResult};
+use crate::model::event_timeline::EventTimeline;
+use crate::model::living_index::LivingIndex;
+use crate::model::relationship_index::RelationshipIndex;
+use crate::model::{
+    Entity, Event, EventEffect, EventParticipant, Relationship, RngBackend, SimTimestamp, World,
+};
+
+/// World state that isn't part of the flushed history but is needed to resume
+/// a run: the id sequence, the clock, queued actions, and the RNG stream.
+#[derive(Serialize)]
+pub(super) struct CheckpointStateRef<'a> {
+    id_gen: &'a IdGenerator,
+    current_time: SimTimestamp,
+    pending_actions: &'a [Action],
+    action_results: &'a [ActionResult],
+    rng: Option<RngBackend>,
+}
+
+impl<'a> CheckpointStateRef<'a> {
+    pub(super) fn of(world: &'a World) -> Self {
+        Self {
+            id_gen: &world.id_gen,
+            current_time: world.current_time,
+            pending_actions: &world.pending_actions,
+            action_results: &world.action_results,
+            rng: world.rng,
+        }
+    }
+}
+
+/// Owned counterpart of [`CheckpointStateRef`], read back from `state.json`.
+#[derive(Deserialize)]
+pub(super) struct CheckpointState {
+    id_gen: IdGenerator,
+    current_time: SimTimestamp,
+    pending_actions: Vec<Action>,
+    action_results: Vec<ActionResult>,
+    /// Absent from checkpoints written before the RNG was recorded.
+    #[serde(default)]
+    rng: Option<RngBackend>,
+}
+
+/// Read a JSONL file back into a list of items (one JSON object per line).
+fn read_jsonl<T: DeserializeOwned>(path: &Path) -> io::Result<Vec<T>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut items = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            items.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok(items)
+}
+
+/// Write a resumable checkpoint of the world into `output_dir`.
+///
+/// Writes the same 5 JSONL files as [`flush_to_jsonl`] plus `state.json`,
+/// which carries the `IdGenerator` position and current time so a resumed run
+/// never reissues an id.
+pub fn write_checkpoint(world: &World, output_dir: &Path) -> io::Result<()> {
+    flush_to_jsonl(world, output_dir)?;
+
+    let state = CheckpointStateRef::of(world);
+    let mut writer = BufWriter::new(File::create(output_dir.join("state.json"))?);
+    serde_json::to_writer(&mut writer, &state)?;
+    writer.flush()
+}
+
+/// Load a world from a checkpoint written by [`write_checkpoint`].
+pub fn read_checkpoint(checkpoint_dir: &Path) -> io::Result<World> {
+    let state: CheckpointState = serde_json::from_reader(BufReader::new(File::open(
+        checkpoint_dir.join("state.json"),
+    )?))?;
+
+    assemble_world(
+        state,
+        read_jsonl(&checkpoint_dir.join("entities.jsonl"))?,
+        read_jsonl(&checkpoint_dir.join("relationships.jsonl"))?,
+        read_jsonl(&checkpoint_dir.join("events.jsonl"))?,
+        read_jsonl(&checkpoint_dir.join("event_participants.jsonl"))?,
+        read_jsonl(&checkpoint_dir.join("event_effects.jsonl"))?,
+    )
+}
+
+/// Rebuild a world from its saved parts: relationships are re-attached to
+/// their source entities in saved order and the indexes are rebuilt.
+pub(super) fn assemble_world(
+    state: CheckpointState,
+    entities: Vec<Entity>,
+    relationships: Vec<Relationship>,
+    events: Vec<Event>,
+    event_participants: Vec<EventParticipant>,
+    event_effects: Vec<EventEffect>,
+) -> io::Result<World> {
+    let mut entities: BTreeMap<u64, Entity> = entities.into_iter().map(|e| (e.id, e)).collect();
+    for rel in relationships {
+        let entity = entities.get_mut(&rel.source_entity_id).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("relationship source {} not found", rel.source_entity_id),
+            )
+        })?;
+        entity.relationships.push(rel);
+    }
+    let events: BTreeMap<u64, Event> = events.into_iter().map(|e| (e.id, e)).collect();
+
+    let relationship_index = RelationshipIndex::from_entities(&entities);
+    let living_index = LivingIndex::from_entities(&entities);
+    let event_timeline = EventTimeline::from_events(&events);
+    Ok(World {
+        entities,
+        events,
+        event_participants,
+        event_effects,
+        id_gen: state.id_gen,
+        current_time: state.current_time,
+        pending_actions: state.pending_actions,
+        action_results: state.action_results,
+        relationship_index,
+        living_index,
+        event_timeline,
+        rng: state.rng,
+    })
+}
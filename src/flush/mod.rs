@@ -1,3 +1,6 @@
+mod binary;
+mod checkpoint;
 mod jsonl;
 
+pub use checkpoint::{read_checkpoint, write_checkpoint};
 pub use jsonl::flush_to_jsonl;
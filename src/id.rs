@@ -1,20 +1,50 @@
+use serde::{Deserialize, Serialize};
+
 /// Monotonic ID generator shared across all entity types.
 /// Guarantees globally unique IDs — no two objects of any type share an ID.
-#[derive(Debug)]
+/// Serialized with simulation checkpoints so resumed runs continue the sequence.
+#[derive(Debug, Serialize, Deserialize)]
 pub struct IdGenerator {
     next: u64,
+    /// Exclusive upper bound of the ids this generator may issue. Forking
+    /// hands the upper half of the remaining range to the branch.
+    #[serde(default = "unbounded")]
+    end: u64,
+}
+
+fn unbounded() -> u64 {
+    u64::MAX
 }
 
 impl IdGenerator {
     pub fn new() -> Self {
-        Self { next: 1 }
+        Self::starting_from(1)
     }
 
     pub fn starting_from(start: u64) -> Self {
-        Self { next: start }
+        Self {
+            next: start,
+            end: unbounded(),
+        }
+    }
+
+    /// Generator for a forked branch. The branch takes the upper half of the
+    /// ids this generator has left, so no two generators split from the same
+    /// root, siblings or nested forks, ever issue the same id.
+    pub fn forked(&mut self) -> Self {
+        let mid = self.next + (self.end - self.next) / 2;
+        let branch = Self {
+            next: mid,
+            end: self.end,
+        };
+        self.end = mid;
+        branch
     }
 
+    /// # Panics
+    /// Panics if the generator's id range is exhausted.
     pub fn next_id(&mut self) -> u64 {
+        assert!(self.next < self.end, "id range exhausted");
         let id = self.next;
         self.next += 1;
         id
@@ -45,4 +75,36 @@ mod tests {
         assert_eq!(id_gen.next_id(), 100);
         assert_eq!(id_gen.next_id(), 101);
     }
+
+    #[test]
+    fn forked_starts_past_original() {
+        let mut id_gen = IdGenerator::starting_from(100);
+        let mut fork = id_gen.forked();
+        assert_eq!(id_gen.next_id(), 100);
+        assert!(fork.next_id() > 100);
+    }
+
+    #[test]
+    fn sibling_and_nested_forks_issue_disjoint_ids() {
+        let mut root = IdGenerator::new();
+        let mut a = root.forked();
+        let mut b = root.forked();
+        let mut nested = a.forked();
+        let mut seen = std::collections::BTreeSet::new();
+        for id_gen in [&mut root, &mut a, &mut b, &mut nested] {
+            for _ in 0..1000 {
+                assert!(seen.insert(id_gen.next_id()), "id issued twice");
+            }
+        }
+    }
+
+    #[test]
+    fn serde_round_trip_continues_sequence() {
+        let mut id_gen = IdGenerator::new();
+        id_gen.next_id();
+        id_gen.next_id();
+        let json = serde_json::to_string(&id_gen).unwrap();
+        let mut back: IdGenerator = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.next_id(), 3);
+    }
 }
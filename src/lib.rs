@@ -15,12 +15,12 @@ pub use model::{
     Relationship, RelationshipKind, SimTimestamp, StateChange, Trait, World,
 };
 pub use procgen::{
-    GeneratedArtifact, GeneratedPerson, GeneratedWriting, ProcGenConfig, SettlementDetails,
-    SettlementSnapshot,
+    DetailLevel, GeneratedArtifact, GeneratedPerson, GeneratedWriting, ProcGenConfig,
+    SettlementDetails, SettlementSnapshot,
 };
 pub use sim::{
-    ActionSystem, AgencySystem, BuildingSystem, ConflictSystem, CultureSystem, DemographicsSystem,
-    DiseaseSystem, EconomySystem, EnvironmentSystem, KnowledgeSystem, MigrationSystem,
-    PoliticsSystem, PopulationBreakdown, ReputationSystem, SimConfig, SimSystem, TickContext,
-    TickFrequency,
+    ActionSystem, AgencySystem, BuildingSystem, ConflictSystem, CultureSystem, DemographicsConfig,
+    DemographicsSystem, DiseaseSystem, EconomySystem, EnvironmentSystem, KnowledgeSystem,
+    MigrationSystem, PoliticsConfig, PoliticsSystem, PopulationBreakdown, ReputationSystem,
+    SimConfig, SimSystem, TickContext, TickFrequency,
 };
@@ -48,6 +48,7 @@ pub enum ActionKind {
     SeekOffice { faction_id: u64 },
     BetrayAlly { ally_faction_id: u64 },
     PressClaim { target_faction_id: u64 },
+    FoundSettlement { region_id: u64 },
 }
 
 impl fmt::Display for ActionKind {
@@ -77,6 +78,7 @@ impl fmt::Display for ActionKind {
             Self::PressClaim { target_faction_id } => {
                 write!(f, "press_claim({target_faction_id})")
             }
+            Self::FoundSettlement { region_id } => write!(f, "found_settlement({region_id})"),
         }
     }
 }
@@ -0,0 +1,127 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use super::event::Event;
+use super::world::World;
+
+/// How many links of a cause chain `export_causal_dot` follows back from a root event.
+pub const CAUSAL_DOT_MAX_DEPTH: usize = 32;
+
+impl World {
+    /// Render the event cause-effect graph as GraphViz DOT.
+    ///
+    /// With a root event, the graph holds that event and the chain of causes
+    /// that led to it, up to `CAUSAL_DOT_MAX_DEPTH` links back. Without one, it
+    /// holds every event that causes or was caused by another.
+    pub fn export_causal_dot(&self, root_event: Option<u64>) -> String {
+        self.export_causal_dot_to_depth(root_event, CAUSAL_DOT_MAX_DEPTH)
+    }
+
+    /// Like `export_causal_dot`, following at most `max_depth` causes back from the root.
+    pub fn export_causal_dot_to_depth(&self, root_event: Option<u64>, max_depth: usize) -> String {
+        let mut nodes = BTreeSet::new();
+        let mut edges = BTreeSet::new();
+
+        match root_event {
+            Some(root) if self.events.contains_key(&root) => {
+                nodes.insert(root);
+                let mut current = root;
+                // Causes always predate their effects, but a hand-edited history
+                // could still loop; stop at the first revisited event.
+                for _ in 0..max_depth {
+                    let Some(cause) = self.events[&current].caused_by else {
+                        break;
+                    };
+                    if !self.events.contains_key(&cause) {
+                        break;
+                    }
+                    edges.insert((cause, current));
+                    if !nodes.insert(cause) {
+                        break;
+                    }
+                    current = cause;
+                }
+            }
+            Some(_) => {}
+            None => {
+                for event in self.events.values() {
+                    if let Some(cause) = event.caused_by
+                        && self.events.contains_key(&cause)
+                    {
+                        nodes.insert(cause);
+                        nodes.insert(event.id);
+                        edges.insert((cause, event.id));
+                    }
+                }
+            }
+        }
+
+        let mut out = String::from("digraph causes {\n    node [shape=box];\n");
+        for id in &nodes {
+            let _ = writeln!(
+                out,
+                "    e{id} [label=\"{}\"];",
+                dot_label(&self.events[id])
+            );
+        }
+        for (cause, effect) in &edges {
+            let _ = writeln!(out, "    e{cause} -> e{effect};");
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+fn dot_label(event: &Event) -> String {
+    format!("{} ({})", event.kind, event.timestamp.year())
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{EventKind, SimTimestamp};
+
+    fn ts(year: u32) -> SimTimestamp {
+        SimTimestamp::from_year(year)
+    }
+
+    #[test]
+    fn war_caused_by_betrayal_has_edge() {
+        let mut world = World::new();
+        let unrelated = world.add_event(EventKind::Birth, ts(90), "birth".to_string());
+        let betrayal = world.add_event(EventKind::Betrayal, ts(100), "betrayal".to_string());
+        let war =
+            world.add_caused_event(EventKind::WarDeclared, ts(101), "war".to_string(), betrayal);
+
+        let dot = world.export_causal_dot(Some(war));
+        assert!(dot.starts_with("digraph causes {"));
+        assert!(dot.contains(&format!("e{betrayal} -> e{war};")), "{dot}");
+        assert!(dot.contains(&format!("e{betrayal} [label=\"betrayal (100)\"];")));
+        assert!(dot.contains(&format!("e{war} [label=\"war_declared (101)\"];")));
+        assert!(!dot.contains(&format!("e{unrelated} ")));
+
+        let full = world.export_causal_dot(None);
+        assert!(full.contains(&format!("e{betrayal} -> e{war};")));
+        assert!(!full.contains(&format!("e{unrelated} ")));
+    }
+
+    #[test]
+    fn depth_limit_and_cycles_stop_the_walk() {
+        let mut world = World::new();
+        let first = world.add_event(EventKind::Betrayal, ts(100), "first".to_string());
+        let second =
+            world.add_caused_event(EventKind::WarDeclared, ts(101), "second".to_string(), first);
+        let third = world.add_caused_event(EventKind::Battle, ts(102), "third".to_string(), second);
+
+        let shallow = world.export_causal_dot_to_depth(Some(third), 1);
+        assert!(shallow.contains(&format!("e{second} -> e{third};")));
+        assert!(!shallow.contains(&format!("e{first}")));
+
+        // Corrupt the history into a loop; export must still terminate
+        world.events.get_mut(&first).unwrap().caused_by = Some(third);
+        let looped = world.export_causal_dot(Some(third));
+        assert_eq!(looped.matches(" -> ").count(), 3);
+    }
+}
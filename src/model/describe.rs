@@ -0,0 +1,260 @@
+//! Rendering event descriptions from structured payloads.
+//!
+//! Events keep the description written when they were created, but any event
+//! carrying an [`EventPayload`] can be re-rendered on demand through a
+//! [`Describer`] — another language, a terser style, or custom templates —
+//! without touching the simulation. [`EnglishDescriber`] reproduces the
+//! simulation's own phrasing.
+
+use super::entity_data::{ExpansionMotivation, SpyOperation, WarGoal};
+use super::event::Event;
+use super::event_payload::EventPayload;
+use super::timestamp::SimTimestamp;
+use super::world::World;
+
+/// Turns structured event payloads into prose.
+pub trait Describer {
+    /// Describe a payload occurring at `timestamp`, or `None` if this
+    /// describer has no template for it.
+    fn describe_payload(
+        &self,
+        world: &World,
+        payload: &EventPayload,
+        timestamp: SimTimestamp,
+    ) -> Option<String>;
+
+    /// Describe a recorded event, falling back to the description stored at
+    /// creation for events without a payload or a template.
+    fn describe(&self, world: &World, event: &Event) -> String {
+        EventPayload::from_data(&event.data)
+            .and_then(|payload| self.describe_payload(world, &payload, event.timestamp))
+            .unwrap_or_else(|| event.description.clone())
+    }
+}
+
+/// The simulation's default English phrasing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnglishDescriber;
+
+impl Describer for EnglishDescriber {
+    fn describe_payload(
+        &self,
+        world: &World,
+        payload: &EventPayload,
+        timestamp: SimTimestamp,
+    ) -> Option<String> {
+        let name = |id: u64| name_of(world, id);
+        let year = timestamp.year();
+        let description = match payload {
+            EventPayload::WarDeclared {
+                attacker_id,
+                defender_id,
+                war_goal,
+            } => format!(
+                "{} declared war on {}{} in year {year}",
+                name(*attacker_id),
+                name(*defender_id),
+                war_goal_phrase(world, war_goal)
+            ),
+            EventPayload::FirstContact {
+                faction_a,
+                faction_b,
+            } => format!(
+                "{} and {} made first contact in year {year}",
+                name(*faction_a),
+                name(*faction_b)
+            ),
+            EventPayload::Capture {
+                person_id,
+                captor_faction_id,
+                ..
+            } => format!(
+                "{} was taken captive by {} in year {year}",
+                name(*person_id),
+                name(*captor_faction_id)
+            ),
+            EventPayload::Ransom {
+                person_id,
+                payer_faction_id,
+                amount,
+                ..
+            } => format!(
+                "{} ransomed {} for {amount:.0} gold in year {year}",
+                name(*payer_faction_id),
+                name(*person_id)
+            ),
+            EventPayload::Execution {
+                person_id,
+                captor_faction_id,
+            } => format!(
+                "{} executed their captive {} in year {year}",
+                name(*captor_faction_id),
+                name(*person_id)
+            ),
+            EventPayload::Release {
+                person_id,
+                captor_faction_id,
+            } => format!(
+                "{} released their captive {} in year {year}",
+                name(*captor_faction_id),
+                name(*person_id)
+            ),
+            EventPayload::Appointment {
+                person_id,
+                settlement_id,
+                ..
+            } => format!(
+                "{} was appointed governor of {} in year {year}",
+                name(*person_id),
+                name(*settlement_id)
+            ),
+            EventPayload::EspionageOperation {
+                faction_id,
+                target_faction_id,
+                target_id,
+                operation,
+                detected,
+                ..
+            } => {
+                let attempt = match operation {
+                    SpyOperation::StealTreasury => {
+                        format!("steal from the treasury of {}", name(*target_faction_id))
+                    }
+                    SpyOperation::SabotageSupply => {
+                        format!("sabotage the supplies of {}", name(*target_id))
+                    }
+                    SpyOperation::InciteUnrest => format!("incite unrest in {}", name(*target_id)),
+                };
+                if *detected {
+                    format!(
+                        "Spies of {} were caught trying to {attempt} in year {year}",
+                        name(*faction_id)
+                    )
+                } else {
+                    format!(
+                        "Spies of {} managed to {attempt} in year {year}",
+                        name(*faction_id)
+                    )
+                }
+            }
+            EventPayload::KnowledgeAdopted {
+                faction_id,
+                source_faction_id,
+                technology,
+            } => format!(
+                "{} learned {} from {} in year {year}",
+                name(*faction_id),
+                technology.as_str(),
+                name(*source_faction_id)
+            ),
+            EventPayload::SlaveRevolt { settlement_id, .. } => format!(
+                "The unfree of {} rose in revolt in year {year}",
+                name(*settlement_id)
+            ),
+            _ => return None,
+        };
+        Some(description)
+    }
+}
+
+/// What the aggressor is after, as a clause appended to a war declaration.
+fn war_goal_phrase(world: &World, war_goal: &WarGoal) -> String {
+    match war_goal {
+        WarGoal::Territorial { target_settlements } => format!(
+            " seeking territorial expansion ({} settlements targeted)",
+            target_settlements.len()
+        ),
+        WarGoal::Economic { reparation_demand } => {
+            format!(" demanding economic reparations of {reparation_demand:.0} gold")
+        }
+        WarGoal::Punitive => " seeking punitive retribution".to_string(),
+        WarGoal::SuccessionClaim { claimant_id } => {
+            format!(
+                " pressing succession claim for {}",
+                name_of(world, *claimant_id)
+            )
+        }
+        WarGoal::Expansion {
+            target_settlements,
+            motivation,
+        } => {
+            let motivation = match motivation {
+                ExpansionMotivation::Opportunistic => "opportunistic",
+                ExpansionMotivation::ResourceGrab { .. } => "resource grab",
+                ExpansionMotivation::DefensiveBuffer { .. } => "defensive buffer",
+            };
+            format!(
+                " launching expansion war ({motivation}, {} settlements targeted)",
+                target_settlements.len()
+            )
+        }
+        WarGoal::HolyWar { target_settlements } => format!(
+            " waging holy war to retake its holy sites ({} settlements targeted)",
+            target_settlements.len()
+        ),
+    }
+}
+
+fn name_of(world: &World, id: u64) -> String {
+    world
+        .entities
+        .get(&id)
+        .map(|e| e.name.clone())
+        .unwrap_or_else(|| format!("Entity#{id}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{EntityData, EntityKind, EventKind};
+
+    fn faction(world: &mut World, name: &str, ev: u64) -> u64 {
+        world.add_entity(
+            EntityKind::Faction,
+            name.to_string(),
+            None,
+            EntityData::default_for_kind(EntityKind::Faction),
+            ev,
+        )
+    }
+
+    #[test]
+    fn english_describer_renders_war_declaration() {
+        let mut world = World::new();
+        let setup = world.add_event(
+            EventKind::Custom("setup".into()),
+            SimTimestamp::default(),
+            String::new(),
+        );
+        let aldor = faction(&mut world, "Aldor", setup);
+        let brell = faction(&mut world, "Brell", setup);
+
+        let ev = world.add_structured_event(
+            EventPayload::WarDeclared {
+                attacker_id: aldor,
+                defender_id: brell,
+                war_goal: WarGoal::Punitive,
+            },
+            SimTimestamp::from_year(120),
+            "stored text".to_string(),
+        );
+        assert_eq!(
+            EnglishDescriber.describe(&world, &world.events[&ev]),
+            "Aldor declared war on Brell seeking punitive retribution in year 120"
+        );
+    }
+
+    #[test]
+    fn describe_falls_back_to_stored_description() {
+        let mut world = World::new();
+        let ev = world.add_event(
+            EventKind::Birth,
+            SimTimestamp::from_year(5),
+            "Aldric born in year 5".to_string(),
+        );
+        assert_eq!(
+            EnglishDescriber.describe(&world, &world.events[&ev]),
+            "Aldric born in year 5"
+        );
+    }
+}
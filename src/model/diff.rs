@@ -0,0 +1,138 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::entity::EntityKind;
+use super::world::World;
+
+/// How one world differs from another, as reported by [`World::diff`].
+///
+/// "Left" is the world `diff` was called on, "right" the one passed in. Only
+/// differences are kept: two worlds with the same history give an empty diff.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorldDiff {
+    /// Names of living factions found only in the left world.
+    pub factions_only_left: Vec<String>,
+    /// Names of living factions found only in the right world.
+    pub factions_only_right: Vec<String>,
+    /// Total population of living settlements (left, right), when they differ.
+    pub population: Option<(u64, u64)>,
+    /// Event counts (left, right) for each kind whose count differs.
+    pub event_counts: BTreeMap<String, (usize, usize)>,
+    /// Ids of entities found only in the left world.
+    pub entities_only_left: Vec<u64>,
+    /// Ids of entities found only in the right world.
+    pub entities_only_right: Vec<u64>,
+    /// Ids of entities in both worlds whose state or relationships diverged.
+    pub diverged_entities: Vec<u64>,
+}
+
+impl WorldDiff {
+    /// Whether the two worlds match in everything the diff compares.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Number of individual differences found.
+    pub fn len(&self) -> usize {
+        self.factions_only_left.len()
+            + self.factions_only_right.len()
+            + usize::from(self.population.is_some())
+            + self.event_counts.len()
+            + self.entities_only_left.len()
+            + self.entities_only_right.len()
+            + self.diverged_entities.len()
+    }
+}
+
+impl World {
+    /// Summarize how `other` differs from this world: surviving factions,
+    /// population, event counts by kind, and entities that exist in only one
+    /// world or diverged in both. Meant for comparing finished runs, e.g. the
+    /// same seed under two configs.
+    pub fn diff(&self, other: &World) -> WorldDiff {
+        let faction_names = |world: &World| -> BTreeSet<String> {
+            world
+                .living(EntityKind::Faction)
+                .map(|e| e.name.clone())
+                .collect()
+        };
+        let (left_factions, right_factions) = (faction_names(self), faction_names(other));
+
+        let (left_pop, right_pop) = (self.total_population(), other.total_population());
+
+        let mut event_counts: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+        for event in self.events.values() {
+            event_counts.entry(event.kind.to_string()).or_default().0 += 1;
+        }
+        for event in other.events.values() {
+            event_counts.entry(event.kind.to_string()).or_default().1 += 1;
+        }
+        event_counts.retain(|_, (left, right)| left != right);
+
+        let mut diverged_entities = Vec::new();
+        let mut entities_only_left = Vec::new();
+        for (id, entity) in &self.entities {
+            match other.entities.get(id) {
+                Some(theirs) if theirs != entity => diverged_entities.push(*id),
+                Some(_) => {}
+                None => entities_only_left.push(*id),
+            }
+        }
+        let entities_only_right = other
+            .entities
+            .keys()
+            .filter(|id| !self.entities.contains_key(id))
+            .copied()
+            .collect();
+
+        WorldDiff {
+            factions_only_left: left_factions.difference(&right_factions).cloned().collect(),
+            factions_only_right: right_factions.difference(&left_factions).cloned().collect(),
+            population: (left_pop != right_pop).then_some((left_pop, right_pop)),
+            event_counts,
+            entities_only_left,
+            entities_only_right,
+            diverged_entities,
+        }
+    }
+
+    fn total_population(&self) -> u64 {
+        self.living(EntityKind::Settlement)
+            .filter_map(|e| e.data.as_settlement())
+            .map(|sd| sd.population as u64)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scenario::Scenario;
+    use crate::sim::{DemographicsSystem, SimSystem};
+
+    fn run_with_seed(seed: u64) -> crate::model::World {
+        let mut s = Scenario::at_year(100);
+        let k = s.add_kingdom("Seedvale");
+        let _ = s.settlement_mut(k.settlement).population(800);
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(DemographicsSystem::new())];
+        s.run(&mut systems, 20, seed)
+    }
+
+    #[test]
+    fn world_matches_its_own_fork() {
+        let mut world = run_with_seed(7);
+        let fork = world.fork();
+        let diff = world.diff(&fork);
+        assert!(diff.is_empty(), "{diff:?}");
+        assert_eq!(diff.len(), 0);
+    }
+
+    #[test]
+    fn differently_seeded_runs_diverge() {
+        let left = run_with_seed(7);
+        let right = run_with_seed(8);
+        let diff = left.diff(&right);
+        assert!(!diff.is_empty());
+        assert!(!diff.diverged_entities.is_empty() || diff.population.is_some());
+    }
+}
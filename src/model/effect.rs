@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use super::entity::EntityKind;
+use super::entity_data::{EntityData, FactionData};
 use super::relationship::RelationshipKind;
+use super::timestamp::SimTimestamp;
+use super::world::World;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct EventEffect {
@@ -56,6 +59,305 @@ impl StateChange {
     }
 }
 
+/// A numeric faction field that [`Effect::DeltaField`] can adjust. Named
+/// rather than looked up by string so a typo is a compile error and applying
+/// a delta touches the field directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactionField {
+    Stability,
+    Happiness,
+    Legitimacy,
+    Prestige,
+}
+
+string_enum!(FactionField {
+    Stability => "stability",
+    Happiness => "happiness",
+    Legitimacy => "legitimacy",
+    Prestige => "prestige",
+});
+
+impl FactionField {
+    fn value_mut(self, data: &mut FactionData) -> &mut f64 {
+        match self {
+            FactionField::Stability => &mut data.stability,
+            FactionField::Happiness => &mut data.happiness,
+            FactionField::Legitimacy => &mut data.legitimacy,
+            FactionField::Prestige => &mut data.prestige,
+        }
+    }
+}
+
+/// A state mutation a system wants to make. Applied through
+/// [`World::apply_effect`], which performs it and records the resulting
+/// [`StateChange`] in one step.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Effect {
+    /// Add `delta` to a numeric field of a faction, clamped to `[min, max]`.
+    DeltaField {
+        entity_id: u64,
+        field: FactionField,
+        delta: f64,
+        min: f64,
+        max: f64,
+    },
+    /// Overwrite a field of the entity's data, named as it serializes. Goes
+    /// through the serialized form, so it suits tooling and plugins rather
+    /// than per-tick system updates.
+    SetField {
+        entity_id: u64,
+        field: String,
+        value: serde_json::Value,
+    },
+    /// Start a relationship, unless an identical one is already active.
+    SetRelationship {
+        source_id: u64,
+        target_id: u64,
+        kind: RelationshipKind,
+    },
+    /// End an active relationship.
+    EndRelationship {
+        source_id: u64,
+        target_id: u64,
+        kind: RelationshipKind,
+    },
+    Rename {
+        entity_id: u64,
+        name: String,
+    },
+}
+
+impl Effect {
+    /// A delta on a field kept in the usual 0.0-1.0 range (stability, happiness, ...).
+    pub fn unit_delta(entity_id: u64, field: FactionField, delta: f64) -> Self {
+        Effect::DeltaField {
+            entity_id,
+            field,
+            delta,
+            min: 0.0,
+            max: 1.0,
+        }
+    }
+}
+
+/// Read a field of an entity's typed data by its serialized name.
+fn data_field(data: &EntityData, field: &str) -> Option<serde_json::Value> {
+    match serde_json::to_value(data).ok()? {
+        serde_json::Value::Object(mut map) => map.remove(field),
+        _ => None,
+    }
+}
+
+/// Overwrite a field of an entity's typed data by its serialized name.
+/// Returns `false` if the data has no such field or rejects the value.
+fn set_data_field(data: &mut EntityData, field: &str, value: serde_json::Value) -> bool {
+    let Ok(serde_json::Value::Object(mut map)) = serde_json::to_value(&*data) else {
+        return false;
+    };
+    if field == "kind" || !map.contains_key(field) {
+        return false;
+    }
+    map.insert(field.to_string(), value);
+    match serde_json::from_value(serde_json::Value::Object(map)) {
+        Ok(new_data) => {
+            *data = new_data;
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// Value a field held at the end of `year`, given its recorded
+/// `(timestamp, old, new)` changes in the order they happened: the last change
+/// made by then or, if it only changed later, the value before its first
+/// change. `None` if it never changed.
+pub(super) fn replay_changes_at<T: Copy>(changes: &[(SimTimestamp, T, T)], year: u32) -> Option<T> {
+    match changes
+        .iter()
+        .rev()
+        .find(|(timestamp, ..)| timestamp.year() <= year)
+    {
+        Some(&(_, _, new)) => Some(new),
+        None => changes.first().map(|&(_, old, _)| old),
+    }
+}
+
+impl World {
+    /// Perform `effect` and record it against `event_id`, returning the
+    /// recorded change. Returns `None` when there was nothing to do: the
+    /// relationship was already active.
+    ///
+    /// # Panics
+    /// Panics if an entity or `event_id` doesn't exist, if a delta targets a
+    /// non-faction, if a field is missing
+    /// or can't hold the value, or if a relationship to end isn't active.
+    pub fn apply_effect(&mut self, effect: Effect, event_id: u64) -> Option<StateChange> {
+        assert!(
+            self.events.contains_key(&event_id),
+            "apply_effect: event {event_id} not found"
+        );
+        let before = self.event_effects.len();
+        match effect {
+            Effect::DeltaField {
+                entity_id,
+                field,
+                delta,
+                min,
+                max,
+            } => {
+                let value = field.value_mut(self.faction_mut(entity_id));
+                let old = *value;
+                let new = (old + delta).clamp(min, max);
+                *value = new;
+                self.record_change(
+                    entity_id,
+                    event_id,
+                    field.as_str(),
+                    serde_json::json!(old),
+                    serde_json::json!(new),
+                );
+            }
+            Effect::SetField {
+                entity_id,
+                field,
+                value,
+            } => self.set_field(entity_id, &field, value, event_id),
+            Effect::SetRelationship {
+                source_id,
+                target_id,
+                kind,
+            } => self.add_relationship(source_id, target_id, kind, self.current_time, event_id),
+            Effect::EndRelationship {
+                source_id,
+                target_id,
+                kind,
+            } => self.end_relationship(source_id, target_id, kind, self.current_time, event_id),
+            Effect::Rename { entity_id, name } => self.rename_entity(entity_id, name, event_id),
+        }
+        (self.event_effects.len() > before)
+            .then(|| self.event_effects.last().unwrap().effect.clone())
+    }
+
+    fn set_field(&mut self, entity_id: u64, field: &str, value: serde_json::Value, event_id: u64) {
+        let data = &mut self.entity_mut(entity_id).data;
+        let old_value = data_field(data, field).unwrap_or(serde_json::Value::Null);
+        assert!(
+            set_data_field(data, field, value.clone()),
+            "apply_effect: cannot set field {field} on entity {entity_id}"
+        );
+        self.record_change(entity_id, event_id, field, old_value, value);
+    }
+
+    /// Value an entity's field held at the end of `year`, rebuilt from the
+    /// recorded changes: the last change made by then or, if the field only
+    /// changed later, the value it held before its first change. Fields never
+    /// recorded changing read their current value.
+    ///
+    /// Returns `None` if the entity didn't exist yet in `year` (or at all), or
+    /// has no such field.
+    pub fn field_value_at(
+        &self,
+        entity_id: u64,
+        field: &str,
+        year: u32,
+    ) -> Option<serde_json::Value> {
+        let entity = self.entities.get(&entity_id)?;
+        if entity.origin.is_some_and(|origin| origin.year() > year) {
+            return None;
+        }
+
+        let mut changes: Vec<(SimTimestamp, &serde_json::Value, &serde_json::Value)> = self
+            .event_effects
+            .iter()
+            .filter(|e| e.entity_id == entity_id)
+            .filter_map(|e| match &e.effect {
+                StateChange::PropertyChanged {
+                    field: f,
+                    old_value,
+                    new_value,
+                } if f == field => {
+                    let timestamp = self.events.get(&e.event_id)?.timestamp;
+                    Some((timestamp, old_value, new_value))
+                }
+                _ => None,
+            })
+            .collect();
+        // Stable, so same-timestamp changes keep their log order
+        changes.sort_by_key(|&(timestamp, _, _)| timestamp);
+
+        match replay_changes_at(&changes, year) {
+            Some(value) => Some(value.clone()),
+            None => data_field(&entity.data, field).or_else(|| entity.extra.get(field).cloned()),
+        }
+    }
+
+    /// Revert a recorded change, restoring the entity to how it was just
+    /// before. Meant for time-travel tooling walking the effect log
+    /// backwards; the log itself is left untouched. Returns `false` for
+    /// changes that can't be reverted in place (creations and custom changes)
+    /// or whose entity no longer exists.
+    pub fn undo_change(&mut self, effect: &EventEffect) -> bool {
+        let entity_id = effect.entity_id;
+        let Some(entity) = self.entities.get_mut(&entity_id) else {
+            return false;
+        };
+        match &effect.effect {
+            StateChange::PropertyChanged {
+                field, old_value, ..
+            } => {
+                if set_data_field(&mut entity.data, field, old_value.clone()) {
+                    return true;
+                }
+                if old_value.is_null() {
+                    entity.extra.remove(field);
+                } else {
+                    entity.extra.insert(field.clone(), old_value.clone());
+                }
+                true
+            }
+            StateChange::NameChanged { old, .. } => {
+                entity.name = old.clone();
+                true
+            }
+            StateChange::EntityEnded => {
+                entity.end = None;
+                let kind = entity.kind;
+                self.living_index.insert(kind, entity_id);
+                true
+            }
+            StateChange::RelationshipStarted {
+                target_entity_id,
+                kind,
+            } => {
+                let Some(pos) = entity.relationships.iter().rposition(|r| {
+                    r.target_entity_id == *target_entity_id && r.kind == *kind && r.is_active()
+                }) else {
+                    return false;
+                };
+                entity.relationships.remove(pos);
+                self.relationship_index
+                    .remove(kind.clone(), *target_entity_id, entity_id);
+                true
+            }
+            StateChange::RelationshipEnded {
+                target_entity_id,
+                kind,
+            } => {
+                let Some(rel) = entity.relationships.iter_mut().rev().find(|r| {
+                    r.target_entity_id == *target_entity_id && r.kind == *kind && !r.is_active()
+                }) else {
+                    return false;
+                };
+                rel.end = None;
+                self.relationship_index
+                    .insert(kind.clone(), *target_entity_id, entity_id);
+                true
+            }
+            StateChange::EntityCreated { .. } | StateChange::Custom { .. } => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,4 +530,224 @@ mod tests {
         assert_eq!(json["effect"]["change_type"], "spell_learned");
         assert_eq!(json["effect"]["data"]["spell"], "fireball");
     }
+
+    fn effect_world() -> (World, u64, u64, u64) {
+        use crate::scenario::Scenario;
+        let mut s = Scenario::at_year(100);
+        let faction = s.add_faction("Kingdom");
+        let rival = s.add_faction("Rival");
+        let mut world = s.build();
+        let ev = world.add_event(
+            crate::model::EventKind::Custom("test".to_string()),
+            world.current_time,
+            "test".to_string(),
+        );
+        (world, faction, rival, ev)
+    }
+
+    #[test]
+    fn apply_delta_records_change_and_undo_restores_exactly() {
+        let (mut world, faction, _, ev) = effect_world();
+        world.faction_mut(faction).happiness = 0.37;
+
+        let change = world
+            .apply_effect(
+                Effect::unit_delta(faction, FactionField::Happiness, 0.25),
+                ev,
+            )
+            .unwrap();
+        assert_eq!(world.faction(faction).happiness, 0.62);
+        assert_eq!(
+            change,
+            StateChange::PropertyChanged {
+                field: "happiness".to_string(),
+                old_value: serde_json::json!(0.37),
+                new_value: serde_json::json!(0.62),
+            }
+        );
+        assert_eq!(world.event_effects.last().unwrap().effect, change);
+
+        let recorded = world.event_effects.last().unwrap().clone();
+        assert!(world.undo_change(&recorded));
+        assert_eq!(world.faction(faction).happiness, 0.37);
+    }
+
+    #[test]
+    fn faction_field_names_match_serialized_data() {
+        let data = EntityData::default_for_kind(EntityKind::Faction);
+        for field in [
+            FactionField::Stability,
+            FactionField::Happiness,
+            FactionField::Legitimacy,
+            FactionField::Prestige,
+        ] {
+            assert!(
+                data_field(&data, field.as_str()).is_some_and(|v| v.is_f64()),
+                "{field} should name a numeric faction field"
+            );
+        }
+    }
+
+    #[test]
+    fn apply_delta_clamps_to_bounds() {
+        let (mut world, faction, _, ev) = effect_world();
+        world.faction_mut(faction).stability = 0.9;
+        world.apply_effect(
+            Effect::unit_delta(faction, FactionField::Stability, 0.5),
+            ev,
+        );
+        assert_eq!(world.faction(faction).stability, 1.0);
+
+        let recorded = world.event_effects.last().unwrap().clone();
+        assert!(world.undo_change(&recorded));
+        assert_eq!(world.faction(faction).stability, 0.9);
+    }
+
+    #[test]
+    fn set_field_and_rename_undo() {
+        let (mut world, faction, _, ev) = effect_world();
+        let old_treasury = world.faction(faction).treasury;
+        world.apply_effect(
+            Effect::SetField {
+                entity_id: faction,
+                field: "treasury".to_string(),
+                value: serde_json::json!(123.5),
+            },
+            ev,
+        );
+        assert_eq!(world.faction(faction).treasury, 123.5);
+        world.apply_effect(
+            Effect::Rename {
+                entity_id: faction,
+                name: "Empire".to_string(),
+            },
+            ev,
+        );
+        assert_eq!(world.entity(faction).name, "Empire");
+
+        for recorded in world.event_effects.clone().iter().rev().take(2) {
+            assert!(world.undo_change(recorded));
+        }
+        assert_eq!(world.entity(faction).name, "Kingdom");
+        assert_eq!(world.faction(faction).treasury, old_treasury);
+    }
+
+    #[test]
+    fn relationship_effects_undo() {
+        let (mut world, faction, rival, ev) = effect_world();
+        let started = world.apply_effect(
+            Effect::SetRelationship {
+                source_id: faction,
+                target_id: rival,
+                kind: RelationshipKind::Enemy,
+            },
+            ev,
+        );
+        assert!(started.is_some());
+        let again = world.apply_effect(
+            Effect::SetRelationship {
+                source_id: faction,
+                target_id: rival,
+                kind: RelationshipKind::Enemy,
+            },
+            ev,
+        );
+        assert_eq!(again, None, "an active relationship isn't started twice");
+
+        world.apply_effect(
+            Effect::EndRelationship {
+                source_id: faction,
+                target_id: rival,
+                kind: RelationshipKind::Enemy,
+            },
+            ev,
+        );
+        let ended = world.event_effects.last().unwrap().clone();
+        assert!(world.undo_change(&ended));
+        assert!(
+            world
+                .entity(faction)
+                .has_active_rel(RelationshipKind::Enemy, rival)
+        );
+        assert_eq!(
+            world
+                .active_sources(RelationshipKind::Enemy, rival)
+                .map(|e| e.id)
+                .collect::<Vec<_>>(),
+            vec![faction]
+        );
+
+        let started = world.event_effects[world.event_effects.len() - 2].clone();
+        assert!(world.undo_change(&started));
+        assert!(world.entity(faction).relationships.is_empty());
+        assert_eq!(
+            world.active_sources(RelationshipKind::Enemy, rival).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn undo_extra_property_restores_absence() {
+        let (mut world, faction, _, ev) = effect_world();
+        world.set_extra(faction, "motto", serde_json::json!("Onward"), ev);
+        let recorded = world.event_effects.last().unwrap().clone();
+        assert!(world.undo_change(&recorded));
+        assert!(!world.entity(faction).extra.contains_key("motto"));
+    }
+
+    #[test]
+    fn field_value_at_replays_recorded_changes() {
+        let (mut world, faction, _, _) = effect_world();
+        world.faction_mut(faction).stability = 0.5;
+        for (year, delta) in [(101, 0.1), (103, -0.3)] {
+            let ev = world.add_event(
+                crate::model::EventKind::Custom("test".to_string()),
+                SimTimestamp::from_year(year),
+                "test".to_string(),
+            );
+            world.apply_effect(
+                Effect::unit_delta(faction, FactionField::Stability, delta),
+                ev,
+            );
+        }
+
+        let at = |year| {
+            world
+                .field_value_at(faction, "stability", year)
+                .and_then(|v| v.as_f64())
+        };
+        assert_eq!(at(99), None, "the faction was founded in year 100");
+        assert_eq!(at(100), Some(0.5));
+        assert_eq!(at(102), Some(0.6));
+        assert!((at(103).unwrap() - 0.3).abs() < 1e-9);
+        assert_eq!(world.field_value_at(faction, "no_such_field", 102), None);
+    }
+
+    #[test]
+    fn field_value_at_matches_stability_seen_during_run() {
+        use crate::sim::{SimConfig, resume, run};
+        use crate::testutil;
+        use crate::worldgen::{self, WorldGenConfig};
+
+        let seed = 7;
+        let mut world = worldgen::generate_world(WorldGenConfig {
+            seed,
+            ..WorldGenConfig::default()
+        });
+        let mut systems = testutil::core_systems();
+        run(&mut world, &mut systems, SimConfig::new(1, 30, seed)).unwrap();
+        let live: Vec<(u64, f64)> = world
+            .living(EntityKind::Faction)
+            .map(|e| (e.id, e.data.as_faction().unwrap().stability))
+            .collect();
+        assert!(!live.is_empty());
+
+        resume(&mut world, &mut systems, SimConfig::new(1, 50, seed)).unwrap();
+        for (faction, stability) in live {
+            let replayed = world
+                .field_value_at(faction, "stability", 30)
+                .and_then(|v| v.as_f64());
+            assert_eq!(replayed, Some(stability), "faction {faction}");
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +26,10 @@ pub enum EntityKind {
     Knowledge,
     Manifestation,
     Religion,
+    Dynasty,
+    Monument,
+    Guild,
+    HolySite,
 }
 
 string_enum!(EntityKind {
@@ -46,6 +50,10 @@ string_enum!(EntityKind {
     Knowledge => "knowledge",
     Manifestation => "manifestation",
     Religion => "religion",
+    Dynasty => "dynasty",
+    Monument => "monument",
+    Guild => "guild",
+    HolySite => "holy_site",
 });
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -61,8 +69,9 @@ pub struct Entity {
 
     /// Dynamic/extensible properties not captured by EntityData
     /// (e.g. production maps, trade routes, computed bonuses).
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub extra: HashMap<String, serde_json::Value>,
+    /// Ordered by key so serialized output is identical across runs.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, serde_json::Value>,
 
     /// Inline relationships during simulation, normalized at flush time.
     /// Skipped during serialization — extracted via `World::collect_relationships()`.
@@ -162,8 +171,10 @@ mod tests {
                 widowed_at: None,
                 loyalty: std::collections::BTreeMap::new(),
                 education: 0.0,
+                health: 1.0,
+                dynasty_id: None,
             }),
-            extra: HashMap::new(),
+            extra: BTreeMap::new(),
             relationships: vec![],
         };
 
@@ -222,6 +233,10 @@ mod tests {
             EntityKind::Knowledge,
             EntityKind::Manifestation,
             EntityKind::Religion,
+            EntityKind::Dynasty,
+            EntityKind::Monument,
+            EntityKind::Guild,
+            EntityKind::HolySite,
         ] {
             let json = serde_json::to_string(&kind).unwrap();
             let back: EntityKind = serde_json::from_str(&json).unwrap();
@@ -240,7 +255,7 @@ mod tests {
             origin: None,
             end: None,
             data: EntityData::default_for_kind(EntityKind::Person),
-            extra: HashMap::new(),
+            extra: BTreeMap::new(),
             relationships: vec![Relationship {
                 source_entity_id: 1,
                 target_entity_id: 2,
@@ -257,7 +272,7 @@ mod tests {
 
     #[test]
     fn extra_serialized_when_nonempty() {
-        let mut extra = HashMap::new();
+        let mut extra = BTreeMap::new();
         extra.insert("mana".to_string(), serde_json::json!(50));
 
         let entity = Entity {
@@ -274,4 +289,31 @@ mod tests {
         let json = serde_json::to_value(&entity).unwrap();
         assert_eq!(json["extra"]["mana"], 50);
     }
+
+    #[test]
+    fn extra_serialization_independent_of_insertion_order() {
+        let make = |keys: &[&str]| {
+            let mut entity = Entity {
+                id: 1,
+                kind: EntityKind::Settlement,
+                name: "Ashford".to_string(),
+                origin: None,
+                end: None,
+                data: EntityData::default_for_kind(EntityKind::Settlement),
+                extra: BTreeMap::new(),
+                relationships: vec![],
+            };
+            for key in keys {
+                entity
+                    .extra
+                    .insert(key.to_string(), serde_json::json!(key.len()));
+            }
+            serde_json::to_string(&entity).unwrap()
+        };
+
+        assert_eq!(
+            make(&["trade", "harvest", "levy", "omen"]),
+            make(&["omen", "levy", "harvest", "trade"])
+        );
+    }
 }
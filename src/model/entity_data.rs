@@ -1,16 +1,59 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use super::cultural_value::{CulturalValue, NamingStyle};
 use super::entity::EntityKind;
 use super::grievance::Grievance;
+use super::heraldry::Heraldry;
 use super::population::{NUM_BRACKETS, PopulationBreakdown};
 use super::secret::SecretDesire;
-use super::terrain::{Terrain, TerrainTag};
+use super::terrain::{Climate, Terrain, TerrainTag};
 use super::timestamp::SimTimestamp;
 use super::traits::Trait;
 
+/// Deserialize an id-keyed map. JSON stores the ids as string keys, and the
+/// buffering behind `EntityData`'s internal tag won't parse them back to `u64`;
+/// binary saves keep them as integers.
+fn id_keyed<'de, D, V>(deserializer: D) -> Result<BTreeMap<u64, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    Ok(BTreeMap::<IdKey, V>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(IdKey(id), v)| (id, v))
+        .collect())
+}
+
+/// A map key holding an id, as either a string or an integer.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct IdKey(u64);
+
+impl<'de> Deserialize<'de> for IdKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IdKeyVisitor;
+
+        impl serde::de::Visitor<'_> for IdKeyVisitor {
+            type Value = IdKey;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an id as an integer or a string")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<IdKey, E> {
+                Ok(IdKey(v))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<IdKey, E> {
+                v.parse().map(IdKey).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(IdKeyVisitor)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Sub-structs for promoted extras
 // ---------------------------------------------------------------------------
@@ -90,6 +133,9 @@ pub struct TradeRoute {
     pub distance: u32,
     #[serde(default)]
     pub resource: String,
+    /// How well patrolled the route is (0.0-1.0); damps bandit raids.
+    #[serde(default)]
+    pub security: f64,
 }
 
 /// Disease risk factors for a settlement.
@@ -123,6 +169,10 @@ pub enum WarGoal {
         target_settlements: Vec<u64>,
         motivation: ExpansionMotivation,
     },
+    /// Retake settlements holding the attacker's holy sites from a rival faith.
+    HolyWar {
+        target_settlements: Vec<u64>,
+    },
 }
 
 /// Motivation behind an expansion war.
@@ -139,6 +189,21 @@ pub enum ExpansionMotivation {
     DefensiveBuffer { threat_faction_id: u64 },
 }
 
+/// How far a faction's war goal was met by the peace that ended the war.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum WarGoalOutcome {
+    Achieved,
+    Partial,
+    Denied,
+}
+
+string_enum!(WarGoalOutcome {
+    Achieved => "achieved",
+    Partial => "partial",
+    Denied => "denied",
+});
+
 /// A tribute obligation owed to another faction.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TributeObligation {
@@ -170,6 +235,13 @@ fn default_diplomatic_trust() -> f64 {
     1.0
 }
 
+/// Value of a faction policy axis that neither favors nor shuns the policy.
+pub const POLICY_NEUTRAL: f64 = 0.5;
+
+fn default_policy() -> f64 {
+    POLICY_NEUTRAL
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(into = "String", try_from = "String")]
 pub enum Sex {
@@ -245,13 +317,25 @@ pub struct PersonData {
     #[serde(default)]
     pub prestige: f64,
     /// Personal vendettas against factions, keyed by faction ID.
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub grievances: BTreeMap<u64, Grievance>,
     /// Knowledge this person wants to keep secret, keyed by knowledge entity ID.
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub secrets: BTreeMap<u64, SecretDesire>,
     /// Succession claims on factions, keyed by faction ID.
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub claims: BTreeMap<u64, Claim>,
     /// When this person was widowed (spouse died).
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -260,11 +344,21 @@ pub struct PersonData {
     #[serde(default)]
     pub prestige_tier: u8,
     /// Generic loyalty toward other entities (target entity ID → loyalty score 0.0-1.0).
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub loyalty: BTreeMap<u64, f64>,
     /// Education level: 0.0 (illiterate) to 1.0 (highly educated).
     #[serde(default)]
     pub education: f64,
+    /// Physical health: 1.0 (hale) to 0.0 (failing). Declines with old age.
+    #[serde(default = "default_one")]
+    pub health: f64,
+    /// Dynasty (ruling house) this person belongs to, inherited at birth.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dynasty_id: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -280,7 +374,11 @@ pub struct SettlementData {
     pub treasury: f64,
     #[serde(default)]
     pub dominant_culture: Option<u64>,
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub culture_makeup: BTreeMap<u64, f64>,
     #[serde(default)]
     pub cultural_tension: f64,
@@ -290,6 +388,10 @@ pub struct SettlementData {
     pub plague_immunity: f64,
     #[serde(default)]
     pub fortification_level: u8,
+    /// State of the walls: 1.0 (intact) to 0.0 (breached). Worn down by siege
+    /// assaults and bombardment, repaired in peacetime.
+    #[serde(default = "default_one")]
+    pub wall_integrity: f64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active_siege: Option<ActiveSiege>,
     /// Settlement renown: 0.0 (forgotten hamlet) to 1.0 (legendary city). Decays toward baseline.
@@ -310,7 +412,11 @@ pub struct SettlementData {
     #[serde(default)]
     pub dominant_religion: Option<u64>,
     /// Share of each religion: religion_id → fraction (0.0-1.0, sums to ~1.0).
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub religion_makeup: BTreeMap<u64, f64>,
     /// Religious tension: 0.0 (homogeneous) to 1.0 (deeply divided).
     #[serde(default)]
@@ -318,9 +424,16 @@ pub struct SettlementData {
     /// Carrying capacity of this settlement.
     #[serde(default)]
     pub capacity: u32,
+    /// Extra carrying capacity from land improvements (irrigation, cleared
+    /// fields) paid for by the owning faction. Degrades in disasters unless maintained.
+    #[serde(default)]
+    pub land_improvement: f64,
     /// Happiness bonus from active trade routes.
     #[serde(default)]
     pub trade_happiness_bonus: f64,
+    /// Fading happiness bonus from recent festivals. Set by CultureSystem.
+    #[serde(default)]
+    pub festival_happiness_bonus: f64,
     /// Culture blending countdown timer (years remaining).
     #[serde(default)]
     pub blend_timer: u32,
@@ -339,6 +452,10 @@ pub struct SettlementData {
     /// Resource surplus/deficit by type.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub surplus: BTreeMap<ResourceType, f64>,
+    /// Local market price per unit of each resource known to this settlement's
+    /// market, from supply and demand across its trade partners.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub market_prices: BTreeMap<ResourceType, f64>,
     /// Seasonal modifiers (set by EnvironmentSystem each month).
     #[serde(default)]
     pub seasonal: SeasonalModifiers,
@@ -357,6 +474,20 @@ pub struct SettlementData {
     /// Whether this settlement is on the coast, a river, or other water body.
     #[serde(default)]
     pub is_coastal: bool,
+    /// Economic specialization derived from dominant production/trade. Recomputed yearly.
+    #[serde(default)]
+    pub economy_type: SettlementEconomyType,
+    /// Post-plague labor shortage: 0.0 (none) to 1.0. Raised by plague deaths, recovers over time.
+    #[serde(default)]
+    pub labor_shortage: f64,
+    /// Share of the population held as slaves or serfs: 0.0 (all free) to 1.0.
+    /// Raised by conquest, lowered by manumission and revolts.
+    #[serde(default)]
+    pub unfree_fraction: f64,
+    /// Post-siege rebuilding boom: 0.0 (none) to 1.0. Raised when a siege is lifted,
+    /// by how many civilians were lost; fades over a few years.
+    #[serde(default)]
+    pub rebuilding_boom: f64,
 }
 
 impl SettlementData {
@@ -366,6 +497,25 @@ impl SettlementData {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum SettlementEconomyType {
+    #[default]
+    Agricultural,
+    Mining,
+    Trade,
+    Craft,
+    Port,
+}
+
+string_enum!(SettlementEconomyType {
+    Agricultural => "agricultural",
+    Mining => "mining",
+    Trade => "trade",
+    Craft => "craft",
+    Port => "port",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ActiveSiege {
     pub attacker_army_id: u64,
@@ -421,6 +571,21 @@ string_enum!(SiegeOutcome {
     Abandoned => "abandoned",
 });
 
+/// What a spymaster's agents attempt against an enemy faction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum SpyOperation {
+    StealTreasury,
+    SabotageSupply,
+    InciteUnrest,
+}
+
+string_enum!(SpyOperation {
+    StealTreasury => "steal_treasury",
+    SabotageSupply => "sabotage_supply",
+    InciteUnrest => "incite_unrest",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ActiveDisaster {
     pub disaster_type: DisasterType,
@@ -463,9 +628,66 @@ string_enum!(GovernmentType {
     MercenaryCompany => "mercenary_company",
 });
 
+/// How a hereditary faction chooses among its late ruler's kin.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum SuccessionLaw {
+    /// Eldest child, then eldest sibling.
+    #[default]
+    Primogeniture,
+    /// Eldest living member of the family, whatever the branch.
+    Seniority,
+    /// The family elects one of its own.
+    Elective,
+    /// Youngest child, then youngest sibling.
+    Ultimogeniture,
+}
+
+string_enum!(SuccessionLaw {
+    Primogeniture => "primogeniture",
+    Seniority => "seniority",
+    Elective => "elective",
+    Ultimogeniture => "ultimogeniture",
+});
+
+impl SuccessionLaw {
+    pub const ALL: [SuccessionLaw; 4] = [
+        SuccessionLaw::Primogeniture,
+        SuccessionLaw::Seniority,
+        SuccessionLaw::Elective,
+        SuccessionLaw::Ultimogeniture,
+    ];
+}
+
+/// The arm a faction's armies are built around, shaping where they fight
+/// well, how they take walls, and how they march.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum MilitaryDoctrine {
+    /// Steady foot soldiers, at home on any ground.
+    #[default]
+    Infantry,
+    /// Horsemen: overwhelming in the open, hampered in rough country.
+    Cavalry,
+    /// Archers and skirmishers who fight best from cover and high ground.
+    Ranged,
+    /// Engineers and siege trains: slow and weak in the field, strong at walls.
+    Siege,
+}
+
+string_enum!(MilitaryDoctrine {
+    Infantry => "infantry",
+    Cavalry => "cavalry",
+    Ranged => "ranged",
+    Siege => "siege",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct FactionData {
     pub government_type: GovernmentType,
+    /// How hereditary succession picks among the late ruler's kin.
+    #[serde(default)]
+    pub succession_law: SuccessionLaw,
     #[serde(default)]
     pub stability: f64,
     #[serde(default)]
@@ -485,10 +707,18 @@ pub struct FactionData {
     #[serde(default)]
     pub primary_religion: Option<u64>,
     /// Institutional grudges against other factions, keyed by target faction ID.
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub grievances: BTreeMap<u64, Grievance>,
     /// Knowledge this faction wants to keep secret, keyed by knowledge entity ID.
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub secrets: BTreeMap<u64, SecretDesire>,
     /// When the current war started (None if not at war).
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -502,6 +732,9 @@ pub struct FactionData {
     /// Number of times this faction has betrayed allies.
     #[serde(default)]
     pub betrayal_count: u32,
+    /// Number of treaties this faction has broken by declaring war on the other party.
+    #[serde(default)]
+    pub treaties_broken: u32,
     /// When this faction last committed a betrayal.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_betrayal: Option<SimTimestamp>,
@@ -512,22 +745,49 @@ pub struct FactionData {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub succession_crisis_at: Option<SimTimestamp>,
     /// Tribute obligations owed to other factions, keyed by payee faction ID.
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub tributes: BTreeMap<u64, TributeObligation>,
     /// Cached prestige tier (0=Obscure, 1=Notable, 2=Renowned, 3=Illustrious, 4=Legendary).
     #[serde(default)]
     pub prestige_tier: u8,
     /// Cached trade partner route counts (partner faction ID → route count).
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub trade_partner_routes: BTreeMap<u64, u32>,
     /// Marriage alliance years (partner faction ID → year formed).
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub marriage_alliances: BTreeMap<u64, u32>,
     /// Active war goals against other factions, keyed by target faction ID.
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub war_goals: BTreeMap<u64, WarGoal>,
+    /// Active ceasefires (other faction ID → year the ceasefire expires).
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
+    pub ceasefire_until: BTreeMap<u64, u32>,
     /// Generic loyalty toward other entities (target entity ID → loyalty score 0.0-1.0).
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
     pub loyalty: BTreeMap<u64, f64>,
     /// Gold per strength per month (only meaningful for MercenaryCompany factions).
     #[serde(default)]
@@ -535,9 +795,30 @@ pub struct FactionData {
     /// Consecutive months the employer failed to pay mercenary wages.
     #[serde(default)]
     pub unpaid_months: u32,
+    /// Consecutive year starts this faction has found its treasury empty.
+    #[serde(default)]
+    pub insolvent_years: u32,
     /// Faction-wide literacy rate: population-weighted average of settlements.
     #[serde(default)]
     pub literacy_rate: f64,
+    /// Technologies this faction has developed or adopted.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub technologies: BTreeSet<Technology>,
+    /// Appetite for conquest (0.0-1.0). Favors territorial war goals and war.
+    #[serde(default = "default_policy")]
+    pub expansionism: f64,
+    /// Appetite for commerce (0.0-1.0). Favors economic war goals and new trade routes.
+    #[serde(default = "default_policy")]
+    pub mercantilism: f64,
+    /// Wariness of foreigners (0.0-1.0). Discourages alliances and foreign trade.
+    #[serde(default = "default_policy")]
+    pub isolationism: f64,
+    /// Colors and charge renderers draw this faction with.
+    #[serde(default)]
+    pub heraldry: Heraldry,
+    /// What the faction's armies are built around.
+    #[serde(default)]
+    pub doctrine: MilitaryDoctrine,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -545,6 +826,9 @@ pub struct RegionData {
     pub terrain: Terrain,
     #[serde(default)]
     pub terrain_tags: Vec<TerrainTag>,
+    /// Temperature/rainfall band, set at worldgen from latitude and terrain.
+    #[serde(default)]
+    pub climate: Climate,
     pub x: f64,
     pub y: f64,
     #[serde(default)]
@@ -577,6 +861,9 @@ pub struct ArmyData {
     /// Whether this army belongs to a mercenary company.
     #[serde(default)]
     pub is_mercenary: bool,
+    /// Siege engines built during the current siege (0.0-1.0).
+    #[serde(default)]
+    pub siege_equipment: f64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -724,8 +1011,31 @@ pub struct CultureData {
     pub naming_style: NamingStyle,
     /// 0.0-1.0: higher means harder to assimilate.
     pub resistance: f64,
+    /// 0.0-1.0: appetite for war; 0.5 is neither warlike nor peaceful.
+    #[serde(default = "default_militarism")]
+    pub militarism: f64,
+}
+
+fn default_militarism() -> f64 {
+    0.5
 }
 
+/// How a disease passes between people, which sets the season it thrives in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Transmission {
+    /// Coughs and close quarters; peaks in winter.
+    #[default]
+    Respiratory,
+    /// Fouled water; peaks in the warm season, worst in swamps.
+    Waterborne,
+}
+
+string_enum!(Transmission {
+    Respiratory => "respiratory",
+    Waterborne => "waterborne",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DiseaseData {
     /// 0.0-1.0: how easily it spreads between settlements.
@@ -736,6 +1046,8 @@ pub struct DiseaseData {
     pub duration_years: u32,
     /// Per-bracket mortality multipliers (indexes match population brackets).
     pub bracket_severity: [f64; NUM_BRACKETS],
+    #[serde(default)]
+    pub transmission: Transmission,
 }
 
 // ---------------------------------------------------------------------------
@@ -815,6 +1127,73 @@ pub struct DeityData {
     pub worship_strength: f64,
 }
 
+// ---------------------------------------------------------------------------
+// Dynasty data
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DynastyData {
+    /// The person whose rise to power founded the house.
+    pub founder_id: u64,
+    /// Factions ruled by a member of this dynasty, keyed by faction ID, with
+    /// when a member first took power there.
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "id_keyed"
+    )]
+    pub ruled_factions: BTreeMap<u64, SimTimestamp>,
+}
+
+// ---------------------------------------------------------------------------
+// Monument data
+// ---------------------------------------------------------------------------
+
+/// Ruins marking where a settlement once stood.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MonumentData {
+    /// The settlement these are the ruins of.
+    pub settlement_id: u64,
+    /// The settlement's name when it fell.
+    pub settlement_name: String,
+    /// Why the settlement ended (e.g. "population_collapse", "exodus").
+    pub cause: String,
+}
+
+// ---------------------------------------------------------------------------
+// Guild data
+// ---------------------------------------------------------------------------
+
+/// A merchant guild trading out of chapters in several settlements, which
+/// may belong to different factions.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GuildData {
+    /// Settlement hosting the guild's headquarters.
+    pub seat_settlement_id: u64,
+    /// Settlements with a guild chapter, the seat included.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub chapters: BTreeSet<u64>,
+    /// Wealth the guild has accumulated from its trade.
+    #[serde(default)]
+    pub treasury: f64,
+    /// Sway over the factions hosting its chapters (0.0-1.0).
+    #[serde(default)]
+    pub influence: f64,
+}
+
+// ---------------------------------------------------------------------------
+// Holy site data
+// ---------------------------------------------------------------------------
+
+/// A place sacred to a religion, lending legitimacy to whoever holds it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HolySiteData {
+    /// The religion that holds this place sacred.
+    pub religion_id: u64,
+    /// The settlement the site stands in; its owner controls the site.
+    pub settlement_id: u64,
+}
+
 // ---------------------------------------------------------------------------
 // Item data
 // ---------------------------------------------------------------------------
@@ -898,6 +1277,38 @@ string_enum!(KnowledgeCategory {
     Religious => "religious",
 });
 
+/// A technology a faction can develop and pass to its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Technology {
+    Ironworking,
+    Irrigation,
+    Masonry,
+    Medicine,
+    Navigation,
+    Writing,
+}
+
+impl Technology {
+    pub const ALL: [Technology; 6] = [
+        Technology::Ironworking,
+        Technology::Irrigation,
+        Technology::Masonry,
+        Technology::Medicine,
+        Technology::Navigation,
+        Technology::Writing,
+    ];
+}
+
+string_enum!(Technology {
+    Ironworking => "ironworking",
+    Irrigation => "irrigation",
+    Masonry => "masonry",
+    Medicine => "medicine",
+    Navigation => "navigation",
+    Writing => "writing",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct KnowledgeData {
     pub category: KnowledgeCategory,
@@ -1041,6 +1452,10 @@ pub enum EntityData {
     Item(ItemData),
     Religion(ReligionData),
     Deity(DeityData),
+    Dynasty(DynastyData),
+    Monument(MonumentData),
+    Guild(GuildData),
+    HolySite(HolySiteData),
     None,
 }
 
@@ -1082,6 +1497,8 @@ impl EntityData {
                 prestige_tier: 0,
                 loyalty: BTreeMap::new(),
                 education: 0.0,
+                health: 1.0,
+                dynasty_id: None,
             }),
             EntityKind::Settlement => EntityData::Settlement(SettlementData {
                 population: 0,
@@ -1097,6 +1514,7 @@ impl EntityData {
                 active_disease: None,
                 plague_immunity: 0.0,
                 fortification_level: 0,
+                wall_integrity: 1.0,
                 active_siege: None,
                 prestige: 0.0,
                 active_disaster: None,
@@ -1107,12 +1525,15 @@ impl EntityData {
                 religion_makeup: BTreeMap::new(),
                 religious_tension: 0.0,
                 capacity: 0,
+                land_improvement: 0.0,
                 trade_happiness_bonus: 0.0,
+                festival_happiness_bonus: 0.0,
                 blend_timer: 0,
                 last_prophecy_year: None,
                 trade_routes: Vec::new(),
                 production: BTreeMap::new(),
                 surplus: BTreeMap::new(),
+                market_prices: BTreeMap::new(),
                 seasonal: SeasonalModifiers::default(),
                 building_bonuses: BuildingBonuses::default(),
                 disease_risk: DiseaseRisk::default(),
@@ -1120,9 +1541,14 @@ impl EntityData {
                 trade_income: 0.0,
                 literacy_rate: 0.0,
                 is_coastal: false,
+                economy_type: SettlementEconomyType::default(),
+                labor_shortage: 0.0,
+                unfree_fraction: 0.0,
+                rebuilding_boom: 0.0,
             }),
             EntityKind::Faction => EntityData::Faction(FactionData {
                 government_type: GovernmentType::Chieftain,
+                succession_law: SuccessionLaw::default(),
                 stability: 0.5,
                 happiness: 0.5,
                 legitimacy: 0.5,
@@ -1137,6 +1563,7 @@ impl EntityData {
                 economic_motivation: 0.0,
                 diplomatic_trust: 1.0,
                 betrayal_count: 0,
+                treaties_broken: 0,
                 last_betrayal: None,
                 last_betrayed_by: None,
                 succession_crisis_at: None,
@@ -1144,20 +1571,30 @@ impl EntityData {
                 prestige_tier: 0,
                 trade_partner_routes: BTreeMap::new(),
                 marriage_alliances: BTreeMap::new(),
+                ceasefire_until: BTreeMap::new(),
                 war_goals: BTreeMap::new(),
                 loyalty: BTreeMap::new(),
                 mercenary_wage: 0.0,
                 unpaid_months: 0,
+                insolvent_years: 0,
                 literacy_rate: 0.0,
+                technologies: BTreeSet::new(),
+                expansionism: POLICY_NEUTRAL,
+                mercantilism: POLICY_NEUTRAL,
+                isolationism: POLICY_NEUTRAL,
+                heraldry: Heraldry::default(),
+                doctrine: MilitaryDoctrine::default(),
             }),
             EntityKind::Culture => EntityData::Culture(CultureData {
                 values: Vec::new(),
                 naming_style: NamingStyle::Nordic,
                 resistance: 0.5,
+                militarism: 0.5,
             }),
             EntityKind::Region => EntityData::Region(RegionData {
                 terrain: Terrain::Plains,
                 terrain_tags: Vec::new(),
+                climate: Climate::Temperate,
                 x: 0.0,
                 y: 0.0,
                 resources: Vec::new(),
@@ -1172,6 +1609,7 @@ impl EntityData {
                 months_campaigning: 0,
                 starting_strength: 0,
                 is_mercenary: false,
+                siege_equipment: 0.0,
             }),
             EntityKind::GeographicFeature => EntityData::GeographicFeature(GeographicFeatureData {
                 feature_type: FeatureType::Crater,
@@ -1204,6 +1642,7 @@ impl EntityData {
                 lethality: 0.3,
                 duration_years: 3,
                 bracket_severity: [1.0; NUM_BRACKETS],
+                transmission: Transmission::Respiratory,
             }),
             EntityKind::Knowledge => EntityData::Knowledge(KnowledgeData {
                 category: KnowledgeCategory::Battle,
@@ -1245,6 +1684,25 @@ impl EntityData {
                 domain: DeityDomain::Sky,
                 worship_strength: 0.5,
             }),
+            EntityKind::Dynasty => EntityData::Dynasty(DynastyData {
+                founder_id: 0,
+                ruled_factions: BTreeMap::new(),
+            }),
+            EntityKind::Monument => EntityData::Monument(MonumentData {
+                settlement_id: 0,
+                settlement_name: String::new(),
+                cause: String::new(),
+            }),
+            EntityKind::Guild => EntityData::Guild(GuildData {
+                seat_settlement_id: 0,
+                chapters: BTreeSet::new(),
+                treasury: 0.0,
+                influence: 0.0,
+            }),
+            EntityKind::HolySite => EntityData::HolySite(HolySiteData {
+                religion_id: 0,
+                settlement_id: 0,
+            }),
             EntityKind::Creature => EntityData::None,
         }
     }
@@ -1266,6 +1724,10 @@ impl EntityData {
         Item, ItemData, as_item, as_item_mut;
         Religion, ReligionData, as_religion, as_religion_mut;
         Deity, DeityData, as_deity, as_deity_mut;
+        Dynasty, DynastyData, as_dynasty, as_dynasty_mut;
+        Monument, MonumentData, as_monument, as_monument_mut;
+        Guild, GuildData, as_guild, as_guild_mut;
+        HolySite, HolySiteData, as_holy_site, as_holy_site_mut;
     }
 }
 
@@ -1317,9 +1779,22 @@ mod tests {
             prestige_tier: 0,
             loyalty: BTreeMap::new(),
             education: 0.0,
+            health: 1.0,
+            dynasty_id: None,
         });
         let json = serde_json::to_string(&data).unwrap();
         let back: EntityData = serde_json::from_str(&json).unwrap();
         assert_eq!(data, back);
     }
+
+    #[test]
+    fn serde_round_trip_id_keyed_maps() {
+        let mut data = EntityData::default_for_kind(EntityKind::Faction);
+        let fd = data.as_faction_mut().unwrap();
+        fd.trade_partner_routes.insert(114, 2);
+        fd.loyalty.insert(7, 0.25);
+        let json = serde_json::to_string(&data).unwrap();
+        let back: EntityData = serde_json::from_str(&json).unwrap();
+        assert_eq!(data, back);
+    }
 }
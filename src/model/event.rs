@@ -1,6 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+use super::action::ActionSource;
 use super::timestamp::SimTimestamp;
+use super::world::World;
+
+/// Significance added per participant, up to [`SIGNIFICANCE_MAX_PARTICIPANTS`].
+const SIGNIFICANCE_PER_PARTICIPANT: f64 = 0.1;
+const SIGNIFICANCE_MAX_PARTICIPANTS: usize = 5;
+/// Significance per natural-log unit of events citing this one as their cause.
+const SIGNIFICANCE_FAN_OUT_WEIGHT: f64 = 0.5;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(into = "String", try_from = "String")]
@@ -20,6 +28,7 @@ pub enum EventKind {
     Battle,
     Siege,
     Treaty,
+    Ceasefire,
     Migration,
     Exile,
     Abandoned,
@@ -34,37 +43,70 @@ pub enum EventKind {
     Renamed,
     CulturalShift,
     Rebellion,
+    SlaveRevolt,
     SuccessionCrisis,
     // Military/Conflict
     Muster,
     March,
     Retreat,
     Attrition,
+    Desertion,
     Assault,
+    SiegeEnginesBuilt,
+    WallsDamaged,
+    WallsRepaired,
     TreatyBroken,
+    Capture,
+    Ransom,
+    Execution,
+    Release,
     // Crime
     BanditFormed,
     Raid,
     // Politics/Diplomacy
     FailedCoup,
     Election,
+    Abdication,
+    Mourning,
     Rivalry,
+    Friendship,
     Betrayal,
     Defection,
     TrustRecovered,
+    FirstContact,
+    DynastyFounded,
+    Appointment,
+    EspionageOperation,
+    CapitalDesignated,
+    CapitalMoved,
+    CapitalRelocated,
     // Actions/Agency
     Assassination,
     Alliance,
+    AllianceDissolved,
     Intrigue,
     // Economy
     TradeEstablished,
+    GuildFounded,
+    GuildRelocated,
+    GuildBribe,
     TributeEnded,
     TributeDefaulted,
+    TributeRefused,
+    EmbargoImposed,
+    EmbargoLifted,
+    StrategicEmbargoImposed,
+    StrategicEmbargoLifted,
+    Bankruptcy,
+    ResourceDepleted,
+    ResourceDiscovered,
+    LandImproved,
     // Items
     Upgrade,
     // Knowledge/Secrets
     Propagation,
     Transcription,
+    KnowledgeAdopted,
     SecretRevealed,
     SecretLeaked,
     SecretCaptured,
@@ -73,6 +115,8 @@ pub enum EventKind {
     CultureBlended,
     Prophecy,
     Conversion,
+    HolySiteCaptured,
+    Festival,
     // World
     Genesis,
     // Ambition/Expansion
@@ -104,6 +148,7 @@ string_enum_open!(EventKind, "event kind", {
     Battle => "battle",
     Siege => "siege",
     Treaty => "treaty",
+    Ceasefire => "ceasefire",
     Migration => "migration",
     Exile => "exile",
     Abandoned => "abandoned",
@@ -118,30 +163,60 @@ string_enum_open!(EventKind, "event kind", {
     Renamed => "renamed",
     CulturalShift => "cultural_shift",
     Rebellion => "rebellion",
+    SlaveRevolt => "slave_revolt",
     SuccessionCrisis => "succession_crisis",
     Muster => "muster",
     March => "march",
     Retreat => "retreat",
     Attrition => "attrition",
+    Desertion => "desertion",
     Assault => "assault",
+    SiegeEnginesBuilt => "siege_engines_built",
+    WallsDamaged => "walls_damaged",
+    WallsRepaired => "walls_repaired",
     TreatyBroken => "treaty_broken",
+    Capture => "capture",
+    Ransom => "ransom",
+    Execution => "execution",
+    Release => "release",
     BanditFormed => "bandit_formed",
     Raid => "raid",
     FailedCoup => "failed_coup",
     Election => "election",
+    Abdication => "abdication",
+    Mourning => "mourning",
     Rivalry => "rivalry",
+    Friendship => "friendship",
     Betrayal => "betrayal",
     Defection => "defection",
     TrustRecovered => "trust_recovered",
+    FirstContact => "first_contact",
+    DynastyFounded => "dynasty_founded",
+    Appointment => "appointment",
+    EspionageOperation => "espionage_operation",
+    CapitalDesignated => "capital_designated",
+    CapitalMoved => "capital_moved",
+    CapitalRelocated => "capital_relocated",
     Assassination => "assassination",
     Alliance => "alliance",
+    AllianceDissolved => "alliance_dissolved",
     Intrigue => "intrigue",
     TradeEstablished => "trade_established",
+    GuildFounded => "guild_founded",
+    GuildRelocated => "guild_relocated",
+    GuildBribe => "guild_bribe",
     TributeEnded => "tribute_ended",
     TributeDefaulted => "tribute_defaulted",
+    TributeRefused => "tribute_refused",
+    EmbargoImposed => "embargo_imposed",
+    EmbargoLifted => "embargo_lifted",
+    StrategicEmbargoImposed => "strategic_embargo_imposed",
+    StrategicEmbargoLifted => "strategic_embargo_lifted",
+    Bankruptcy => "bankruptcy",
     Upgrade => "upgrade",
     Propagation => "propagation",
     Transcription => "transcription",
+    KnowledgeAdopted => "knowledge_adopted",
     SecretRevealed => "secret_revealed",
     SecretLeaked => "secret_leaked",
     SecretCaptured => "secret_captured",
@@ -149,6 +224,8 @@ string_enum_open!(EventKind, "event kind", {
     CultureBlended => "culture_blended",
     Prophecy => "prophecy",
     Conversion => "conversion",
+    HolySiteCaptured => "holy_site_captured",
+    Festival => "festival",
     Genesis => "genesis",
     ExpansionWar => "expansion_war",
     MercenaryFormed => "mercenary_formed",
@@ -156,9 +233,91 @@ string_enum_open!(EventKind, "event kind", {
     MercenaryDeserted => "mercenary_deserted",
     MercenarySwitched => "mercenary_switched",
     MercenaryDisbanded => "mercenary_disbanded",
+    ResourceDepleted => "resource_depleted",
+    ResourceDiscovered => "resource_discovered",
+    LandImproved => "land_improved",
     Bookkeeping => "bookkeeping",
 });
 
+impl EventKind {
+    /// Per-tick bookkeeping events (e.g. `Custom("economy_tick")`) that anchor
+    /// state changes but carry no story of their own. Other custom kinds
+    /// belong to plugins and count as real events.
+    pub fn is_bookkeeping(&self) -> bool {
+        match self {
+            EventKind::Bookkeeping => true,
+            EventKind::Custom(name) => name.ends_with("_tick"),
+            _ => false,
+        }
+    }
+
+    /// Routine events a condensed history can drop: bookkeeping plus troop
+    /// movements and knowledge copying.
+    pub fn is_minor(&self) -> bool {
+        self.is_bookkeeping()
+            || matches!(
+                self,
+                EventKind::March
+                    | EventKind::Attrition
+                    | EventKind::Propagation
+                    | EventKind::Transcription
+            )
+    }
+
+    /// How much an event of this kind matters on its own, before counting
+    /// who took part and what it led to: 1.0 for turning points, 0.0 for
+    /// bookkeeping.
+    pub fn significance_weight(&self) -> f64 {
+        if self.is_bookkeeping() {
+            return 0.0;
+        }
+        if self.is_minor() {
+            return 0.1;
+        }
+        match self {
+            EventKind::Genesis
+            | EventKind::WarDeclared
+            | EventKind::ExpansionWar
+            | EventKind::Conquest
+            | EventKind::Coup
+            | EventKind::FactionFormed
+            | EventKind::Rebellion
+            | EventKind::SlaveRevolt
+            | EventKind::SuccessionCrisis
+            | EventKind::Schism
+            | EventKind::Founded
+            | EventKind::Treaty
+            | EventKind::TreatyBroken
+            | EventKind::Betrayal => 1.0,
+            EventKind::SettlementFounded
+            | EventKind::Abandoned
+            | EventKind::Battle
+            | EventKind::Siege
+            | EventKind::Succession
+            | EventKind::Assassination
+            | EventKind::Execution
+            | EventKind::Alliance
+            | EventKind::AllianceDissolved
+            | EventKind::Disaster
+            | EventKind::CulturalShift
+            | EventKind::Conversion
+            | EventKind::Prophecy
+            | EventKind::SecretRevealed
+            | EventKind::TributeRefused
+            | EventKind::Bankruptcy
+            | EventKind::Exile
+            | EventKind::Ceasefire
+            | EventKind::FailedCoup
+            | EventKind::Abdication
+            | EventKind::Destruction
+            | EventKind::Discovery
+            | EventKind::GuildFounded
+            | EventKind::MercenarySwitched => 0.6,
+            _ => 0.3,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Event {
     pub id: u64,
@@ -169,6 +328,37 @@ pub struct Event {
     /// Setting-specific structured data for this event.
     #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
     pub data: serde_json::Value,
+    /// Who ordered the action this event records, for events created by the
+    /// action queue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<ActionSource>,
+}
+
+impl Event {
+    /// How pivotal this event was to the run: its kind's weight, plus a little
+    /// for each participant, plus its downstream fan-out — the number of
+    /// events that cite it as their cause, on a log scale.
+    ///
+    /// Scans the whole event log; use [`World::top_events`] to rank many.
+    pub fn significance(&self, world: &World) -> f64 {
+        let participants = world
+            .event_participants
+            .iter()
+            .filter(|p| p.event_id == self.id)
+            .count();
+        let fan_out = world
+            .events
+            .values()
+            .filter(|e| e.caused_by == Some(self.id))
+            .count();
+        self.significance_from(participants, fan_out)
+    }
+
+    pub(crate) fn significance_from(&self, participants: usize, fan_out: usize) -> f64 {
+        self.kind.significance_weight()
+            + SIGNIFICANCE_PER_PARTICIPANT * participants.min(SIGNIFICANCE_MAX_PARTICIPANTS) as f64
+            + SIGNIFICANCE_FAN_OUT_WEIGHT * (fan_out as f64).ln_1p()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -220,6 +410,7 @@ mod tests {
             description: "A child is born".to_string(),
             caused_by: None,
             data: serde_json::Value::Null,
+            source: None,
         };
 
         let json = serde_json::to_value(&event).unwrap();
@@ -243,6 +434,7 @@ mod tests {
             description: "Died in battle".to_string(),
             caused_by: Some(10),
             data: serde_json::Value::Null,
+            source: None,
         };
 
         let json = serde_json::to_value(&event).unwrap();
@@ -258,6 +450,7 @@ mod tests {
             description: "A magical birth".to_string(),
             caused_by: None,
             data: serde_json::json!({"omen": "comet", "intensity": 9}),
+            source: None,
         };
 
         let json = serde_json::to_value(&event).unwrap();
@@ -322,6 +515,7 @@ mod tests {
             EventKind::Battle,
             EventKind::Siege,
             EventKind::Treaty,
+            EventKind::Ceasefire,
             EventKind::Migration,
             EventKind::Exile,
             EventKind::Abandoned,
@@ -336,30 +530,60 @@ mod tests {
             EventKind::Renamed,
             EventKind::CulturalShift,
             EventKind::Rebellion,
+            EventKind::SlaveRevolt,
             EventKind::SuccessionCrisis,
             EventKind::Muster,
             EventKind::March,
             EventKind::Retreat,
             EventKind::Attrition,
+            EventKind::Desertion,
             EventKind::Assault,
+            EventKind::SiegeEnginesBuilt,
+            EventKind::WallsDamaged,
+            EventKind::WallsRepaired,
             EventKind::TreatyBroken,
+            EventKind::Capture,
+            EventKind::Ransom,
+            EventKind::Execution,
+            EventKind::Release,
             EventKind::BanditFormed,
             EventKind::Raid,
             EventKind::FailedCoup,
             EventKind::Election,
+            EventKind::Abdication,
+            EventKind::Mourning,
             EventKind::Rivalry,
+            EventKind::Friendship,
             EventKind::Betrayal,
             EventKind::Defection,
             EventKind::TrustRecovered,
+            EventKind::FirstContact,
+            EventKind::DynastyFounded,
+            EventKind::Appointment,
+            EventKind::EspionageOperation,
+            EventKind::CapitalDesignated,
+            EventKind::CapitalMoved,
+            EventKind::CapitalRelocated,
             EventKind::Assassination,
             EventKind::Alliance,
+            EventKind::AllianceDissolved,
             EventKind::Intrigue,
             EventKind::TradeEstablished,
+            EventKind::GuildFounded,
+            EventKind::GuildRelocated,
+            EventKind::GuildBribe,
             EventKind::TributeEnded,
             EventKind::TributeDefaulted,
+            EventKind::TributeRefused,
+            EventKind::EmbargoImposed,
+            EventKind::EmbargoLifted,
+            EventKind::StrategicEmbargoImposed,
+            EventKind::StrategicEmbargoLifted,
+            EventKind::Bankruptcy,
             EventKind::Upgrade,
             EventKind::Propagation,
             EventKind::Transcription,
+            EventKind::KnowledgeAdopted,
             EventKind::SecretRevealed,
             EventKind::SecretLeaked,
             EventKind::SecretCaptured,
@@ -367,6 +591,8 @@ mod tests {
             EventKind::CultureBlended,
             EventKind::Prophecy,
             EventKind::Conversion,
+            EventKind::HolySiteCaptured,
+            EventKind::Festival,
             EventKind::Genesis,
             EventKind::ExpansionWar,
             EventKind::MercenaryFormed,
@@ -374,6 +600,9 @@ mod tests {
             EventKind::MercenaryDeserted,
             EventKind::MercenarySwitched,
             EventKind::MercenaryDisbanded,
+            EventKind::ResourceDepleted,
+            EventKind::ResourceDiscovered,
+            EventKind::LandImproved,
         ] {
             let json = serde_json::to_string(&kind).unwrap();
             let back: EventKind = serde_json::from_str(&json).unwrap();
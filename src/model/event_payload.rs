@@ -0,0 +1,451 @@
+use serde::{Deserialize, Serialize};
+
+use super::entity_data::{
+    ResourceType, SiegeOutcome, SpyOperation, Technology, WarGoal, WarGoalOutcome,
+};
+use super::event::EventKind;
+
+/// Terms imposed on the losing side when a war ends.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeaceTerms {
+    pub decisive: bool,
+    pub territory_ceded: Vec<u64>,
+    pub reparations: f64,
+    pub tribute_per_year: f64,
+    pub tribute_duration_years: u32,
+}
+
+/// Machine-readable payload stored in `Event.data`.
+///
+/// Covers the event kinds the simulation records with structured metadata, so
+/// downstream consumers can parse participants and values without reading the
+/// prose `description`; other kinds leave `data` empty or setting-specific.
+/// Serialized with a `kind` tag matching the event kind string. Create events
+/// carrying a payload with
+/// [`World::add_structured_event`](super::World::add_structured_event).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EventPayload {
+    Birth {
+        person_id: u64,
+        #[serde(default)]
+        parent_ids: Vec<u64>,
+        #[serde(default)]
+        settlement_id: Option<u64>,
+    },
+    Death {
+        entity_id: u64,
+        #[serde(default)]
+        cause: String,
+    },
+    SettlementFounded {
+        settlement_id: u64,
+        #[serde(default)]
+        faction_id: Option<u64>,
+        region_id: u64,
+    },
+    FactionFormed {
+        faction_id: u64,
+        #[serde(default)]
+        parent_faction_id: Option<u64>,
+        #[serde(default)]
+        cause: String,
+    },
+    Union {
+        person_a: u64,
+        person_b: u64,
+    },
+    Dissolution {
+        entity_id: u64,
+    },
+    Joined {
+        entity_id: u64,
+        group_id: u64,
+    },
+    Left {
+        entity_id: u64,
+        group_id: u64,
+    },
+    Succession {
+        faction_id: u64,
+        leader_id: u64,
+        #[serde(default)]
+        previous_leader_id: Option<u64>,
+    },
+    Conquest {
+        settlement_id: u64,
+        old_faction_id: u64,
+        new_faction_id: u64,
+    },
+    WarDeclared {
+        attacker_id: u64,
+        defender_id: u64,
+        #[serde(flatten)]
+        war_goal: WarGoal,
+    },
+    Battle {
+        attacker_faction_id: u64,
+        defender_faction_id: u64,
+        winner_faction_id: u64,
+        loser_faction_id: u64,
+        region_id: u64,
+        winner_casualties: u32,
+        loser_casualties: u32,
+    },
+    Siege {
+        settlement_id: u64,
+        attacker_faction_id: u64,
+        defender_faction_id: u64,
+        #[serde(default)]
+        outcome: Option<SiegeOutcome>,
+    },
+    Treaty {
+        winner_id: u64,
+        loser_id: u64,
+        #[serde(flatten)]
+        terms: PeaceTerms,
+        /// How the winner's own war goal fared, if it went to war with one.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        winner_goal: Option<WarGoalOutcome>,
+        /// How the loser's war goal fared, if it went to war with one.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        loser_goal: Option<WarGoalOutcome>,
+    },
+    Ceasefire {
+        faction_a: u64,
+        faction_b: u64,
+        until_year: u32,
+    },
+    SlaveRevolt {
+        settlement_id: u64,
+        faction_id: u64,
+        freed: u32,
+    },
+    Desertion {
+        army_id: u64,
+        faction_id: u64,
+        deserters: u32,
+        homeward: u32,
+        outlaws: u32,
+    },
+    Assault {
+        army_id: u64,
+        settlement_id: u64,
+        succeeded: bool,
+        casualties: u32,
+    },
+    SiegeEnginesBuilt {
+        army_id: u64,
+        settlement_id: u64,
+    },
+    TreatyBroken {
+        breaker_id: u64,
+        victim_id: u64,
+    },
+    Capture {
+        person_id: u64,
+        captor_faction_id: u64,
+        from_faction_id: u64,
+    },
+    Ransom {
+        person_id: u64,
+        captor_faction_id: u64,
+        payer_faction_id: u64,
+        amount: f64,
+    },
+    Execution {
+        person_id: u64,
+        captor_faction_id: u64,
+    },
+    Release {
+        person_id: u64,
+        captor_faction_id: u64,
+    },
+    Abdication {
+        faction_id: u64,
+        leader_id: u64,
+        heir_id: u64,
+    },
+    Mourning {
+        faction_id: u64,
+        ruler_id: u64,
+        stature: f64,
+    },
+    FirstContact {
+        faction_a: u64,
+        faction_b: u64,
+    },
+    DynastyFounded {
+        founder_id: u64,
+        faction_id: u64,
+    },
+    Appointment {
+        person_id: u64,
+        settlement_id: u64,
+        faction_id: u64,
+    },
+    EspionageOperation {
+        spymaster_id: u64,
+        faction_id: u64,
+        target_faction_id: u64,
+        /// The army or settlement acted against; the faction itself for theft.
+        target_id: u64,
+        operation: SpyOperation,
+        detected: bool,
+    },
+    CapitalDesignated {
+        faction_id: u64,
+        settlement_id: u64,
+    },
+    CapitalMoved {
+        faction_id: u64,
+        leader_id: u64,
+        from_settlement_id: u64,
+        to_settlement_id: u64,
+    },
+    CapitalRelocated {
+        faction_id: u64,
+        lost_settlement_id: u64,
+        to_settlement_id: u64,
+    },
+    AllianceDissolved {
+        faction_a: u64,
+        faction_b: u64,
+        #[serde(default)]
+        cause: String,
+    },
+    GuildFounded {
+        settlement_id: u64,
+        faction_id: u64,
+    },
+    GuildRelocated {
+        guild_id: u64,
+        from_settlement_id: u64,
+        to_settlement_id: u64,
+    },
+    GuildBribe {
+        guild_id: u64,
+        faction_id: u64,
+        cost: f64,
+    },
+    TributeRefused {
+        payer_id: u64,
+        payee_id: u64,
+    },
+    EmbargoImposed {
+        faction_id: u64,
+        target_id: u64,
+    },
+    EmbargoLifted {
+        faction_id: u64,
+        target_id: u64,
+    },
+    StrategicEmbargoImposed {
+        faction_id: u64,
+        target_id: u64,
+    },
+    StrategicEmbargoLifted {
+        faction_id: u64,
+        target_id: u64,
+    },
+    Bankruptcy {
+        faction_id: u64,
+        insolvent_years: u32,
+    },
+    KnowledgeAdopted {
+        faction_id: u64,
+        source_faction_id: u64,
+        technology: Technology,
+    },
+    HolySiteCaptured {
+        settlement_id: u64,
+        religion_id: u64,
+        captor_id: u64,
+        loser_id: u64,
+        sites: u32,
+    },
+    Festival {
+        settlement_id: u64,
+        faction_id: u64,
+        #[serde(default)]
+        religion_id: Option<u64>,
+        happiness_bonus: f64,
+    },
+    ExpansionWar {
+        attacker_id: u64,
+        defender_id: u64,
+        #[serde(flatten)]
+        war_goal: WarGoal,
+    },
+    ResourceDepleted {
+        deposit_id: u64,
+        region_id: u64,
+        resource_type: ResourceType,
+    },
+    ResourceDiscovered {
+        deposit_id: u64,
+        region_id: u64,
+        resource_type: ResourceType,
+        quality: f64,
+    },
+    LandImproved {
+        settlement_id: u64,
+        faction_id: u64,
+        cost: f64,
+        land_improvement: f64,
+    },
+}
+
+impl EventPayload {
+    /// The `EventKind` this payload describes.
+    pub fn kind(&self) -> EventKind {
+        match self {
+            EventPayload::Birth { .. } => EventKind::Birth,
+            EventPayload::Death { .. } => EventKind::Death,
+            EventPayload::SettlementFounded { .. } => EventKind::SettlementFounded,
+            EventPayload::FactionFormed { .. } => EventKind::FactionFormed,
+            EventPayload::Union { .. } => EventKind::Union,
+            EventPayload::Dissolution { .. } => EventKind::Dissolution,
+            EventPayload::Joined { .. } => EventKind::Joined,
+            EventPayload::Left { .. } => EventKind::Left,
+            EventPayload::Succession { .. } => EventKind::Succession,
+            EventPayload::Conquest { .. } => EventKind::Conquest,
+            EventPayload::WarDeclared { .. } => EventKind::WarDeclared,
+            EventPayload::Battle { .. } => EventKind::Battle,
+            EventPayload::Siege { .. } => EventKind::Siege,
+            EventPayload::Treaty { .. } => EventKind::Treaty,
+            EventPayload::Ceasefire { .. } => EventKind::Ceasefire,
+            EventPayload::SlaveRevolt { .. } => EventKind::SlaveRevolt,
+            EventPayload::Desertion { .. } => EventKind::Desertion,
+            EventPayload::Assault { .. } => EventKind::Assault,
+            EventPayload::SiegeEnginesBuilt { .. } => EventKind::SiegeEnginesBuilt,
+            EventPayload::TreatyBroken { .. } => EventKind::TreatyBroken,
+            EventPayload::Capture { .. } => EventKind::Capture,
+            EventPayload::Ransom { .. } => EventKind::Ransom,
+            EventPayload::Execution { .. } => EventKind::Execution,
+            EventPayload::Release { .. } => EventKind::Release,
+            EventPayload::Abdication { .. } => EventKind::Abdication,
+            EventPayload::Mourning { .. } => EventKind::Mourning,
+            EventPayload::FirstContact { .. } => EventKind::FirstContact,
+            EventPayload::DynastyFounded { .. } => EventKind::DynastyFounded,
+            EventPayload::Appointment { .. } => EventKind::Appointment,
+            EventPayload::EspionageOperation { .. } => EventKind::EspionageOperation,
+            EventPayload::CapitalDesignated { .. } => EventKind::CapitalDesignated,
+            EventPayload::CapitalMoved { .. } => EventKind::CapitalMoved,
+            EventPayload::CapitalRelocated { .. } => EventKind::CapitalRelocated,
+            EventPayload::AllianceDissolved { .. } => EventKind::AllianceDissolved,
+            EventPayload::GuildFounded { .. } => EventKind::GuildFounded,
+            EventPayload::GuildRelocated { .. } => EventKind::GuildRelocated,
+            EventPayload::GuildBribe { .. } => EventKind::GuildBribe,
+            EventPayload::TributeRefused { .. } => EventKind::TributeRefused,
+            EventPayload::EmbargoImposed { .. } => EventKind::EmbargoImposed,
+            EventPayload::EmbargoLifted { .. } => EventKind::EmbargoLifted,
+            EventPayload::StrategicEmbargoImposed { .. } => EventKind::StrategicEmbargoImposed,
+            EventPayload::StrategicEmbargoLifted { .. } => EventKind::StrategicEmbargoLifted,
+            EventPayload::Bankruptcy { .. } => EventKind::Bankruptcy,
+            EventPayload::KnowledgeAdopted { .. } => EventKind::KnowledgeAdopted,
+            EventPayload::HolySiteCaptured { .. } => EventKind::HolySiteCaptured,
+            EventPayload::Festival { .. } => EventKind::Festival,
+            EventPayload::ExpansionWar { .. } => EventKind::ExpansionWar,
+            EventPayload::ResourceDepleted { .. } => EventKind::ResourceDepleted,
+            EventPayload::ResourceDiscovered { .. } => EventKind::ResourceDiscovered,
+            EventPayload::LandImproved { .. } => EventKind::LandImproved,
+        }
+    }
+
+    /// Parse a payload back out of an event's `data` field.
+    /// Returns `None` for events created without a structured payload.
+    pub fn from_data(data: &serde_json::Value) -> Option<Self> {
+        serde_json::from_value(data.clone()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_tag_matches_event_kind_string() {
+        let payload = EventPayload::Conquest {
+            settlement_id: 1,
+            old_faction_id: 2,
+            new_faction_id: 3,
+        };
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["kind"], payload.kind().as_str());
+    }
+
+    #[test]
+    fn battle_payload_round_trips() {
+        let payload = EventPayload::Battle {
+            attacker_faction_id: 10,
+            defender_faction_id: 20,
+            winner_faction_id: 10,
+            loser_faction_id: 20,
+            region_id: 30,
+            winner_casualties: 12,
+            loser_casualties: 40,
+        };
+        let json = serde_json::to_string(&payload).unwrap();
+        let back: EventPayload = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, payload);
+        let EventPayload::Battle {
+            winner_faction_id,
+            loser_faction_id,
+            region_id,
+            ..
+        } = back
+        else {
+            panic!("expected battle payload");
+        };
+        assert_eq!(
+            (winner_faction_id, loser_faction_id, region_id),
+            (10, 20, 30)
+        );
+    }
+
+    #[test]
+    fn treaty_payload_flattens_terms() {
+        let payload = EventPayload::Treaty {
+            winner_id: 5,
+            loser_id: 6,
+            terms: PeaceTerms {
+                decisive: true,
+                territory_ceded: vec![7],
+                reparations: 50.0,
+                tribute_per_year: 0.0,
+                tribute_duration_years: 0,
+            },
+            winner_goal: Some(WarGoalOutcome::Achieved),
+            loser_goal: Some(WarGoalOutcome::Denied),
+        };
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["decisive"], true);
+        assert_eq!(json["loser_goal"], "denied");
+        assert_eq!(json["territory_ceded"][0], 7);
+        let back = EventPayload::from_data(&json).unwrap();
+        assert_eq!(back, payload);
+    }
+
+    #[test]
+    fn war_declared_payload_keeps_goal_type() {
+        let payload = EventPayload::WarDeclared {
+            attacker_id: 1,
+            defender_id: 2,
+            war_goal: WarGoal::Punitive,
+        };
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["kind"], "war_declared");
+        assert_eq!(json["type"], "punitive");
+        assert_eq!(EventPayload::from_data(&json), Some(payload));
+    }
+
+    #[test]
+    fn from_data_returns_none_for_unstructured_data() {
+        assert_eq!(EventPayload::from_data(&serde_json::Value::Null), None);
+        assert_eq!(
+            EventPayload::from_data(&serde_json::json!({"omen": "comet"})),
+            None
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::event::Event;
+use super::timestamp::SimTimestamp;
+
+/// Event ids ordered by timestamp, then id, so history can be streamed in
+/// chronological order without sorting every event on each read.
+///
+/// Maintained by `World::add_event`, `World::add_caused_event`, and
+/// `World::prune_events`. Entries are candidates: callers should go through
+/// `World::events_chronological`, which skips any whose event is gone or
+/// whose timestamp was changed in place.
+#[derive(Debug, Clone, Default)]
+pub struct EventTimeline {
+    keys: BTreeSet<(SimTimestamp, u64)>,
+}
+
+impl EventTimeline {
+    /// Build a timeline from scratch over every event.
+    pub fn from_events(events: &BTreeMap<u64, Event>) -> Self {
+        Self {
+            keys: events.values().map(|e| (e.timestamp, e.id)).collect(),
+        }
+    }
+
+    pub fn insert(&mut self, timestamp: SimTimestamp, id: u64) {
+        self.keys.insert((timestamp, id));
+    }
+
+    /// Keep only the entries whose id satisfies `keep`.
+    pub fn retain(&mut self, mut keep: impl FnMut(u64) -> bool) {
+        self.keys.retain(|&(_, id)| keep(id));
+    }
+
+    /// Candidate `(timestamp, id)` pairs in chronological order.
+    pub fn keys(&self) -> impl Iterator<Item = (SimTimestamp, u64)> + '_ {
+        self.keys.iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Event, EventKind, EventRetention, SimTimestamp, World};
+    use crate::testutil;
+
+    fn sorted(world: &World) -> Vec<u64> {
+        let mut events: Vec<&Event> = world.events.values().collect();
+        events.sort_by_key(|e| (e.timestamp, e.id));
+        events.into_iter().map(|e| e.id).collect()
+    }
+
+    #[test]
+    fn chronological_matches_sorted_events() {
+        let world = testutil::generate_and_run(7, 30, testutil::core_systems());
+        let ids: Vec<u64> = world.events_chronological().map(|e| e.id).collect();
+        assert_eq!(ids, sorted(&world));
+        assert!(
+            world
+                .events_chronological()
+                .zip(world.events_chronological().skip(1))
+                .all(|(a, b)| a.timestamp <= b.timestamp)
+        );
+    }
+
+    #[test]
+    fn chronological_orders_out_of_order_insertions() {
+        let mut world = World::new();
+        let late = world.add_event(
+            EventKind::Custom("late".to_string()),
+            SimTimestamp::from_year(20),
+            "late".to_string(),
+        );
+        let early = world.add_event(
+            EventKind::Custom("early".to_string()),
+            SimTimestamp::from_year(5),
+            "early".to_string(),
+        );
+        let ids: Vec<u64> = world.events_chronological().map(|e| e.id).collect();
+        assert_eq!(ids, vec![early, late]);
+    }
+
+    #[test]
+    fn chronological_follows_pruning() {
+        let mut world = testutil::generate_and_run(7, 30, testutil::core_systems());
+        let removed = world.prune_events(EventRetention::MajorOnly);
+        assert!(removed > 0);
+        let ids: Vec<u64> = world.events_chronological().map(|e| e.id).collect();
+        assert_eq!(ids, sorted(&world));
+    }
+}
@@ -0,0 +1,303 @@
+//! Causal explanations of single events: what led to them and what followed.
+//!
+//! Two kinds of link connect events. The explicit one is `caused_by`. The
+//! implicit one is resolution: an event that ends a relationship between its
+//! own participants (a treaty ending a war) follows from the event that
+//! began it (the declaration), even when the simulation never recorded the
+//! first as the cause of the second.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use super::causal_graph::CAUSAL_DOT_MAX_DEPTH;
+use super::effect::StateChange;
+use super::event::{Event, EventKind};
+use super::relationship::RelationshipKind;
+use super::world::World;
+
+/// The essentials of an event in an [`Explanation`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExplainedEvent {
+    pub id: u64,
+    pub kind: EventKind,
+    pub year: u32,
+    pub description: String,
+}
+
+impl ExplainedEvent {
+    fn of(event: &Event) -> Self {
+        Self {
+            id: event.id,
+            kind: event.kind.clone(),
+            year: event.timestamp.year(),
+            description: event.description.clone(),
+        }
+    }
+}
+
+/// How an earlier event led to a later one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CausalLink {
+    /// The later event names the earlier as its cause.
+    Caused,
+    /// The later event ended a relationship of this kind that the earlier began.
+    Resolved(RelationshipKind),
+}
+
+/// One event on a causal chain and the link that ties it in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CausalStep {
+    pub event: ExplainedEvent,
+    /// For a cause, the later event it led to; for a consequence, the
+    /// explained event.
+    pub linked_to: u64,
+    pub link: CausalLink,
+}
+
+/// Why an event happened and what it immediately led to, as built by
+/// [`World::explain`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Explanation {
+    pub event: ExplainedEvent,
+    /// Everything that led to the event, nearest first, back to the roots.
+    pub causes: Vec<CausalStep>,
+    /// Events that directly follow from this one.
+    pub consequences: Vec<CausalStep>,
+}
+
+impl Explanation {
+    /// The earliest event on the chain of causes, if there are any.
+    pub fn root(&self) -> Option<&ExplainedEvent> {
+        self.causes
+            .iter()
+            .map(|step| &step.event)
+            .min_by_key(|e| (e.year, e.id))
+    }
+
+    /// Causes of the given kind, nearest first.
+    pub fn causes_of_kind<'a>(
+        &'a self,
+        kind: &'a EventKind,
+    ) -> impl Iterator<Item = &'a ExplainedEvent> + 'a {
+        self.causes
+            .iter()
+            .map(|step| &step.event)
+            .filter(move |e| e.kind == *kind)
+    }
+}
+
+impl World {
+    /// Explain `event_id`: walk its causes back to their roots, following up
+    /// to `CAUSAL_DOT_MAX_DEPTH` links, and list its immediate consequences.
+    /// Returns `None` for an unknown event.
+    pub fn explain(&self, event_id: u64) -> Option<Explanation> {
+        let event = self.events.get(&event_id)?;
+
+        let mut causes = Vec::new();
+        let mut seen = BTreeSet::from([event_id]);
+        let mut queue = VecDeque::from([(event_id, 0)]);
+        while let Some((id, depth)) = queue.pop_front() {
+            if depth == CAUSAL_DOT_MAX_DEPTH {
+                continue;
+            }
+            let direct = self.events[&id]
+                .caused_by
+                .filter(|c| self.events.contains_key(c))
+                .map(|c| (c, CausalLink::Caused));
+            for (cause, link) in direct.into_iter().chain(self.resolved_origins(id)) {
+                if !seen.insert(cause) {
+                    continue;
+                }
+                causes.push(CausalStep {
+                    event: ExplainedEvent::of(&self.events[&cause]),
+                    linked_to: id,
+                    link,
+                });
+                queue.push_back((cause, depth + 1));
+            }
+        }
+
+        let mut consequences: Vec<CausalStep> = self
+            .events
+            .values()
+            .filter(|e| e.caused_by == Some(event_id))
+            .map(|e| CausalStep {
+                event: ExplainedEvent::of(e),
+                linked_to: event_id,
+                link: CausalLink::Caused,
+            })
+            .collect();
+        for (later, kind) in self.resolutions(event_id) {
+            if consequences.iter().all(|step| step.event.id != later) {
+                consequences.push(CausalStep {
+                    event: ExplainedEvent::of(&self.events[&later]),
+                    linked_to: event_id,
+                    link: CausalLink::Resolved(kind),
+                });
+            }
+        }
+
+        Some(Explanation {
+            event: ExplainedEvent::of(event),
+            causes,
+            consequences,
+        })
+    }
+
+    fn participant_ids(&self, event_id: u64) -> BTreeSet<u64> {
+        self.event_participants
+            .iter()
+            .filter(|p| p.event_id == event_id)
+            .map(|p| p.entity_id)
+            .collect()
+    }
+
+    /// Events that began relationships between `event_id`'s participants
+    /// which `event_id` ended.
+    fn resolved_origins(&self, event_id: u64) -> Vec<(u64, CausalLink)> {
+        let participants = self.participant_ids(event_id);
+        let mut origins: Vec<(u64, CausalLink)> = Vec::new();
+        for ended in self
+            .event_effects
+            .iter()
+            .filter(|fx| fx.event_id == event_id)
+        {
+            let StateChange::RelationshipEnded {
+                target_entity_id,
+                kind,
+            } = &ended.effect
+            else {
+                continue;
+            };
+            if !participants.contains(&ended.entity_id) || !participants.contains(target_entity_id)
+            {
+                continue;
+            }
+            let start = self.event_effects.iter().rev().find(|fx| {
+                fx.event_id < event_id
+                    && fx.entity_id == ended.entity_id
+                    && matches!(
+                        &fx.effect,
+                        StateChange::RelationshipStarted { target_entity_id: t, kind: k }
+                            if t == target_entity_id && k == kind
+                    )
+            });
+            if let Some(start) = start
+                && self.events.contains_key(&start.event_id)
+                && origins.iter().all(|(id, _)| *id != start.event_id)
+            {
+                origins.push((start.event_id, CausalLink::Resolved(kind.clone())));
+            }
+        }
+        origins
+    }
+
+    /// Later events that ended relationships `event_id` began between its
+    /// participants, when both parties took part in the ending too.
+    fn resolutions(&self, event_id: u64) -> Vec<(u64, RelationshipKind)> {
+        let participants = self.participant_ids(event_id);
+        let mut found: Vec<(u64, RelationshipKind)> = Vec::new();
+        for started in self
+            .event_effects
+            .iter()
+            .filter(|fx| fx.event_id == event_id)
+        {
+            let StateChange::RelationshipStarted {
+                target_entity_id,
+                kind,
+            } = &started.effect
+            else {
+                continue;
+            };
+            if !participants.contains(&started.entity_id)
+                || !participants.contains(target_entity_id)
+            {
+                continue;
+            }
+            let end = self.event_effects.iter().find(|fx| {
+                fx.event_id > event_id
+                    && fx.entity_id == started.entity_id
+                    && matches!(
+                        &fx.effect,
+                        StateChange::RelationshipEnded { target_entity_id: t, kind: k }
+                            if t == target_entity_id && k == kind
+                    )
+            });
+            let Some(end) = end else {
+                continue;
+            };
+            let enders = self.participant_ids(end.event_id);
+            if enders.contains(&started.entity_id)
+                && enders.contains(target_entity_id)
+                && self.events.contains_key(&end.event_id)
+                && found.iter().all(|(id, _)| *id != end.event_id)
+            {
+                found.push((end.event_id, kind.clone()));
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{EntityData, EntityKind, ParticipantRole, SimTimestamp};
+
+    fn ts(year: u32) -> SimTimestamp {
+        SimTimestamp::from_year(year)
+    }
+
+    #[test]
+    fn treaty_is_explained_by_war_and_its_betrayal() {
+        let mut world = World::new();
+        let founding = world.add_event(EventKind::Custom("founding".into()), ts(1), "f".into());
+        let faction = |world: &mut World, name: &str| {
+            world.add_entity(
+                EntityKind::Faction,
+                name.to_string(),
+                Some(ts(1)),
+                EntityData::default_for_kind(EntityKind::Faction),
+                founding,
+            )
+        };
+        let a = faction(&mut world, "A");
+        let b = faction(&mut world, "B");
+
+        let betrayal = world.add_event(EventKind::Betrayal, ts(100), "betrayal".into());
+        let war = world.add_caused_event(EventKind::WarDeclared, ts(101), "war".into(), betrayal);
+        world.add_event_participant(war, a, ParticipantRole::Attacker);
+        world.add_event_participant(war, b, ParticipantRole::Defender);
+        world.add_relationship(a, b, RelationshipKind::AtWar, ts(101), war);
+        world.add_relationship(b, a, RelationshipKind::AtWar, ts(101), war);
+        let battle = world.add_caused_event(EventKind::Battle, ts(102), "battle".into(), war);
+
+        let treaty = world.add_event(EventKind::Treaty, ts(104), "treaty".into());
+        world.add_event_participant(treaty, a, ParticipantRole::Subject);
+        world.add_event_participant(treaty, b, ParticipantRole::Object);
+        world.end_relationship(a, b, RelationshipKind::AtWar, ts(104), treaty);
+        world.end_relationship(b, a, RelationshipKind::AtWar, ts(104), treaty);
+
+        let explanation = world.explain(treaty).unwrap();
+        let chain: Vec<u64> = explanation.causes.iter().map(|s| s.event.id).collect();
+        assert_eq!(chain, vec![war, betrayal]);
+        assert_eq!(
+            explanation.causes[0].link,
+            CausalLink::Resolved(RelationshipKind::AtWar)
+        );
+        assert_eq!(explanation.causes[1].link, CausalLink::Caused);
+        assert_eq!(explanation.causes[1].linked_to, war);
+        assert_eq!(explanation.root().map(|e| e.id), Some(betrayal));
+
+        let war_explained = world.explain(war).unwrap();
+        let followed: Vec<u64> = war_explained
+            .consequences
+            .iter()
+            .map(|s| s.event.id)
+            .collect();
+        assert_eq!(followed, vec![battle, treaty]);
+
+        assert!(world.explain(9999).is_none());
+    }
+}
@@ -16,4 +16,15 @@ pub struct Grievance {
     pub peak: f64,
     /// When this grievance was last updated.
     pub updated: SimTimestamp,
+    /// When the target last committed an atrocity against the holder. Set, it
+    /// turns the grievance into a blood feud that outlives generations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub atrocity_at: Option<SimTimestamp>,
+}
+
+impl Grievance {
+    /// Whether an atrocity lies behind this grievance.
+    pub fn is_blood_feud(&self) -> bool {
+        self.atrocity_at.is_some()
+    }
 }
@@ -0,0 +1,228 @@
+//! Faction heraldry: a stable visual identity for maps and other renderers.
+//!
+//! Arms are a pure function of the faction's id and culture, so the same
+//! world always draws the same way. Factions of one culture bear the same
+//! charge in their own colors; splinter factions keep their parent's primary
+//! color and charge and differ only in the secondary.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Tincture {
+    Argent,
+    Or,
+    Gules,
+    Azure,
+    Vert,
+    Purpure,
+    Sable,
+    Tenne,
+    Sanguine,
+}
+
+string_enum!(Tincture {
+    Argent => "argent",
+    Or => "or",
+    Gules => "gules",
+    Azure => "azure",
+    Vert => "vert",
+    Purpure => "purpure",
+    Sable => "sable",
+    Tenne => "tenne",
+    Sanguine => "sanguine",
+});
+
+impl Tincture {
+    pub const ALL: [Tincture; 9] = [
+        Tincture::Argent,
+        Tincture::Or,
+        Tincture::Gules,
+        Tincture::Azure,
+        Tincture::Vert,
+        Tincture::Purpure,
+        Tincture::Sable,
+        Tincture::Tenne,
+        Tincture::Sanguine,
+    ];
+
+    /// Display color as `#rrggbb`.
+    pub fn hex(self) -> &'static str {
+        match self {
+            Tincture::Argent => "#f0f0f0",
+            Tincture::Or => "#e8b828",
+            Tincture::Gules => "#c0202a",
+            Tincture::Azure => "#1f4fa8",
+            Tincture::Vert => "#1f7a3a",
+            Tincture::Purpure => "#7a2f8a",
+            Tincture::Sable => "#1a1a1a",
+            Tincture::Tenne => "#c06a1a",
+            Tincture::Sanguine => "#7a1a1a",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Charge {
+    Lion,
+    Eagle,
+    Stag,
+    Wolf,
+    Boar,
+    Tower,
+    Sword,
+    Crown,
+    Star,
+    Sun,
+    Moon,
+    Tree,
+    Ship,
+    Key,
+}
+
+string_enum!(Charge {
+    Lion => "lion",
+    Eagle => "eagle",
+    Stag => "stag",
+    Wolf => "wolf",
+    Boar => "boar",
+    Tower => "tower",
+    Sword => "sword",
+    Crown => "crown",
+    Star => "star",
+    Sun => "sun",
+    Moon => "moon",
+    Tree => "tree",
+    Ship => "ship",
+    Key => "key",
+});
+
+impl Charge {
+    pub const ALL: [Charge; 14] = [
+        Charge::Lion,
+        Charge::Eagle,
+        Charge::Stag,
+        Charge::Wolf,
+        Charge::Boar,
+        Charge::Tower,
+        Charge::Sword,
+        Charge::Crown,
+        Charge::Star,
+        Charge::Sun,
+        Charge::Moon,
+        Charge::Tree,
+        Charge::Ship,
+        Charge::Key,
+    ];
+}
+
+/// A faction's arms: a field of `primary` with a `charge` in `secondary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Heraldry {
+    pub primary: Tincture,
+    pub secondary: Tincture,
+    pub charge: Charge,
+}
+
+impl Default for Heraldry {
+    fn default() -> Self {
+        Self {
+            primary: Tincture::Argent,
+            secondary: Tincture::Sable,
+            charge: Charge::Star,
+        }
+    }
+}
+
+const PRIMARY_SALT: u64 = 0x5052_494d;
+const SECONDARY_SALT: u64 = 0x5345_434f;
+const CHARGE_SALT: u64 = 0x4348_4152;
+
+impl Heraldry {
+    /// Arms for a newly founded faction. The colors come from the faction
+    /// itself; the charge from its culture, when it has one.
+    pub fn for_faction(faction_id: u64, culture_id: Option<u64>) -> Self {
+        let primary = Tincture::ALL[pick(mix(faction_id, PRIMARY_SALT), Tincture::ALL.len())];
+        let secondary = other_tincture(mix(faction_id, SECONDARY_SALT), &[primary]);
+        let charge_seed = culture_id.unwrap_or(faction_id);
+        let charge = Charge::ALL[pick(mix(charge_seed, CHARGE_SALT), Charge::ALL.len())];
+        Self {
+            primary,
+            secondary,
+            charge,
+        }
+    }
+
+    /// Arms for a splinter of this faction: the parent's field and charge
+    /// with a secondary color of its own.
+    pub fn cadency(&self, child_id: u64) -> Self {
+        Self {
+            secondary: other_tincture(
+                mix(child_id, SECONDARY_SALT),
+                &[self.primary, self.secondary],
+            ),
+            ..*self
+        }
+    }
+}
+
+/// SplitMix64 finalizer over the id and a per-component salt.
+fn mix(id: u64, salt: u64) -> u64 {
+    let mut z = (id ^ salt).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+fn pick(hash: u64, len: usize) -> usize {
+    (hash % len as u64) as usize
+}
+
+/// A tincture other than any in `taken`.
+fn other_tincture(hash: u64, taken: &[Tincture]) -> Tincture {
+    let free: Vec<Tincture> = Tincture::ALL
+        .into_iter()
+        .filter(|t| !taken.contains(t))
+        .collect();
+    free[pick(hash, free.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_faction_and_culture_give_same_arms() {
+        assert_eq!(
+            Heraldry::for_faction(17, Some(4)),
+            Heraldry::for_faction(17, Some(4))
+        );
+    }
+
+    #[test]
+    fn colors_are_distinct() {
+        for id in 0..200 {
+            let arms = Heraldry::for_faction(id, None);
+            assert_ne!(arms.primary, arms.secondary);
+            let child = arms.cadency(id + 1000);
+            assert_ne!(child.primary, child.secondary);
+            assert_ne!(child.secondary, arms.secondary);
+        }
+    }
+
+    #[test]
+    fn culture_shares_charge() {
+        let a = Heraldry::for_faction(10, Some(3));
+        let b = Heraldry::for_faction(11, Some(3));
+        assert_eq!(a.charge, b.charge);
+    }
+
+    #[test]
+    fn heraldry_round_trips_through_json() {
+        let arms = Heraldry::for_faction(5, Some(9));
+        let json = serde_json::to_string(&arms).unwrap();
+        let back: Heraldry = serde_json::from_str(&json).unwrap();
+        assert_eq!(arms, back);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::entity::{Entity, EntityKind};
+
+/// Lookup from an entity kind to the ids of entities of that kind that are
+/// still alive.
+///
+/// Maintained by `World::add_entity` and `World::end_entity`. Code that ends
+/// an entity by setting `end` in place leaves it behind, so entries are
+/// candidates: callers should go through `World::living`, which re-checks
+/// each one.
+#[derive(Debug, Clone, Default)]
+pub struct LivingIndex {
+    ids: BTreeMap<EntityKind, BTreeSet<u64>>,
+}
+
+impl LivingIndex {
+    /// Build an index from scratch over every living entity.
+    pub fn from_entities(entities: &BTreeMap<u64, Entity>) -> Self {
+        let mut index = Self::default();
+        for entity in entities.values().filter(|e| e.is_alive()) {
+            index.insert(entity.kind, entity.id);
+        }
+        index
+    }
+
+    pub fn insert(&mut self, kind: EntityKind, id: u64) {
+        self.ids.entry(kind).or_default().insert(id);
+    }
+
+    pub fn remove(&mut self, kind: EntityKind, id: u64) {
+        if let Some(set) = self.ids.get_mut(&kind) {
+            set.remove(&id);
+        }
+    }
+
+    /// Candidate ids of the given kind in ascending order.
+    pub fn ids(&self, kind: EntityKind) -> impl Iterator<Item = u64> + '_ {
+        self.ids
+            .get(&kind)
+            .into_iter()
+            .flat_map(|set| set.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{EntityKind, SimTimestamp, World};
+    use crate::testutil;
+
+    fn scan(world: &World, kind: EntityKind) -> Vec<u64> {
+        world
+            .entities
+            .values()
+            .filter(|e| e.kind == kind && e.end.is_none())
+            .map(|e| e.id)
+            .collect()
+    }
+
+    #[test]
+    fn living_matches_manual_filter() {
+        let world = testutil::generate_and_run(7, 30, testutil::core_systems());
+        for kind in [
+            EntityKind::Person,
+            EntityKind::Settlement,
+            EntityKind::Faction,
+            EntityKind::Region,
+            EntityKind::Building,
+            EntityKind::Army,
+            EntityKind::Culture,
+        ] {
+            let ids: Vec<u64> = world.living_ids(kind).collect();
+            assert_eq!(ids, scan(&world, kind), "{kind:?}");
+            assert_eq!(world.count_living(kind), ids.len());
+            assert!(world.living(kind).all(|e| e.kind == kind));
+        }
+        assert!(world.count_living(EntityKind::Person) > 0);
+    }
+
+    #[test]
+    fn living_excludes_ended_entities() {
+        let mut world = testutil::generate_and_run(7, 10, testutil::core_systems());
+        let people: Vec<u64> = world.living_ids(EntityKind::Person).collect();
+        let (ended, ended_in_place) = (people[0], people[1]);
+        let before = people.len();
+
+        let ev = world.add_event(
+            crate::model::EventKind::Death,
+            world.current_time,
+            "died".to_string(),
+        );
+        world.end_entity(ended, world.current_time, ev);
+        // Ending without going through `end_entity` leaves a stale index entry
+        world.entities.get_mut(&ended_in_place).unwrap().end =
+            Some(SimTimestamp::from_year(world.current_time.year()));
+
+        let ids: Vec<u64> = world.living_ids(EntityKind::Person).collect();
+        assert!(!ids.contains(&ended));
+        assert!(!ids.contains(&ended_in_place));
+        assert_eq!(world.count_living(EntityKind::Person), before - 2);
+        assert_eq!(ids, scan(&world, EntityKind::Person));
+    }
+}
@@ -1,38 +1,58 @@
 #[macro_use]
 pub mod macros;
 pub mod action;
+pub mod causal_graph;
 pub mod cultural_value;
+pub mod describe;
+pub mod diff;
 pub mod effect;
 pub mod entity;
 pub mod entity_data;
 pub mod event;
+pub mod event_payload;
+pub mod event_timeline;
+pub mod explain;
 pub mod grievance;
+pub mod heraldry;
+pub mod living_index;
 pub mod population;
 pub mod relationship;
+pub mod relationship_index;
 pub mod secret;
+pub mod stats;
 pub mod terrain;
 pub mod timestamp;
 pub mod traits;
+pub mod validate;
 pub mod world;
 
 pub use action::{Action, ActionKind, ActionOutcome, ActionResult, ActionSource};
 pub use cultural_value::{CulturalValue, NamingStyle};
-pub use effect::{EventEffect, StateChange};
+pub use describe::{Describer, EnglishDescriber};
+pub use diff::WorldDiff;
+pub use effect::{Effect, EventEffect, FactionField, StateChange};
 pub use entity::{Entity, EntityKind};
 pub use entity_data::{
     ActiveDisaster, ActiveDisease, ActiveSiege, ArmyData, BuildingBonuses, BuildingData,
     BuildingType, Claim, CultureData, DerivationMethod, DisasterType, DiseaseData, DiseaseRisk,
-    EntityData, ExpansionMotivation, FactionData, FeatureType, GeographicFeatureData,
-    GovernmentType, ItemData, ItemType, KnowledgeCategory, KnowledgeData, ManifestationData,
-    Medium, PersonData, RegionData, ResourceDepositData, ResourceType, RiverData, Role,
-    SeasonalModifiers, SettlementData, Sex, SiegeOutcome, TradeRoute, TributeObligation, WarGoal,
+    DynastyData, EntityData, ExpansionMotivation, FactionData, FeatureType, GeographicFeatureData,
+    GovernmentType, GuildData, HolySiteData, ItemData, ItemType, KnowledgeCategory, KnowledgeData,
+    ManifestationData, Medium, MilitaryDoctrine, MonumentData, PersonData, RegionData,
+    ResourceDepositData, ResourceType, RiverData, Role, SeasonalModifiers, SettlementData,
+    SettlementEconomyType, Sex, SiegeOutcome, SpyOperation, SuccessionLaw, Technology, TradeRoute,
+    Transmission, TributeObligation, WarGoal, WarGoalOutcome,
 };
 pub use event::{Event, EventKind, EventParticipant, ParticipantRole};
+pub use event_payload::{EventPayload, PeaceTerms};
+pub use explain::{CausalLink, CausalStep, ExplainedEvent, Explanation};
 pub use grievance::Grievance;
+pub use heraldry::{Charge, Heraldry, Tincture};
 pub use population::PopulationBreakdown;
 pub use relationship::{Relationship, RelationshipKind};
 pub use secret::{SecretDesire, SecretMotivation};
-pub use terrain::{Terrain, TerrainTag};
+pub use stats::YearStats;
+pub use terrain::{Climate, Terrain, TerrainTag};
 pub use timestamp::SimTimestamp;
 pub use traits::Trait;
-pub use world::World;
+pub use validate::ConsistencyError;
+pub use world::{EventRetention, RngBackend, World};
@@ -195,6 +195,18 @@ impl PopulationBreakdown {
 
     /// Advance one year: apply deaths, age cohorts, then compute births.
     pub fn tick_year(&mut self, carrying_capacity: u32, rng: &mut dyn RngCore) {
+        self.tick_year_scaled(carrying_capacity, 1.0, 1.0, rng);
+    }
+
+    /// Like [`tick_year`](Self::tick_year), with every bracket's mortality and
+    /// the birth rate scaled by the given multipliers.
+    pub fn tick_year_scaled(
+        &mut self,
+        carrying_capacity: u32,
+        mortality_multiplier: f64,
+        fertility_multiplier: f64,
+        rng: &mut dyn RngCore,
+    ) {
         use rand::Rng;
 
         // Phase 1: Deaths
@@ -205,7 +217,8 @@ impl PopulationBreakdown {
                     counts[i] = 0;
                 } else {
                     let noise: f64 = rng.random_range(0.85..1.15);
-                    let deaths = (counts[i] as f64 * BRACKET_MORTALITY[i] * noise).round() as u32;
+                    let rate = (BRACKET_MORTALITY[i] * mortality_multiplier).min(1.0);
+                    let deaths = (counts[i] as f64 * rate * noise).round() as u32;
                     counts[i] = counts[i].saturating_sub(deaths);
                 }
             }
@@ -230,8 +243,12 @@ impl PopulationBreakdown {
         let total = self.total();
         let capacity_factor = (1.0 - total as f64 / carrying_capacity.max(1) as f64).max(0.0);
         let noise: f64 = rng.random_range(0.85..1.15);
-        let births =
-            (self.fertile_women() as f64 * BIRTH_RATE * capacity_factor * noise).round() as u32;
+        let births = (self.fertile_women() as f64
+            * BIRTH_RATE
+            * fertility_multiplier
+            * capacity_factor
+            * noise)
+            .round() as u32;
         let male_births = births / 2;
         let female_births = births - male_births;
         self.male[0] += male_births;
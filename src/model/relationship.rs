@@ -20,6 +20,22 @@ pub enum RelationshipKind {
     TradeRoute,
     HeldBy,
     HiredBy,
+    Rival,
+    Friend,
+    CaptiveOf,
+    /// Trade embargo imposed by the source faction on the target faction.
+    Embargo,
+    /// Contact between two factions, written in both directions.
+    KnownTo,
+    /// Governorship of the target settlement, held by the source person.
+    GovernorOf,
+    /// Command of the target faction's spy network, held by the source person.
+    SpymasterOf,
+    /// Interdiction of strategic goods bound for the target faction, imposed
+    /// by the source faction in wartime.
+    StrategicEmbargo,
+    /// Seat of government of the target faction, held by the source settlement.
+    CapitalOf,
     Custom(String),
 }
 
@@ -39,8 +55,34 @@ string_enum_open!(RelationshipKind, "relationship kind", {
     TradeRoute => "trade_route",
     HeldBy => "held_by",
     HiredBy => "hired_by",
+    Rival => "rival",
+    Friend => "friend",
+    CaptiveOf => "captive_of",
+    Embargo => "embargo",
+    KnownTo => "known_to",
+    GovernorOf => "governor_of",
+    SpymasterOf => "spymaster_of",
+    StrategicEmbargo => "strategic_embargo",
+    CapitalOf => "capital_of",
 });
 
+/// Custom relationship name for an active peace treaty (bidirectional).
+pub const TREATY_WITH: &str = "treaty_with";
+/// Custom relationship name from a tribute payer to its payee.
+pub const TRIBUTE_TO: &str = "tribute_to";
+
+impl RelationshipKind {
+    /// Peace treaty between two factions, written in both directions.
+    pub fn treaty_with() -> Self {
+        RelationshipKind::Custom(TREATY_WITH.to_string())
+    }
+
+    /// Tribute owed by the source faction to the target faction.
+    pub fn tribute_to() -> Self {
+        RelationshipKind::Custom(TRIBUTE_TO.to_string())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Relationship {
     pub source_entity_id: u64,
@@ -135,6 +177,15 @@ mod tests {
             RelationshipKind::TradeRoute,
             RelationshipKind::HeldBy,
             RelationshipKind::HiredBy,
+            RelationshipKind::Rival,
+            RelationshipKind::Friend,
+            RelationshipKind::CaptiveOf,
+            RelationshipKind::Embargo,
+            RelationshipKind::KnownTo,
+            RelationshipKind::GovernorOf,
+            RelationshipKind::SpymasterOf,
+            RelationshipKind::StrategicEmbargo,
+            RelationshipKind::CapitalOf,
         ] {
             let json = serde_json::to_string(&kind).unwrap();
             let back: RelationshipKind = serde_json::from_str(&json).unwrap();
@@ -0,0 +1,203 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use super::entity::Entity;
+use super::relationship::RelationshipKind;
+
+/// Reverse lookup from `(kind, target)` to the ids of entities holding an
+/// active relationship of that kind to that target.
+///
+/// Maintained by `World::add_relationship` and `World::end_relationship`.
+/// Code that ends a relationship by editing it in place leaves its source
+/// behind, so entries are candidates: callers should go through
+/// `World::active_sources`, which re-checks each one.
+#[derive(Debug, Clone, Default)]
+pub struct RelationshipIndex {
+    sources: HashMap<(RelationshipKind, u64), BTreeSet<u64>>,
+}
+
+impl RelationshipIndex {
+    /// Build an index from scratch over every active relationship.
+    pub fn from_entities(entities: &BTreeMap<u64, Entity>) -> Self {
+        let mut index = Self::default();
+        for entity in entities.values() {
+            for rel in entity.relationships.iter().filter(|r| r.is_active()) {
+                index.insert(rel.kind.clone(), rel.target_entity_id, entity.id);
+            }
+        }
+        index
+    }
+
+    pub fn insert(&mut self, kind: RelationshipKind, target: u64, source: u64) {
+        self.sources
+            .entry((kind, target))
+            .or_default()
+            .insert(source);
+    }
+
+    pub fn remove(&mut self, kind: RelationshipKind, target: u64, source: u64) {
+        let key = (kind, target);
+        if let Some(set) = self.sources.get_mut(&key) {
+            set.remove(&source);
+            if set.is_empty() {
+                self.sources.remove(&key);
+            }
+        }
+    }
+
+    /// Candidate source ids in ascending order.
+    pub fn sources(&self, kind: RelationshipKind, target: u64) -> impl Iterator<Item = u64> + '_ {
+        self.sources
+            .get(&(kind, target))
+            .into_iter()
+            .flat_map(|set| set.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crate::model::{EntityData, EntityKind, EventKind, SimTimestamp, World};
+    use crate::testutil;
+
+    use super::*;
+
+    fn scan(world: &World, kind: &RelationshipKind, target: u64) -> Vec<u64> {
+        world
+            .entities
+            .values()
+            .filter(|e| e.has_active_rel(kind.clone(), target))
+            .map(|e| e.id)
+            .collect()
+    }
+
+    fn indexed(world: &World, kind: &RelationshipKind, target: u64) -> Vec<u64> {
+        world
+            .active_sources(kind.clone(), target)
+            .map(|e| e.id)
+            .collect()
+    }
+
+    #[test]
+    fn end_relationship_drops_source() {
+        let mut world = World::new();
+        let ev = world.add_event(
+            EventKind::Custom("test".to_string()),
+            SimTimestamp::from_year(1),
+            "test".to_string(),
+        );
+        let faction = world.add_entity(
+            EntityKind::Faction,
+            "Realm".to_string(),
+            Some(SimTimestamp::from_year(1)),
+            EntityData::default_for_kind(EntityKind::Faction),
+            ev,
+        );
+        let person = world.add_entity(
+            EntityKind::Person,
+            "Ada".to_string(),
+            Some(SimTimestamp::from_year(1)),
+            EntityData::default_for_kind(EntityKind::Person),
+            ev,
+        );
+        let ts = SimTimestamp::from_year(1);
+        world.add_relationship(person, faction, RelationshipKind::MemberOf, ts, ev);
+        assert_eq!(
+            indexed(&world, &RelationshipKind::MemberOf, faction),
+            vec![person]
+        );
+
+        world.end_relationship(person, faction, RelationshipKind::MemberOf, ts, ev);
+        assert!(indexed(&world, &RelationshipKind::MemberOf, faction).is_empty());
+        assert_eq!(
+            world
+                .relationship_index
+                .sources(RelationshipKind::MemberOf, faction)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn scenario_index_matches_scan_after_simulation() {
+        let world = testutil::generate_and_run(42, 60, testutil::combat_systems());
+
+        let kinds = [
+            RelationshipKind::MemberOf,
+            RelationshipKind::LeaderOf,
+            RelationshipKind::LocatedIn,
+            RelationshipKind::AtWar,
+            RelationshipKind::TradeRoute,
+            RelationshipKind::treaty_with(),
+        ];
+        let rebuilt = RelationshipIndex::from_entities(&world.entities);
+        for &target in world.entities.keys() {
+            for kind in &kinds {
+                let expected = scan(&world, kind, target);
+                assert_eq!(
+                    indexed(&world, kind, target),
+                    expected,
+                    "{kind:?} sources of {target} diverge"
+                );
+                assert_eq!(
+                    rebuilt.sources(kind.clone(), target).collect::<Vec<_>>(),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn large_world_lookup_is_faster_than_scan() {
+        let mut world = World::new();
+        let ts = SimTimestamp::from_year(1);
+        let ev = world.add_event(
+            EventKind::Custom("test".to_string()),
+            ts,
+            "test".to_string(),
+        );
+        let factions: Vec<u64> = (0..200)
+            .map(|i| {
+                world.add_entity(
+                    EntityKind::Faction,
+                    format!("Faction {i}"),
+                    Some(ts),
+                    EntityData::default_for_kind(EntityKind::Faction),
+                    ev,
+                )
+            })
+            .collect();
+        for i in 0..20_000 {
+            let person = world.add_entity(
+                EntityKind::Person,
+                format!("Person {i}"),
+                Some(ts),
+                EntityData::default_for_kind(EntityKind::Person),
+                ev,
+            );
+            let faction = factions[i % factions.len()];
+            world.add_relationship(person, faction, RelationshipKind::MemberOf, ts, ev);
+        }
+
+        let start = Instant::now();
+        let via_index: Vec<Vec<u64>> = factions
+            .iter()
+            .map(|&f| indexed(&world, &RelationshipKind::MemberOf, f))
+            .collect();
+        let index_time = start.elapsed();
+
+        let start = Instant::now();
+        let via_scan: Vec<Vec<u64>> = factions
+            .iter()
+            .map(|&f| scan(&world, &RelationshipKind::MemberOf, f))
+            .collect();
+        let scan_time = start.elapsed();
+
+        assert_eq!(via_index, via_scan);
+        assert!(via_index.iter().all(|members| members.len() == 100));
+        assert!(
+            index_time < scan_time,
+            "index lookups took {index_time:?}, full scans {scan_time:?}"
+        );
+    }
+}
@@ -227,3 +227,67 @@ impl TerrainTag {
         }
     }
 }
+
+// ---------------------------------------------------------------------------
+// Climate
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Climate {
+    Tropical,
+    Arid,
+    #[default]
+    Temperate,
+    Boreal,
+}
+
+string_enum!(Climate {
+    Tropical => "tropical",
+    Arid => "arid",
+    Temperate => "temperate",
+    Boreal => "boreal",
+});
+
+impl Climate {
+    pub const ALL: [Climate; 4] = [
+        Climate::Tropical,
+        Climate::Arid,
+        Climate::Temperate,
+        Climate::Boreal,
+    ];
+
+    /// Derive a region's climate from its latitude (0.0 = equator edge of the
+    /// map, 1.0 = polar edge) and terrain. Deserts are dry and tundra is cold
+    /// wherever they lie; jungle is always tropical.
+    pub fn from_latitude(latitude: f64, terrain: Terrain) -> Self {
+        match terrain {
+            Terrain::Desert => Climate::Arid,
+            Terrain::Tundra => Climate::Boreal,
+            Terrain::Jungle => Climate::Tropical,
+            _ if latitude < 0.3 => Climate::Tropical,
+            _ if latitude < 0.7 => Climate::Temperate,
+            _ => Climate::Boreal,
+        }
+    }
+
+    /// Multiplier on annual food yield from temperature and rainfall.
+    pub fn food_yield(self) -> f64 {
+        match self {
+            Climate::Tropical => 1.0,
+            Climate::Arid => 0.7,
+            Climate::Temperate => 1.0,
+            Climate::Boreal => 0.8,
+        }
+    }
+
+    /// Multiplier on the base rate of disease outbreaks.
+    pub fn disease_rate(self) -> f64 {
+        match self {
+            Climate::Tropical => 1.5,
+            Climate::Arid => 0.7,
+            Climate::Temperate => 1.0,
+            Climate::Boreal => 0.8,
+        }
+    }
+}
@@ -14,6 +14,10 @@ pub const DAYS_PER_YEAR: u32 = 360;
 pub const HOURS_PER_DAY: u32 = 24;
 pub const MONTHS_PER_YEAR: u32 = 12;
 pub const DAYS_PER_MONTH: u32 = 30;
+/// Weeks start on days 1, 8, 15 and 22 of each month; the fourth week runs
+/// nine days to the month's end so weeks never straddle a month boundary.
+pub const WEEKS_PER_MONTH: u32 = 4;
+pub const DAYS_PER_WEEK: u32 = 7;
 
 /// Compact simulation timestamp encoding year/day/hour in a single `u32`.
 ///
@@ -133,6 +137,19 @@ impl SimTimestamp {
     pub fn is_month_start(self) -> bool {
         self.day_of_month() == 1 && self.hour() == 0
     }
+
+    /// Week within the month (1–4); the fourth week absorbs days 29–30.
+    pub fn week_of_month(self) -> u32 {
+        ((self.day_of_month() - 1) / DAYS_PER_WEEK + 1).min(WEEKS_PER_MONTH)
+    }
+
+    /// True at the start of a week (day-of-month 1, 8, 15 or 22, hour 0).
+    pub fn is_week_start(self) -> bool {
+        let dom = self.day_of_month();
+        self.hour() == 0
+            && (dom - 1).is_multiple_of(DAYS_PER_WEEK)
+            && dom <= DAYS_PER_WEEK * (WEEKS_PER_MONTH - 1) + 1
+    }
 }
 
 impl Default for SimTimestamp {
@@ -276,4 +293,20 @@ mod tests {
         assert!(!SimTimestamp::new(100, 2, 0).is_month_start()); // day 2 of month 1
         assert!(!SimTimestamp::new(100, 31, 5).is_month_start()); // month 2 day 1 but hour 5
     }
+
+    #[test]
+    fn weeks_align_to_months() {
+        let week_starts: Vec<u32> = (1..=DAYS_PER_YEAR)
+            .filter(|&d| SimTimestamp::new(100, d, 0).is_week_start())
+            .collect();
+        assert_eq!(week_starts.len() as u32, WEEKS_PER_MONTH * MONTHS_PER_YEAR);
+        assert_eq!(&week_starts[..5], &[1, 8, 15, 22, 31]);
+        assert!(!SimTimestamp::new(100, 29, 0).is_week_start()); // tail of week 4
+        assert!(!SimTimestamp::new(100, 8, 3).is_week_start());
+
+        assert_eq!(SimTimestamp::new(100, 1, 0).week_of_month(), 1);
+        assert_eq!(SimTimestamp::new(100, 14, 0).week_of_month(), 2);
+        assert_eq!(SimTimestamp::new(100, 30, 0).week_of_month(), 4);
+        assert_eq!(SimTimestamp::new(100, 38, 0).week_of_month(), 2);
+    }
 }
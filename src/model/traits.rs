@@ -1,4 +1,4 @@
-use rand::RngCore;
+use rand::{Rng, RngCore};
 use serde::{Deserialize, Serialize};
 
 use super::entity::Entity;
@@ -124,21 +124,77 @@ fn opposite_of(t: &Trait) -> Option<&'static Trait> {
     None
 }
 
+/// Chance a newborn inherits a core trait carried by exactly one parent.
+pub const TRAIT_INHERIT_ONE_PARENT: f64 = 0.35;
+
+/// Chance a newborn inherits a core trait carried by both parents.
+pub const TRAIT_INHERIT_BOTH_PARENTS: f64 = 0.65;
+
+/// Chance an inherited trait mutates into its opposite instead.
+pub const TRAIT_MUTATION_CHANCE: f64 = 0.10;
+
 /// Generate 2-4 traits for an NPC based on role, respecting opposing constraints.
 pub fn generate_traits(role: &Role, rng: &mut dyn RngCore) -> Vec<Trait> {
-    // Decide count: 2 (50%), 3 (35%), 4 (15%)
+    let count = roll_trait_count(rng);
+    let mut chosen: Vec<Trait> = Vec::with_capacity(count);
+    fill_traits(role, &mut chosen, count, rng);
+    chosen
+}
+
+/// Generate 2-4 traits for a newborn, inheriting from its parents' core traits
+/// before filling the rest by role. With no known parents this is the same as
+/// [`generate_traits`].
+pub fn inherit_traits(role: &Role, parents: &[&[Trait]], rng: &mut dyn RngCore) -> Vec<Trait> {
+    if parents.is_empty() {
+        return generate_traits(role, rng);
+    }
+
+    let count = roll_trait_count(rng);
+    let mut chosen: Vec<Trait> = Vec::with_capacity(count);
+
+    for t in &ALL_TRAITS {
+        if chosen.len() >= count {
+            break;
+        }
+        let chance = match parents.iter().filter(|p| p.contains(t)).count() {
+            0 => continue,
+            1 => TRAIT_INHERIT_ONE_PARENT,
+            _ => TRAIT_INHERIT_BOTH_PARENTS,
+        };
+        if !rng.random_bool(chance) {
+            continue;
+        }
+        let inherited = if rng.random_bool(TRAIT_MUTATION_CHANCE) {
+            opposite_of(t).unwrap_or(t)
+        } else {
+            t
+        };
+        if chosen.contains(inherited) || opposite_of(inherited).is_some_and(|o| chosen.contains(o))
+        {
+            continue;
+        }
+        chosen.push(inherited.clone());
+    }
+
+    fill_traits(role, &mut chosen, count, rng);
+    chosen
+}
+
+/// Decide trait count: 2 (50%), 3 (35%), 4 (15%)
+fn roll_trait_count(rng: &mut dyn RngCore) -> usize {
     let roll: u32 = rng.next_u32() % 100;
-    let count = if roll < 50 {
+    if roll < 50 {
         2
     } else if roll < 85 {
         3
     } else {
         4
-    };
-
-    let mut chosen: Vec<Trait> = Vec::with_capacity(count);
+    }
+}
 
-    for _ in 0..count {
+/// Add role-weighted traits to `chosen` until it holds `count` traits.
+fn fill_traits(role: &Role, chosen: &mut Vec<Trait>, count: usize, rng: &mut dyn RngCore) {
+    while chosen.len() < count {
         // Build candidate weights excluding already-chosen and their opposites
         let mut candidates: Vec<(&Trait, u32)> = Vec::new();
         for t in &ALL_TRAITS {
@@ -168,8 +224,6 @@ pub fn generate_traits(role: &Role, rng: &mut dyn RngCore) -> Vec<Trait> {
         }
         chosen.push(candidates[picked_idx].0.clone());
     }
-
-    chosen
 }
 
 /// Read an NPC's traits from its typed PersonData.
@@ -221,8 +275,10 @@ mod tests {
                 widowed_at: None,
                 loyalty: std::collections::BTreeMap::new(),
                 education: 0.0,
+                health: 1.0,
+                dynasty_id: None,
             }),
-            extra: HashMap::new(),
+            extra: std::collections::BTreeMap::new(),
             relationships: vec![],
         }
     }
@@ -324,6 +380,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn children_of_charismatic_parents_are_more_often_charismatic() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let parent: &[Trait] = &[Trait::Charismatic, Trait::Honorable];
+        let trials = 2000;
+        let inherited = (0..trials)
+            .filter(|_| {
+                inherit_traits(&Role::Common, &[parent, parent], &mut rng)
+                    .contains(&Trait::Charismatic)
+            })
+            .count();
+        let baseline = (0..trials)
+            .filter(|_| generate_traits(&Role::Common, &mut rng).contains(&Trait::Charismatic))
+            .count();
+        assert!(
+            inherited as f64 > baseline as f64 * 1.5,
+            "inherited {inherited} vs baseline {baseline}"
+        );
+    }
+
+    #[test]
+    fn inherited_traits_respect_count_and_opposites() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let father: &[Trait] = &[Trait::Aggressive, Trait::Ambitious, Trait::Ruthless];
+        let mother: &[Trait] = &[Trait::Cautious, Trait::Content, Trait::Honorable];
+        for _ in 0..200 {
+            let traits = inherit_traits(&Role::Warrior, &[father, mother], &mut rng);
+            assert!(traits.len() >= 2 && traits.len() <= 4, "got {traits:?}");
+            for (a, b) in &OPPOSING_PAIRS {
+                assert!(
+                    !(traits.contains(a) && traits.contains(b)),
+                    "got {traits:?}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn get_npc_traits_reads_properties() {
         let entity = make_person_with_traits(&[Trait::Ambitious, Trait::Cunning]);
@@ -349,7 +442,7 @@ mod tests {
             origin: None,
             end: None,
             data: EntityData::None,
-            extra: HashMap::new(),
+            extra: std::collections::BTreeMap::new(),
             relationships: vec![],
         };
         assert!(get_npc_traits(&entity).is_empty());
@@ -0,0 +1,222 @@
+use std::fmt;
+
+use super::entity::EntityKind;
+use super::relationship::RelationshipKind;
+use super::world::World;
+
+/// A broken invariant found by [`World::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsistencyError {
+    /// A living entity holds an active relationship to an entity that has
+    /// ended or doesn't exist.
+    DanglingRelationship {
+        source_id: u64,
+        target_id: u64,
+        kind: RelationshipKind,
+    },
+    /// A settlement belongs to more than one faction at once.
+    MultipleMemberships {
+        settlement_id: u64,
+        faction_ids: Vec<u64>,
+    },
+    /// A living person still leads a faction that has ended or doesn't exist.
+    LeaderOfDeadFaction { person_id: u64, faction_id: u64 },
+    /// A grievance is held against an entity that doesn't exist.
+    MissingGrievanceTarget { holder_id: u64, target_id: u64 },
+    /// A claim is staked on a faction that doesn't exist.
+    MissingClaimFaction { person_id: u64, faction_id: u64 },
+}
+
+impl fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingRelationship {
+                source_id,
+                target_id,
+                kind,
+            } => write!(
+                f,
+                "entity {source_id} has an active {kind} relationship to ended or missing entity {target_id}"
+            ),
+            Self::MultipleMemberships {
+                settlement_id,
+                faction_ids,
+            } => write!(
+                f,
+                "settlement {settlement_id} is an active member of factions {faction_ids:?}"
+            ),
+            Self::LeaderOfDeadFaction {
+                person_id,
+                faction_id,
+            } => write!(
+                f,
+                "person {person_id} leads ended or missing faction {faction_id}"
+            ),
+            Self::MissingGrievanceTarget {
+                holder_id,
+                target_id,
+            } => write!(
+                f,
+                "entity {holder_id} holds a grievance against missing entity {target_id}"
+            ),
+            Self::MissingClaimFaction {
+                person_id,
+                faction_id,
+            } => write!(f, "person {person_id} claims missing faction {faction_id}"),
+        }
+    }
+}
+
+impl World {
+    /// Check the world's cross-entity invariants and report every violation.
+    ///
+    /// Only living entities are checked: the dead keep whatever relationships
+    /// they had when they ended. Lineage (Parent/Child) outlives the people it
+    /// links, so it never dangles.
+    pub fn validate(&self) -> Vec<ConsistencyError> {
+        let mut errors = Vec::new();
+        let is_alive = |id: u64| self.entities.get(&id).is_some_and(|e| e.is_alive());
+
+        for entity in self.entities.values().filter(|e| e.is_alive()) {
+            for rel in entity.relationships.iter().filter(|r| r.end.is_none()) {
+                let target_id = rel.target_entity_id;
+                match rel.kind {
+                    RelationshipKind::Parent | RelationshipKind::Child => {}
+                    RelationshipKind::LeaderOf if !is_alive(target_id) => {
+                        errors.push(ConsistencyError::LeaderOfDeadFaction {
+                            person_id: entity.id,
+                            faction_id: target_id,
+                        });
+                    }
+                    _ if !is_alive(target_id) => {
+                        errors.push(ConsistencyError::DanglingRelationship {
+                            source_id: entity.id,
+                            target_id,
+                            kind: rel.kind.clone(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            if entity.kind == EntityKind::Settlement {
+                let faction_ids: Vec<u64> =
+                    entity.active_rels(RelationshipKind::MemberOf).collect();
+                if faction_ids.len() > 1 {
+                    errors.push(ConsistencyError::MultipleMemberships {
+                        settlement_id: entity.id,
+                        faction_ids,
+                    });
+                }
+            }
+
+            let grievances = entity
+                .data
+                .as_faction()
+                .map(|fd| &fd.grievances)
+                .or_else(|| entity.data.as_person().map(|pd| &pd.grievances));
+            for &target_id in grievances.into_iter().flat_map(|g| g.keys()) {
+                if !self.entities.contains_key(&target_id) {
+                    errors.push(ConsistencyError::MissingGrievanceTarget {
+                        holder_id: entity.id,
+                        target_id,
+                    });
+                }
+            }
+
+            if let Some(pd) = entity.data.as_person() {
+                for &faction_id in pd.claims.keys() {
+                    if !self.entities.contains_key(&faction_id) {
+                        errors.push(ConsistencyError::MissingClaimFaction {
+                            person_id: entity.id,
+                            faction_id,
+                        });
+                    }
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Claim, EventKind, Grievance, SimTimestamp};
+    use crate::scenario::Scenario;
+    use crate::testutil;
+
+    #[test]
+    fn simulated_world_is_consistent() {
+        let world = testutil::generate_and_run(7, 50, testutil::core_systems());
+        let errors = world.validate();
+        assert!(
+            errors.is_empty(),
+            "{} consistency errors, first: {}",
+            errors.len(),
+            errors[0]
+        );
+    }
+
+    #[test]
+    fn corrupted_world_reports_each_broken_invariant() {
+        let mut s = Scenario::at_year(100);
+        let r = s.add_region("R");
+        let fa = s.add_faction("Kingdom");
+        let fb = s.add_faction("Duchy");
+        let town = s.add_settlement("Town", fa, r);
+        let king = s.add_person("King", fa);
+        s.make_leader(king, fa);
+        let pretender = s.add_person("Pretender", fb);
+        let mut world = s.build();
+        let ev = world.add_event(EventKind::Dissolution, world.current_time, "gone".into());
+
+        // A second active membership for the town
+        world.add_relationship(town, fb, RelationshipKind::MemberOf, world.current_time, ev);
+        // The kingdom ends but nobody tells its king or its members
+        world.end_entity(fa, world.current_time, ev);
+        // Grudges and claims against things that never existed
+        world.faction_mut(fb).grievances.insert(
+            9999,
+            Grievance {
+                severity: 0.5,
+                sources: Vec::new(),
+                peak: 0.5,
+                updated: SimTimestamp::from_year(100),
+                atrocity_at: None,
+            },
+        );
+        world.person_mut(pretender).claims.insert(
+            8888,
+            Claim {
+                strength: 0.5,
+                source: "bloodline".into(),
+                year: 100,
+            },
+        );
+
+        let errors = world.validate();
+        assert!(errors.contains(&ConsistencyError::LeaderOfDeadFaction {
+            person_id: king,
+            faction_id: fa,
+        }));
+        assert!(errors.contains(&ConsistencyError::DanglingRelationship {
+            source_id: town,
+            target_id: fa,
+            kind: RelationshipKind::MemberOf,
+        }));
+        assert!(errors.contains(&ConsistencyError::MultipleMemberships {
+            settlement_id: town,
+            faction_ids: vec![fa, fb],
+        }));
+        assert!(errors.contains(&ConsistencyError::MissingGrievanceTarget {
+            holder_id: fb,
+            target_id: 9999,
+        }));
+        assert!(errors.contains(&ConsistencyError::MissingClaimFaction {
+            person_id: pretender,
+            faction_id: 8888,
+        }));
+    }
+}
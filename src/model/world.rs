@@ -1,17 +1,60 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet};
 
 use super::action::{Action, ActionResult};
 use super::effect::{EventEffect, StateChange};
 use super::entity::{Entity, EntityKind};
 use super::entity_data::EntityData;
 use super::event::{Event, EventKind, EventParticipant, ParticipantRole};
+use super::event_payload::EventPayload;
+use super::event_timeline::EventTimeline;
+use super::living_index::LivingIndex;
 use super::relationship::{Relationship, RelationshipKind};
+use super::relationship_index::RelationshipIndex;
 use super::timestamp::SimTimestamp;
 use crate::id::IdGenerator;
 
+/// Which events [`World::prune_events`] keeps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EventRetention {
+    /// Keep every event.
+    #[default]
+    All,
+    /// Drop minor events (see [`EventKind::is_minor`]) regardless of age.
+    MajorOnly,
+    /// Drop bookkeeping events once they are more than this many years old.
+    PruneTicksAfter(u32),
+}
+
+/// Which random number generator a simulation run draws from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RngBackend {
+    /// `rand`'s `SmallRng`. Fast, but its algorithm may change between `rand`
+    /// releases, so old seeds can stop reproducing after an upgrade.
+    #[default]
+    #[serde(rename = "small-rng")]
+    SmallRng,
+    /// A vendored PCG-XSH-RR 64/32 whose output is fixed for a given seed;
+    /// use it for seeds that need to reproduce indefinitely.
+    #[serde(rename = "pcg32-v1")]
+    Pcg32V1,
+}
+
+impl RngBackend {
+    /// Stable identifier, as recorded in saved worlds.
+    pub fn id(self) -> &'static str {
+        match self {
+            RngBackend::SmallRng => "small-rng",
+            RngBackend::Pcg32V1 => "pcg32-v1",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct World {
+    /// Keyed by id; iteration is always in ascending id order, which systems
+    /// rely on for seed-determinism when they draw from the RNG while looping.
     pub entities: BTreeMap<u64, Entity>,
+    /// Keyed by id, so iteration follows creation order.
     pub events: BTreeMap<u64, Event>,
     pub event_participants: Vec<EventParticipant>,
     pub event_effects: Vec<EventEffect>,
@@ -19,6 +62,17 @@ pub struct World {
     pub current_time: SimTimestamp,
     pub pending_actions: Vec<Action>,
     pub action_results: Vec<ActionResult>,
+    /// Reverse relationship lookup, kept in sync by `add_relationship` and
+    /// `end_relationship`.
+    pub relationship_index: RelationshipIndex,
+    /// Living entity ids by kind, kept in sync by `add_entity` and `end_entity`.
+    pub living_index: LivingIndex,
+    /// Event ids in chronological order, kept in sync by `add_event`,
+    /// `add_caused_event`, and `prune_events`.
+    pub event_timeline: EventTimeline,
+    /// The RNG stream the simulation has run on, set by the runner; `None`
+    /// until a run starts.
+    pub rng: Option<RngBackend>,
 }
 
 impl World {
@@ -32,7 +86,71 @@ impl World {
             current_time: SimTimestamp::from_year(0),
             pending_actions: Vec::new(),
             action_results: Vec::new(),
+            relationship_index: RelationshipIndex::default(),
+            living_index: LivingIndex::default(),
+            event_timeline: EventTimeline::default(),
+            rng: None,
+        }
+    }
+
+    /// Branch this world into an independent copy that shares its history up
+    /// to now. After the split the two worlds don't affect each other; the
+    /// fork takes its own id range from this world (see
+    /// [`IdGenerator::forked`]), so new entities and events in any two
+    /// branches never share an id.
+    pub fn fork(&mut self) -> World {
+        World {
+            entities: self.entities.clone(),
+            events: self.events.clone(),
+            event_participants: self.event_participants.clone(),
+            event_effects: self.event_effects.clone(),
+            id_gen: self.id_gen.forked(),
+            current_time: self.current_time,
+            pending_actions: self.pending_actions.clone(),
+            action_results: self.action_results.clone(),
+            relationship_index: self.relationship_index.clone(),
+            living_index: self.living_index.clone(),
+            event_timeline: self.event_timeline.clone(),
+            rng: self.rng,
+        }
+    }
+
+    /// Fingerprint of the recorded history: entities with their relationships,
+    /// events, participants, and effects. Worlds that simulated the same history
+    /// hash equal, however they got there (e.g. straight through or resumed from
+    /// a checkpoint).
+    pub fn content_hash(&self) -> u64 {
+        use std::hash::Hasher;
+
+        fn feed<T: serde::Serialize + ?Sized>(hasher: &mut std::hash::DefaultHasher, value: &T) {
+            let bytes = serde_json::to_vec(value).expect("world content serialization");
+            hasher.write(&bytes);
+        }
+
+        let mut hasher = std::hash::DefaultHasher::new();
+        for entity in self.entities.values() {
+            feed(&mut hasher, entity);
+            feed(&mut hasher, &entity.relationships);
+        }
+        for event in self.events.values() {
+            feed(&mut hasher, event);
         }
+        feed(&mut hasher, &self.event_participants);
+        feed(&mut hasher, &self.event_effects);
+        hasher.finish()
+    }
+
+    /// Entities with an active `kind` relationship to `target`, in ascending id
+    /// order, found through the relationship index instead of a full scan.
+    pub fn active_sources(
+        &self,
+        kind: RelationshipKind,
+        target: u64,
+    ) -> impl Iterator<Item = &Entity> + '_ {
+        self.relationship_index
+            .sources(kind.clone(), target)
+            .filter_map(|id| self.entities.get(&id))
+            .filter(move |e| e.has_active_rel(kind.clone(), target))
     }
 
     /// Get a reference to an entity by ID.
@@ -76,11 +194,44 @@ impl World {
             description,
             caused_by: None,
             data: serde_json::Value::Null,
+            source: None,
         };
+        self.event_timeline.insert(timestamp, id);
         self.events.insert(id, event);
         id
     }
 
+    /// Add an event whose kind and `data` come from a structured payload.
+    /// Returns the assigned ID. Participants are not added automatically.
+    pub fn add_structured_event(
+        &mut self,
+        payload: EventPayload,
+        timestamp: SimTimestamp,
+        description: String,
+    ) -> u64 {
+        let id = self.add_event(payload.kind(), timestamp, description);
+        self.events.get_mut(&id).unwrap().data =
+            serde_json::to_value(&payload).expect("event payload serializes");
+        id
+    }
+
+    /// Add a caused event whose kind and `data` come from a structured payload.
+    ///
+    /// # Panics
+    /// Panics if `caused_by` does not exist in the world.
+    pub fn add_caused_structured_event(
+        &mut self,
+        payload: EventPayload,
+        timestamp: SimTimestamp,
+        description: String,
+        caused_by: u64,
+    ) -> u64 {
+        let id = self.add_caused_event(payload.kind(), timestamp, description, caused_by);
+        self.events.get_mut(&id).unwrap().data =
+            serde_json::to_value(&payload).expect("event payload serializes");
+        id
+    }
+
     /// Add an event caused by another event.
     /// Returns the assigned ID.
     ///
@@ -109,7 +260,9 @@ impl World {
             description,
             caused_by: Some(caused_by),
             data: serde_json::Value::Null,
+            source: None,
         };
+        self.event_timeline.insert(timestamp, id);
         self.events.insert(id, event);
         id
     }
@@ -148,11 +301,40 @@ impl World {
         data: EntityData,
         event_id: u64,
     ) -> u64 {
+        let id = self.reserve_entity_id();
+        self.add_reserved_entity(id, kind, name, origin, data, event_id);
+        id
+    }
+
+    /// Take an id for an entity that will be added later with
+    /// [`World::add_reserved_entity`], so the event creating it can name it in
+    /// its payload.
+    pub fn reserve_entity_id(&mut self) -> u64 {
+        self.id_gen.next_id()
+    }
+
+    /// Add an entity under an id taken with [`World::reserve_entity_id`].
+    /// Records an `EntityCreated` effect linked to the given event.
+    ///
+    /// # Panics
+    /// Panics if `event_id` does not exist in the world or `id` is already taken.
+    pub fn add_reserved_entity(
+        &mut self,
+        id: u64,
+        kind: EntityKind,
+        name: String,
+        origin: Option<SimTimestamp>,
+        data: EntityData,
+        event_id: u64,
+    ) {
         assert!(
             self.events.contains_key(&event_id),
             "add_entity: event {event_id} not found"
         );
-        let id = self.id_gen.next_id();
+        assert!(
+            !self.entities.contains_key(&id),
+            "add_reserved_entity: entity {id} already exists"
+        );
         let entity = Entity {
             id,
             kind,
@@ -160,16 +342,16 @@ impl World {
             origin,
             end: None,
             data,
-            extra: HashMap::new(),
+            extra: BTreeMap::new(),
             relationships: Vec::new(),
         };
         self.entities.insert(id, entity);
+        self.living_index.insert(kind, id);
         self.event_effects.push(EventEffect {
             event_id,
             entity_id: id,
             effect: StateChange::EntityCreated { kind, name },
         });
-        id
     }
 
     /// Add a relationship between two entities (stored inline on the source entity).
@@ -217,6 +399,8 @@ impl World {
             start,
             end: None,
         });
+        self.relationship_index
+            .insert(kind.clone(), target_id, source_id);
         self.event_effects.push(EventEffect {
             event_id,
             entity_id: source_id,
@@ -273,6 +457,8 @@ impl World {
             "end_entity: end timestamp cannot be before origin timestamp"
         );
         entity.end = Some(timestamp);
+        let kind = entity.kind;
+        self.living_index.remove(kind, entity_id);
         self.event_effects.push(EventEffect {
             event_id,
             entity_id,
@@ -312,6 +498,8 @@ impl World {
             "end_relationship: end timestamp cannot be before start timestamp"
         );
         rel.end = Some(timestamp);
+        self.relationship_index
+            .remove(kind.clone(), target_id, source_id);
         self.event_effects.push(EventEffect {
             event_id,
             entity_id: source_id,
@@ -409,6 +597,110 @@ impl World {
         });
     }
 
+    /// Remove low-importance events under `policy`, along with their
+    /// participants and effects. Returns how many events were removed.
+    ///
+    /// An event is kept, whatever the policy, if a retained event descends from
+    /// it through `caused_by` or if a knowledge entity or tribute obligation
+    /// refers to it.
+    pub fn prune_events(&mut self, policy: EventRetention) -> usize {
+        let prunable = |event: &Event| match policy {
+            EventRetention::All => false,
+            EventRetention::MajorOnly => event.kind.is_minor(),
+            EventRetention::PruneTicksAfter(years) => {
+                event.kind.is_bookkeeping()
+                    && event.timestamp.year() + years < self.current_time.year()
+            }
+        };
+
+        let mut keep: BTreeSet<u64> = self
+            .events
+            .values()
+            .filter(|e| !prunable(e))
+            .map(|e| e.id)
+            .collect();
+        if keep.len() == self.events.len() {
+            return 0;
+        }
+        for entity in self.entities.values() {
+            match &entity.data {
+                EntityData::Knowledge(kd) => {
+                    keep.insert(kd.source_event_id);
+                }
+                EntityData::Faction(fd) => {
+                    keep.extend(fd.tributes.values().map(|t| t.treaty_event_id));
+                }
+                _ => {}
+            }
+        }
+
+        // Keep every ancestor of a retained event so causal chains stay whole
+        let mut stack: Vec<u64> = keep.iter().copied().collect();
+        while let Some(id) = stack.pop() {
+            if let Some(parent) = self.events.get(&id).and_then(|e| e.caused_by)
+                && keep.insert(parent)
+            {
+                stack.push(parent);
+            }
+        }
+
+        let before = self.events.len();
+        self.events.retain(|id, _| keep.contains(id));
+        self.event_timeline.retain(|id| keep.contains(&id));
+        self.event_participants
+            .retain(|p| keep.contains(&p.event_id));
+        self.event_effects.retain(|e| keep.contains(&e.event_id));
+        before - self.events.len()
+    }
+
+    /// Iterate all events in chronological order (by timestamp, then id),
+    /// streamed from the event timeline instead of sorting every event.
+    pub fn events_chronological(&self) -> impl Iterator<Item = &Event> {
+        self.event_timeline
+            .keys()
+            .filter_map(|(timestamp, id)| self.events.get(&id).filter(|e| e.timestamp == timestamp))
+    }
+
+    /// Events `entity_id` took part in (any role), in chronological order.
+    pub fn entity_timeline(&self, entity_id: u64) -> Vec<&Event> {
+        let mut events: Vec<&Event> = self
+            .event_participants
+            .iter()
+            .filter(|p| p.entity_id == entity_id)
+            .filter_map(|p| self.events.get(&p.event_id))
+            .collect();
+        events.sort_by_key(|e| (e.timestamp, e.id));
+        events.dedup_by_key(|e| e.id);
+        events
+    }
+
+    /// The `n` most significant events (see [`Event::significance`]), most
+    /// significant first; ties go to the earlier event.
+    pub fn top_events(&self, n: usize) -> Vec<&Event> {
+        let mut participants: BTreeMap<u64, usize> = BTreeMap::new();
+        for p in &self.event_participants {
+            *participants.entry(p.event_id).or_default() += 1;
+        }
+        let mut fan_out: BTreeMap<u64, usize> = BTreeMap::new();
+        for cause in self.events.values().filter_map(|e| e.caused_by) {
+            *fan_out.entry(cause).or_default() += 1;
+        }
+
+        let mut scored: Vec<(f64, &Event)> = self
+            .events
+            .values()
+            .map(|e| {
+                let score = e.significance_from(
+                    participants.get(&e.id).copied().unwrap_or(0),
+                    fan_out.get(&e.id).copied().unwrap_or(0),
+                );
+                (score, e)
+            })
+            .collect();
+        scored.sort_by(|(a, ea), (b, eb)| b.total_cmp(a).then(ea.id.cmp(&eb.id)));
+        scored.into_iter().take(n).map(|(_, e)| e).collect()
+    }
+
     /// Extract all inline relationships from entities as an iterator.
     /// Used at flush time to normalize relationships for JSONL output.
     pub fn collect_relationships(&self) -> impl Iterator<Item = &Relationship> {
@@ -441,29 +733,51 @@ impl World {
         })
     }
 
-    /// Iterate all living entities of a given kind.
-    pub fn living(&self, kind: EntityKind) -> impl Iterator<Item = (u64, &Entity)> {
-        self.entities
-            .iter()
-            .filter(move |(_, e)| e.kind == kind && e.is_alive())
-            .map(|(id, e)| (*id, e))
+    /// Factions holding an active peace treaty with `faction_id`.
+    pub fn active_treaty_partners(&self, faction_id: u64) -> Vec<u64> {
+        self.try_entity(faction_id)
+            .map(|e| e.active_rels(RelationshipKind::treaty_with()).collect())
+            .unwrap_or_default()
     }
 
-    /// Count living entities of the given kind.
-    pub fn count_living(&self, kind: &EntityKind) -> usize {
+    /// Factions currently paying tribute to `faction_id`.
+    pub fn tribute_payers(&self, faction_id: u64) -> Vec<u64> {
         self.entities
             .values()
-            .filter(|e| e.kind == *kind && e.end.is_none())
-            .count()
+            .filter(|e| e.has_active_rel(RelationshipKind::tribute_to(), faction_id))
+            .map(|e| e.id)
+            .collect()
+    }
+
+    /// Factions that `faction_id` currently pays tribute to.
+    pub fn tribute_payees(&self, faction_id: u64) -> Vec<u64> {
+        self.try_entity(faction_id)
+            .map(|e| e.active_rels(RelationshipKind::tribute_to()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Iterate all living entities of a given kind in ascending id order,
+    /// found through the living index instead of a full scan.
+    pub fn living(&self, kind: EntityKind) -> impl Iterator<Item = &Entity> {
+        self.living_index
+            .ids(kind)
+            .filter_map(|id| self.entities.get(&id))
+            .filter(|e| e.is_alive())
+    }
+
+    /// Ids of all living entities of a given kind, in ascending order.
+    pub fn living_ids(&self, kind: EntityKind) -> impl Iterator<Item = u64> + '_ {
+        self.living(kind).map(|e| e.id)
+    }
+
+    /// Count living entities of the given kind.
+    pub fn count_living(&self, kind: EntityKind) -> usize {
+        self.living(kind).count()
     }
 
     /// Collect IDs of living entities of the given kind.
     pub fn living_entities(&self, kind: &EntityKind) -> Vec<u64> {
-        self.entities
-            .values()
-            .filter(|e| e.kind == *kind && e.end.is_none())
-            .map(|e| e.id)
-            .collect()
+        self.living_ids(*kind).collect()
     }
 
     /// Non-panicking entity accessor.
@@ -489,8 +803,9 @@ impl World {
 
 use super::entity_data::{
     ArmyData, BuildingData, CultureData, DeityData, DiseaseData, FactionData,
-    GeographicFeatureData, ItemData, KnowledgeData, ManifestationData, PersonData, RegionData,
-    ReligionData, ResourceDepositData, RiverData, SettlementData,
+    GeographicFeatureData, GuildData, HolySiteData, ItemData, KnowledgeData, ManifestationData,
+    MonumentData, PersonData, RegionData, ReligionData, ResourceDepositData, RiverData,
+    SettlementData,
 };
 
 /// Generate typed data accessors on World.
@@ -535,6 +850,9 @@ world_data_accessors! {
     ItemData, item, item_mut, as_item, as_item_mut, "item";
     ReligionData, religion, religion_mut, as_religion, as_religion_mut, "religion";
     DeityData, deity, deity_mut, as_deity, as_deity_mut, "deity";
+    MonumentData, monument, monument_mut, as_monument, as_monument_mut, "monument";
+    GuildData, guild, guild_mut, as_guild, as_guild_mut, "guild";
+    HolySiteData, holy_site, holy_site_mut, as_holy_site, as_holy_site_mut, "holy site";
 }
 
 impl Default for World {
@@ -551,6 +869,134 @@ mod tests {
         SimTimestamp::from_year(year)
     }
 
+    #[test]
+    fn top_events_ranks_causal_roots_over_isolated_events() {
+        let mut world = World::new();
+        let tick = world.add_event(
+            EventKind::Custom("economy_tick".to_string()),
+            ts(100),
+            "tick".to_string(),
+        );
+        let isolated = world.add_event(EventKind::WarDeclared, ts(100), "a skirmish".to_string());
+        let root = world.add_event(EventKind::WarDeclared, ts(101), "the great war".to_string());
+        for i in 0..5 {
+            world.add_caused_event(EventKind::Battle, ts(102), format!("battle {i}"), root);
+        }
+
+        let significance = |id: u64| world.events[&id].significance(&world);
+        assert!(significance(root) > significance(isolated));
+        assert!(significance(isolated) > significance(tick));
+
+        let top: Vec<u64> = world
+            .top_events(world.events.len())
+            .iter()
+            .map(|e| e.id)
+            .collect();
+        assert_eq!(top.len(), world.events.len());
+        assert_eq!(top[0], root);
+        assert_eq!(top.last(), Some(&tick));
+        assert_eq!(world.top_events(2).len(), 2);
+    }
+
+    #[test]
+    fn add_structured_event_sets_kind_and_data() {
+        let mut world = World::new();
+        let payload = EventPayload::Battle {
+            attacker_faction_id: 1,
+            defender_faction_id: 2,
+            winner_faction_id: 2,
+            loser_faction_id: 1,
+            region_id: 3,
+            winner_casualties: 5,
+            loser_casualties: 9,
+        };
+        let ev = world.add_structured_event(payload.clone(), ts(100), "Battle".to_string());
+        let event = &world.events[&ev];
+        assert_eq!(event.kind, EventKind::Battle);
+        assert_eq!(EventPayload::from_data(&event.data), Some(payload));
+
+        let caused = world.add_caused_structured_event(
+            EventPayload::Conquest {
+                settlement_id: 4,
+                old_faction_id: 1,
+                new_faction_id: 2,
+            },
+            ts(100),
+            "Conquest".to_string(),
+            ev,
+        );
+        assert_eq!(world.events[&caused].kind, EventKind::Conquest);
+        assert_eq!(world.events[&caused].caused_by, Some(ev));
+    }
+
+    #[test]
+    fn prune_events_keeps_causes_of_retained_events() {
+        let mut world = World::new();
+        let old_tick = world.add_event(
+            EventKind::Custom("conflict_tick".to_string()),
+            ts(10),
+            "tick".to_string(),
+        );
+        let faction = world.add_entity(
+            EntityKind::Faction,
+            "Aldor".to_string(),
+            Some(ts(10)),
+            EntityData::default_for_kind(EntityKind::Faction),
+            old_tick,
+        );
+        let cause_tick = world.add_event(
+            EventKind::Custom("conflict_tick".to_string()),
+            ts(10),
+            "tick".to_string(),
+        );
+        let battle =
+            world.add_caused_event(EventKind::Battle, ts(10), "Battle".to_string(), cause_tick);
+        world.add_event_participant(battle, faction, ParticipantRole::Attacker);
+        world.add_event_participant(old_tick, faction, ParticipantRole::Subject);
+        world.record_change(faction, old_tick, "treasury", 1.0.into(), 2.0.into());
+        let recent_tick = world.add_event(
+            EventKind::Custom("conflict_tick".to_string()),
+            ts(48),
+            "tick".to_string(),
+        );
+        world.current_time = ts(50);
+
+        assert_eq!(world.prune_events(EventRetention::All), 0);
+        assert_eq!(world.prune_events(EventRetention::PruneTicksAfter(5)), 1);
+        assert!(!world.events.contains_key(&old_tick));
+        assert!(world.events.contains_key(&cause_tick));
+        assert!(world.events.contains_key(&battle));
+        assert!(world.events.contains_key(&recent_tick));
+        assert_eq!(world.event_participants.len(), 1);
+        assert!(world.event_effects.is_empty());
+
+        assert_eq!(world.prune_events(EventRetention::MajorOnly), 1);
+        assert!(!world.events.contains_key(&recent_tick));
+        assert!(world.events.contains_key(&cause_tick));
+    }
+
+    #[test]
+    fn prune_events_spares_plugin_custom_events() {
+        let mut world = World::new();
+        let tick = world.add_event(
+            EventKind::Custom("economy_tick".to_string()),
+            ts(10),
+            "tick".to_string(),
+        );
+        let spell = world.add_event(
+            EventKind::Custom("spell_cast".to_string()),
+            ts(10),
+            "A comet was called down".to_string(),
+        );
+        world.current_time = ts(50);
+
+        assert_eq!(world.prune_events(EventRetention::PruneTicksAfter(5)), 1);
+        assert!(!world.events.contains_key(&tick));
+        assert_eq!(world.prune_events(EventRetention::MajorOnly), 0);
+        assert!(world.events.contains_key(&spell));
+        assert!(world.events[&spell].kind.significance_weight() > 0.0);
+    }
+
     #[test]
     fn add_entity_assigns_unique_ids() {
         let mut world = World::new();
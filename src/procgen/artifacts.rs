@@ -88,6 +88,9 @@ mod tests {
             resources: vec!["iron".to_string(), "timber".to_string()],
             terrain: Some("plains".to_string()),
             terrain_tags: vec![],
+            economy_type: None,
+            literacy: 0.5,
+            heraldry: None,
             notable_events: vec![],
         }
     }
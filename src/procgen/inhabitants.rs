@@ -42,19 +42,31 @@ fn bracket_max_age(bracket: usize) -> u32 {
     }
 }
 
+/// How many inhabitants [`generate_inhabitants`] materializes for this
+/// settlement, without generating them.
+pub fn inhabitant_count(
+    snapshot: &super::SettlementSnapshot,
+    config: &super::ProcGenConfig,
+) -> usize {
+    let total = snapshot.population.total();
+    if total == 0 {
+        return 0;
+    }
+    ((total as f64 * config.inhabitant_sample_rate).ceil() as usize)
+        .max(1)
+        .min(config.max_inhabitants)
+}
+
 pub fn generate_inhabitants(
     snapshot: &super::SettlementSnapshot,
     config: &super::ProcGenConfig,
 ) -> Vec<GeneratedPerson> {
     let total = snapshot.population.total();
-    if total == 0 {
+    let target = inhabitant_count(snapshot, config);
+    if target == 0 {
         return Vec::new();
     }
 
-    let target = ((total as f64 * config.inhabitant_sample_rate).ceil() as usize)
-        .max(1)
-        .min(config.max_inhabitants);
-
     let mut rng = make_rng(snapshot.settlement_id, snapshot.year, "inhabitants");
     let bases = bracket_base_ages();
     let mut people = Vec::with_capacity(target);
@@ -83,10 +95,24 @@ pub fn generate_inhabitants(
         }
     }
 
-    // If rounding left us short, add remainder to largest bracket
-    if assigned < target && !slots.is_empty() {
-        let remaining = target - assigned;
-        slots[0].2 += remaining as u32;
+    // If rounding left us short, add remainder to the first bracket, or to
+    // the most populous group when every share rounded down to nothing
+    if assigned < target {
+        let remaining = (target - assigned) as u32;
+        if let Some(first) = slots.first_mut() {
+            first.2 += remaining;
+        } else if let Some((bracket, sex)) = (0..NUM_BRACKETS)
+            .flat_map(|b| {
+                [
+                    (b, Sex::Male, snapshot.population.male[b]),
+                    (b, Sex::Female, snapshot.population.female[b]),
+                ]
+            })
+            .max_by_key(|&(_, _, count)| count)
+            .map(|(b, sex, _)| (b, sex))
+        {
+            slots.push((bracket, sex, remaining));
+        }
     }
 
     for (bracket, sex, count) in slots {
@@ -101,12 +127,20 @@ pub fn generate_inhabitants(
                 "child"
             } else if bracket >= 5 {
                 if rng.random_bool(0.3) {
-                    select_occupation(&snapshot.resources, &mut rng)
+                    select_occupation(
+                        &snapshot.resources,
+                        snapshot.economy_type.as_deref(),
+                        &mut rng,
+                    )
                 } else {
                     "elder"
                 }
             } else {
-                select_occupation(&snapshot.resources, &mut rng)
+                select_occupation(
+                    &snapshot.resources,
+                    snapshot.economy_type.as_deref(),
+                    &mut rng,
+                )
             };
 
             people.push(GeneratedPerson {
@@ -141,6 +175,9 @@ mod tests {
             resources: vec!["iron".to_string(), "timber".to_string()],
             terrain: Some("plains".to_string()),
             terrain_tags: vec![],
+            economy_type: None,
+            literacy: 0.5,
+            heraldry: None,
             notable_events: vec![],
         }
     }
@@ -1,15 +1,19 @@
 pub mod artifacts;
 pub mod inhabitants;
+pub mod myths;
+pub mod obituaries;
 pub mod seed;
 pub mod tables;
 pub mod writings;
 
 pub use artifacts::GeneratedArtifact;
 pub use inhabitants::{GeneratedPerson, Sex};
+pub use myths::generate_origin_myth;
+pub use obituaries::{Obituary, generate_obituaries};
 pub use writings::{GeneratedWriting, WritingCategory};
 
-use crate::model::PopulationBreakdown;
 use crate::model::{EntityKind, ParticipantRole, RelationshipKind, World};
+use crate::model::{Heraldry, PopulationBreakdown};
 
 /// Minimal snapshot of settlement state needed for procedural generation.
 /// Can be constructed from a live World or from deserialized checkpoint data.
@@ -22,6 +26,12 @@ pub struct SettlementSnapshot {
     pub resources: Vec<String>,
     pub terrain: Option<String>,
     pub terrain_tags: Vec<String>,
+    /// Settlement economy specialization (e.g. "mining", "port").
+    pub economy_type: Option<String>,
+    /// Share of the population that can read and write (0.0-1.0).
+    pub literacy: f64,
+    /// Arms of the faction holding the settlement, if any.
+    pub heraldry: Option<Heraldry>,
     pub notable_events: Vec<EventSummary>,
 }
 
@@ -33,12 +43,53 @@ pub struct EventSummary {
     pub description: String,
 }
 
+/// How much procedural content to materialize per settlement. Coarser levels
+/// save memory on large worlds; finer ones give more people and objects to
+/// explore.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DetailLevel {
+    /// Few named inhabitants, generated only on demand.
+    Coarse,
+    #[default]
+    Standard,
+    /// Many inhabitants, artifacts and writings, all generated up front.
+    Fine,
+}
+
 /// Configuration for procedural generation.
 pub struct ProcGenConfig {
     pub max_inhabitants: usize,
     pub max_artifacts: usize,
     pub max_writings: usize,
     pub inhabitant_sample_rate: f64,
+    /// Leave [`SettlementDetails::inhabitants`] empty and let callers
+    /// generate them with [`inhabitants::generate_inhabitants`] when needed.
+    /// Ids of the other content are unchanged.
+    pub lazy_inhabitants: bool,
+}
+
+impl ProcGenConfig {
+    /// Limits for the given detail level. Output is deterministic at every
+    /// level.
+    pub fn with_detail(level: DetailLevel) -> Self {
+        match level {
+            DetailLevel::Coarse => Self {
+                max_inhabitants: 40,
+                max_artifacts: 10,
+                max_writings: 8,
+                inhabitant_sample_rate: 0.01,
+                lazy_inhabitants: true,
+            },
+            DetailLevel::Standard => Self::default(),
+            DetailLevel::Fine => Self {
+                max_inhabitants: 1000,
+                max_artifacts: 150,
+                max_writings: 60,
+                inhabitant_sample_rate: 0.2,
+                lazy_inhabitants: false,
+            },
+        }
+    }
 }
 
 impl Default for ProcGenConfig {
@@ -48,12 +99,14 @@ impl Default for ProcGenConfig {
             max_artifacts: 50,
             max_writings: 20,
             inhabitant_sample_rate: 0.05,
+            lazy_inhabitants: false,
         }
     }
 }
 
 /// All generated content for a settlement at a given point in time.
 pub struct SettlementDetails {
+    /// Empty when the config generates inhabitants lazily.
     pub inhabitants: Vec<GeneratedPerson>,
     pub artifacts: Vec<GeneratedArtifact>,
     pub writings: Vec<GeneratedWriting>,
@@ -81,6 +134,17 @@ pub fn snapshot_from_world(
         .map(|s| s.resources.iter().map(|r| r.to_string()).collect())
         .unwrap_or_default();
 
+    let economy_type = sd.map(|s| s.economy_type.to_string());
+    let literacy = sd.map(|s| s.literacy_rate).unwrap_or(0.0);
+
+    let heraldry = entity
+        .relationships
+        .iter()
+        .find(|r| r.kind == RelationshipKind::MemberOf && r.end.is_none())
+        .and_then(|r| world.entities.get(&r.target_entity_id))
+        .and_then(|f| f.data.as_faction())
+        .map(|fd| fd.heraldry);
+
     let founded_year = entity.origin.map(|t| t.year()).unwrap_or(0);
 
     // Follow LocatedIn to find region for terrain/tags
@@ -135,6 +199,9 @@ pub fn snapshot_from_world(
         resources,
         terrain,
         terrain_tags,
+        economy_type,
+        literacy,
+        heraldry,
         notable_events,
     })
 }
@@ -144,8 +211,12 @@ pub fn generate_settlement_details(
     snapshot: &SettlementSnapshot,
     config: &ProcGenConfig,
 ) -> SettlementDetails {
-    let inhabitants_result = inhabitants::generate_inhabitants(snapshot, config);
-    let inhabitant_count = inhabitants_result.len() as u64;
+    let inhabitants_result = if config.lazy_inhabitants {
+        Vec::new()
+    } else {
+        inhabitants::generate_inhabitants(snapshot, config)
+    };
+    let inhabitant_count = inhabitants::inhabitant_count(snapshot, config) as u64;
     let artifacts_result = artifacts::generate_artifacts(snapshot, config, inhabitant_count);
     let artifact_count = artifacts_result.len() as u64;
     let writings_result =
@@ -173,6 +244,9 @@ mod tests {
             resources: vec!["iron".to_string(), "grain".to_string()],
             terrain: Some("plains".to_string()),
             terrain_tags: vec![],
+            economy_type: None,
+            literacy: 0.5,
+            heraldry: None,
             notable_events: vec![],
         }
     }
@@ -218,5 +292,28 @@ mod tests {
         assert_eq!(config.max_artifacts, 50);
         assert_eq!(config.max_writings, 20);
         assert!((config.inhabitant_sample_rate - 0.05).abs() < f64::EPSILON);
+        assert!(!config.lazy_inhabitants);
+    }
+
+    #[test]
+    fn lazy_inhabitants_keep_other_ids_stable() {
+        let snapshot = test_snapshot();
+        let eager = generate_settlement_details(&snapshot, &ProcGenConfig::default());
+        let lazy = generate_settlement_details(
+            &snapshot,
+            &ProcGenConfig {
+                lazy_inhabitants: true,
+                ..ProcGenConfig::default()
+            },
+        );
+        assert!(lazy.inhabitants.is_empty());
+        let ids = |d: &SettlementDetails| -> Vec<u64> {
+            d.artifacts
+                .iter()
+                .map(|a| a.id)
+                .chain(d.writings.iter().map(|w| w.id))
+                .collect()
+        };
+        assert_eq!(ids(&eager), ids(&lazy));
     }
 }
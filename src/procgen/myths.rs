@@ -0,0 +1,227 @@
+use rand::Rng;
+
+use crate::model::{EntityKind, EventKind, EventPayload, RelationshipKind, StateChange, World};
+
+use super::seed::{PROCGEN_ID_BASE, make_seed};
+use super::tables::{
+    MYTH_CLOSINGS, MYTH_EMBELLISHMENTS, MYTH_FOUNDING_TEMPLATES, MYTH_LEADER_TEMPLATES,
+    MYTH_WAR_TEMPLATES,
+};
+use super::writings::{GeneratedWriting, WritingCategory};
+
+/// Chance the teller adds a marvel that never appears in the record.
+const EMBELLISHMENT_CHANCE: f64 = 0.5;
+
+/// What the event log remembers of a faction's beginnings.
+struct FoundingRecord {
+    faction: String,
+    founded_year: u32,
+    first_leader: Option<String>,
+    first_war: Option<(String, u32)>,
+}
+
+/// Retell a faction's recorded beginnings — its founding, first leader, and
+/// first war — as a stylized origin myth, optionally embellished.
+pub fn generate_origin_myth(
+    world: &World,
+    faction_id: u64,
+    rng: &mut dyn rand::RngCore,
+) -> GeneratedWriting {
+    let record = founding_record(world, faction_id);
+    let year_written = world.current_time.year();
+    let pick = |rng: &mut dyn rand::RngCore, table: &[&'static str]| -> &'static str {
+        table[rng.random_range(0..table.len())]
+    };
+
+    let mut sentences = vec![
+        pick(rng, MYTH_FOUNDING_TEMPLATES).replace("{year}", &record.founded_year.to_string()),
+    ];
+    if let Some(leader) = &record.first_leader {
+        sentences.push(pick(rng, MYTH_LEADER_TEMPLATES).replace("{leader}", leader));
+    }
+    if rng.random_bool(EMBELLISHMENT_CHANCE) {
+        sentences.push(pick(rng, MYTH_EMBELLISHMENTS).to_string());
+    }
+    if let Some((enemy, war_year)) = &record.first_war {
+        sentences.push(
+            pick(rng, MYTH_WAR_TEMPLATES)
+                .replace("{enemy}", enemy)
+                .replace("{war_year}", &war_year.to_string()),
+        );
+    }
+    sentences.push(pick(rng, MYTH_CLOSINGS).to_string());
+
+    let text = sentences
+        .iter()
+        .map(|s| format!("{}.", s.replace("{faction}", &record.faction)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    GeneratedWriting {
+        id: PROCGEN_ID_BASE | make_seed(faction_id, year_written, "origin_myth"),
+        category: WritingCategory::OriginMyth,
+        text,
+        year_written,
+    }
+}
+
+fn founding_record(world: &World, faction_id: u64) -> FoundingRecord {
+    let entity = world.entities.get(&faction_id);
+    let name_of = |id: u64| {
+        world
+            .entities
+            .get(&id)
+            .map(|e| e.name.clone())
+            .unwrap_or_else(|| "a forgotten people".to_string())
+    };
+
+    // The event that created the faction (FactionFormed for worldgen and splits)
+    let founded_year = world
+        .event_effects
+        .iter()
+        .find(|ef| {
+            ef.entity_id == faction_id && matches!(ef.effect, StateChange::EntityCreated { .. })
+        })
+        .and_then(|ef| world.events.get(&ef.event_id))
+        .map(|ev| ev.timestamp.year())
+        .or_else(|| entity.and_then(|e| e.origin).map(|t| t.year()))
+        .unwrap_or(0);
+
+    let first_leader = world
+        .entities
+        .values()
+        .filter(|e| e.kind == EntityKind::Person)
+        .flat_map(|e| {
+            e.relationships
+                .iter()
+                .filter(|r| {
+                    r.kind == RelationshipKind::LeaderOf && r.target_entity_id == faction_id
+                })
+                .map(move |r| (r.start, e.id))
+        })
+        .min()
+        .map(|(_, id)| name_of(id));
+
+    let first_war = world
+        .events
+        .values()
+        .filter(|ev| ev.kind == EventKind::WarDeclared)
+        .filter_map(|ev| match EventPayload::from_data(&ev.data)? {
+            EventPayload::WarDeclared {
+                attacker_id,
+                defender_id,
+                ..
+            } if attacker_id == faction_id => Some((ev.timestamp, ev.id, defender_id)),
+            EventPayload::WarDeclared {
+                attacker_id,
+                defender_id,
+                ..
+            } if defender_id == faction_id => Some((ev.timestamp, ev.id, attacker_id)),
+            _ => None,
+        })
+        .min()
+        .map(|(time, _, enemy)| (name_of(enemy), time.year()));
+
+    FoundingRecord {
+        faction: name_of(faction_id),
+        founded_year,
+        first_leader,
+        first_war,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{SimTimestamp, WarGoal};
+    use crate::scenario::Scenario;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    /// Aldor founded in year 37; its first king is later succeeded, and it
+    /// goes to war with Brell in year 52.
+    fn aldor_history() -> (World, u64) {
+        let mut s = Scenario::at_year(37);
+        let aldor = s.add_kingdom("Aldor");
+        let brell = s.add_rival_kingdom("Brell", aldor.region);
+        let mut world = s.build();
+
+        world.current_time = SimTimestamp::from_year(45);
+        let ev = world.add_event(
+            EventKind::Succession,
+            world.current_time,
+            "Aldor's first king died".to_string(),
+        );
+        world.end_relationship(
+            aldor.leader,
+            aldor.faction,
+            RelationshipKind::LeaderOf,
+            world.current_time,
+            ev,
+        );
+        world.end_entity(aldor.leader, world.current_time, ev);
+        let heir = world.add_entity(
+            EntityKind::Person,
+            "Second King".to_string(),
+            Some(SimTimestamp::from_year(20)),
+            crate::model::EntityData::default_for_kind(EntityKind::Person),
+            ev,
+        );
+        world.add_relationship(
+            heir,
+            aldor.faction,
+            RelationshipKind::LeaderOf,
+            world.current_time,
+            ev,
+        );
+
+        world.current_time = SimTimestamp::from_year(52);
+        world.add_structured_event(
+            EventPayload::WarDeclared {
+                attacker_id: brell.faction,
+                defender_id: aldor.faction,
+                war_goal: WarGoal::Punitive,
+            },
+            world.current_time,
+            "Brell declared war on Aldor".to_string(),
+        );
+        world.current_time = SimTimestamp::from_year(300);
+        (world, aldor.faction)
+    }
+
+    #[test]
+    fn myth_names_founding_year_and_first_leader() {
+        let (world, aldor) = aldor_history();
+        for seed in 0..10 {
+            let myth = generate_origin_myth(&world, aldor, &mut SmallRng::seed_from_u64(seed));
+            assert_eq!(myth.category, WritingCategory::OriginMyth);
+            assert_eq!(myth.year_written, 300);
+            assert!(
+                myth.text.contains("year 37"),
+                "missing founding year: {}",
+                myth.text
+            );
+            assert!(
+                myth.text.contains("Aldor Leader"),
+                "missing first leader: {}",
+                myth.text
+            );
+            assert!(
+                myth.text.contains("Brell"),
+                "missing first war: {}",
+                myth.text
+            );
+            assert!(!myth.text.contains('{'), "raw placeholder: {}", myth.text);
+            assert!(myth.id >= PROCGEN_ID_BASE);
+        }
+    }
+
+    #[test]
+    fn myth_is_deterministic_for_seed() {
+        let (world, aldor) = aldor_history();
+        let a = generate_origin_myth(&world, aldor, &mut SmallRng::seed_from_u64(7));
+        let b = generate_origin_myth(&world, aldor, &mut SmallRng::seed_from_u64(7));
+        assert_eq!(a.text, b.text);
+        assert_eq!(a.id, b.id);
+    }
+}
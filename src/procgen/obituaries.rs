@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+
+use crate::model::{EntityKind, Event, EventKind, ParticipantRole, RelationshipKind, SimTimestamp};
+use crate::model::{EventPayload, StateChange, World};
+
+/// A curated account of a notable person's life, drawn from their timeline.
+#[derive(Debug, Clone)]
+pub struct Obituary {
+    pub person_id: u64,
+    pub name: String,
+    pub birth_year: Option<u32>,
+    pub death_year: u32,
+    /// Summed significance weight of the events the life touched.
+    pub significance: f64,
+    /// Each faction led, with the years spent leading it.
+    pub reigns: Vec<(String, u32)>,
+    /// Enemies fought while leading, with the year each war was declared.
+    pub wars: Vec<(String, u32)>,
+    /// Buildings raised in the realm while leading.
+    pub buildings: Vec<String>,
+    pub heirs: Vec<String>,
+    pub text: String,
+}
+
+/// One stretch at the head of a faction.
+struct Reign {
+    faction_id: u64,
+    start: SimTimestamp,
+    end: SimTimestamp,
+}
+
+/// A building raised in a settlement, as the event log records it.
+struct Construction {
+    time: SimTimestamp,
+    settlement_id: u64,
+    buildings: Vec<String>,
+}
+
+/// Obituaries for every dead person whose life reaches `min_significance`,
+/// in order of death.
+pub fn generate_obituaries(world: &World, min_significance: f64) -> Vec<Obituary> {
+    let constructions = constructions(world);
+    let mut obituaries: Vec<Obituary> = world
+        .entities
+        .values()
+        .filter(|e| e.kind == EntityKind::Person)
+        .filter_map(|e| Some((e.id, e.end?)))
+        .filter_map(|(id, died)| obituary_for(world, &constructions, id, died))
+        .filter(|o| o.significance >= min_significance)
+        .collect();
+    obituaries.sort_by_key(|o| (o.death_year, o.person_id));
+    obituaries
+}
+
+fn constructions(world: &World) -> Vec<Construction> {
+    let mut by_event: BTreeMap<u64, Construction> = world
+        .events
+        .values()
+        .filter(|e| e.kind == EventKind::Construction)
+        .filter_map(|e| {
+            let settlement_id = world
+                .event_participants
+                .iter()
+                .find(|p| p.event_id == e.id && p.role == ParticipantRole::Subject)?
+                .entity_id;
+            Some((
+                e.id,
+                Construction {
+                    time: e.timestamp,
+                    settlement_id,
+                    buildings: Vec::new(),
+                },
+            ))
+        })
+        .collect();
+    for ef in &world.event_effects {
+        if let StateChange::EntityCreated {
+            kind: EntityKind::Building,
+            name,
+        } = &ef.effect
+            && let Some(c) = by_event.get_mut(&ef.event_id)
+        {
+            c.buildings.push(name.clone());
+        }
+    }
+    by_event.into_values().collect()
+}
+
+fn obituary_for(
+    world: &World,
+    constructions: &[Construction],
+    person_id: u64,
+    died: SimTimestamp,
+) -> Option<Obituary> {
+    let person = world.entities.get(&person_id)?;
+    let name_of = |id: u64| {
+        world
+            .entities
+            .get(&id)
+            .map(|e| e.name.clone())
+            .unwrap_or_else(|| "a forgotten foe".to_string())
+    };
+
+    let reigns: Vec<Reign> = person
+        .relationships
+        .iter()
+        .filter(|r| r.kind == RelationshipKind::LeaderOf)
+        .map(|r| Reign {
+            faction_id: r.target_entity_id,
+            start: r.start,
+            end: r.end.unwrap_or(died).min(died),
+        })
+        .collect();
+
+    let mut significance: f64 = world
+        .entity_timeline(person_id)
+        .iter()
+        .map(|e| e.kind.significance_weight())
+        .sum();
+
+    let mut wars = Vec::new();
+    let mut buildings = Vec::new();
+    for reign in &reigns {
+        for ev in world.entity_timeline(reign.faction_id) {
+            if ev.kind != EventKind::WarDeclared || !within(reign, ev.timestamp) {
+                continue;
+            }
+            if let Some(enemy) = war_enemy(world, ev, reign.faction_id) {
+                significance += ev.kind.significance_weight();
+                wars.push((name_of(enemy), ev.timestamp.year()));
+            }
+        }
+        for c in constructions {
+            if within(reign, c.time) && held_at(world, c.settlement_id, reign.faction_id, c.time) {
+                significance += EventKind::Construction.significance_weight();
+                buildings.extend(c.buildings.iter().cloned());
+            }
+        }
+    }
+
+    let heirs: Vec<String> = person
+        .relationships
+        .iter()
+        .filter(|r| r.kind == RelationshipKind::Parent)
+        .map(|r| name_of(r.target_entity_id))
+        .collect();
+
+    let reigns: Vec<(String, u32)> = reigns
+        .iter()
+        .map(|r| (name_of(r.faction_id), r.end.years_since(r.start)))
+        .collect();
+    let birth_year = person
+        .data
+        .as_person()
+        .map(|pd| pd.born)
+        .or(person.origin)
+        .map(|t| t.year());
+    let death_year = died.year();
+    let text = compose(
+        &person.name,
+        birth_year,
+        death_year,
+        &reigns,
+        &wars,
+        &buildings,
+        &heirs,
+    );
+
+    Some(Obituary {
+        person_id,
+        name: person.name.clone(),
+        birth_year,
+        death_year,
+        significance,
+        reigns,
+        wars,
+        buildings,
+        heirs,
+        text,
+    })
+}
+
+fn within(reign: &Reign, time: SimTimestamp) -> bool {
+    reign.start <= time && time <= reign.end
+}
+
+/// The other side of a war declaration involving `faction_id`, from its
+/// payload or, for hand-written history, its Attacker/Defender participants.
+fn war_enemy(world: &World, ev: &Event, faction_id: u64) -> Option<u64> {
+    if let Some(EventPayload::WarDeclared {
+        attacker_id,
+        defender_id,
+        ..
+    }) = EventPayload::from_data(&ev.data)
+    {
+        return match faction_id {
+            id if id == attacker_id => Some(defender_id),
+            id if id == defender_id => Some(attacker_id),
+            _ => None,
+        };
+    }
+    let mut sides = world.event_participants.iter().filter(|p| {
+        p.event_id == ev.id
+            && matches!(
+                p.role,
+                ParticipantRole::Attacker | ParticipantRole::Defender
+            )
+    });
+    if !sides.clone().any(|p| p.entity_id == faction_id) {
+        return None;
+    }
+    sides
+        .find(|p| p.entity_id != faction_id)
+        .map(|p| p.entity_id)
+}
+
+/// Whether `faction_id` held `settlement_id` at `time`.
+fn held_at(world: &World, settlement_id: u64, faction_id: u64, time: SimTimestamp) -> bool {
+    world.entities.get(&settlement_id).is_some_and(|e| {
+        e.relationships.iter().any(|r| {
+            r.kind == RelationshipKind::MemberOf
+                && r.target_entity_id == faction_id
+                && r.start <= time
+                && r.end.is_none_or(|end| time < end)
+        })
+    })
+}
+
+fn compose(
+    name: &str,
+    birth_year: Option<u32>,
+    death_year: u32,
+    reigns: &[(String, u32)],
+    wars: &[(String, u32)],
+    buildings: &[String],
+    heirs: &[String],
+) -> String {
+    let mut sentences = vec![match birth_year {
+        Some(born) => format!(
+            "{name} died in year {death_year}, aged {}",
+            death_year.saturating_sub(born)
+        ),
+        None => format!("{name} died in year {death_year}"),
+    }];
+    for (faction, years) in reigns {
+        let unit = if *years == 1 { "year" } else { "years" };
+        sentences.push(format!("{name} ruled {faction} for {years} {unit}"));
+    }
+    if !wars.is_empty() {
+        let wars: Vec<String> = wars
+            .iter()
+            .map(|(enemy, year)| format!("{enemy} in year {year}"))
+            .collect();
+        sentences.push(format!("Under that rule came war with {}", list(&wars)));
+    }
+    if !buildings.is_empty() {
+        sentences.push(format!("The reign raised {}", list(buildings)));
+    }
+    if !heirs.is_empty() {
+        sentences.push(format!("{name} is survived by {}", list(heirs)));
+    }
+    sentences
+        .iter()
+        .map(|s| format!("{s}."))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// "a", "a and b", "a, b and c".
+fn list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [init @ .., last] => format!("{} and {last}", init.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn long_reigning_warrior_king_gets_an_obituary() {
+        let mut s = Scenario::at_year(100);
+        let realm = s.add_kingdom("Aldor");
+        let rival = s.add_kingdom("Brell");
+        let king = realm.leader;
+        let mut world = s.build();
+
+        world.current_time = SimTimestamp::from_year(115);
+        let declared = world.add_event(
+            EventKind::WarDeclared,
+            world.current_time,
+            "Aldor declared war on Brell".to_string(),
+        );
+        world.add_event_participant(declared, realm.faction, ParticipantRole::Attacker);
+        world.add_event_participant(declared, rival.faction, ParticipantRole::Defender);
+
+        world.current_time = SimTimestamp::from_year(140);
+        let death = world.add_event(
+            EventKind::Death,
+            world.current_time,
+            "The king of Aldor died".to_string(),
+        );
+        world.add_event_participant(death, king, ParticipantRole::Subject);
+        world.end_relationship(
+            king,
+            realm.faction,
+            RelationshipKind::LeaderOf,
+            world.current_time,
+            death,
+        );
+        world.end_entity(king, world.current_time, death);
+
+        let obituaries = generate_obituaries(&world, 1.0);
+        let obituary = obituaries
+            .iter()
+            .find(|o| o.person_id == king)
+            .expect("the warrior king should be notable");
+        assert_eq!(obituary.reigns, vec![("Aldor".to_string(), 40)]);
+        assert_eq!(obituary.wars, vec![("Brell".to_string(), 115)]);
+        assert!(
+            obituary.text.contains("40 years"),
+            "missing reign length: {}",
+            obituary.text
+        );
+        assert!(
+            obituary.text.contains("Brell"),
+            "missing war: {}",
+            obituary.text
+        );
+        assert!(
+            obituaries.iter().all(|o| o.person_id != rival.leader),
+            "the living should not get obituaries"
+        );
+    }
+}
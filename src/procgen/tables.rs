@@ -4,6 +4,8 @@ pub struct OccupationDef {
     pub weight: u32,
     /// Resources that double this occupation's weight when present.
     pub resource_affinity: &'static [&'static str],
+    /// Settlement economy types that double this occupation's weight.
+    pub economy_affinity: &'static [&'static str],
 }
 
 pub const OCCUPATIONS: &[OccupationDef] = &[
@@ -11,86 +13,103 @@ pub const OCCUPATIONS: &[OccupationDef] = &[
         name: "farmer",
         weight: 30,
         resource_affinity: &["grain", "cattle"],
+        economy_affinity: &["agricultural"],
     },
     OccupationDef {
         name: "laborer",
         weight: 20,
         resource_affinity: &[],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "craftsman",
         weight: 12,
         resource_affinity: &["timber", "clay"],
+        economy_affinity: &["craft"],
     },
     OccupationDef {
         name: "miner",
         weight: 5,
         resource_affinity: &["iron", "copper", "gems", "stone"],
+        economy_affinity: &["mining"],
     },
     OccupationDef {
         name: "fisher",
         weight: 8,
         resource_affinity: &["fish"],
+        economy_affinity: &["port"],
     },
     OccupationDef {
         name: "merchant",
         weight: 6,
         resource_affinity: &[],
+        economy_affinity: &["trade", "port"],
     },
     OccupationDef {
         name: "soldier",
         weight: 5,
         resource_affinity: &[],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "herbalist",
         weight: 4,
         resource_affinity: &["herbs"],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "shepherd",
         weight: 6,
         resource_affinity: &["sheep", "cattle"],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "woodcutter",
         weight: 5,
         resource_affinity: &["timber"],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "hunter",
         weight: 5,
         resource_affinity: &["game", "furs"],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "tanner",
         weight: 3,
         resource_affinity: &["furs", "game"],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "potter",
         weight: 3,
         resource_affinity: &["clay"],
+        economy_affinity: &["craft"],
     },
     OccupationDef {
         name: "smith",
         weight: 4,
         resource_affinity: &["iron", "copper"],
+        economy_affinity: &["mining", "craft"],
     },
     OccupationDef {
         name: "brewer",
         weight: 3,
         resource_affinity: &["grain"],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "priest",
         weight: 2,
         resource_affinity: &[],
+        economy_affinity: &[],
     },
     OccupationDef {
         name: "scribe",
         weight: 1,
         resource_affinity: &[],
+        economy_affinity: &[],
     },
 ];
 
@@ -179,8 +198,53 @@ pub const PROCLAMATION_TEMPLATES: &[&str] = &[
     "Year {year}: the council of {settlement} establishes new laws for {occupation}s",
 ];
 
-/// Select an occupation using weighted random, boosting weights for resource affinity matches.
-pub fn select_occupation(resources: &[String], rng: &mut dyn rand::RngCore) -> &'static str {
+pub const ORAL_TRADITION_TEMPLATES: &[&str] = &[
+    "The elders of {settlement} still tell how {name} drove the wolves from the {terrain} in the year {year}",
+    "Around the fires of {settlement} they sing of {name}, who stood against the hard winter of {year}",
+    "Every child of {settlement} learns the old saying of {name}: never trust the {terrain} after dark",
+    "It is told in {settlement} that {name} once bargained with a spirit of the {terrain} and came home rich",
+];
+
+// Origin myths: one sentence per recorded founding fact, in the order told.
+
+pub const MYTH_FOUNDING_TEMPLATES: &[&str] = &[
+    "In the year {year}, when the world was young, the people of {faction} were gathered out of the wilderness",
+    "Hear how {faction} was born: in the year {year} the first hearths were lit and an oath was sworn beneath the open sky",
+    "Before {faction} there was only scattered kin; in the year {year} the elders bound them into one people",
+];
+
+pub const MYTH_LEADER_TEMPLATES: &[&str] = &[
+    "{leader} was the first to rule them, and the people followed where {leader} walked",
+    "The gods set {leader} above them, first of all who would lead {faction}",
+    "From among them rose {leader}, whose name the children of {faction} still swear by",
+];
+
+pub const MYTH_WAR_TEMPLATES: &[&str] = &[
+    "In the year {war_year} {enemy} came against them, and the young people learned the weight of the spear",
+    "Their first trial was {enemy}, met in the year {war_year}, and from that strife {faction} was tempered like iron",
+    "When {enemy} rose against them in the year {war_year}, {faction} first knew war",
+];
+
+pub const MYTH_EMBELLISHMENTS: &[&str] = &[
+    "It is said a white stag led the way, and that none who followed it ever went hungry",
+    "The storytellers add that the stars burned brighter on that night than on any since",
+    "Some swear the earth itself shook in welcome",
+    "The old songs claim a river changed its course to water their first fields",
+];
+
+pub const MYTH_CLOSINGS: &[&str] = &[
+    "So it was, and so {faction} endures",
+    "This is the beginning of {faction}, and it shall not be forgotten",
+    "Remember it, children of {faction}, and tell it to your children",
+];
+
+/// Select an occupation using weighted random, boosting weights for resource and
+/// economy-type affinity matches.
+pub fn select_occupation(
+    resources: &[String],
+    economy_type: Option<&str>,
+    rng: &mut dyn rand::RngCore,
+) -> &'static str {
     use rand::Rng;
 
     let weights: Vec<u32> = OCCUPATIONS
@@ -190,11 +254,16 @@ pub fn select_occupation(resources: &[String], rng: &mut dyn rand::RngCore) -> &
                 .resource_affinity
                 .iter()
                 .any(|r| resources.iter().any(|sr| sr == r));
+            let has_economy_affinity =
+                economy_type.is_some_and(|et| occ.economy_affinity.contains(&et));
+            let mut weight = occ.weight;
             if has_affinity {
-                occ.weight * 2
-            } else {
-                occ.weight
+                weight *= 2;
+            }
+            if has_economy_affinity {
+                weight *= 2;
             }
+            weight
         })
         .collect();
 
@@ -259,6 +328,24 @@ mod tests {
                 "trade record template missing placeholder: {t}"
             );
         }
+        for t in MYTH_FOUNDING_TEMPLATES {
+            assert!(
+                t.contains("{year}") && t.contains("{faction}"),
+                "myth founding template missing placeholder: {t}"
+            );
+        }
+        for t in MYTH_LEADER_TEMPLATES {
+            assert!(
+                t.contains("{leader}"),
+                "myth leader template missing placeholder: {t}"
+            );
+        }
+        for t in MYTH_WAR_TEMPLATES {
+            assert!(
+                t.contains("{enemy}") && t.contains("{war_year}"),
+                "myth war template missing placeholder: {t}"
+            );
+        }
         for t in PROCLAMATION_TEMPLATES {
             assert!(
                 t.contains('{'),
@@ -290,11 +377,25 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(42);
         let resources = vec!["iron".to_string()];
         for _ in 0..100 {
-            let occ = select_occupation(&resources, &mut rng);
+            let occ = select_occupation(&resources, None, &mut rng);
             assert!(
                 OCCUPATIONS.iter().any(|o| o.name == occ),
                 "invalid occupation: {occ}"
             );
         }
     }
+
+    #[test]
+    fn economy_type_boosts_matching_occupations() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        let resources: Vec<String> = Vec::new();
+        let count_miners = |economy_type: Option<&str>| {
+            let mut rng = SmallRng::seed_from_u64(7);
+            (0..2000)
+                .filter(|_| select_occupation(&resources, economy_type, &mut rng) == "miner")
+                .count()
+        };
+        assert!(count_miners(Some("mining")) > count_miners(None));
+    }
 }
@@ -4,14 +4,22 @@ use crate::sim::names::generate_person_name;
 
 use super::seed::{PROCGEN_ID_BASE, make_rng};
 use super::tables::{
-    PROCLAMATION_TEMPLATES, TOMBSTONE_TEMPLATES, TRADE_RECORD_TEMPLATES, select_occupation,
+    ORAL_TRADITION_TEMPLATES, PROCLAMATION_TEMPLATES, TOMBSTONE_TEMPLATES, TRADE_RECORD_TEMPLATES,
+    select_occupation,
 };
 
+/// Literacy below which a settlement keeps no written records, only oral traditions.
+const ORAL_TRADITION_LITERACY: f64 = 0.1;
+/// Share of the full writing volume a barely literate settlement still produces.
+const WRITING_VOLUME_FLOOR: f64 = 0.25;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WritingCategory {
     Tombstone,
     TradeRecord,
     Proclamation,
+    OriginMyth,
+    OralTradition,
 }
 
 #[derive(Debug, Clone)]
@@ -35,19 +43,27 @@ pub fn generate_writings(
 
     let mut rng = make_rng(snapshot.settlement_id, snapshot.year, "writings");
 
+    if snapshot.literacy < ORAL_TRADITION_LITERACY {
+        return generate_oral_traditions(snapshot, config, id_offset, &mut rng);
+    }
+
+    // Fewer hands to write with means fewer records
+    let volume = WRITING_VOLUME_FLOOR + (1.0 - WRITING_VOLUME_FLOOR) * snapshot.literacy.min(1.0);
+    let scaled = |count: usize| (count as f64 * volume).ceil() as usize;
+
     // Determine counts per category
-    let tombstone_count = ((population as f64 * 0.01).ceil() as usize
-        + settlement_age as usize / 20)
-        .min(config.max_writings / 2)
-        .max(1);
+    let tombstone_count =
+        scaled((population as f64 * 0.01).ceil() as usize + settlement_age as usize / 20)
+            .min(config.max_writings / 2)
+            .max(1);
     let trade_count = if snapshot.resources.is_empty() {
         0
     } else {
-        (snapshot.resources.len())
+        scaled(snapshot.resources.len())
             .min(config.max_writings / 4)
             .max(1)
     };
-    let proclamation_count = (settlement_age as usize / 50)
+    let proclamation_count = scaled(settlement_age as usize / 50)
         .max(1)
         .min(config.max_writings / 4);
 
@@ -62,7 +78,11 @@ pub fn generate_writings(
     for _ in 0..actual_tombstones {
         let template = TOMBSTONE_TEMPLATES[rng.random_range(0..TOMBSTONE_TEMPLATES.len())];
         let name = generate_person_name(&mut rng);
-        let occupation = select_occupation(&snapshot.resources, &mut rng);
+        let occupation = select_occupation(
+            &snapshot.resources,
+            snapshot.economy_type.as_deref(),
+            &mut rng,
+        );
         let age = rng.random_range(20..=85);
         let year_written = snapshot.founded_year + rng.random_range(0..=settlement_age);
 
@@ -119,7 +139,11 @@ pub fn generate_writings(
     let actual_proclamations = proclamation_count.min(remaining);
     for _ in 0..actual_proclamations {
         let template = PROCLAMATION_TEMPLATES[rng.random_range(0..PROCLAMATION_TEMPLATES.len())];
-        let occupation = select_occupation(&snapshot.resources, &mut rng);
+        let occupation = select_occupation(
+            &snapshot.resources,
+            snapshot.economy_type.as_deref(),
+            &mut rng,
+        );
         let terrain = snapshot.terrain.as_deref().unwrap_or("lands");
         let resource = if snapshot.resources.is_empty() {
             "harvest"
@@ -147,6 +171,41 @@ pub fn generate_writings(
     writings
 }
 
+/// Stories an illiterate settlement passes down by word of mouth in place of
+/// written records.
+fn generate_oral_traditions(
+    snapshot: &super::SettlementSnapshot,
+    config: &super::ProcGenConfig,
+    id_offset: u64,
+    rng: &mut dyn rand::RngCore,
+) -> Vec<GeneratedWriting> {
+    let settlement_age = snapshot.year.saturating_sub(snapshot.founded_year);
+    let count = (settlement_age as usize / 50)
+        .max(1)
+        .min(config.max_writings / 4);
+    let terrain = snapshot.terrain.as_deref().unwrap_or("wilds");
+
+    (0..count as u64)
+        .map(|i| {
+            let template =
+                ORAL_TRADITION_TEMPLATES[rng.random_range(0..ORAL_TRADITION_TEMPLATES.len())];
+            let name = generate_person_name(rng);
+            let year_written = snapshot.founded_year + rng.random_range(0..=settlement_age);
+            let text = template
+                .replace("{name}", &name)
+                .replace("{settlement}", &snapshot.name)
+                .replace("{terrain}", terrain)
+                .replace("{year}", &year_written.to_string());
+            GeneratedWriting {
+                id: PROCGEN_ID_BASE + id_offset + i,
+                category: WritingCategory::OralTradition,
+                text,
+                year_written,
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,6 +222,9 @@ mod tests {
             resources: vec!["iron".to_string(), "grain".to_string()],
             terrain: Some("plains".to_string()),
             terrain_tags: vec![],
+            economy_type: None,
+            literacy: 0.5,
+            heraldry: None,
             notable_events: vec![],
         }
     }
@@ -249,4 +311,48 @@ mod tests {
             assert!(writing.id >= PROCGEN_ID_BASE);
         }
     }
+
+    #[test]
+    fn literate_settlement_writes_more_than_barely_literate() {
+        let config = ProcGenConfig {
+            max_writings: 100,
+            ..ProcGenConfig::default()
+        };
+        let scholarly = SettlementSnapshot {
+            literacy: 0.9,
+            heraldry: None,
+            ..test_snapshot()
+        };
+        let frontier = SettlementSnapshot {
+            literacy: 0.15,
+            ..test_snapshot()
+        };
+
+        let scholarly_count = generate_writings(&scholarly, &config, 0).len();
+        let frontier_count = generate_writings(&frontier, &config, 0).len();
+
+        assert!(
+            scholarly_count > frontier_count,
+            "scholarly={scholarly_count}, frontier={frontier_count}"
+        );
+    }
+
+    #[test]
+    fn illiterate_settlement_keeps_only_oral_traditions() {
+        let snapshot = SettlementSnapshot {
+            literacy: 0.0,
+            ..test_snapshot()
+        };
+        let config = ProcGenConfig::default();
+        let result = generate_writings(&snapshot, &config, 0);
+        assert!(!result.is_empty());
+        for writing in &result {
+            assert_eq!(writing.category, WritingCategory::OralTradition);
+            assert!(
+                !writing.text.contains('{'),
+                "raw placeholder: {}",
+                writing.text
+            );
+        }
+    }
 }
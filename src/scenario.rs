@@ -2,6 +2,11 @@ use crate::model::entity_data::*;
 use crate::model::population::PopulationBreakdown;
 use crate::model::*;
 use crate::sim::extra_keys as K;
+use std::ops::Range;
+
+use crate::sim::grievance;
+use crate::sim::helpers;
+use crate::sim::politics::diplomacy;
 use crate::sim::{SimConfig, SimSystem, run};
 
 /// IDs returned by [`Scenario::add_settlement_standalone`].
@@ -134,6 +139,10 @@ impl FactionRef<'_> {
         self.data_mut().government_type = v;
         self
     }
+    pub fn succession_law(mut self, v: SuccessionLaw) -> Self {
+        self.data_mut().succession_law = v;
+        self
+    }
     pub fn stability(mut self, v: f64) -> Self {
         self.data_mut().stability = v;
         self
@@ -223,6 +232,10 @@ impl SettlementRef<'_> {
         self.data_mut().religious_tension = v;
         self
     }
+    pub fn unfree_fraction(mut self, v: f64) -> Self {
+        self.data_mut().unfree_fraction = v;
+        self
+    }
 }
 
 impl PersonRef<'_> {
@@ -258,6 +271,10 @@ impl PersonRef<'_> {
         self.data_mut().last_action = SimTimestamp::from_year(v);
         self
     }
+    pub fn health(mut self, v: f64) -> Self {
+        self.data_mut().health = v;
+        self
+    }
 }
 
 impl ArmyRef<'_> {
@@ -1116,6 +1133,14 @@ impl Scenario {
         );
     }
 
+    /// Put two factions in contact (bidirectional `known_to`), so they may
+    /// deal with each other even if they cannot reach each other.
+    pub fn make_contact(&mut self, faction_a: u64, faction_b: u64) {
+        let ts = self.ts();
+        let ev = self.setup_event;
+        diplomacy::establish_contact(&mut self.world, faction_a, faction_b, ts, ev);
+    }
+
     /// Make two factions enemies (bidirectional Enemy).
     pub fn make_enemies(&mut self, faction_a: u64, faction_b: u64) {
         self.make_bidirectional(
@@ -1376,10 +1401,88 @@ impl Scenario {
                 sources: vec!["scenario".to_string()],
                 peak: severity,
                 updated: ts,
+                atrocity_at: None,
             },
         );
     }
 
+    /// Seed a mutual grievance: each faction holds `severity` against the other.
+    pub fn grievance(&mut self, faction_a: u64, faction_b: u64, severity: f64) {
+        self.add_grievance(faction_a, faction_b, severity);
+        self.add_grievance(faction_b, faction_a, severity);
+    }
+
+    /// Make two factions sworn enemies: bidirectional Enemy and in contact, so
+    /// the war-declaration pipeline weighs them even if they only share a border.
+    pub fn enemies(&mut self, faction_a: u64, faction_b: u64) {
+        self.make_enemies(faction_a, faction_b);
+        if !helpers::have_contact(&self.world, faction_a, faction_b) {
+            self.make_contact(faction_a, faction_b);
+        }
+    }
+
+    /// Pre-seed a backstory of past wars between two factions. Each year range
+    /// becomes a `WarDeclared` event at its start and a `Treaty` ending it, and
+    /// leaves both sides with a grievance dated to the peace that then decays
+    /// as any other would.
+    pub fn with_history(&mut self, aggressor: u64, defender: u64, wars: &[Range<u32>]) {
+        const OLD_WAR_GRIEVANCE: f64 = 0.2;
+        let aggressor_name = self.world.entities[&aggressor].name.clone();
+        let defender_name = self.world.entities[&defender].name.clone();
+        for war in wars {
+            assert!(
+                war.start < war.end && war.end <= self.start_year,
+                "with_history: war {war:?} must end by the scenario start"
+            );
+            let declared = self.world.add_event(
+                EventKind::WarDeclared,
+                SimTimestamp::from_year(war.start),
+                format!(
+                    "{aggressor_name} declared war on {defender_name} in year {}",
+                    war.start
+                ),
+            );
+            self.world
+                .add_event_participant(declared, aggressor, ParticipantRole::Attacker);
+            self.world
+                .add_event_participant(declared, defender, ParticipantRole::Defender);
+
+            let ended = SimTimestamp::from_year(war.end);
+            let treaty = self.world.add_caused_event(
+                EventKind::Treaty,
+                ended,
+                format!(
+                    "{aggressor_name} and {defender_name} made peace in year {}",
+                    war.end
+                ),
+                declared,
+            );
+            for faction in [aggressor, defender] {
+                self.world
+                    .add_event_participant(treaty, faction, ParticipantRole::Subject);
+            }
+
+            grievance::add_grievance(
+                &mut self.world,
+                aggressor,
+                defender,
+                OLD_WAR_GRIEVANCE,
+                "old_war",
+                ended,
+                treaty,
+            );
+            grievance::add_grievance(
+                &mut self.world,
+                defender,
+                aggressor,
+                OLD_WAR_GRIEVANCE,
+                "old_war",
+                ended,
+                treaty,
+            );
+        }
+    }
+
     // -- Composite builders --
 
     /// Create a kingdom: region + faction + settlement + leader with LeaderOf.
@@ -1693,7 +1796,7 @@ impl Scenario {
 
     /// Count living entities of a given kind.
     pub fn count_living(&self, kind: &EntityKind) -> usize {
-        self.world.count_living(kind)
+        self.world.count_living(*kind)
     }
 
     /// Get all living entity IDs of a given kind.
@@ -1791,7 +1894,20 @@ impl Scenario {
     // -- Output --
 
     /// Consume the scenario and return the constructed World.
-    pub fn build(self) -> World {
+    ///
+    /// Factions that could already have met (see `can_make_contact`) start in
+    /// contact, as if they had met before the scenario began.
+    pub fn build(mut self) -> World {
+        let factions: Vec<u64> = self.world.living_ids(EntityKind::Faction).collect();
+        for (i, &a) in factions.iter().enumerate() {
+            for &b in &factions[i + 1..] {
+                if !helpers::have_contact(&self.world, a, b)
+                    && diplomacy::can_make_contact(&self.world, a, b)
+                {
+                    self.make_contact(a, b);
+                }
+            }
+        }
         self.world
     }
 
@@ -1818,3 +1934,108 @@ impl Scenario {
         &mut self.world
     }
 }
+
+// -- Parameter sweeps --
+
+/// Recipe for a batch of runs: how to set up the starting world and which
+/// systems to run on it. Both are factories so every run starts from a freshly
+/// built world with freshly constructed systems.
+pub struct ScenarioSpec {
+    scenario: Box<dyn Fn() -> Scenario>,
+    systems: Box<dyn Fn() -> Vec<Box<dyn SimSystem>>>,
+}
+
+impl ScenarioSpec {
+    pub fn new(
+        scenario: impl Fn() -> Scenario + 'static,
+        systems: impl Fn() -> Vec<Box<dyn SimSystem>> + 'static,
+    ) -> Self {
+        Self {
+            scenario: Box::new(scenario),
+            systems: Box::new(systems),
+        }
+    }
+}
+
+/// Aggregate outcome of a single sweep run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunSummary {
+    pub seed: u64,
+    /// Index into the `configs` passed to [`sweep`].
+    pub config_index: usize,
+    /// Factions still standing at the end of the run.
+    pub final_faction_count: usize,
+    /// Wars declared or expansion wars launched during the run (requires war
+    /// events to be retained).
+    pub total_wars: usize,
+    /// Combined population of all living settlements at the end of the run.
+    pub surviving_population: u64,
+}
+
+/// Results of [`sweep`], one row per (config, seed) pair in config-major order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SweepResults {
+    pub runs: Vec<RunSummary>,
+}
+
+impl SweepResults {
+    /// Mean of `f` over all runs, or `None` for an empty sweep.
+    pub fn mean(&self, f: impl Fn(&RunSummary) -> f64) -> Option<f64> {
+        if self.runs.is_empty() {
+            return None;
+        }
+        Some(self.runs.iter().map(f).sum::<f64>() / self.runs.len() as f64)
+    }
+}
+
+/// Run `base` once for every combination of config and seed and summarize
+/// each outcome. Runs are independent: each builds its own world and systems.
+/// Every config's seed and start year are replaced by the run's seed and the
+/// scenario's start year, and a config's `output_dir` gets one subdirectory
+/// per run (`config_{index}/seed_{seed}`) so runs don't overwrite each other.
+pub fn sweep(
+    base: &ScenarioSpec,
+    seeds: Range<u64>,
+    configs: &[SimConfig],
+) -> std::io::Result<SweepResults> {
+    let mut runs = Vec::new();
+    for (config_index, config) in configs.iter().enumerate() {
+        for seed in seeds.clone() {
+            let scenario = (base.scenario)();
+            let start_year = scenario.start_year;
+            let mut world = scenario.build();
+            let mut systems = (base.systems)();
+            let output_dir = config.output_dir.as_ref().map(|dir| {
+                dir.join(format!("config_{config_index}"))
+                    .join(format!("seed_{seed}"))
+            });
+            let config = SimConfig {
+                start_year,
+                seed,
+                output_dir,
+                ..config.clone()
+            };
+            run(&mut world, &mut systems, config)?;
+            runs.push(summarize_run(&world, seed, config_index));
+        }
+    }
+    Ok(SweepResults { runs })
+}
+
+fn summarize_run(world: &World, seed: u64, config_index: usize) -> RunSummary {
+    RunSummary {
+        seed,
+        config_index,
+        final_faction_count: world.living(EntityKind::Faction).count(),
+        total_wars: world
+            .events
+            .values()
+            .filter(|e| matches!(e.kind, EventKind::WarDeclared | EventKind::ExpansionWar))
+            .count(),
+        surviving_population: world
+            .living(EntityKind::Settlement)
+            .filter_map(|e| e.data.as_settlement())
+            .map(|sd| u64::from(sd.population))
+            .sum(),
+    }
+}
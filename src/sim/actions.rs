@@ -5,8 +5,10 @@ use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
 use crate::model::action::{Action, ActionKind, ActionOutcome, ActionResult, ActionSource};
 use crate::model::{
-    EntityKind, EventKind, GovernmentType, ParticipantRole, RelationshipKind, WarGoal, World,
+    EntityData, EntityKind, EventKind, EventPayload, GovernmentType, Heraldry, ParticipantRole,
+    RelationshipKind, WarGoal, World,
 };
+use crate::sim::faction_names::generate_unique_faction_name;
 use crate::sim::helpers;
 
 // --- Support faction ---
@@ -27,6 +29,8 @@ const COUP_RESISTANCE_HAPPINESS_COMPLEMENT: f64 = 0.7;
 const COUP_NOISE_RANGE: f64 = 0.1;
 const COUP_POWER_BASE: f64 = 0.2;
 const COUP_POWER_INSTABILITY_FACTOR: f64 = 0.3;
+const COUP_POWER_PER_FRIEND: f64 = 0.05;
+const COUP_MAX_FRIENDS: usize = 4;
 const COUP_SUCCESS_MIN: f64 = 0.1;
 const COUP_SUCCESS_MAX: f64 = 0.9;
 const COUP_STABILITY_MULTIPLIER: f64 = 0.6;
@@ -52,6 +56,10 @@ const BETRAYAL_OTHER_ALLY_BREAK_CHANCE: f64 = 0.40;
 const BETRAYAL_OTHER_ALLY_ENEMY_CHANCE: f64 = 0.25;
 const BETRAYAL_VICTIM_ALLY_ENEMY_CHANCE: f64 = 0.50;
 
+// --- Frontier founding ---
+const FRONTIER_FOLLOWER_FRACTION: f64 = 0.1;
+const FRONTIER_MIN_FOLLOWERS: u32 = 20;
+
 pub struct ActionSystem;
 
 impl SimSystem for ActionSystem {
@@ -113,6 +121,9 @@ impl SimSystem for ActionSystem {
                 ActionKind::PressClaim { target_faction_id } => {
                     process_press_claim(ctx, action.actor_id, &action.source, target_faction_id)
                 }
+                ActionKind::FoundSettlement { region_id } => {
+                    process_found_settlement(ctx, action.actor_id, &action.source, region_id)
+                }
             };
             ctx.world.action_results.push(ActionResult {
                 actor_id: action.actor_id,
@@ -142,7 +153,7 @@ fn validate_living(world: &World, id: u64, kind: EntityKind, label: &str) -> Res
 
 fn store_source_on_event(world: &mut World, event_id: u64, source: &ActionSource) {
     if let Some(event) = world.events.get_mut(&event_id) {
-        event.data = serde_json::to_value(source).unwrap();
+        event.source = Some(source.clone());
     }
 }
 
@@ -176,8 +187,11 @@ fn process_assassinate(
         .add_event_participant(assassination_ev, target_id, ParticipantRole::Object);
 
     // Create caused Death event
-    let death_ev = ctx.world.add_caused_event(
-        EventKind::Death,
+    let death_ev = ctx.world.add_caused_structured_event(
+        EventPayload::Death {
+            entity_id: target_id,
+            cause: "assassination".to_string(),
+        },
         time,
         format!("{target_name} was killed in year {year}"),
         assassination_ev,
@@ -461,12 +475,31 @@ fn process_declare_war(
         };
     }
 
+    if helpers::in_ceasefire(ctx.world, actor_faction, target_faction_id, year) {
+        return ActionOutcome::Failed {
+            reason: "factions are bound by a ceasefire".to_string(),
+        };
+    }
+
+    if !helpers::have_contact(ctx.world, actor_faction, target_faction_id) {
+        return ActionOutcome::Failed {
+            reason: "factions have not made contact".to_string(),
+        };
+    }
+
     let attacker_name = helpers::entity_name(ctx.world, actor_faction);
     let defender_name = helpers::entity_name(ctx.world, target_faction_id);
     let actor_name = helpers::entity_name(ctx.world, actor_id);
 
-    let ev = ctx.world.add_event(
-        EventKind::WarDeclared,
+    // Goal-less wars settle on territorial terms; the payload says as much
+    let ev = ctx.world.add_structured_event(
+        EventPayload::WarDeclared {
+            attacker_id: actor_faction,
+            defender_id: target_faction_id,
+            war_goal: WarGoal::Territorial {
+                target_settlements: Vec::new(),
+            },
+        },
         time,
         format!("{actor_name} of {attacker_name} declared war on {defender_name} in year {year}"),
     );
@@ -563,8 +596,27 @@ fn process_attempt_coup(
             * legitimacy
             * (COUP_RESISTANCE_HAPPINESS_WEIGHT + COUP_RESISTANCE_HAPPINESS_COMPLEMENT * happiness);
     let noise: f64 = ctx.rng.random_range(-COUP_NOISE_RANGE..COUP_NOISE_RANGE);
-    let coup_power =
-        (COUP_POWER_BASE + COUP_POWER_INSTABILITY_FACTOR * instability + noise).max(0.0);
+    // Friends within the faction rally behind the instigator
+    let friends = ctx
+        .world
+        .entities
+        .get(&actor_id)
+        .map(|e| {
+            e.active_rels(RelationshipKind::Friend)
+                .filter(|&fid| {
+                    ctx.world.entities.get(&fid).is_some_and(|f| {
+                        f.end.is_none() && f.has_active_rel(RelationshipKind::MemberOf, faction_id)
+                    })
+                })
+                .count()
+        })
+        .unwrap_or(0)
+        .min(COUP_MAX_FRIENDS);
+    let coup_power = (COUP_POWER_BASE
+        + COUP_POWER_INSTABILITY_FACTOR * instability
+        + COUP_POWER_PER_FRIEND * friends as f64
+        + noise)
+        .max(0.0);
     let success_chance =
         (coup_power / (coup_power + resistance)).clamp(COUP_SUCCESS_MIN, COUP_SUCCESS_MAX);
 
@@ -640,8 +692,11 @@ fn process_attempt_coup(
 
         if ctx.rng.random_bool(COUP_FAILED_EXECUTION_CHANCE) {
             // Instigator executed
-            let death_ev = ctx.world.add_caused_event(
-                EventKind::Death,
+            let death_ev = ctx.world.add_caused_structured_event(
+                EventPayload::Death {
+                    entity_id: actor_id,
+                    cause: "execution".to_string(),
+                },
                 time,
                 format!("{actor_name} was executed after a failed coup in year {year}"),
                 ev,
@@ -930,6 +985,8 @@ fn process_seek_office(
         store_source_on_event(ctx.world, ev, source);
         ctx.world
             .add_event_participant(ev, actor_id, ParticipantRole::Instigator);
+        ctx.world
+            .add_event_participant(ev, leader_id, ParticipantRole::Subject);
         ctx.world
             .add_event_participant(ev, faction_id, ParticipantRole::Object);
 
@@ -1263,9 +1320,14 @@ fn process_press_claim(
     let attacker_name = helpers::entity_name(ctx.world, actor_faction);
     let target_name = helpers::entity_name(ctx.world, target_faction_id);
 
-    // Create war_declared event
-    let ev = ctx.world.add_event(
-        EventKind::WarDeclared,
+    let ev = ctx.world.add_structured_event(
+        EventPayload::WarDeclared {
+            attacker_id: actor_faction,
+            defender_id: target_faction_id,
+            war_goal: WarGoal::SuccessionClaim {
+                claimant_id: actor_id,
+            },
+        },
         time,
         format!(
             "{actor_name} of {attacker_name} pressed their claim on the throne of {target_name} in year {year}"
@@ -1336,6 +1398,211 @@ fn process_press_claim(
     ActionOutcome::Success { event_id: ev }
 }
 
+/// An adventurer leads a band of settlers from their home settlement into an
+/// unclaimed neighboring region, founding a new settlement and a faction of
+/// their own with themselves as leader.
+fn process_found_settlement(
+    ctx: &mut TickContext,
+    actor_id: u64,
+    source: &ActionSource,
+    region_id: u64,
+) -> ActionOutcome {
+    let time = ctx.world.current_time;
+    let year = time.year();
+
+    if let Err(reason) = validate_living(ctx.world, actor_id, EntityKind::Person, "actor").and(
+        validate_living(ctx.world, region_id, EntityKind::Region, "region"),
+    ) {
+        return ActionOutcome::Failed { reason };
+    }
+
+    let Some(old_faction) = find_actor_faction(ctx.world, actor_id) else {
+        return ActionOutcome::Failed {
+            reason: "actor has no faction".to_string(),
+        };
+    };
+    let actor = ctx.world.entities.get(&actor_id).unwrap();
+    if actor.has_active_rel(RelationshipKind::LeaderOf, old_faction) {
+        return ActionOutcome::Failed {
+            reason: "leaders cannot abandon their faction".to_string(),
+        };
+    }
+    let Some(home_id) = actor.active_rels(RelationshipKind::LocatedIn).find(|&id| {
+        ctx.world
+            .entities
+            .get(&id)
+            .is_some_and(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+    }) else {
+        return ActionOutcome::Failed {
+            reason: "actor does not live in a settlement".to_string(),
+        };
+    };
+    let home_region = helpers::active_rel_target(ctx.world, home_id, RelationshipKind::LocatedIn);
+    let is_frontier =
+        home_region.is_some_and(|r| helpers::adjacent_regions(ctx.world, r).contains(&region_id));
+    if !is_frontier || !helpers::region_is_unclaimed(ctx.world, region_id) {
+        return ActionOutcome::Failed {
+            reason: "region is not unclaimed land next to the actor's home".to_string(),
+        };
+    }
+
+    let home = ctx.world.settlement(home_id);
+    let home_pop = home.population;
+    if ((home_pop as f64 * FRONTIER_FOLLOWER_FRACTION) as u32) < FRONTIER_MIN_FOLLOWERS {
+        return ActionOutcome::Failed {
+            reason: "too few followers to found a settlement".to_string(),
+        };
+    }
+    let (culture, religion) = (home.dominant_culture, home.dominant_religion);
+
+    // Take the settlers out of the home settlement
+    let settlers = {
+        let sd = ctx.world.settlement_mut(home_id);
+        let settlers = sd
+            .population_breakdown
+            .subtract_fraction(FRONTIER_FOLLOWER_FRACTION, ctx.rng);
+        sd.population = sd.population_breakdown.total();
+        settlers
+    };
+    let settler_count = settlers.total();
+
+    let actor_name = helpers::entity_name(ctx.world, actor_id);
+    let home_name = helpers::entity_name(ctx.world, home_id);
+    let faction_name = generate_unique_faction_name(ctx.world, ctx.rng);
+    let settlement_name = format!("{actor_name}'s Hold");
+    let faction_id = ctx.world.reserve_entity_id();
+    let ev = ctx.world.add_structured_event(
+        EventPayload::FactionFormed {
+            faction_id,
+            parent_faction_id: Some(old_faction),
+            cause: "frontier".to_string(),
+        },
+        time,
+        format!(
+            "{actor_name} led {settler_count} settlers from {home_name} to found {settlement_name}, \
+             seat of {faction_name}, in year {year}"
+        ),
+    );
+    store_source_on_event(ctx.world, ev, source);
+
+    let mut faction_data = EntityData::default_for_kind(EntityKind::Faction);
+    if let EntityData::Faction(ref mut fd) = faction_data {
+        fd.government_type = GovernmentType::Chieftain;
+        fd.primary_culture = culture;
+        fd.primary_religion = religion;
+    }
+    ctx.world.add_reserved_entity(
+        faction_id,
+        EntityKind::Faction,
+        faction_name,
+        Some(time),
+        faction_data,
+        ev,
+    );
+    ctx.world.faction_mut(faction_id).heraldry = Heraldry::for_faction(faction_id, culture);
+
+    let (x, y) = ctx
+        .world
+        .entities
+        .get(&region_id)
+        .and_then(|e| e.data.as_region())
+        .map(|rd| (rd.x, rd.y))
+        .unwrap_or((0.0, 0.0));
+    let mut settlement_data = EntityData::default_for_kind(EntityKind::Settlement);
+    if let EntityData::Settlement(ref mut sd) = settlement_data {
+        sd.population = settler_count;
+        sd.population_breakdown = settlers;
+        sd.x = x;
+        sd.y = y;
+        sd.dominant_culture = culture;
+        if let Some(culture_id) = culture {
+            sd.culture_makeup.insert(culture_id, 1.0);
+        }
+        sd.dominant_religion = religion;
+        if let Some(religion_id) = religion {
+            sd.religion_makeup.insert(religion_id, 1.0);
+        }
+    }
+    let settlement_id = ctx.world.add_entity(
+        EntityKind::Settlement,
+        settlement_name,
+        Some(time),
+        settlement_data,
+        ev,
+    );
+    ctx.world.add_relationship(
+        settlement_id,
+        faction_id,
+        RelationshipKind::MemberOf,
+        time,
+        ev,
+    );
+    ctx.world.add_relationship(
+        settlement_id,
+        region_id,
+        RelationshipKind::LocatedIn,
+        time,
+        ev,
+    );
+
+    ctx.world
+        .add_event_participant(ev, actor_id, ParticipantRole::Instigator);
+    ctx.world
+        .add_event_participant(ev, faction_id, ParticipantRole::Subject);
+    ctx.world
+        .add_event_participant(ev, settlement_id, ParticipantRole::Object);
+    ctx.world
+        .add_event_participant(ev, home_id, ParticipantRole::Origin);
+    ctx.world.record_change(
+        home_id,
+        ev,
+        "population",
+        serde_json::json!(home_pop),
+        serde_json::json!(home_pop - settler_count),
+    );
+
+    // The founder's friends in the home settlement follow them out
+    let friends: Vec<u64> = ctx
+        .world
+        .entities
+        .get(&actor_id)
+        .map(|e| e.active_rels(RelationshipKind::Friend).collect())
+        .unwrap_or_default();
+    let mut movers = vec![actor_id];
+    movers.extend(friends.into_iter().filter(|&id| {
+        ctx.world.entities.get(&id).is_some_and(|e| {
+            e.end.is_none()
+                && e.has_active_rel(RelationshipKind::LocatedIn, home_id)
+                && e.has_active_rel(RelationshipKind::MemberOf, old_faction)
+                && !e.has_active_rel(RelationshipKind::LeaderOf, old_faction)
+        })
+    }));
+    for id in movers {
+        ctx.world
+            .end_relationship(id, old_faction, RelationshipKind::MemberOf, time, ev);
+        ctx.world
+            .add_relationship(id, faction_id, RelationshipKind::MemberOf, time, ev);
+        ctx.world
+            .end_relationship(id, home_id, RelationshipKind::LocatedIn, time, ev);
+        ctx.world
+            .add_relationship(id, settlement_id, RelationshipKind::LocatedIn, time, ev);
+    }
+    ctx.world
+        .add_relationship(actor_id, faction_id, RelationshipKind::LeaderOf, time, ev);
+
+    ctx.signals.push(Signal {
+        event_id: ev,
+        kind: SignalKind::FrontierSettlementFounded {
+            settlement_id,
+            faction_id,
+            founder_id: actor_id,
+            origin_settlement_id: home_id,
+        },
+    });
+
+    ActionOutcome::Success { event_id: ev }
+}
+
 fn find_actor_faction(world: &World, actor_id: u64) -> Option<u64> {
     world.entities.get(&actor_id).and_then(|e| {
         e.active_rels(RelationshipKind::MemberOf).find(|&target| {
@@ -1801,7 +2068,8 @@ mod tests {
             .find(|e| e.kind == EventKind::Intrigue)
             .expect("should have faction_support event");
 
-        assert_eq!(ev.data, serde_json::json!("player"));
+        assert_eq!(ev.source, Some(ActionSource::Player));
+        assert!(ev.data.is_null());
     }
 
     #[test]
@@ -1855,6 +2123,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scenario_found_settlement_creates_frontier_faction() {
+        let mut s = Scenario::at_year(100);
+        let home_region = s.add_region("Heartland");
+        let frontier = s.add_region("Frontier");
+        s.make_adjacent(home_region, frontier);
+        let old_faction = s.add_faction("Old Kingdom");
+        let home = s
+            .settlement("Homestead", old_faction, home_region)
+            .population(400)
+            .id();
+        let actor_id = s.add_person_in("Brenna", old_faction, home);
+        let friend_id = s.add_person_in("Tam", old_faction, home);
+        let bystander_id = s.add_person_in("Ulric", old_faction, home);
+        s.add_relationship(actor_id, friend_id, RelationshipKind::Friend);
+        s.add_relationship(friend_id, actor_id, RelationshipKind::Friend);
+        let mut world = s.build();
+
+        world.queue_action(Action {
+            actor_id,
+            source: ActionSource::Autonomous,
+            kind: ActionKind::FoundSettlement {
+                region_id: frontier,
+            },
+        });
+        let signals = tick(&mut world);
+
+        let ActionOutcome::Success { event_id } = world.action_results[0].outcome else {
+            panic!("expected success: {:?}", world.action_results[0].outcome);
+        };
+        let event = &world.events[&event_id];
+        assert_eq!(event.kind, EventKind::FactionFormed);
+        assert_eq!(event.source, Some(ActionSource::Autonomous));
+        let Ok(EventPayload::FactionFormed {
+            faction_id,
+            parent_faction_id,
+            cause,
+        }) = serde_json::from_value(event.data.clone())
+        else {
+            panic!("expected a FactionFormed payload");
+        };
+        assert_eq!(parent_faction_id, Some(old_faction));
+        assert_eq!(cause, "frontier");
+
+        let new_settlement = world
+            .living(EntityKind::Settlement)
+            .find(|e| e.has_active_rel(RelationshipKind::LocatedIn, frontier))
+            .expect("a settlement should stand in the frontier region")
+            .id;
+        assert!(
+            world.entities[&new_settlement].has_active_rel(RelationshipKind::MemberOf, faction_id)
+        );
+        let settlers = world.settlement(new_settlement).population;
+        assert!(settlers > 0);
+        assert_eq!(world.settlement(home).population, 400 - settlers);
+
+        let actor = &world.entities[&actor_id];
+        assert!(actor.has_active_rel(RelationshipKind::LeaderOf, faction_id));
+        assert!(actor.has_active_rel(RelationshipKind::LocatedIn, new_settlement));
+        assert!(
+            world.entities[&friend_id].has_active_rel(RelationshipKind::MemberOf, faction_id),
+            "friends should follow the founder"
+        );
+        assert!(
+            world.entities[&bystander_id].has_active_rel(RelationshipKind::MemberOf, old_faction)
+        );
+        assert!(signals.iter().any(|sig| matches!(
+            sig.kind,
+            SignalKind::FrontierSettlementFounded { founder_id, origin_settlement_id, .. }
+                if founder_id == actor_id && origin_settlement_id == home
+        )));
+    }
+
+    #[test]
+    fn scenario_found_settlement_in_claimed_region_fails() {
+        let mut s = Scenario::at_year(100);
+        let home_region = s.add_region("Heartland");
+        let neighbor = s.add_region("Neighbor");
+        s.make_adjacent(home_region, neighbor);
+        let faction = s.add_faction("Old Kingdom");
+        let home = s
+            .settlement("Homestead", faction, home_region)
+            .population(400)
+            .id();
+        s.add_settlement("Rival Town", faction, neighbor);
+        let actor_id = s.add_person_in("Brenna", faction, home);
+        let mut world = s.build();
+
+        world.queue_action(Action {
+            actor_id,
+            source: ActionSource::Autonomous,
+            kind: ActionKind::FoundSettlement {
+                region_id: neighbor,
+            },
+        });
+        tick(&mut world);
+
+        assert!(matches!(
+            &world.action_results[0].outcome,
+            ActionOutcome::Failed { .. }
+        ));
+        assert_eq!(world.count_living(EntityKind::Faction), 1);
+    }
+
     #[test]
     fn scenario_defect_as_leader_fails() {
         let mut s = Scenario::at_year(100);
@@ -2219,13 +2591,24 @@ mod tests {
         }
 
         // WarDeclared event
-        assert!(
-            world
-                .events
-                .values()
-                .any(|e| e.kind == EventKind::WarDeclared
-                    && e.description.contains("pressed their claim"))
+        let war_declared = world
+            .events
+            .values()
+            .find(|e| {
+                e.kind == EventKind::WarDeclared && e.description.contains("pressed their claim")
+            })
+            .expect("should have WarDeclared event");
+        assert_eq!(
+            EventPayload::from_data(&war_declared.data),
+            Some(EventPayload::WarDeclared {
+                attacker_id: fa,
+                defender_id: fb,
+                war_goal: WarGoal::SuccessionClaim {
+                    claimant_id: leader
+                },
+            })
         );
+        assert_eq!(war_declared.source, Some(ActionSource::Player));
 
         // WarStarted signal
         assert!(
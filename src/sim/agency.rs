@@ -1,17 +1,36 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use rand::Rng;
 
 use super::context::TickContext;
 use super::signal::SignalKind;
 use super::system::{SimSystem, TickFrequency};
 use crate::model::action::{Action, ActionKind, ActionSource};
+use crate::model::entity_data::Role;
 use crate::model::traits::Trait;
-use crate::model::{EntityKind, GovernmentType, RelationshipKind, SimTimestamp};
+use crate::model::{
+    EntityKind, EventKind, GovernmentType, ParticipantRole, RelationshipKind, SimTimestamp,
+};
 use crate::sim::helpers;
 use crate::sim::politics::diplomacy;
 
+/// Chance that two warriors who fought on the same side of a war become friends.
+const COMRADE_FRIENDSHIP_CHANCE: f64 = 0.3;
+/// Extra SeizePower urgency for an NPC whose personal rival leads their faction.
+const RIVAL_LEADER_COUP_URGENCY: f64 = 0.25;
+/// Prestige below which an ambitious NPC is low-status enough to strike out
+/// for the frontier rather than wait for a seat at home.
+const FRONTIER_MAX_PRESTIGE: f64 = 0.3;
+/// Base urgency to found a settlement in unclaimed land next door.
+const FRONTIER_BASE_URGENCY: f64 = 0.3;
+/// Extra frontier urgency from an unstable home faction.
+const FRONTIER_INSTABILITY_URGENCY: f64 = 0.2;
+
 pub struct AgencySystem {
     /// Signals received this tick, available during next tick's desire evaluation.
     recent_signals: Vec<SignalKind>,
+    /// Highest event id already scanned for leadership contests.
+    last_event_scanned: u64,
 }
 
 impl Default for AgencySystem {
@@ -24,6 +43,7 @@ impl AgencySystem {
     pub fn new() -> Self {
         Self {
             recent_signals: Vec::new(),
+            last_event_scanned: 0,
         }
     }
 }
@@ -43,6 +63,8 @@ impl SimSystem for AgencySystem {
         // Consume signals from previous tick
         let signals = std::mem::take(&mut self.recent_signals);
 
+        self.form_personal_bonds(ctx, &signals, time);
+
         // Collect living notable NPCs (persons with traits)
         let npcs: Vec<NpcInfo> = ctx
             .world
@@ -158,6 +180,160 @@ impl SimSystem for AgencySystem {
     }
 }
 
+impl AgencySystem {
+    /// Turn shared and opposed experiences into personal relationships:
+    /// contenders for a leadership become rivals, and warriors who served on
+    /// the same side of a finished war may become friends.
+    fn form_personal_bonds(
+        &mut self,
+        ctx: &mut TickContext,
+        signals: &[SignalKind],
+        time: SimTimestamp,
+    ) {
+        // Leadership contests recorded since the last scan: the instigator
+        // challenged the subject for the faction's leadership
+        let contests: BTreeSet<u64> = ctx
+            .world
+            .events
+            .range(self.last_event_scanned + 1..)
+            .filter(|(_, ev)| {
+                matches!(
+                    ev.kind,
+                    EventKind::Coup
+                        | EventKind::FailedCoup
+                        | EventKind::Succession
+                        | EventKind::Election
+                )
+            })
+            .map(|(&id, _)| id)
+            .collect();
+        if let Some((&last, _)) = ctx.world.events.last_key_value() {
+            self.last_event_scanned = self.last_event_scanned.max(last);
+        }
+
+        let mut contenders: BTreeMap<u64, (Option<u64>, Option<u64>)> = BTreeMap::new();
+        if !contests.is_empty() {
+            for p in &ctx.world.event_participants {
+                if !contests.contains(&p.event_id) {
+                    continue;
+                }
+                let entry = contenders.entry(p.event_id).or_default();
+                match p.role {
+                    ParticipantRole::Instigator => entry.0 = Some(p.entity_id),
+                    ParticipantRole::Subject => entry.1 = Some(p.entity_id),
+                    _ => {}
+                }
+            }
+        }
+        let mut rivals: Vec<(u64, u64, Option<u64>)> = contenders
+            .into_iter()
+            .filter_map(|(ev_id, pair)| match pair {
+                (Some(a), Some(b)) => Some((a, b, Some(ev_id))),
+                _ => None,
+            })
+            .collect();
+        for signal in signals {
+            if let SignalKind::SuccessionCrisis {
+                new_leader_id,
+                claimant_ids,
+                ..
+            } = signal
+            {
+                for &claimant in claimant_ids {
+                    rivals.push((claimant, *new_leader_id, None));
+                }
+            }
+        }
+        for (a, b, cause) in rivals {
+            form_bond(ctx, a, b, RelationshipKind::Rival, cause, time);
+        }
+
+        for signal in signals {
+            let SignalKind::WarEnded {
+                winner_id,
+                loser_id,
+                ..
+            } = signal
+            else {
+                continue;
+            };
+            for faction_id in [*winner_id, *loser_id] {
+                let warriors: Vec<u64> = ctx
+                    .world
+                    .entities
+                    .values()
+                    .filter(|e| {
+                        e.kind == EntityKind::Person
+                            && e.end.is_none()
+                            && e.has_active_rel(RelationshipKind::MemberOf, faction_id)
+                            && e.data.as_person().is_some_and(|p| p.role == Role::Warrior)
+                    })
+                    .map(|e| e.id)
+                    .collect();
+                for (i, &a) in warriors.iter().enumerate() {
+                    for &b in &warriors[i + 1..] {
+                        if ctx.rng.random_bool(COMRADE_FRIENDSHIP_CHANCE) {
+                            form_bond(ctx, a, b, RelationshipKind::Friend, None, time);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Make two living persons rivals or friends, in both directions, recording
+/// the event that bound them. Existing bonds between the pair are left alone.
+fn form_bond(
+    ctx: &mut TickContext,
+    a: u64,
+    b: u64,
+    kind: RelationshipKind,
+    cause: Option<u64>,
+    time: SimTimestamp,
+) {
+    let living_person = |id: u64| {
+        ctx.world
+            .entities
+            .get(&id)
+            .is_some_and(|e| e.kind == EntityKind::Person && e.end.is_none())
+    };
+    if a == b || !living_person(a) || !living_person(b) {
+        return;
+    }
+    if helpers::has_active_rel_of_kind(ctx.world, a, b, RelationshipKind::Rival)
+        || helpers::has_active_rel_of_kind(ctx.world, a, b, RelationshipKind::Friend)
+    {
+        return;
+    }
+
+    let a_name = helpers::entity_name(ctx.world, a);
+    let b_name = helpers::entity_name(ctx.world, b);
+    let (event_kind, description) = if kind == RelationshipKind::Rival {
+        (
+            EventKind::Rivalry,
+            format!("{a_name} and {b_name} became bitter rivals"),
+        )
+    } else {
+        (
+            EventKind::Friendship,
+            format!("{a_name} and {b_name} became fast friends"),
+        )
+    };
+    let ev = match cause {
+        Some(cause) => ctx
+            .world
+            .add_caused_event(event_kind, time, description, cause),
+        None => ctx.world.add_event(event_kind, time, description),
+    };
+    ctx.world
+        .add_event_participant(ev, a, ParticipantRole::Subject);
+    ctx.world
+        .add_event_participant(ev, b, ParticipantRole::Object);
+    ctx.world.add_relationship(a, b, kind.clone(), time, ev);
+    ctx.world.add_relationship(b, a, kind, time, ev);
+}
+
 struct NpcInfo {
     id: u64,
     traits: Vec<Trait>,
@@ -206,6 +382,9 @@ enum DesireKind {
         target_faction_id: u64,
         _claim_strength: f64,
     },
+    FoundSettlement {
+        region_id: u64,
+    },
 }
 
 #[derive(Debug)]
@@ -272,18 +451,18 @@ fn evaluate_desires(
         .and_then(|e| e.data.as_faction())
         .map(|f| f.prestige)
         .unwrap_or(0.0);
-    let leader_prestige = ctx
-        .world
-        .entities
-        .values()
-        .find(|e| {
-            e.kind == EntityKind::Person
-                && e.end.is_none()
-                && e.has_active_rel(RelationshipKind::LeaderOf, faction_id)
-        })
+    let leader = ctx.world.entities.values().find(|e| {
+        e.kind == EntityKind::Person
+            && e.end.is_none()
+            && e.has_active_rel(RelationshipKind::LeaderOf, faction_id)
+    });
+    let leader_prestige = leader
         .and_then(|e| e.data.as_person())
         .map(|pd| pd.prestige)
         .unwrap_or(0.0);
+    let leader_is_rival = leader.is_some_and(|l| {
+        helpers::has_active_rel_of_kind(ctx.world, npc.id, l.id, RelationshipKind::Rival)
+    });
 
     // Faction context: is faction at war?
     let faction_at_war = ctx
@@ -344,6 +523,10 @@ fn evaluate_desires(
                 if faction_leaderless || leader_just_died {
                     urgency += 0.4;
                 }
+                // Personal grudge against whoever holds the seat
+                if leader_is_rival {
+                    urgency += RIVAL_LEADER_COUP_URGENCY;
+                }
                 urgency *= age_risk_factor;
                 urgency = urgency.max(0.0);
                 desires.push(ScoredDesire {
@@ -359,6 +542,19 @@ fn evaluate_desires(
                         urgency: office_urgency,
                     });
                 }
+
+                // FoundSettlement — low-status adventurers carve out their own domain
+                if npc.prestige < FRONTIER_MAX_PRESTIGE
+                    && let Some(region_id) = find_frontier_region(ctx, npc.id)
+                {
+                    let urgency = (FRONTIER_BASE_URGENCY
+                        + FRONTIER_INSTABILITY_URGENCY * instability)
+                        * age_risk_factor;
+                    desires.push(ScoredDesire {
+                        kind: DesireKind::FoundSettlement { region_id },
+                        urgency,
+                    });
+                }
             }
             Trait::Ambitious if npc.is_leader => {
                 // ExpandTerritory — look for enemy factions, fall back to weak neighbors
@@ -764,6 +960,9 @@ fn desire_to_action(desire: &ScoredDesire, _npc: &NpcInfo) -> Option<ActionKind>
         } => Some(ActionKind::PressClaim {
             target_faction_id: *target_faction_id,
         }),
+        DesireKind::FoundSettlement { region_id } => Some(ActionKind::FoundSettlement {
+            region_id: *region_id,
+        }),
     }
 }
 
@@ -781,6 +980,25 @@ fn find_enemy_faction_leader(ctx: &TickContext, faction_id: u64) -> Option<u64>
     helpers::faction_leader(ctx.world, enemy_faction)
 }
 
+/// Unclaimed land region next to the region of the NPC's home settlement.
+fn find_frontier_region(ctx: &TickContext, npc_id: u64) -> Option<u64> {
+    let home = ctx
+        .world
+        .entities
+        .get(&npc_id)?
+        .active_rels(RelationshipKind::LocatedIn)
+        .find(|id| {
+            ctx.world
+                .entities
+                .get(id)
+                .is_some_and(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+        })?;
+    let home_region = helpers::active_rel_target(ctx.world, home, RelationshipKind::LocatedIn)?;
+    helpers::adjacent_regions(ctx.world, home_region)
+        .into_iter()
+        .find(|&r| helpers::region_is_unclaimed(ctx.world, r))
+}
+
 fn find_potential_ally(ctx: &TickContext, faction_id: u64) -> Option<u64> {
     let faction = ctx.world.entities.get(&faction_id)?;
     let existing_rels: Vec<u64> = faction
@@ -1725,4 +1943,208 @@ mod tests {
             "ambitious leader without enemy should still get ExpandTerritory from expansion target: {desires:?}"
         );
     }
+
+    #[test]
+    fn scenario_leadership_contest_makes_rivals() {
+        let mut s = Scenario::at_year(100);
+        let faction_id = s.faction("The Republic").stability(0.8).id();
+        let challenger = s
+            .person("Cato", faction_id)
+            .traits(vec![Trait::Content])
+            .last_action_year(100)
+            .id();
+        let incumbent = s
+            .person("Cicero", faction_id)
+            .traits(vec![Trait::Content])
+            .last_action_year(100)
+            .id();
+        s.make_leader(incumbent, faction_id);
+        let mut world = s.build();
+
+        // Cato lost the election to the sitting leader
+        let ev = world.add_event(
+            EventKind::Election,
+            world.current_time,
+            "Cato failed to win election".to_string(),
+        );
+        world.add_event_participant(ev, challenger, ParticipantRole::Instigator);
+        world.add_event_participant(ev, incumbent, ParticipantRole::Subject);
+        world.add_event_participant(ev, faction_id, ParticipantRole::Object);
+
+        tick_agency(&mut world);
+
+        for (a, b) in [(challenger, incumbent), (incumbent, challenger)] {
+            assert!(
+                world.entities[&a].has_active_rel(RelationshipKind::Rival, b),
+                "{a} should count {b} as a rival"
+            );
+        }
+        let rivalry = world
+            .events
+            .values()
+            .find(|e| e.kind == EventKind::Rivalry)
+            .expect("rivalry event recorded");
+        assert_eq!(rivalry.caused_by, Some(ev));
+        assert!(
+            world
+                .event_participants
+                .iter()
+                .any(|p| p.event_id == rivalry.id && p.entity_id == challenger)
+        );
+
+        // A second pass does not duplicate the rivalry
+        tick_agency(&mut world);
+        let rival_rels = world.entities[&challenger]
+            .active_rels(RelationshipKind::Rival)
+            .count();
+        assert_eq!(rival_rels, 1);
+    }
+
+    #[test]
+    fn scenario_rival_leader_boosts_seize_power() {
+        let mut s = Scenario::at_year(100);
+        let faction_id = s.faction("The Empire").stability(0.6).id();
+        let npc_id = s
+            .person("Brutus", faction_id)
+            .traits(vec![Trait::Ambitious])
+            .id();
+        let leader_id = s
+            .person("Caesar", faction_id)
+            .traits(vec![Trait::Content])
+            .id();
+        s.make_leader(leader_id, faction_id);
+        let mut world = s.build();
+
+        let npc_info = NpcInfo {
+            id: npc_id,
+            traits: vec![Trait::Ambitious],
+            faction_id: Some(faction_id),
+            is_leader: false,
+            last_action: SimTimestamp::default(),
+            born: SimTimestamp::from_year(70),
+            prestige: 0.0,
+        };
+        let seize_urgency = |world: &mut crate::model::World| {
+            let mut rng = SmallRng::seed_from_u64(42);
+            let mut signals_out = Vec::new();
+            let ctx = TickContext {
+                world,
+                rng: &mut rng,
+                signals: &mut signals_out,
+                inbox: &[],
+            };
+            evaluate_desires(&npc_info, &ctx, &[], SimTimestamp::from_year(100))
+                .iter()
+                .find(|d| matches!(d.kind, DesireKind::SeizePower { .. }))
+                .map(|d| d.urgency)
+                .unwrap_or(0.0)
+        };
+
+        let before = seize_urgency(&mut world);
+        let ev = world.add_event(
+            EventKind::Rivalry,
+            world.current_time,
+            "Brutus and Caesar became rivals".to_string(),
+        );
+        world.add_relationship(
+            npc_id,
+            leader_id,
+            RelationshipKind::Rival,
+            world.current_time,
+            ev,
+        );
+        let after = seize_urgency(&mut world);
+
+        assert!(
+            after > before,
+            "rivalry with the leader should raise coup urgency: {after} > {before}"
+        );
+    }
+
+    fn frontier_scenario() -> (Scenario, u64, u64) {
+        let mut s = Scenario::at_year(100);
+        let home_region = s.add_region("Heartland");
+        let frontier = s.add_region("Frontier");
+        s.make_adjacent(home_region, frontier);
+        let faction_id = s.faction("The Old Kingdom").stability(0.9).id();
+        let home = s
+            .settlement("Homestead", faction_id, home_region)
+            .population(400)
+            .id();
+        let npc_id = s
+            .person_in("Brenna", faction_id, home)
+            .traits(vec![Trait::Ambitious])
+            .id();
+        let leader_id = s
+            .person_in("Old King", faction_id, home)
+            .traits(vec![Trait::Content])
+            .prestige(0.8)
+            .id();
+        s.make_leader(leader_id, faction_id);
+        (s, npc_id, frontier)
+    }
+
+    #[test]
+    fn scenario_ambitious_frontier_npc_wants_to_found_settlement() {
+        let (s, npc_id, frontier) = frontier_scenario();
+        let mut world = s.build();
+        let faction_id = helpers::active_rel_target(&world, npc_id, RelationshipKind::MemberOf);
+        let found_region = |world: &mut crate::model::World, prestige: f64| {
+            let npc_info = NpcInfo {
+                id: npc_id,
+                traits: vec![Trait::Ambitious],
+                faction_id,
+                is_leader: false,
+                last_action: SimTimestamp::default(),
+                born: SimTimestamp::from_year(70),
+                prestige,
+            };
+            let mut rng = SmallRng::seed_from_u64(42);
+            let mut signals_out = Vec::new();
+            let ctx = TickContext {
+                world,
+                rng: &mut rng,
+                signals: &mut signals_out,
+                inbox: &[],
+            };
+            evaluate_desires(&npc_info, &ctx, &[], SimTimestamp::from_year(100))
+                .iter()
+                .find_map(|d| match d.kind {
+                    DesireKind::FoundSettlement { region_id } => Some(region_id),
+                    _ => None,
+                })
+        };
+
+        assert_eq!(found_region(&mut world, 0.0), Some(frontier));
+        assert_eq!(
+            found_region(&mut world, 0.8),
+            None,
+            "renowned NPCs stay home"
+        );
+    }
+
+    #[test]
+    fn scenario_ambitious_npc_founds_frontier_faction_across_seeds() {
+        // Coups compete for the same ambition, so some seeds end in a coup instead
+        let founded = (0..20)
+            .filter(|&seed| {
+                let (s, npc_id, frontier) = frontier_scenario();
+                let mut systems: Vec<Box<dyn crate::sim::SimSystem>> = vec![
+                    Box::new(AgencySystem::new()),
+                    Box::new(crate::sim::ActionSystem),
+                ];
+                let world = s.run(&mut systems, 30, seed);
+                world.living(EntityKind::Settlement).any(|e| {
+                    e.has_active_rel(RelationshipKind::LocatedIn, frontier)
+                        && e.active_rel(RelationshipKind::MemberOf).is_some_and(|f| {
+                            world.entities[&npc_id].has_active_rel(RelationshipKind::LeaderOf, f)
+                        })
+                })
+            })
+            .count();
+        assert!(
+            founded >= 10,
+            "an ambitious adventurer should often found a frontier faction, got {founded}/20"
+        );
+    }
 }
@@ -103,6 +103,15 @@ const UPGRADE_DEFAULT_BASE_COST: f64 = 20.0;
 /// Annual probability that an eligible building is upgraded.
 const UPGRADE_PROBABILITY: f64 = 0.2;
 
+// ---------------------------------------------------------------------------
+// Wall repair
+// ---------------------------------------------------------------------------
+
+/// Wall integrity restored per peacetime year.
+const WALL_REPAIR_RATE: f64 = 0.25;
+/// Treasury cost to restore a full point of integrity, per fortification level.
+const WALL_REPAIR_COST_PER_LEVEL: f64 = 20.0;
+
 // ---------------------------------------------------------------------------
 // Conquest damage
 // ---------------------------------------------------------------------------
@@ -135,6 +144,7 @@ impl SimSystem for BuildingSystem {
 
         compute_building_bonuses(ctx);
         decay_buildings(ctx, time, current_year, year_event);
+        repair_walls(ctx, time, current_year, year_event);
         construct_buildings(ctx, time, current_year, year_event);
         upgrade_buildings(ctx, time, current_year, year_event);
     }
@@ -885,6 +895,89 @@ fn upgrade_buildings(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Wall repair
+// ---------------------------------------------------------------------------
+
+/// Mend siege-worn walls in settlements whose faction is at peace, paid for
+/// from the faction treasury. Repairs as much as the treasury can afford.
+fn repair_walls(ctx: &mut TickContext, time: SimTimestamp, current_year: u32, year_event: u64) {
+    struct RepairCandidate {
+        settlement_id: u64,
+        faction_id: u64,
+        fort_level: u8,
+        integrity: f64,
+    }
+
+    let candidates: Vec<RepairCandidate> = ctx
+        .world
+        .entities
+        .values()
+        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+        .filter_map(|e| {
+            let sd = e.data.as_settlement()?;
+            if sd.fortification_level == 0 || sd.wall_integrity >= 1.0 || sd.active_siege.is_some()
+            {
+                return None;
+            }
+            let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
+            let at_war = ctx
+                .world
+                .entities
+                .get(&faction_id)
+                .is_some_and(|f| f.active_rels(RelationshipKind::AtWar).next().is_some());
+            if at_war {
+                return None;
+            }
+            Some(RepairCandidate {
+                settlement_id: e.id,
+                faction_id,
+                fort_level: sd.fortification_level,
+                integrity: sd.wall_integrity,
+            })
+        })
+        .collect();
+
+    for c in candidates {
+        let cost_per_point = WALL_REPAIR_COST_PER_LEVEL * c.fort_level as f64;
+        let treasury = ctx
+            .world
+            .entities
+            .get(&c.faction_id)
+            .and_then(|e| e.data.as_faction())
+            .map(|f| f.treasury)
+            .unwrap_or(0.0);
+        let amount = WALL_REPAIR_RATE
+            .min(1.0 - c.integrity)
+            .min(treasury.max(0.0) / cost_per_point);
+        if amount <= 0.0 {
+            continue;
+        }
+        let cost = amount * cost_per_point;
+        let new_integrity = (c.integrity + amount).min(1.0);
+
+        ctx.world.faction_mut(c.faction_id).treasury -= cost;
+        ctx.world.settlement_mut(c.settlement_id).wall_integrity = new_integrity;
+
+        let settlement_name = helpers::entity_name(ctx.world, c.settlement_id);
+        let ev = ctx.world.add_caused_event(
+            EventKind::WallsRepaired,
+            time,
+            format!("The walls of {settlement_name} were repaired in year {current_year}"),
+            year_event,
+        );
+        ctx.world
+            .add_event_participant(ev, c.settlement_id, ParticipantRole::Subject);
+        ctx.world.record_change(
+            c.settlement_id,
+            ev,
+            "wall_integrity",
+            serde_json::json!(c.integrity),
+            serde_json::json!(new_integrity),
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Cross-system: siege/conquest damage
 // ---------------------------------------------------------------------------
@@ -1163,6 +1256,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scenario_peacetime_repairs_walls() {
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone("Town");
+        let _ = s.faction_mut(setup.faction).treasury(500.0);
+        let _ = s.settlement_mut(setup.settlement).fortification_level(2);
+        let sett = setup.settlement;
+        let faction = setup.faction;
+        let mut world = s.build();
+        world.settlement_mut(sett).wall_integrity = 0.4;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        for year in 100..103 {
+            let mut signals = Vec::new();
+            let (mut ctx, year_event) = make_ctx(&mut world, &mut rng, &mut signals);
+            repair_walls(&mut ctx, SimTimestamp::from_year(year), year, year_event);
+        }
+
+        assert_approx(
+            world.settlement(sett).wall_integrity,
+            1.0,
+            1e-9,
+            "walls restored after three quiet years",
+        );
+        assert_approx(
+            world.faction(faction).treasury,
+            500.0 - 0.6 * WALL_REPAIR_COST_PER_LEVEL * 2.0,
+            1e-9,
+            "repairs paid from treasury",
+        );
+        assert_eq!(testutil::count_events(&world, &EventKind::WallsRepaired), 3);
+    }
+
+    #[test]
+    fn scenario_no_wall_repair_under_siege() {
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone("Town");
+        let _ = s.faction_mut(setup.faction).treasury(500.0);
+        let _ = s.settlement_mut(setup.settlement).fortification_level(2);
+        let sett = setup.settlement;
+        let mut world = s.build();
+        {
+            let sd = world.settlement_mut(sett);
+            sd.wall_integrity = 0.4;
+            sd.active_siege = Some(ActiveSiege {
+                attacker_army_id: 999,
+                attacker_faction_id: 888,
+                started: SimTimestamp::from_year_month(99, 1),
+                months_elapsed: 3,
+                civilian_deaths: 0,
+            });
+        }
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let (mut ctx, year_event) = make_ctx(&mut world, &mut rng, &mut signals);
+        repair_walls(&mut ctx, SimTimestamp::from_year(100), 100, year_event);
+
+        assert_eq!(world.settlement(sett).wall_integrity, 0.4);
+    }
+
     #[test]
     fn scenario_capacity_limit_respected() {
         let mut s = Scenario::at_year(100);
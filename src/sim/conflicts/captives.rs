@@ -0,0 +1,416 @@
+//! Prisoners of war: notable figures taken alive in battle, held by the
+//! victor, and ransomed, executed, or released when the war ends.
+
+use rand::Rng;
+
+use crate::model::traits::{Trait, has_trait};
+use crate::model::{
+    Describer, EnglishDescriber, EntityKind, EventPayload, ParticipantRole, RelationshipKind,
+    SimTimestamp, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::grievance as grv;
+use crate::sim::helpers;
+use crate::sim::signal::{Signal, SignalKind};
+
+/// Chance a high-value person who would have fallen is taken alive instead.
+pub(super) const CAPTURE_CHANCE: f64 = 0.5;
+/// Personal prestige at which someone other than a ruler is worth a ransom.
+const CAPTIVE_MIN_PRESTIGE: f64 = 0.3;
+const RANSOM_BASE: f64 = 30.0;
+const RANSOM_PRESTIGE_FACTOR: f64 = 100.0;
+/// Extra ransom asked for a (former) ruler.
+const RANSOM_RULER_BONUS: f64 = 50.0;
+/// Chance an ordinary captor executes a captive at war's end; ruthless
+/// captors always do, honorable ones never.
+const EXECUTION_BASE_CHANCE: f64 = 0.15;
+/// Grievance the captive's faction holds against the captor after an execution.
+const EXECUTION_GRIEVANCE: f64 = 0.4;
+/// Diplomatic trust a captor earns by releasing a captive unransomed.
+const RELEASE_TRUST_GAIN: f64 = 0.1;
+
+/// Faction holding a person captive, if any.
+pub fn captor_of(world: &World, person_id: u64) -> Option<u64> {
+    world
+        .entities
+        .get(&person_id)?
+        .active_rel(RelationshipKind::CaptiveOf)
+}
+
+/// Rulers and prestigious figures are worth more alive than dead.
+pub(super) fn is_high_value(world: &World, person_id: u64) -> bool {
+    world.entities.get(&person_id).is_some_and(|e| {
+        e.active_rel(RelationshipKind::LeaderOf).is_some()
+            || e.data
+                .as_person()
+                .is_some_and(|pd| pd.prestige >= CAPTIVE_MIN_PRESTIGE)
+    })
+}
+
+/// Take `person_id` prisoner. A captured ruler loses their throne, leaving a
+/// vacancy just as a death would.
+pub(super) fn capture_person(
+    ctx: &mut TickContext,
+    person_id: u64,
+    from_faction_id: u64,
+    captor_faction_id: u64,
+    battle_ev: u64,
+    time: SimTimestamp,
+) {
+    let payload = EventPayload::Capture {
+        person_id,
+        captor_faction_id,
+        from_faction_id,
+    };
+    let description = EnglishDescriber
+        .describe_payload(ctx.world, &payload, time)
+        .expect("captures have an English template");
+    let ev = ctx
+        .world
+        .add_caused_structured_event(payload, time, description, battle_ev);
+    ctx.world
+        .add_event_participant(ev, person_id, ParticipantRole::Subject);
+    ctx.world
+        .add_event_participant(ev, captor_faction_id, ParticipantRole::Attacker);
+
+    let Some(person) = ctx.world.entities.get(&person_id) else {
+        return;
+    };
+    let ruled: Vec<u64> = person.active_rels(RelationshipKind::LeaderOf).collect();
+    let governed: Vec<u64> = person.active_rels(RelationshipKind::GovernorOf).collect();
+    for faction_id in ruled {
+        ctx.world
+            .end_relationship(person_id, faction_id, RelationshipKind::LeaderOf, time, ev);
+        ctx.signals.push(Signal {
+            event_id: ev,
+            kind: SignalKind::LeaderVacancy {
+                faction_id,
+                previous_leader_id: person_id,
+            },
+        });
+    }
+    for settlement_id in governed {
+        ctx.world.end_relationship(
+            person_id,
+            settlement_id,
+            RelationshipKind::GovernorOf,
+            time,
+            ev,
+        );
+    }
+    ctx.world.add_relationship(
+        person_id,
+        captor_faction_id,
+        RelationshipKind::CaptiveOf,
+        time,
+        ev,
+    );
+}
+
+/// Ransom demanded for a captive: more for the prestigious and for anyone
+/// who has ever ruled.
+pub(super) fn ransom_price(world: &World, person_id: u64) -> f64 {
+    let Some(person) = world.entities.get(&person_id) else {
+        return RANSOM_BASE;
+    };
+    let prestige = person.data.as_person().map_or(0.0, |pd| pd.prestige);
+    let was_ruler = person
+        .relationships
+        .iter()
+        .any(|r| r.kind == RelationshipKind::LeaderOf);
+    RANSOM_BASE
+        + prestige * RANSOM_PRESTIGE_FACTOR
+        + if was_ruler { RANSOM_RULER_BONUS } else { 0.0 }
+}
+
+/// Settle the fate of every captive either side holds from the other, now
+/// that the war between them is over.
+pub(super) fn resolve_captives(
+    ctx: &mut TickContext,
+    faction_a: u64,
+    faction_b: u64,
+    war_end_ev: u64,
+    time: SimTimestamp,
+) {
+    for (captor_id, home_id) in [(faction_a, faction_b), (faction_b, faction_a)] {
+        let captives: Vec<u64> = ctx
+            .world
+            .living(EntityKind::Person)
+            .filter(|e| {
+                e.has_active_rel(RelationshipKind::CaptiveOf, captor_id)
+                    && e.has_active_rel(RelationshipKind::MemberOf, home_id)
+            })
+            .map(|e| e.id)
+            .collect();
+        if captives.is_empty() {
+            continue;
+        }
+
+        let (ruthless, honorable) =
+            helpers::faction_leader_entity(ctx.world, captor_id).map_or((false, false), |leader| {
+                (
+                    has_trait(leader, &Trait::Ruthless),
+                    has_trait(leader, &Trait::Honorable),
+                )
+            });
+        for person_id in captives {
+            let execute = ruthless || (!honorable && ctx.rng.random_bool(EXECUTION_BASE_CHANCE));
+            let price = ransom_price(ctx.world, person_id);
+            if execute {
+                execute_captive(ctx, person_id, captor_id, home_id, war_end_ev, time);
+            } else if ctx.world.faction(home_id).treasury >= price {
+                ransom_captive(ctx, person_id, captor_id, home_id, price, war_end_ev, time);
+            } else {
+                release_captive(ctx, person_id, captor_id, war_end_ev, time);
+            }
+        }
+    }
+}
+
+fn execute_captive(
+    ctx: &mut TickContext,
+    person_id: u64,
+    captor_id: u64,
+    home_id: u64,
+    war_end_ev: u64,
+    time: SimTimestamp,
+) {
+    let ev = captive_event(
+        ctx.world,
+        EventPayload::Execution {
+            person_id,
+            captor_faction_id: captor_id,
+        },
+        person_id,
+        captor_id,
+        war_end_ev,
+        time,
+    );
+    helpers::end_all_person_relationships(ctx.world, person_id, time, ev);
+    ctx.world.end_entity(person_id, time, ev);
+    ctx.signals.push(Signal {
+        event_id: ev,
+        kind: SignalKind::EntityDied {
+            entity_id: person_id,
+        },
+    });
+    // Killing prisoners is remembered as an atrocity
+    grv::add_atrocity_grievance(
+        ctx.world,
+        home_id,
+        captor_id,
+        EXECUTION_GRIEVANCE,
+        "execution",
+        time,
+        ev,
+    );
+}
+
+fn ransom_captive(
+    ctx: &mut TickContext,
+    person_id: u64,
+    captor_id: u64,
+    payer_id: u64,
+    amount: f64,
+    war_end_ev: u64,
+    time: SimTimestamp,
+) {
+    let ev = captive_event(
+        ctx.world,
+        EventPayload::Ransom {
+            person_id,
+            captor_faction_id: captor_id,
+            payer_faction_id: payer_id,
+            amount,
+        },
+        person_id,
+        captor_id,
+        war_end_ev,
+        time,
+    );
+    ctx.world
+        .add_event_participant(ev, payer_id, ParticipantRole::Object);
+    for (faction_id, delta) in [(payer_id, -amount), (captor_id, amount)] {
+        let old = ctx.world.faction(faction_id).treasury;
+        ctx.world.faction_mut(faction_id).treasury = old + delta;
+        ctx.world.record_change(
+            faction_id,
+            ev,
+            "treasury",
+            serde_json::json!(old),
+            serde_json::json!(old + delta),
+        );
+    }
+    ctx.world
+        .end_relationship(person_id, captor_id, RelationshipKind::CaptiveOf, time, ev);
+}
+
+fn release_captive(
+    ctx: &mut TickContext,
+    person_id: u64,
+    captor_id: u64,
+    war_end_ev: u64,
+    time: SimTimestamp,
+) {
+    let ev = captive_event(
+        ctx.world,
+        EventPayload::Release {
+            person_id,
+            captor_faction_id: captor_id,
+        },
+        person_id,
+        captor_id,
+        war_end_ev,
+        time,
+    );
+    let old = ctx.world.faction(captor_id).diplomatic_trust;
+    let new = (old + RELEASE_TRUST_GAIN).min(1.0);
+    ctx.world.faction_mut(captor_id).diplomatic_trust = new;
+    ctx.world.record_change(
+        captor_id,
+        ev,
+        "diplomatic_trust",
+        serde_json::json!(old),
+        serde_json::json!(new),
+    );
+    ctx.world
+        .end_relationship(person_id, captor_id, RelationshipKind::CaptiveOf, time, ev);
+}
+
+/// Record the fate of a captive, caused by the end of the war.
+fn captive_event(
+    world: &mut World,
+    payload: EventPayload,
+    person_id: u64,
+    captor_id: u64,
+    war_end_ev: u64,
+    time: SimTimestamp,
+) -> u64 {
+    let description = EnglishDescriber
+        .describe_payload(world, &payload, time)
+        .expect("captive fates have an English template");
+    let ev = world.add_caused_structured_event(payload, time, description, war_end_ev);
+    world.add_event_participant(ev, person_id, ParticipantRole::Subject);
+    world.add_event_participant(ev, captor_id, ParticipantRole::Attacker);
+    ev
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use super::*;
+    use crate::model::EventKind;
+    use crate::scenario::Scenario;
+
+    fn ts(year: u32) -> SimTimestamp {
+        SimTimestamp::from_year(year)
+    }
+
+    /// Two kingdoms; `captor_traits` go to the captor's ruler. The home
+    /// kingdom's ruler is returned as the captive.
+    fn captive_scenario(captor_traits: Vec<Trait>, home_treasury: f64) -> (World, u64, u64, u64) {
+        let mut s = Scenario::at_year(100);
+        let captor = s.add_kingdom_with(
+            "Captor",
+            |fd| fd.treasury = 100.0,
+            |_| {},
+            |pd| pd.traits = captor_traits,
+        );
+        let home = s.add_kingdom_with(
+            "Home",
+            |fd| fd.treasury = home_treasury,
+            |_| {},
+            |pd| pd.prestige = 0.5,
+        );
+        s.make_at_war(captor.faction, home.faction);
+        (s.build(), captor.faction, home.faction, home.leader)
+    }
+
+    fn capture(world: &mut World, person: u64, home: u64, captor: u64) -> Vec<Signal> {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let battle_ev = world.add_event(EventKind::Battle, ts(100), "Battle".into());
+        let mut ctx = TickContext {
+            world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        capture_person(&mut ctx, person, home, captor, battle_ev, ts(100));
+        signals
+    }
+
+    fn resolve(world: &mut World, captor: u64, home: u64) {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let treaty_ev = world.add_event(EventKind::Treaty, ts(101), "Peace".into());
+        let mut ctx = TickContext {
+            world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        resolve_captives(&mut ctx, captor, home, treaty_ev, ts(101));
+    }
+
+    #[test]
+    fn captured_ruler_loses_throne_and_is_held() {
+        let (mut world, captor, home, ruler) = captive_scenario(vec![], 0.0);
+        assert!(is_high_value(&world, ruler));
+
+        let signals = capture(&mut world, ruler, home, captor);
+
+        assert_eq!(captor_of(&world, ruler), Some(captor));
+        assert!(helpers::faction_leader_entity(&world, home).is_none());
+        assert!(signals.iter().any(|s| matches!(
+            s.kind,
+            SignalKind::LeaderVacancy { faction_id, previous_leader_id }
+                if faction_id == home && previous_leader_id == ruler
+        )));
+    }
+
+    #[test]
+    fn ransom_moves_treasury_from_home_to_captor() {
+        let (mut world, captor, home, ruler) = captive_scenario(vec![Trait::Honorable], 500.0);
+        capture(&mut world, ruler, home, captor);
+        let price = ransom_price(&world, ruler);
+        assert!(price > RANSOM_BASE + RANSOM_RULER_BONUS);
+
+        resolve(&mut world, captor, home);
+
+        assert!(world.entities[&ruler].end.is_none());
+        assert_eq!(captor_of(&world, ruler), None);
+        assert!((world.faction(home).treasury - (500.0 - price)).abs() < 1e-9);
+        assert!((world.faction(captor).treasury - (100.0 + price)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unaffordable_ransom_means_release() {
+        let (mut world, captor, home, ruler) = captive_scenario(vec![Trait::Honorable], 0.0);
+        capture(&mut world, ruler, home, captor);
+        let trust_before = world.faction(captor).diplomatic_trust;
+
+        resolve(&mut world, captor, home);
+
+        assert_eq!(captor_of(&world, ruler), None);
+        assert!(world.entities[&ruler].end.is_none());
+        assert_eq!(world.faction(home).treasury, 0.0);
+        assert!(world.faction(captor).diplomatic_trust >= trust_before);
+    }
+
+    #[test]
+    fn ruthless_captor_executes_and_earns_grievance() {
+        let (mut world, captor, home, ruler) = captive_scenario(vec![Trait::Ruthless], 500.0);
+        capture(&mut world, ruler, home, captor);
+        let before = grv::get_grievance(&world, home, captor);
+
+        resolve(&mut world, captor, home);
+
+        assert!(world.entities[&ruler].end.is_some());
+        assert_eq!(captor_of(&world, ruler), None);
+        assert_eq!(world.faction(home).treasury, 500.0);
+        assert!(grv::get_grievance(&world, home, captor) >= before + EXECUTION_GRIEVANCE - 1e-9);
+    }
+}
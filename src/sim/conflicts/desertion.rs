@@ -0,0 +1,235 @@
+//! Hunger desertion: starving, dispirited soldiers slip away from the ranks.
+
+use rand::Rng;
+
+use super::{army_strength, scatter_soldiers};
+use crate::model::{
+    EntityKind, EventPayload, GovernmentType, ParticipantRole, RelationshipKind, SimTimestamp,
+    World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::helpers;
+
+/// Morale below which an army without supply starts losing men to desertion.
+const DESERTION_MORALE_THRESHOLD: f64 = 0.3;
+/// Share of a starving army's strength that deserts in a month.
+const DESERTION_RATE: f64 = 0.1;
+/// Share of deserters who make their way home; the rest turn outlaw.
+const DESERTER_HOMEWARD_SHARE: f64 = 0.6;
+
+/// Whether an army with `supply` and `morale` is ripe for desertion.
+pub(super) fn is_deserting(supply: f64, morale: f64) -> bool {
+    supply <= 0.0 && morale < DESERTION_MORALE_THRESHOLD
+}
+
+/// A bandit clan's army in `region_id` or a neighbouring region, strongest first.
+fn nearby_bandit_army(world: &World, region_id: u64) -> Option<u64> {
+    world
+        .living(EntityKind::Army)
+        .filter_map(|e| {
+            let ad = e.data.as_army()?;
+            let bandit = world
+                .entities
+                .get(&ad.faction_id)
+                .and_then(|f| f.data.as_faction())
+                .is_some_and(|fd| fd.government_type == GovernmentType::BanditClan);
+            let army_region = e.active_rel(RelationshipKind::LocatedIn)?;
+            let nearby = army_region == region_id
+                || world
+                    .entities
+                    .get(&army_region)
+                    .is_some_and(|r| r.has_active_rel(RelationshipKind::AdjacentTo, region_id));
+            (bandit && nearby).then_some((ad.strength, e.id))
+        })
+        .max_by_key(|&(strength, id)| (strength, std::cmp::Reverse(id)))
+        .map(|(_, id)| id)
+}
+
+/// Settlements deserters of `faction_id` head home to: those in the army's
+/// home region, or anywhere in the faction if none remain there.
+fn home_settlements(world: &World, faction_id: u64, home_region: Option<u64>) -> Vec<u64> {
+    let settlements = helpers::faction_settlements(world, faction_id);
+    let at_home: Vec<u64> = settlements
+        .iter()
+        .copied()
+        .filter(|&sid| {
+            home_region.is_some_and(|region| {
+                world
+                    .entities
+                    .get(&sid)
+                    .is_some_and(|e| e.has_active_rel(RelationshipKind::LocatedIn, region))
+            })
+        })
+        .collect();
+    if at_home.is_empty() {
+        settlements
+    } else {
+        at_home
+    }
+}
+
+/// Soldiers desert a starving, low-morale army. Most walk home and rejoin
+/// their settlements' population; the rest swell a nearby bandit clan, or
+/// vanish into the countryside if there is none. Returns the deserter count.
+pub(super) fn desert_starving_army(
+    ctx: &mut TickContext,
+    army_id: u64,
+    region_id: u64,
+    time: SimTimestamp,
+    current_year: u32,
+) -> u32 {
+    let Some(ad) = ctx
+        .world
+        .entities
+        .get(&army_id)
+        .filter(|e| e.end.is_none())
+        .and_then(|e| e.data.as_army())
+    else {
+        return 0;
+    };
+    if ad.is_mercenary || helpers::is_non_state_faction(ctx.world, ad.faction_id) {
+        return 0;
+    }
+    let faction_id = ad.faction_id;
+    let home_region = Some(ad.home_region_id).filter(|&id| id != 0);
+
+    let strength = army_strength(ctx.world, army_id);
+    let deserters = ((strength as f64 * DESERTION_RATE * ctx.rng.random_range(0.5..1.5)).round()
+        as u32)
+        .min(strength);
+    if deserters == 0 {
+        return 0;
+    }
+    let homeward = (deserters as f64 * DESERTER_HOMEWARD_SHARE).round() as u32;
+    let outlaws = deserters - homeward;
+    let remaining = strength - deserters;
+
+    let army_name = helpers::entity_name(ctx.world, army_id);
+    let ev = ctx.world.add_structured_event(
+        EventPayload::Desertion {
+            army_id,
+            faction_id,
+            deserters,
+            homeward,
+            outlaws,
+        },
+        time,
+        format!("{deserters} starving soldiers deserted {army_name} in year {current_year}"),
+    );
+    ctx.world
+        .add_event_participant(ev, army_id, ParticipantRole::Subject);
+    ctx.world.army_mut(army_id).strength = remaining;
+    ctx.world.record_change(
+        army_id,
+        ev,
+        "strength",
+        serde_json::json!(strength),
+        serde_json::json!(remaining),
+    );
+
+    let settlements = home_settlements(ctx.world, faction_id, home_region);
+    scatter_soldiers(ctx.world, &settlements, homeward, ev);
+
+    if outlaws > 0
+        && let Some(bandit_army) = nearby_bandit_army(ctx.world, region_id)
+    {
+        let old = army_strength(ctx.world, bandit_army);
+        ctx.world.army_mut(bandit_army).strength = old + outlaws;
+        ctx.world
+            .add_event_participant(ev, bandit_army, ParticipantRole::Object);
+        ctx.world.record_change(
+            bandit_army,
+            ev,
+            "strength",
+            serde_json::json!(old),
+            serde_json::json!(old + outlaws),
+        );
+    }
+
+    if remaining == 0 {
+        ctx.world.end_entity(army_id, time, ev);
+    }
+    deserters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EventKind;
+    use crate::scenario::Scenario;
+    use crate::sim::conflicts::apply_supply_and_attrition;
+    use crate::testutil::count_events;
+    use crate::worldgen::terrain::Terrain;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn only_starving_dispirited_armies_desert() {
+        assert!(is_deserting(0.0, 0.1));
+        assert!(!is_deserting(0.5, 0.1));
+        assert!(!is_deserting(0.0, 0.5));
+    }
+
+    #[test]
+    fn scenario_starving_army_deserts_home_and_to_bandits() {
+        let mut s = Scenario::at_year(100);
+        let homeland = s.add_region("Homeland");
+        let wastes = s.add_region_with("Wastes", |rd| rd.terrain = Terrain::Desert);
+        let badlands = s.add_region_with("Badlands", |rd| rd.terrain = Terrain::Desert);
+        s.make_adjacent(wastes, badlands);
+
+        let kingdom = s.add_faction("Kingdom");
+        let hometown = s
+            .settlement("Hometown", kingdom, homeland)
+            .population(500)
+            .id();
+        let army = s.add_army_with("Host", kingdom, wastes, 500, |ad| {
+            ad.supply = 0.0;
+            ad.morale = 0.1;
+            ad.home_region_id = homeland;
+        });
+
+        let bandits = s
+            .faction("Bandits")
+            .government_type(GovernmentType::BanditClan)
+            .id();
+        let warband = s.add_army("Warband", bandits, badlands, 40);
+        let mut world = s.build();
+        let hometown_pop = world.settlement(hometown).population;
+
+        let time = world.current_time;
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world: &mut world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        apply_supply_and_attrition(&mut ctx, time, time.year());
+
+        assert_eq!(count_events(&world, &EventKind::Desertion), 1);
+        let Some(EventPayload::Desertion {
+            deserters,
+            homeward,
+            outlaws,
+            ..
+        }) = world
+            .events
+            .values()
+            .find_map(|e| EventPayload::from_data(&e.data))
+        else {
+            panic!("desertion should carry a structured payload");
+        };
+        assert_eq!(homeward + outlaws, deserters);
+        assert!(world.army(army).strength < 500);
+        assert!(
+            world.settlement(hometown).population > hometown_pop,
+            "homeward deserters should rejoin their settlement"
+        );
+        assert!(
+            world.army(warband).strength > 40,
+            "outlaw deserters should swell the nearby bandits"
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::model::{MilitaryDoctrine, World};
+use crate::worldgen::terrain::Terrain;
+
+// Field battle constants
+/// Cavalry power on open ground, where charges can be pressed home.
+const CAVALRY_OPEN_GROUND: f64 = 1.5;
+/// Cavalry power in broken country, where horses cannot charge.
+const CAVALRY_ROUGH_GROUND: f64 = 0.7;
+/// Ranged power with cover or high ground to shoot from.
+const RANGED_COVER: f64 = 1.2;
+/// Ranged power in the open, where archers are easily ridden down.
+const RANGED_OPEN_GROUND: f64 = 0.9;
+/// Siege trains fight poorly in the field anywhere.
+const SIEGE_FIELD_POWER: f64 = 0.8;
+/// Power bonus when a doctrine meets the one it counters.
+const COUNTER_BONUS: f64 = 1.2;
+
+// Assault constants
+const CAVALRY_ASSAULT: f64 = 0.6;
+const RANGED_ASSAULT: f64 = 0.9;
+const SIEGE_ASSAULT: f64 = 1.5;
+
+// Movement constants
+/// Chance per month a siege train stalls entering rough country.
+pub(super) const SIEGE_TRAIN_STALL_CHANCE: f64 = 0.5;
+
+/// The doctrine `faction_id`'s armies fight by, Infantry if unknown.
+pub(crate) fn faction_doctrine(world: &World, faction_id: u64) -> MilitaryDoctrine {
+    world
+        .entities
+        .get(&faction_id)
+        .and_then(|e| e.data.as_faction())
+        .map(|fd| fd.doctrine)
+        .unwrap_or_default()
+}
+
+/// Terrain of `region_id`, if it is a region.
+pub(crate) fn region_terrain(world: &World, region_id: u64) -> Option<Terrain> {
+    Some(world.entities.get(&region_id)?.data.as_region()?.terrain)
+}
+
+/// Ground where horses can charge.
+pub(crate) fn is_open_ground(terrain: Terrain) -> bool {
+    matches!(
+        terrain,
+        Terrain::Plains | Terrain::Desert | Terrain::Tundra | Terrain::Coast
+    )
+}
+
+/// Broken, wooded, or boggy ground.
+pub(crate) fn is_rough_ground(terrain: Terrain) -> bool {
+    matches!(
+        terrain,
+        Terrain::Mountains
+            | Terrain::Hills
+            | Terrain::Forest
+            | Terrain::Jungle
+            | Terrain::Swamp
+            | Terrain::Volcanic
+    )
+}
+
+/// Multiplier on a doctrine's field power on `terrain`.
+pub(crate) fn terrain_factor(doctrine: MilitaryDoctrine, terrain: Terrain) -> f64 {
+    match doctrine {
+        MilitaryDoctrine::Infantry => 1.0,
+        MilitaryDoctrine::Cavalry if is_open_ground(terrain) => CAVALRY_OPEN_GROUND,
+        MilitaryDoctrine::Cavalry if is_rough_ground(terrain) => CAVALRY_ROUGH_GROUND,
+        MilitaryDoctrine::Cavalry => 1.0,
+        MilitaryDoctrine::Ranged if is_rough_ground(terrain) => RANGED_COVER,
+        MilitaryDoctrine::Ranged if is_open_ground(terrain) => RANGED_OPEN_GROUND,
+        MilitaryDoctrine::Ranged => 1.0,
+        MilitaryDoctrine::Siege => SIEGE_FIELD_POWER,
+    }
+}
+
+/// Multiplier on `own`'s field power against `enemy`: infantry holds off
+/// cavalry, cavalry rides down archers, archers shoot down infantry.
+pub(crate) fn matchup_factor(own: MilitaryDoctrine, enemy: MilitaryDoctrine) -> f64 {
+    use MilitaryDoctrine::*;
+    match (own, enemy) {
+        (Infantry, Cavalry) | (Cavalry, Ranged) | (Ranged, Infantry) => COUNTER_BONUS,
+        _ => 1.0,
+    }
+}
+
+/// Combined doctrine multiplier on an army's field power.
+pub(crate) fn battle_factor(
+    own: MilitaryDoctrine,
+    enemy: MilitaryDoctrine,
+    terrain: Option<Terrain>,
+) -> f64 {
+    let terrain = terrain.map_or(1.0, |t| terrain_factor(own, t));
+    terrain * matchup_factor(own, enemy)
+}
+
+/// Multiplier on an army's strength when storming walls.
+pub(crate) fn assault_factor(doctrine: MilitaryDoctrine) -> f64 {
+    match doctrine {
+        MilitaryDoctrine::Infantry => 1.0,
+        MilitaryDoctrine::Cavalry => CAVALRY_ASSAULT,
+        MilitaryDoctrine::Ranged => RANGED_ASSAULT,
+        MilitaryDoctrine::Siege => SIEGE_ASSAULT,
+    }
+}
+
+/// Whether an army can push on a second region in the same month after
+/// entering `terrain`: horsemen cover open ground quickly.
+pub(crate) fn presses_on(doctrine: MilitaryDoctrine, terrain: Terrain) -> bool {
+    doctrine == MilitaryDoctrine::Cavalry && is_open_ground(terrain)
+}
+
+/// Whether an army risks stalling on its way into `terrain`: siege trains
+/// labor through rough country.
+pub(crate) fn may_stall(doctrine: MilitaryDoctrine, terrain: Terrain) -> bool {
+    doctrine == MilitaryDoctrine::Siege && is_rough_ground(terrain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cavalry_rules_the_open_but_not_the_hills() {
+        let cavalry = |t| battle_factor(MilitaryDoctrine::Cavalry, MilitaryDoctrine::Infantry, t);
+        let infantry = |t| battle_factor(MilitaryDoctrine::Infantry, MilitaryDoctrine::Cavalry, t);
+        assert!(cavalry(Some(Terrain::Plains)) > infantry(Some(Terrain::Plains)));
+        assert!(cavalry(Some(Terrain::Mountains)) < infantry(Some(Terrain::Mountains)));
+    }
+
+    #[test]
+    fn matchups_form_a_cycle() {
+        use MilitaryDoctrine::*;
+        for (strong, weak) in [(Infantry, Cavalry), (Cavalry, Ranged), (Ranged, Infantry)] {
+            assert!(matchup_factor(strong, weak) > matchup_factor(weak, strong));
+        }
+        assert_eq!(matchup_factor(Siege, Infantry), 1.0);
+    }
+}
@@ -0,0 +1,242 @@
+use rand::Rng;
+
+use crate::model::{
+    EntityKind, EventPayload, ParticipantRole, RelationshipKind, ResourceType, SimTimestamp, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::helpers::{self, entity_name};
+use crate::sim::signal::{Signal, SignalKind};
+
+use super::collect_war_pairs;
+
+// Strategic embargo constants
+/// Yearly chance a faction at war starts interdicting its enemy's strategic imports.
+const STRATEGIC_EMBARGO_CHANCE: f64 = 0.3;
+/// Fighting power an army loses when its faction can neither mine nor import
+/// weapon-grade goods.
+const EMBARGO_ARMAMENT_PENALTY: f64 = 0.25;
+
+/// Goods that arm and mount an army. A faction producing any of them can
+/// outfit its troops without imports.
+const WEAPON_GRADE_RESOURCES: &[ResourceType] = &[
+    ResourceType::Iron,
+    ResourceType::Copper,
+    ResourceType::Ore,
+    ResourceType::Horses,
+];
+
+/// Goods a faction at war may interdict: anything mined, plus horses.
+pub(crate) fn is_strategic_good(resource: &ResourceType) -> bool {
+    helpers::is_mining_resource(resource) || *resource == ResourceType::Horses
+}
+
+/// Whether some enemy is interdicting strategic goods bound for `faction_id`.
+pub(crate) fn under_strategic_embargo(world: &World, faction_id: u64) -> bool {
+    world
+        .living(EntityKind::Faction)
+        .any(|e| e.has_active_rel(RelationshipKind::StrategicEmbargo, faction_id))
+}
+
+/// Multiplier on a faction's fighting power and siege works from its access to
+/// arms. Below 1.0 only when strategic imports are cut off and none of its own
+/// settlements produce weapon-grade goods.
+pub(crate) fn armament_factor(world: &World, faction_id: u64) -> f64 {
+    if !under_strategic_embargo(world, faction_id) {
+        return 1.0;
+    }
+    let self_sufficient = helpers::faction_settlements(world, faction_id)
+        .into_iter()
+        .filter_map(|sid| world.entities.get(&sid)?.data.as_settlement())
+        .any(|sd| {
+            sd.resources
+                .iter()
+                .any(|r| WEAPON_GRADE_RESOURCES.contains(r))
+        });
+    if self_sufficient {
+        1.0
+    } else {
+        1.0 - EMBARGO_ARMAMENT_PENALTY
+    }
+}
+
+/// Lift interdictions that outlived their war, then let belligerents start
+/// new ones. The economy system cuts the affected routes on the signal.
+pub(super) fn update_strategic_embargoes(
+    ctx: &mut TickContext,
+    time: SimTimestamp,
+    current_year: u32,
+) {
+    let active: Vec<(u64, u64)> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .flat_map(|e| {
+            e.active_rels(RelationshipKind::StrategicEmbargo)
+                .map(move |target| (e.id, target))
+        })
+        .collect();
+    for (fid, target) in active {
+        if helpers::has_active_rel_of_kind(ctx.world, fid, target, RelationshipKind::AtWar) {
+            continue;
+        }
+        let ev = ctx.world.add_structured_event(
+            EventPayload::StrategicEmbargoLifted {
+                faction_id: fid,
+                target_id: target,
+            },
+            time,
+            format!(
+                "{} stopped interdicting strategic goods bound for {} in year {current_year}",
+                entity_name(ctx.world, fid),
+                entity_name(ctx.world, target)
+            ),
+        );
+        ctx.world
+            .add_event_participant(ev, fid, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, target, ParticipantRole::Object);
+        ctx.world
+            .end_relationship(fid, target, RelationshipKind::StrategicEmbargo, time, ev);
+    }
+
+    for (a, b) in collect_war_pairs(ctx.world) {
+        for (fid, target) in [(a, b), (b, a)] {
+            let already = ctx
+                .world
+                .entities
+                .get(&fid)
+                .is_some_and(|e| e.has_active_rel(RelationshipKind::StrategicEmbargo, target));
+            if already || ctx.rng.random_range(0.0..1.0) >= STRATEGIC_EMBARGO_CHANCE {
+                continue;
+            }
+            impose_strategic_embargo(ctx, fid, target, time, current_year);
+        }
+    }
+}
+
+fn impose_strategic_embargo(
+    ctx: &mut TickContext,
+    faction_id: u64,
+    target_id: u64,
+    time: SimTimestamp,
+    current_year: u32,
+) {
+    let ev = ctx.world.add_structured_event(
+        EventPayload::StrategicEmbargoImposed {
+            faction_id,
+            target_id,
+        },
+        time,
+        format!(
+            "{} began interdicting iron, horses and other strategic goods bound for {} in year {current_year}",
+            entity_name(ctx.world, faction_id),
+            entity_name(ctx.world, target_id)
+        ),
+    );
+    ctx.world
+        .add_event_participant(ev, faction_id, ParticipantRole::Subject);
+    ctx.world
+        .add_event_participant(ev, target_id, ParticipantRole::Object);
+    ctx.world.add_relationship(
+        faction_id,
+        target_id,
+        RelationshipKind::StrategicEmbargo,
+        time,
+        ev,
+    );
+    ctx.signals.push(Signal {
+        event_id: ev,
+        kind: SignalKind::StrategicEmbargoImposed {
+            faction_id,
+            target_id,
+        },
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn only_mined_goods_and_horses_are_strategic() {
+        assert!(is_strategic_good(&ResourceType::Iron));
+        assert!(is_strategic_good(&ResourceType::Horses));
+        assert!(!is_strategic_good(&ResourceType::Grain));
+        assert!(!is_strategic_good(&ResourceType::Spices));
+    }
+
+    #[test]
+    fn scenario_iron_embargo_weakens_armies_against_control() {
+        let mut s = Scenario::at_year(100);
+        let blockader = s.add_kingdom("Blockader");
+        let embargoed = s.add_rival_kingdom("Embargoed", blockader.region);
+        let control = s.add_rival_kingdom("Control", blockader.region);
+        let miners = s.add_rival_kingdom("Miners", blockader.region);
+        for k in [&embargoed, &control, &miners] {
+            s.make_at_war(blockader.faction, k.faction);
+        }
+        for k in [&embargoed, &control] {
+            s.modify_settlement(k.settlement, |sd| sd.resources = vec![ResourceType::Grain]);
+        }
+        s.modify_settlement(miners.settlement, |sd| {
+            sd.resources = vec![ResourceType::Grain, ResourceType::Iron]
+        });
+        s.add_relationship(
+            blockader.faction,
+            embargoed.faction,
+            RelationshipKind::StrategicEmbargo,
+        );
+        s.add_relationship(
+            blockader.faction,
+            miners.faction,
+            RelationshipKind::StrategicEmbargo,
+        );
+        let world = s.build();
+
+        assert!(
+            armament_factor(&world, embargoed.faction) < armament_factor(&world, control.faction)
+        );
+        assert_eq!(armament_factor(&world, control.faction), 1.0);
+        assert_eq!(
+            armament_factor(&world, miners.faction),
+            1.0,
+            "factions with their own iron shrug off the embargo"
+        );
+    }
+
+    #[test]
+    fn scenario_peace_lifts_strategic_embargo() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut s = Scenario::at_year(100);
+        let a = s.add_kingdom("A");
+        let b = s.add_rival_kingdom("B", a.region);
+        s.add_relationship(a.faction, b.faction, RelationshipKind::StrategicEmbargo);
+        let mut world = s.build();
+        let time = world.current_time;
+
+        let mut rng = SmallRng::seed_from_u64(1);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world: &mut world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        update_strategic_embargoes(&mut ctx, time, 100);
+
+        assert!(!under_strategic_embargo(&world, b.faction));
+        let lifted = world
+            .events
+            .values()
+            .find_map(|e| EventPayload::from_data(&e.data));
+        assert_eq!(
+            lifted,
+            Some(EventPayload::StrategicEmbargoLifted {
+                faction_id: a.faction,
+                target_id: b.faction,
+            })
+        );
+    }
+}
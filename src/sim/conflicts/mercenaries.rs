@@ -7,6 +7,7 @@ use rand::Rng;
 use crate::model::entity::EntityKind;
 use crate::model::entity_data::{EntityData, GovernmentType, Role};
 use crate::model::event::EventKind;
+use crate::model::heraldry::Heraldry;
 use crate::model::relationship::RelationshipKind;
 use crate::model::timestamp::SimTimestamp;
 use crate::model::traits::Trait;
@@ -189,6 +190,7 @@ fn create_mercenary_company(
         faction_data,
         ev,
     );
+    ctx.world.faction_mut(faction_id).heraldry = Heraldry::for_faction(faction_id, None);
 
     // Create army
     let mut army_data = EntityData::default_for_kind(EntityKind::Army);
@@ -1387,7 +1389,7 @@ mod tests {
         s.make_enemies(small.faction, large.faction);
 
         let mut systems: Vec<Box<dyn crate::SimSystem>> = vec![
-            Box::new(crate::DemographicsSystem),
+            Box::new(crate::DemographicsSystem::new()),
             Box::new(crate::EconomySystem),
             Box::new(crate::ConflictSystem),
         ];
@@ -2,7 +2,8 @@ use rand::Rng;
 
 use crate::model::entity_data::ActiveSiege;
 use crate::model::{
-    EntityKind, EventKind, ParticipantRole, RelationshipKind, SiegeOutcome, SimTimestamp, World,
+    EntityKind, EventKind, EventPayload, ParticipantRole, RelationshipKind, SiegeOutcome,
+    SimTimestamp, World,
 };
 use crate::sim::context::TickContext;
 use crate::sim::signal::{Signal, SignalKind};
@@ -23,6 +24,22 @@ const SIEGE_ASSAULT_CASUALTY_MIN: f64 = 0.15;
 const SIEGE_ASSAULT_CASUALTY_MAX: f64 = 0.30;
 const SIEGE_ASSAULT_MORALE_PENALTY: f64 = 0.15;
 
+// Siege engines: built up month by month, they breach walls brute force can't
+const SIEGE_ENGINE_BUILD_RATE: f64 = 0.05;
+const SIEGE_ENGINE_REFERENCE_STRENGTH: f64 = 200.0;
+const SIEGE_ENGINE_STRENGTH_FACTOR_MAX: f64 = 2.0;
+const SIEGE_ENGINE_LITERACY_WEIGHT: f64 = 1.0;
+/// Fraction of fortification defense negated by a full siege train.
+const SIEGE_ENGINE_WALL_BREACH: f64 = 0.6;
+/// Equipment level at which the engines are considered complete.
+const SIEGE_ENGINES_BUILT_THRESHOLD: f64 = 0.5;
+
+// Wall wear: integrity lost to bombardment and storming, repaired in peacetime
+/// Monthly wall integrity lost to a full siege train's bombardment.
+const SIEGE_BOMBARDMENT_WALL_DAMAGE: f64 = 0.03;
+/// Wall integrity lost to each assault, whether or not it carries the walls.
+const SIEGE_ASSAULT_WALL_DAMAGE: f64 = 0.15;
+
 pub(super) fn start_sieges(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
     struct ConquestCandidate {
         army_id: u64,
@@ -116,8 +133,13 @@ pub(super) fn start_sieges(ctx: &mut TickContext, time: SimTimestamp, current_ye
                 let settlement_name = entity_name(ctx.world, settlement_id);
                 let loser_name = entity_name(ctx.world, loser_faction);
 
-                let siege_ev = ctx.world.add_event(
-                    EventKind::Siege,
+                let siege_ev = ctx.world.add_structured_event(
+                    EventPayload::Siege {
+                        settlement_id,
+                        attacker_faction_id: winner_faction,
+                        defender_faction_id: loser_faction,
+                        outcome: None,
+                    },
                     time,
                     format!(
                         "{winner_name} began siege of {settlement_name} of {loser_name} in year {current_year}"
@@ -173,8 +195,13 @@ pub(super) fn execute_conquest(
     let loser_name = entity_name(ctx.world, loser_faction);
     let settlement_name = entity_name(ctx.world, settlement_id);
 
-    let siege_ev = ctx.world.add_event(
-        EventKind::Siege,
+    let siege_ev = ctx.world.add_structured_event(
+        EventPayload::Siege {
+            settlement_id,
+            attacker_faction_id: winner_faction,
+            defender_faction_id: loser_faction,
+            outcome: Some(SiegeOutcome::Conquered),
+        },
         time,
         format!("{winner_name} besieged {settlement_name} of {loser_name} in year {current_year}"),
     );
@@ -183,8 +210,12 @@ pub(super) fn execute_conquest(
     ctx.world
         .add_event_participant(siege_ev, settlement_id, ParticipantRole::Object);
 
-    let conquest_ev = ctx.world.add_caused_event(
-        EventKind::Conquest,
+    let conquest_ev = ctx.world.add_caused_structured_event(
+        EventPayload::Conquest {
+            settlement_id,
+            old_faction_id: loser_faction,
+            new_faction_id: winner_faction,
+        },
         time,
         format!(
             "{winner_name} conquered {settlement_name} from {loser_name} in year {current_year}"
@@ -250,20 +281,21 @@ pub(super) fn execute_conquest(
     conquest_ev
 }
 
+struct SiegeInfo {
+    settlement_id: u64,
+    defender_faction_id: u64,
+    attacker_army_id: u64,
+    attacker_faction_id: u64,
+    months_elapsed: u32,
+    fort_level: u8,
+    wall_integrity: f64,
+    prosperity: f64,
+    population: u32,
+    civilian_deaths: u32,
+}
+
 pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
     // Collect settlements with active sieges
-    struct SiegeInfo {
-        settlement_id: u64,
-        defender_faction_id: u64,
-        attacker_army_id: u64,
-        attacker_faction_id: u64,
-        months_elapsed: u32,
-        fort_level: u8,
-        prosperity: f64,
-        population: u32,
-        civilian_deaths: u32,
-    }
-
     let sieges: Vec<SiegeInfo> = ctx
         .world
         .entities
@@ -280,6 +312,7 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
                 attacker_faction_id: siege.attacker_faction_id,
                 months_elapsed: siege.months_elapsed,
                 fort_level: sd.fortification_level,
+                wall_integrity: sd.wall_integrity,
                 prosperity: sd.prosperity,
                 population: sd.population,
                 civilian_deaths: siege.civilian_deaths,
@@ -374,7 +407,8 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
             // Lower prosperity increases surrender chance
             let prosperity_mod = 1.0 + (1.0 - prosperity);
             // Higher fortification reduces surrender chance
-            let fort_mod = 1.0 / (1.0 + info.fort_level as f64 * 0.3);
+            let fort_mod =
+                1.0 / (1.0 + effective_fort_level(info.fort_level, info.wall_integrity) * 0.3);
             let surrender_chance = base_chance * prosperity_mod * fort_mod;
 
             if ctx.rng.random_range(0.0..1.0) < surrender_chance {
@@ -395,18 +429,35 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
                         attacker_faction_id: info.attacker_faction_id,
                         defender_faction_id: info.defender_faction_id,
                         outcome: SiegeOutcome::Conquered,
+                        civilian_deaths,
                     },
                 });
                 continue;
             }
         }
 
+        let siege_equipment = build_siege_engines(ctx, &info, time, current_year);
+
+        // Bombardment wears the walls down month by month
+        let mut wall_integrity = info.wall_integrity;
+        if siege_equipment > 0.0 {
+            wall_integrity =
+                (wall_integrity - SIEGE_BOMBARDMENT_WALL_DAMAGE * siege_equipment).max(0.0);
+            ctx.world.settlement_mut(info.settlement_id).wall_integrity = wall_integrity;
+        }
+
         // Assault attempt (after minimum months, with morale check)
         if months >= SIEGE_ASSAULT_MIN_MONTHS
             && ctx.rng.random_range(0.0..1.0) < SIEGE_ASSAULT_CHANCE
         {
             let army_strength = super::army_strength(ctx.world, info.attacker_army_id);
             let army_morale = super::army_morale(ctx.world, info.attacker_army_id);
+            let assault_strength = (army_strength as f64
+                * super::doctrine::assault_factor(super::doctrine::faction_doctrine(
+                    ctx.world,
+                    info.attacker_faction_id,
+                )))
+            .round() as u32;
 
             if army_morale >= SIEGE_ASSAULT_MORALE_MIN {
                 let settlement_region = ctx
@@ -418,10 +469,15 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
                     .and_then(|r| get_terrain_defense_bonus(ctx.world, r))
                     .unwrap_or(1.0);
 
-                let attacker_power = army_strength as f64 * army_morale;
-                let defender_power = pop as f64 * 0.05 * info.fort_level as f64 * terrain_bonus;
-
-                if attacker_power >= defender_power * SIEGE_ASSAULT_POWER_RATIO {
+                if assault_succeeds(
+                    assault_strength,
+                    army_morale,
+                    pop,
+                    info.fort_level,
+                    wall_integrity,
+                    terrain_bonus,
+                    siege_equipment,
+                ) {
                     // Assault succeeds
                     let conquest_ev = execute_conquest(
                         ctx,
@@ -431,6 +487,14 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
                         time,
                         current_year,
                     );
+                    damage_walls(
+                        ctx,
+                        info.settlement_id,
+                        SIEGE_ASSAULT_WALL_DAMAGE,
+                        conquest_ev,
+                        time,
+                        current_year,
+                    );
                     clear_besieging(ctx.world, info.attacker_army_id);
                     ctx.signals.push(Signal {
                         event_id: conquest_ev,
@@ -439,6 +503,7 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
                             attacker_faction_id: info.attacker_faction_id,
                             defender_faction_id: info.defender_faction_id,
                             outcome: SiegeOutcome::Conquered,
+                            civilian_deaths,
                         },
                     });
                 } else {
@@ -452,8 +517,13 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
 
                     let army_name = entity_name(ctx.world, info.attacker_army_id);
                     let settlement_name = entity_name(ctx.world, info.settlement_id);
-                    let ev = ctx.world.add_event(
-                        EventKind::Assault,
+                    let ev = ctx.world.add_structured_event(
+                        EventPayload::Assault {
+                            army_id: info.attacker_army_id,
+                            settlement_id: info.settlement_id,
+                            succeeded: false,
+                            casualties,
+                        },
                         time,
                         format!(
                             "{army_name} failed to storm {settlement_name}, losing {casualties} troops in year {current_year}"
@@ -469,6 +539,14 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
                         info.settlement_id,
                         ParticipantRole::Object,
                     );
+                    damage_walls(
+                        ctx,
+                        info.settlement_id,
+                        SIEGE_ASSAULT_WALL_DAMAGE,
+                        ev,
+                        time,
+                        current_year,
+                    );
 
                     {
                         let entity = ctx.world.entities.get_mut(&info.attacker_army_id).unwrap();
@@ -505,6 +583,133 @@ pub(super) fn progress_sieges(ctx: &mut TickContext, time: SimTimestamp, current
     }
 }
 
+/// Fortification level as it stands, scaled down by wear on the walls.
+pub(super) fn effective_fort_level(fort_level: u8, wall_integrity: f64) -> f64 {
+    fort_level as f64 * wall_integrity.clamp(0.0, 1.0)
+}
+
+/// Whether an assault carries the walls. Worn walls and siege engines both
+/// cut into the fortification's contribution to the defense.
+pub(super) fn assault_succeeds(
+    army_strength: u32,
+    army_morale: f64,
+    population: u32,
+    fort_level: u8,
+    wall_integrity: f64,
+    terrain_bonus: f64,
+    siege_equipment: f64,
+) -> bool {
+    let attacker_power = army_strength as f64 * army_morale;
+    let effective_fort = effective_fort_level(fort_level, wall_integrity)
+        * (1.0 - siege_equipment.clamp(0.0, 1.0) * SIEGE_ENGINE_WALL_BREACH);
+    let defender_power = population as f64 * 0.05 * effective_fort * terrain_bonus;
+    attacker_power >= defender_power * SIEGE_ASSAULT_POWER_RATIO
+}
+
+/// Knock `amount` off a settlement's wall integrity, recording the damage as a
+/// consequence of `caused_by` (the assault or conquest).
+fn damage_walls(
+    ctx: &mut TickContext,
+    settlement_id: u64,
+    amount: f64,
+    caused_by: u64,
+    time: SimTimestamp,
+    current_year: u32,
+) {
+    let sd = ctx.world.settlement(settlement_id);
+    if sd.fortification_level == 0 {
+        return;
+    }
+    let old_integrity = sd.wall_integrity;
+    let new_integrity = (old_integrity - amount).max(0.0);
+    if new_integrity >= old_integrity {
+        return;
+    }
+    ctx.world.settlement_mut(settlement_id).wall_integrity = new_integrity;
+
+    let settlement_name = entity_name(ctx.world, settlement_id);
+    let ev = ctx.world.add_caused_event(
+        EventKind::WallsDamaged,
+        time,
+        format!("The walls of {settlement_name} were battered in year {current_year}"),
+        caused_by,
+    );
+    ctx.world
+        .add_event_participant(ev, settlement_id, ParticipantRole::Subject);
+    ctx.world.record_change(
+        settlement_id,
+        ev,
+        "wall_integrity",
+        serde_json::json!(old_integrity),
+        serde_json::json!(new_integrity),
+    );
+}
+
+/// Monthly siege-engine construction for the besieging army. Larger and more
+/// literate attackers build faster. Returns the army's equipment level.
+fn build_siege_engines(
+    ctx: &mut TickContext,
+    info: &SiegeInfo,
+    time: SimTimestamp,
+    current_year: u32,
+) -> f64 {
+    let Some(ad) = ctx
+        .world
+        .entities
+        .get(&info.attacker_army_id)
+        .and_then(|e| e.data.as_army())
+    else {
+        return 0.0;
+    };
+    let old_equipment = ad.siege_equipment;
+    let strength_factor = (ad.strength as f64 / SIEGE_ENGINE_REFERENCE_STRENGTH)
+        .sqrt()
+        .min(SIEGE_ENGINE_STRENGTH_FACTOR_MAX);
+    let literacy = ctx
+        .world
+        .entities
+        .get(&info.attacker_faction_id)
+        .and_then(|e| e.data.as_faction())
+        .map(|fd| fd.literacy_rate)
+        .unwrap_or(0.0);
+    let gain = SIEGE_ENGINE_BUILD_RATE
+        * strength_factor
+        * (1.0 + literacy * SIEGE_ENGINE_LITERACY_WEIGHT)
+        * super::interdiction::armament_factor(ctx.world, info.attacker_faction_id);
+    let new_equipment = (old_equipment + gain).min(1.0);
+    ctx.world.army_mut(info.attacker_army_id).siege_equipment = new_equipment;
+
+    if old_equipment < SIEGE_ENGINES_BUILT_THRESHOLD
+        && new_equipment >= SIEGE_ENGINES_BUILT_THRESHOLD
+    {
+        let army_name = entity_name(ctx.world, info.attacker_army_id);
+        let settlement_name = entity_name(ctx.world, info.settlement_id);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::SiegeEnginesBuilt {
+                army_id: info.attacker_army_id,
+                settlement_id: info.settlement_id,
+            },
+            time,
+            format!(
+                "{army_name} completed siege engines before {settlement_name} in year {current_year}"
+            ),
+        );
+        ctx.world
+            .add_event_participant(ev, info.attacker_army_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, info.settlement_id, ParticipantRole::Object);
+        ctx.world.record_change(
+            info.attacker_army_id,
+            ev,
+            "siege_equipment",
+            serde_json::json!(old_equipment),
+            serde_json::json!(new_equipment),
+        );
+    }
+
+    new_equipment
+}
+
 pub(super) struct SiegeClearParams {
     pub settlement_id: u64,
     pub army_id: u64,
@@ -527,19 +732,26 @@ pub(super) fn clear_siege(
         outcome,
     } = params;
     let settlement_name = entity_name(ctx.world, settlement_id);
-    let ev = ctx.world.add_event(
-        EventKind::Siege,
+    let ev = ctx.world.add_structured_event(
+        EventPayload::Siege {
+            settlement_id,
+            attacker_faction_id,
+            defender_faction_id,
+            outcome: Some(outcome),
+        },
         time,
         format!("Siege of {settlement_name} ended ({outcome}) in year {current_year}"),
     );
     ctx.world
         .add_event_participant(ev, settlement_id, ParticipantRole::Subject);
 
-    {
+    let civilian_deaths = {
         let entity = ctx.world.entities.get_mut(&settlement_id).unwrap();
         let sd = entity.data.as_settlement_mut().unwrap();
-        sd.active_siege = None;
-    }
+        sd.active_siege
+            .take()
+            .map_or(0, |siege| siege.civilian_deaths)
+    };
     ctx.world.record_change(
         settlement_id,
         ev,
@@ -557,6 +769,7 @@ pub(super) fn clear_siege(
             attacker_faction_id,
             defender_faction_id,
             outcome,
+            civilian_deaths,
         },
     });
 }
@@ -566,5 +779,6 @@ pub(super) fn clear_besieging(world: &mut World, army_id: u64) {
         && let Some(ad) = entity.data.as_army_mut()
     {
         ad.besieging_settlement_id = None;
+        ad.siege_equipment = 0.0;
     }
 }
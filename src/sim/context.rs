@@ -9,6 +9,7 @@ use crate::model::World;
 /// the `SimSystem` trait signature.
 pub struct TickContext<'a> {
     pub world: &'a mut World,
+    /// This system's own random stream; other systems' draws don't affect it.
     pub rng: &'a mut dyn RngCore,
     /// Systems push signals here during tick/handle_signals.
     pub signals: &'a mut Vec<Signal>,
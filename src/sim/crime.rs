@@ -5,11 +5,14 @@ use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
 use crate::model::entity_data::{
     ArmyData, BuildingBonuses, DiseaseRisk, EntityData, FactionData, GovernmentType,
-    SeasonalModifiers, SettlementData,
+    MilitaryDoctrine, POLICY_NEUTRAL, SeasonalModifiers, SettlementData, SettlementEconomyType,
+    SuccessionLaw,
 };
 use crate::model::population::PopulationBreakdown;
 use crate::model::traits::Trait;
-use crate::model::{EntityKind, EventKind, ParticipantRole, RelationshipKind, Role, SimTimestamp};
+use crate::model::{
+    EntityKind, EventKind, Heraldry, ParticipantRole, RelationshipKind, Role, SimTimestamp,
+};
 use crate::sim::helpers;
 
 // ---------------------------------------------------------------------------
@@ -48,6 +51,19 @@ const RAID_TRADE_STRENGTH_SCALE: f64 = 30.0;
 const RAID_TRADE_MAX_CHANCE: f64 = 0.3;
 const RAID_TRADE_INCOME_LOSS_FRACTION: f64 = 0.4;
 const RAID_TRADE_SEVER_STRENGTH: u32 = 50;
+/// Share of the raid chance a fully secured route shrugs off.
+const RAID_TRADE_SECURITY_REDUCTION: f64 = 0.8;
+
+// ---------------------------------------------------------------------------
+// Trade route patrols
+// ---------------------------------------------------------------------------
+const ROUTE_PATROL_COST: f64 = 1.0;
+/// Treasury a faction keeps in reserve before paying for route patrols.
+const ROUTE_PATROL_MIN_TREASURY: f64 = 20.0;
+const ROUTE_PATROL_SECURITY_GAIN: f64 = 0.2;
+const ROUTE_UNPATROLLED_DECAY: f64 = 0.1;
+/// Security lost per unit of bandit threat at the worse-off endpoint.
+const ROUTE_BANDIT_SECURITY_LOSS: f64 = 0.3;
 
 // ---------------------------------------------------------------------------
 // Settlement raiding
@@ -121,6 +137,7 @@ impl SimSystem for CrimeSystem {
 
         update_crime_rates(ctx, tick_event);
         update_guard_strength(ctx, tick_event);
+        update_route_security(ctx, tick_event);
         form_bandit_gangs(ctx, time, current_year, tick_event);
         raid_trade_routes(ctx, time, current_year, tick_event);
         raid_settlements(ctx, time, current_year, tick_event);
@@ -349,6 +366,110 @@ fn update_guard_strength(ctx: &mut TickContext, tick_event: u64) {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Phase 2b: Trade route patrols
+// ---------------------------------------------------------------------------
+
+fn update_route_security(ctx: &mut TickContext, tick_event: u64) {
+    struct RouteUpdate {
+        settlement_id: u64,
+        index: usize,
+        faction_id: u64,
+        security: f64,
+        bandit_threat: f64,
+    }
+
+    let routes: Vec<RouteUpdate> = ctx
+        .world
+        .entities
+        .values()
+        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+        .filter_map(|e| {
+            let sd = e.data.as_settlement()?;
+            let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
+            if is_bandit_faction(ctx.world, faction_id) {
+                return None;
+            }
+            Some((e.id, faction_id, sd))
+        })
+        .flat_map(|(sid, faction_id, sd)| {
+            sd.trade_routes
+                .iter()
+                .enumerate()
+                .map(move |(index, route)| (sid, faction_id, sd.bandit_threat, index, route))
+        })
+        .map(|(sid, faction_id, threat, index, route)| {
+            let target_threat = ctx
+                .world
+                .entities
+                .get(&route.target)
+                .and_then(|e| e.data.as_settlement())
+                .map(|sd| sd.bandit_threat)
+                .unwrap_or(0.0);
+            RouteUpdate {
+                settlement_id: sid,
+                index,
+                faction_id,
+                security: route.security,
+                bandit_threat: threat.max(target_threat),
+            }
+        })
+        .collect();
+
+    // Patrol routes in order while each faction can spare the coin
+    let mut treasuries: std::collections::BTreeMap<u64, (f64, f64)> =
+        std::collections::BTreeMap::new();
+    for route in routes {
+        let (_, treasury) = treasuries.entry(route.faction_id).or_insert_with(|| {
+            let t = ctx.world.faction(route.faction_id).treasury;
+            (t, t)
+        });
+        let patrolled = *treasury - ROUTE_PATROL_COST >= ROUTE_PATROL_MIN_TREASURY;
+        let mut security = route.security;
+        if patrolled {
+            *treasury -= ROUTE_PATROL_COST;
+            security += ROUTE_PATROL_SECURITY_GAIN;
+        } else {
+            security -= ROUTE_UNPATROLLED_DECAY;
+        }
+        security = (security - route.bandit_threat * ROUTE_BANDIT_SECURITY_LOSS).clamp(0.0, 1.0);
+        ctx.world.settlement_mut(route.settlement_id).trade_routes[route.index].security = security;
+    }
+
+    for (fid, (old, new)) in treasuries {
+        if new < old {
+            ctx.world.faction_mut(fid).treasury = new;
+            ctx.world.record_change(
+                fid,
+                tick_event,
+                "treasury",
+                serde_json::json!(old),
+                serde_json::json!(new),
+            );
+        }
+    }
+}
+
+/// Security of the better-patrolled direction of a route.
+fn route_security(world: &crate::model::World, from: u64, to: u64) -> f64 {
+    let leg = |a: u64, b: u64| {
+        world
+            .entities
+            .get(&a)
+            .and_then(|e| e.data.as_settlement())
+            .and_then(|sd| sd.trade_routes.iter().find(|r| r.target == b))
+            .map(|r| r.security)
+            .unwrap_or(0.0)
+    };
+    leg(from, to).max(leg(to, from))
+}
+
+fn trade_raid_chance(bandit_strength: u32, security: f64) -> f64 {
+    (RAID_TRADE_BASE_CHANCE * (bandit_strength as f64 / RAID_TRADE_STRENGTH_SCALE))
+        .min(RAID_TRADE_MAX_CHANCE)
+        * (1.0 - security * RAID_TRADE_SECURITY_REDUCTION)
+}
+
 // ---------------------------------------------------------------------------
 // Phase 3: Bandit gang formation
 // ---------------------------------------------------------------------------
@@ -412,6 +533,7 @@ fn form_bandit_gangs(
             Some(time),
             EntityData::Faction(FactionData {
                 government_type: GovernmentType::BanditClan,
+                succession_law: SuccessionLaw::default(),
                 stability: 0.5,
                 happiness: 0.5,
                 legitimacy: 0.0,
@@ -426,6 +548,7 @@ fn form_bandit_gangs(
                 economic_motivation: 0.0,
                 diplomatic_trust: 1.0,
                 betrayal_count: 0,
+                treaties_broken: 0,
                 last_betrayal: None,
                 last_betrayed_by: None,
                 succession_crisis_at: None,
@@ -433,14 +556,23 @@ fn form_bandit_gangs(
                 prestige_tier: 0,
                 trade_partner_routes: std::collections::BTreeMap::new(),
                 marriage_alliances: std::collections::BTreeMap::new(),
+                ceasefire_until: std::collections::BTreeMap::new(),
                 war_goals: std::collections::BTreeMap::new(),
                 loyalty: std::collections::BTreeMap::new(),
                 mercenary_wage: 0.0,
                 unpaid_months: 0,
+                insolvent_years: 0,
                 literacy_rate: 0.0,
+                technologies: std::collections::BTreeSet::new(),
+                expansionism: POLICY_NEUTRAL,
+                mercantilism: POLICY_NEUTRAL,
+                isolationism: POLICY_NEUTRAL,
+                heraldry: Heraldry::default(),
+                doctrine: MilitaryDoctrine::default(),
             }),
             ev,
         );
+        ctx.world.faction_mut(faction_id).heraldry = Heraldry::for_faction(faction_id, None);
 
         // Create hideout settlement (pop 0)
         let hideout_id = ctx.world.add_entity(
@@ -461,6 +593,7 @@ fn form_bandit_gangs(
                 active_disease: None,
                 plague_immunity: 0.0,
                 fortification_level: 0,
+                wall_integrity: 1.0,
                 active_siege: None,
                 prestige: 0.0,
                 active_disaster: None,
@@ -471,12 +604,15 @@ fn form_bandit_gangs(
                 religion_makeup: std::collections::BTreeMap::new(),
                 religious_tension: 0.0,
                 capacity: 0,
+                land_improvement: 0.0,
                 trade_happiness_bonus: 0.0,
+                festival_happiness_bonus: 0.0,
                 blend_timer: 0,
                 last_prophecy_year: None,
                 trade_routes: Vec::new(),
                 production: std::collections::BTreeMap::new(),
                 surplus: std::collections::BTreeMap::new(),
+                market_prices: std::collections::BTreeMap::new(),
                 seasonal: SeasonalModifiers::default(),
                 building_bonuses: BuildingBonuses::default(),
                 disease_risk: DiseaseRisk::default(),
@@ -484,6 +620,10 @@ fn form_bandit_gangs(
                 trade_income: 0.0,
                 literacy_rate: 0.0,
                 is_coastal: false,
+                economy_type: SettlementEconomyType::default(),
+                labor_shortage: 0.0,
+                unfree_fraction: 0.0,
+                rebuilding_boom: 0.0,
             }),
             ev,
         );
@@ -512,6 +652,7 @@ fn form_bandit_gangs(
                 months_campaigning: 0,
                 starting_strength: strength,
                 is_mercenary: false,
+                siege_equipment: 0.0,
             }),
             ev,
         );
@@ -543,6 +684,8 @@ fn form_bandit_gangs(
                 prestige_tier: 0,
                 loyalty: std::collections::BTreeMap::new(),
                 education: 0.0,
+                health: 1.0,
+                dynasty_id: None,
             }),
             ev,
         );
@@ -687,9 +830,8 @@ fn raid_trade_routes(
     });
 
     for target in targets {
-        let raid_chance = (RAID_TRADE_BASE_CHANCE
-            * (target.bandit_strength as f64 / RAID_TRADE_STRENGTH_SCALE))
-            .min(RAID_TRADE_MAX_CHANCE);
+        let security = route_security(ctx.world, target.from_settlement, target.to_settlement);
+        let raid_chance = trade_raid_chance(target.bandit_strength, security);
 
         if ctx.rng.random_range(0.0..1.0) >= raid_chance {
             continue;
@@ -1252,6 +1394,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scenario_poor_overcrowded_town_outpaces_prosperous_one() {
+        let mut s = Scenario::at_year(100);
+        let region = s.add_region("Plains");
+        let faction = s.faction("Kingdom").stability(0.6).treasury(0.0).id();
+        let slum = s
+            .settlement("Slum", faction, region)
+            .population(600)
+            .prosperity(0.1)
+            .id();
+        let market = s
+            .settlement("Market", faction, region)
+            .population(200)
+            .prosperity(0.9)
+            .id();
+        let mut world = s.build();
+
+        testutil::tick_system(&mut world, &mut CrimeSystem, 100, 42);
+
+        let slum_crime = world.settlement(slum).crime_rate;
+        let market_crime = world.settlement(market).crime_rate;
+        assert!(
+            slum_crime > market_crime + 0.1,
+            "poor, overcrowded town should breed more crime: {slum_crime} vs {market_crime}"
+        );
+    }
+
     #[test]
     fn scenario_guard_strength_set_with_treasury() {
         let mut s = Scenario::at_year(100);
@@ -1500,6 +1669,90 @@ mod tests {
         assert!(raided, "trade routes should be raided by bandits");
     }
 
+    fn secured_route_world(security: f64) -> (crate::model::World, u64, u64) {
+        let mut s = Scenario::at_year(100);
+        let region = s.add_region("Plains");
+        let faction = s.faction("Traders").treasury(50.0).id();
+        let town_a = s.settlement("Town A", faction, region).population(300).id();
+        let town_b = s.settlement("Town B", faction, region).population(300).id();
+        let bandit_faction = s
+            .faction("Bandits")
+            .government_type(GovernmentType::BanditClan)
+            .id();
+        let _ = s
+            .settlement("Hideout", bandit_faction, region)
+            .population(0);
+        s.add_army("Warband", bandit_faction, region, 40);
+        s.make_trade_route(town_a, town_b);
+        for (from, to) in [(town_a, town_b), (town_b, town_a)] {
+            s.modify_settlement(from, |sd| {
+                sd.trade_income = 10.0;
+                sd.trade_routes.push(crate::model::TradeRoute {
+                    target: to,
+                    path: vec![],
+                    distance: 1,
+                    resource: "grain".to_string(),
+                    security,
+                })
+            });
+        }
+        (s.build(), town_a, town_b)
+    }
+
+    #[test]
+    fn scenario_route_security_lowers_raid_chance() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let raids = |security: f64| {
+            let (mut world, _, _) = secured_route_world(security);
+            let time = world.current_time;
+            let mut count = 0;
+            for seed in 0..500 {
+                let mut rng = SmallRng::seed_from_u64(seed);
+                let mut signals = Vec::new();
+                let mut ctx = TickContext {
+                    world: &mut world,
+                    rng: &mut rng,
+                    signals: &mut signals,
+                    inbox: &[],
+                };
+                let ev = ctx.world.add_event(
+                    EventKind::Custom("test".to_string()),
+                    time,
+                    "test".to_string(),
+                );
+                raid_trade_routes(&mut ctx, time, 100, ev);
+                count += signals
+                    .iter()
+                    .filter(|s| matches!(s.kind, SignalKind::TradeRouteRaided { .. }))
+                    .count();
+            }
+            count
+        };
+
+        let open = raids(0.0);
+        let patrolled = raids(1.0);
+        assert!(
+            patrolled * 2 < open,
+            "secured route should be raided far less (open {open}, patrolled {patrolled})"
+        );
+    }
+
+    #[test]
+    fn scenario_patrols_raise_route_security_at_a_cost() {
+        let (mut world, town_a, _) = secured_route_world(0.0);
+        let traders = helpers::settlement_faction(&world, town_a).unwrap();
+        world.faction_mut(traders).treasury = 100.0;
+
+        let treasury_before = world.faction(traders).treasury;
+        testutil::tick_system(&mut world, &mut CrimeSystem, 100, 42);
+
+        let security = world.settlement(town_a).trade_routes[0].security;
+        assert!(security > 0.0, "patrols should secure the route");
+        assert!(world.faction(traders).treasury < treasury_before);
+    }
+
     #[test]
     fn scenario_deterministic_with_crime() {
         let world1 = testutil::generate_and_run(42, 50, testutil::all_systems());
@@ -4,11 +4,15 @@ use rand::Rng;
 
 use super::context::TickContext;
 use super::culture_names::generate_culture_entity_name;
+use super::helpers;
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
 use crate::model::cultural_value::NamingStyle;
 use crate::model::entity_data::CultureData;
-use crate::model::{EntityData, EntityKind, EventKind, ParticipantRole, RelationshipKind};
+use crate::model::{
+    EntityData, EntityKind, EventKind, EventPayload, GovernmentType, ParticipantRole,
+    RelationshipKind,
+};
 
 // --- Signal: culture share adjustments ---
 const CONQUEST_CULTURE_SHARE: f64 = 0.05;
@@ -25,6 +29,16 @@ const DRIFT_PURGE_THRESHOLD: f64 = 0.03;
 const DRIFT_NORMALIZE_TOLERANCE: f64 = 0.001;
 const DOMINANT_CULTURE_MIN_FRACTION: f64 = 0.5;
 
+// --- Assimilation under long, stable rule ---
+/// Years a settlement must be held before assimilation begins.
+const ASSIMILATION_MIN_RULE_YEARS: u32 = 10;
+/// Years of rule at which assimilation reaches full strength.
+const ASSIMILATION_FULL_RULE_YEARS: u32 = 50;
+/// Extra yearly minority loss toward the ruling culture at full strength.
+const ASSIMILATION_MAX_RATE: f64 = 0.03;
+/// Faction stability required for assimilation to proceed.
+const ASSIMILATION_MIN_STABILITY: f64 = 0.5;
+
 // --- Cultural blending ---
 const BLEND_QUALIFYING_SHARE: f64 = 0.30;
 const BLEND_TIMER_THRESHOLD: u64 = 50;
@@ -42,6 +56,20 @@ const REBELLION_LOW_STABILITY_BONUS: f64 = 0.10;
 const REBELLION_FAILED_STABILITY_PENALTY: f64 = 0.10;
 const REBELLION_CRACKDOWN_CULTURE_SHARE: f64 = 0.10;
 
+// --- Festivals ---
+/// Fervor or prosperity a settlement needs before it holds festivals.
+const FESTIVAL_MIN_DRIVE: f64 = 0.6;
+/// Yearly festival chance for a settlement at full drive.
+const FESTIVAL_MAX_CHANCE: f64 = 0.5;
+/// Theocracies celebrate their state faith more zealously.
+const FESTIVAL_THEOCRACY_FERVOR_MULTIPLIER: f64 = 1.5;
+const FESTIVAL_HAPPINESS_BASE: f64 = 0.05;
+const FESTIVAL_HAPPINESS_DRIVE_WEIGHT: f64 = 0.10;
+/// Fraction of a festival's happiness bonus still felt a year later.
+const FESTIVAL_BONUS_RETENTION: f64 = 0.5;
+const FESTIVAL_BONUS_FLOOR: f64 = 0.001;
+const FESTIVAL_TREASURY_COST: f64 = 1.0;
+
 pub struct CultureSystem;
 
 impl SimSystem for CultureSystem {
@@ -62,6 +90,7 @@ impl SimSystem for CultureSystem {
         cultural_drift(ctx, year_event);
         cultural_blending(ctx, year_event);
         rebellion_check(ctx, year_event);
+        festivals(ctx);
     }
 
     fn handle_signals(&mut self, ctx: &mut TickContext) {
@@ -210,6 +239,7 @@ fn cultural_drift(ctx: &mut TickContext, year_event: u64) {
         faction_id: Option<u64>,
         makeup: BTreeMap<u64, f64>,
         prosperity: f64,
+        assimilation: f64,
     }
 
     let settlements: Vec<SettlementInfo> = ctx
@@ -222,12 +252,30 @@ fn cultural_drift(ctx: &mut TickContext, year_event: u64) {
             if sd.culture_makeup.is_empty() {
                 return None;
             }
-            let faction_id = e.active_rel(RelationshipKind::MemberOf);
+            let membership = e
+                .relationships
+                .iter()
+                .find(|r| r.kind == RelationshipKind::MemberOf && r.is_active());
+            let faction_id = membership.map(|r| r.target_entity_id);
+            // The rule clock starts with the current membership, so conquest resets it
+            let assimilation = membership
+                .map(|r| {
+                    let stability = ctx
+                        .world
+                        .entities
+                        .get(&r.target_entity_id)
+                        .and_then(|f| f.data.as_faction())
+                        .map(|fd| fd.stability)
+                        .unwrap_or(0.0);
+                    assimilation_rate(time.years_since(r.start), stability)
+                })
+                .unwrap_or(0.0);
             Some(SettlementInfo {
                 id: e.id,
                 faction_id,
                 makeup: sd.culture_makeup.clone(),
                 prosperity: sd.prosperity,
+                assimilation,
             })
         })
         .collect();
@@ -273,7 +321,7 @@ fn cultural_drift(ctx: &mut TickContext, year_event: u64) {
                     .map(|cd| cd.resistance)
                     .unwrap_or(0.5);
 
-                let mut loss = DRIFT_BASE_MINORITY_LOSS * (1.0 - resistance);
+                let mut loss = (DRIFT_BASE_MINORITY_LOSS + s.assimilation) * (1.0 - resistance);
                 loss += trade_bonus * DRIFT_TRADE_BONUS_MULTIPLIER;
                 if s.prosperity > DRIFT_PROSPERITY_THRESHOLD {
                     loss += DRIFT_PROSPERITY_BONUS;
@@ -428,6 +476,18 @@ fn cultural_drift(ctx: &mut TickContext, year_event: u64) {
     }
 }
 
+/// Extra yearly pull toward the ruling culture for a settlement held for
+/// `rule_years` by a faction at the given stability. Nothing until the rule has
+/// settled in, then ramping up to full strength over decades.
+fn assimilation_rate(rule_years: u32, stability: f64) -> f64 {
+    if rule_years < ASSIMILATION_MIN_RULE_YEARS || stability < ASSIMILATION_MIN_STABILITY {
+        return 0.0;
+    }
+    let progress = (rule_years - ASSIMILATION_MIN_RULE_YEARS) as f64
+        / (ASSIMILATION_FULL_RULE_YEARS - ASSIMILATION_MIN_RULE_YEARS) as f64;
+    ASSIMILATION_MAX_RATE * progress.min(1.0)
+}
+
 fn count_ruling_culture_trade_routes(
     ctx: &TickContext,
     settlement_id: u64,
@@ -560,6 +620,19 @@ fn cultural_blending(ctx: &mut TickContext, _year_event: u64) {
             .and_then(|e| e.data.as_culture())
             .map(|cd| cd.resistance)
             .unwrap_or(0.5);
+        // A blended culture splits the difference between its parents' temperaments
+        let militarism = [parent_a, parent_b]
+            .iter()
+            .map(|pid| {
+                ctx.world
+                    .entities
+                    .get(pid)
+                    .and_then(|e| e.data.as_culture())
+                    .map(|cd| cd.militarism)
+                    .unwrap_or(0.5)
+            })
+            .sum::<f64>()
+            / 2.0;
 
         let mut values = Vec::new();
         if let Some(v) = value_a {
@@ -593,6 +666,7 @@ fn cultural_blending(ctx: &mut TickContext, _year_event: u64) {
                 values,
                 naming_style,
                 resistance,
+                militarism,
             }),
             ev,
         );
@@ -856,6 +930,138 @@ fn rebellion_check(ctx: &mut TickContext, _year_event: u64) {
     }
 }
 
+/// Devout or prosperous settlements periodically hold festivals, paid for by
+/// their faction, that lift happiness for a few years as the bonus fades.
+fn festivals(ctx: &mut TickContext) {
+    let time = ctx.world.current_time;
+    let current_year = time.year();
+
+    struct FestivalCandidate {
+        settlement_id: u64,
+        faction_id: u64,
+        religion_id: Option<u64>,
+        drive: f64,
+    }
+
+    // Last festivals' cheer fades
+    for entity in ctx.world.entities.values_mut() {
+        if entity.kind != EntityKind::Settlement || entity.end.is_some() {
+            continue;
+        }
+        if let Some(sd) = entity.data.as_settlement_mut() {
+            sd.festival_happiness_bonus *= FESTIVAL_BONUS_RETENTION;
+            if sd.festival_happiness_bonus < FESTIVAL_BONUS_FLOOR {
+                sd.festival_happiness_bonus = 0.0;
+            }
+        }
+    }
+
+    let mut candidates: Vec<FestivalCandidate> = Vec::new();
+    for entity in ctx.world.living(EntityKind::Settlement) {
+        let Some(sd) = entity.data.as_settlement() else {
+            continue;
+        };
+        let Some(faction_id) = entity.active_rel(RelationshipKind::MemberOf) else {
+            continue;
+        };
+        let Some(fd) = ctx
+            .world
+            .entities
+            .get(&faction_id)
+            .and_then(|f| f.data.as_faction())
+        else {
+            continue;
+        };
+
+        let mut fervor = sd
+            .dominant_religion
+            .and_then(|rid| ctx.world.entities.get(&rid))
+            .and_then(|r| r.data.as_religion())
+            .map(|rd| rd.fervor)
+            .unwrap_or(0.0);
+        if fd.government_type == GovernmentType::Theocracy
+            && fd.primary_religion.is_some()
+            && fd.primary_religion == sd.dominant_religion
+        {
+            fervor *= FESTIVAL_THEOCRACY_FERVOR_MULTIPLIER;
+        }
+        let drive = fervor.max(sd.prosperity).min(1.0);
+        if drive < FESTIVAL_MIN_DRIVE {
+            continue;
+        }
+
+        candidates.push(FestivalCandidate {
+            settlement_id: entity.id,
+            faction_id,
+            religion_id: sd.dominant_religion.filter(|_| fervor >= sd.prosperity),
+            drive,
+        });
+    }
+
+    for c in candidates {
+        let chance =
+            (c.drive - FESTIVAL_MIN_DRIVE) / (1.0 - FESTIVAL_MIN_DRIVE) * FESTIVAL_MAX_CHANCE;
+        if !ctx.rng.random_bool(chance.clamp(0.0, 1.0)) {
+            continue;
+        }
+
+        let old_treasury = ctx.world.faction(c.faction_id).treasury;
+        if old_treasury < FESTIVAL_TREASURY_COST {
+            continue;
+        }
+
+        let happiness_bonus = FESTIVAL_HAPPINESS_BASE + FESTIVAL_HAPPINESS_DRIVE_WEIGHT * c.drive;
+        let settlement_name = helpers::entity_name(ctx.world, c.settlement_id);
+        let description = match c.religion_id {
+            Some(rid) => format!(
+                "The faithful of {} held a festival in {settlement_name} in year {current_year}",
+                helpers::entity_name(ctx.world, rid)
+            ),
+            None => format!("{settlement_name} held a harvest festival in year {current_year}"),
+        };
+        let ev = ctx.world.add_structured_event(
+            EventPayload::Festival {
+                settlement_id: c.settlement_id,
+                faction_id: c.faction_id,
+                religion_id: c.religion_id,
+                happiness_bonus,
+            },
+            time,
+            description,
+        );
+        ctx.world
+            .add_event_participant(ev, c.settlement_id, ParticipantRole::Location);
+        ctx.world
+            .add_event_participant(ev, c.faction_id, ParticipantRole::Subject);
+        if let Some(rid) = c.religion_id {
+            ctx.world
+                .add_event_participant(ev, rid, ParticipantRole::Object);
+        }
+
+        let new_treasury = old_treasury - FESTIVAL_TREASURY_COST;
+        ctx.world.faction_mut(c.faction_id).treasury = new_treasury;
+        ctx.world.record_change(
+            c.faction_id,
+            ev,
+            "treasury",
+            serde_json::json!(old_treasury),
+            serde_json::json!(new_treasury),
+        );
+
+        let sd = ctx.world.settlement_mut(c.settlement_id);
+        let old_bonus = sd.festival_happiness_bonus;
+        let new_bonus = old_bonus.max(happiness_bonus);
+        sd.festival_happiness_bonus = new_bonus;
+        ctx.world.record_change(
+            c.settlement_id,
+            ev,
+            "festival_happiness_bonus",
+            serde_json::json!(old_bonus),
+            serde_json::json!(new_bonus),
+        );
+    }
+}
+
 // --- Helpers ---
 
 fn add_culture_share(
@@ -1000,6 +1206,73 @@ mod tests {
         assert!(b_share < 0.4, "culture B should shrink, got {b_share}");
     }
 
+    #[test]
+    fn assimilation_needs_settled_stable_rule() {
+        assert_eq!(assimilation_rate(0, 1.0), 0.0, "fresh conquest");
+        assert_eq!(assimilation_rate(ASSIMILATION_MIN_RULE_YEARS - 1, 1.0), 0.0);
+        assert_eq!(assimilation_rate(40, ASSIMILATION_MIN_STABILITY - 0.1), 0.0);
+        let early = assimilation_rate(20, 0.8);
+        let late = assimilation_rate(40, 0.8);
+        assert!(0.0 < early && early < late);
+        assert_eq!(
+            assimilation_rate(ASSIMILATION_FULL_RULE_YEARS + 30, 0.8),
+            ASSIMILATION_MAX_RATE
+        );
+    }
+
+    /// A town of `local` culture conquered at year 100 by a faction of `ruling`
+    /// culture, run through `years` of drift. Returns the final tension and
+    /// dominant culture.
+    fn run_conquered_town(stability: f64, years: u32) -> (f64, Option<u64>, u64) {
+        let mut s = Scenario::at_year(100);
+        let ruling = s.add_culture_with("Ruling", |cd| cd.resistance = 0.7);
+        let local = s.add_culture_with("Local", |cd| cd.resistance = 0.7);
+        let setup = s.add_settlement_standalone("Conquered");
+        let _ = s
+            .faction_mut(setup.faction)
+            .primary_culture(Some(ruling))
+            .stability(stability);
+        let _ = s
+            .settlement_mut(setup.settlement)
+            .dominant_culture(Some(local))
+            .culture_makeup(BTreeMap::from([(local, 0.6), (ruling, 0.4)]))
+            .cultural_tension(0.4);
+        let mut world = s.build();
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        for year in 100..100 + years {
+            world.current_time = ts(year);
+            let ev = test_event(&mut world);
+            let mut ctx = TickContext {
+                world: &mut world,
+                rng: &mut rng,
+                signals: &mut signals,
+                inbox: &[],
+            };
+            cultural_drift(&mut ctx, ev);
+        }
+
+        let sd = world.settlement(setup.settlement);
+        (sd.cultural_tension, sd.dominant_culture, ruling)
+    }
+
+    #[test]
+    fn scenario_stable_rule_assimilates_conquered_town() {
+        let (stable_tension, dominant, ruling) = run_conquered_town(0.8, 40);
+        let (unstable_tension, _, _) = run_conquered_town(0.2, 40);
+
+        assert_eq!(dominant, Some(ruling), "ruling culture should take over");
+        assert!(
+            stable_tension < 0.4,
+            "tension should fall under decades of rule, got {stable_tension}"
+        );
+        assert!(
+            stable_tension < unstable_tension,
+            "stable rule should assimilate faster: {stable_tension} vs {unstable_tension}"
+        );
+    }
+
     fn rebellion_scenario() -> World {
         let mut s = Scenario::at_year(100);
 
@@ -1089,6 +1362,7 @@ mod tests {
                 values: vec![CulturalValue::Martial],
                 naming_style: NamingStyle::Steppe,
                 resistance: 0.5,
+                militarism: 0.8,
             }),
             ev,
         );
@@ -1306,6 +1580,7 @@ mod tests {
                 values: vec![CulturalValue::Martial],
                 naming_style: NamingStyle::Steppe,
                 resistance: 0.5,
+                militarism: 0.8,
             }),
             ev,
         );
@@ -1618,4 +1893,42 @@ mod tests {
             "failed rebellion should record stability change at least once"
         );
     }
+
+    #[test]
+    fn scenario_theocracy_holds_periodic_festivals() {
+        let mut s = Scenario::at_year(100);
+        let faith = s.add_religion_with("Zealous Faith", |rd| rd.fervor = 0.9);
+        let setup = s.add_settlement_standalone("Holy City");
+        let _ = s
+            .faction_mut(setup.faction)
+            .government_type(GovernmentType::Theocracy)
+            .primary_religion(Some(faith))
+            .treasury(100.0);
+        let _ = s
+            .settlement_mut(setup.settlement)
+            .prosperity(0.3)
+            .dominant_religion(Some(faith))
+            .religion_makeup(BTreeMap::from([(faith, 1.0)]));
+        let mut world = s.build();
+
+        let mut festival_years = 0;
+        for year in 100..130 {
+            let before = crate::testutil::count_events(&world, &EventKind::Festival);
+            crate::testutil::tick_system(&mut world, &mut CultureSystem, year, year as u64);
+            let bonus = world.settlement(setup.settlement).festival_happiness_bonus;
+            if crate::testutil::count_events(&world, &EventKind::Festival) > before {
+                festival_years += 1;
+                assert!(
+                    bonus >= FESTIVAL_HAPPINESS_BASE,
+                    "festival should lift happiness, got {bonus}"
+                );
+            }
+        }
+
+        assert!(
+            (5..30).contains(&festival_years),
+            "a zealous theocracy should celebrate often but not every year, got {festival_years}"
+        );
+        assert!(world.faction(setup.faction).treasury < 100.0);
+    }
 }
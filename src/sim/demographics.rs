@@ -7,13 +7,15 @@ use super::culture_names::{
 use super::names::{
     extract_surname, generate_person_name_with_surname, generate_unique_person_name,
 };
+use super::runner::SimConfig;
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
 use crate::model::population::PopulationBreakdown;
-use crate::model::traits::generate_traits;
+use crate::model::traits::{Trait, get_npc_traits, inherit_traits};
 use crate::model::{
-    EntityData, EntityKind, EventKind, NamingStyle, ParticipantRole, PersonData, RelationshipKind,
-    Role, Sex, SimTimestamp, World,
+    EntityData, EntityKind, EventKind, EventPayload, NamingStyle, ParticipantRole, PersonData,
+    RegionData, RelationshipKind, ResourceType, Role, SettlementData, Sex, SimTimestamp,
+    TerrainTag, World,
 };
 use crate::sim::helpers;
 
@@ -34,6 +36,13 @@ const PORT_FISHING_CAPACITY: u32 = 200;
 /// Extra carrying capacity for coastal settlements without a port.
 const COASTAL_FISHING_CAPACITY: u32 = 50;
 
+/// Terrain capacity multiplier for settlements with freshwater (a riverine
+/// region or a freshwater resource).
+const FRESHWATER_CAPACITY_MULTIPLIER: f64 = 1.3;
+
+/// Extra carrying capacity per local food resource (grain, cattle, fish, ...).
+const FOOD_RESOURCE_CAPACITY: u32 = 100;
+
 // --- Population thresholds ---
 
 /// Settlements with population below this are abandoned.
@@ -79,6 +88,18 @@ const MORTALITY_AGED: f64 = 0.10;
 const MORTALITY_ANCIENT: f64 = 0.25;
 const MORTALITY_CENTENARIAN: f64 = 1.0;
 
+/// Extra yearly death chance for a leader at zero health, scaled by lost health.
+/// Rulers' failing health is watched (and recorded) closely enough to matter.
+const FRAIL_LEADER_MORTALITY: f64 = 0.15;
+
+// --- Aging ---
+
+/// Age at which health starts to decline.
+const HEALTH_DECLINE_AGE: u32 = 50;
+
+/// Yearly health loss per decade past `HEALTH_DECLINE_AGE`.
+const HEALTH_DECLINE_PER_DECADE: f64 = 0.02;
+
 // --- Age thresholds ---
 
 /// Minimum age to be considered an adult (for marriage and parenthood).
@@ -98,7 +119,72 @@ const CROSS_FACTION_ALLIANCE_CHANCE: f64 = 0.5;
 /// Years a widowed person must wait before remarrying.
 const WIDOWED_REMARRIAGE_COOLDOWN: u32 = 3;
 
-pub struct DemographicsSystem;
+/// How prosperity and learning shape lifespans and fertility. Set on
+/// [`SimConfig::demographics`] so thriving cities outlive and outgrow
+/// struggling frontier hamlets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemographicsConfig {
+    /// Prosperity at which a settlement sees the base mortality and birth rates.
+    pub baseline_prosperity: f64,
+    /// Relative mortality change per unit of prosperity below the baseline
+    /// (a reduction above it).
+    pub prosperity_mortality_weight: f64,
+    /// Relative fertility change per unit of prosperity above the baseline.
+    pub prosperity_fertility_weight: f64,
+    /// Share of deaths a fully literate settlement's medicine prevents.
+    pub literacy_mortality_reduction: f64,
+    /// Floor on the combined mortality multiplier.
+    pub min_mortality_multiplier: f64,
+    /// Relative fertility boost per unit of post-siege rebuilding boom.
+    pub rebuilding_fertility_weight: f64,
+}
+
+impl Default for DemographicsConfig {
+    fn default() -> Self {
+        Self {
+            baseline_prosperity: 0.5,
+            prosperity_mortality_weight: 0.6,
+            prosperity_fertility_weight: 0.5,
+            literacy_mortality_reduction: 0.3,
+            min_mortality_multiplier: 0.25,
+            rebuilding_fertility_weight: 0.8,
+        }
+    }
+}
+
+impl DemographicsConfig {
+    /// Factor on base mortality for a settlement with this prosperity and literacy.
+    pub fn mortality_multiplier(&self, prosperity: f64, literacy_rate: f64) -> f64 {
+        let prosperity_factor =
+            1.0 + self.prosperity_mortality_weight * (self.baseline_prosperity - prosperity);
+        let medicine_factor = 1.0 - self.literacy_mortality_reduction * literacy_rate;
+        (prosperity_factor * medicine_factor).max(self.min_mortality_multiplier)
+    }
+
+    /// Factor on the base birth rate for a settlement with this prosperity,
+    /// raised while it rebuilds after a siege.
+    pub fn fertility_multiplier(&self, prosperity: f64, rebuilding_boom: f64) -> f64 {
+        let prosperity_factor = (1.0
+            + self.prosperity_fertility_weight * (prosperity - self.baseline_prosperity))
+            .max(0.0);
+        prosperity_factor * (1.0 + self.rebuilding_fertility_weight * rebuilding_boom)
+    }
+}
+
+#[derive(Default)]
+pub struct DemographicsSystem {
+    config: DemographicsConfig,
+}
+
+impl DemographicsSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_config(config: DemographicsConfig) -> Self {
+        Self { config }
+    }
+}
 
 impl SimSystem for DemographicsSystem {
     fn name(&self) -> &str {
@@ -119,12 +205,17 @@ impl SimSystem for DemographicsSystem {
             format!("Year {} demographics tick", time.year()),
         );
 
-        let settlements = compute_capacity(ctx);
+        let settlements = compute_capacity(ctx, &self.config);
         grow_population(ctx, &settlements, time, year_event);
-        process_mortality(ctx, time);
+        process_aging(ctx, time, year_event);
+        process_mortality(ctx, &self.config, time);
         process_births(ctx, time);
         process_marriages(ctx, time);
     }
+
+    fn configure(&mut self, config: &SimConfig) {
+        self.config = config.demographics.clone();
+    }
 }
 
 // --- Helper structs ---
@@ -133,6 +224,8 @@ struct SettlementInfo {
     id: u64,
     breakdown: PopulationBreakdown,
     capacity: u32,
+    mortality_multiplier: f64,
+    fertility_multiplier: f64,
 }
 
 struct PopUpdate {
@@ -145,6 +238,7 @@ struct PopUpdate {
 struct PersonInfo {
     id: u64,
     born: SimTimestamp,
+    health: f64,
     settlement_id: Option<u64>,
     is_leader: bool,
 }
@@ -175,71 +269,87 @@ struct BirthPlan {
 
 // --- Tick sub-functions ---
 
-/// Compute carrying capacity for each living settlement based on region terrain,
-/// building bonuses, and seasonal modifiers. Stores capacity as an extra on each
-/// settlement for use by other systems.
-fn compute_capacity(ctx: &mut TickContext) -> Vec<SettlementInfo> {
-    // Collect region terrain data for carrying capacity
-    let region_capacities: Vec<(u64, u32)> = ctx
-        .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Region)
-        .filter_map(|e| {
-            let region = e.data.as_region()?;
+/// Carrying capacity of a settlement: its region's terrain ceiling (boosted by
+/// freshwater), local food resources, coastal fishing, building bonuses, and
+/// land improvements,
+/// scaled by the seasonal food modifier. Settlements without a region fall back
+/// to `DEFAULT_CAPACITY` for the terrain part.
+pub fn settlement_capacity(region: Option<&RegionData>, sd: &SettlementData) -> u32 {
+    let has_freshwater = sd.resources.contains(&ResourceType::Freshwater)
+        || region.is_some_and(|r| r.terrain_tags.contains(&TerrainTag::Riverine));
+    let base_capacity = match region {
+        Some(region) => {
             let profile = crate::worldgen::terrain::TerrainProfile::new(
                 region.terrain,
                 region.terrain_tags.clone(),
             );
-            let capacity = profile.effective_population_range().1 * REGION_CAPACITY_MULTIPLIER;
-            Some((e.id, capacity))
-        })
-        .collect();
+            let terrain_cap = profile.effective_population_range().1 * REGION_CAPACITY_MULTIPLIER;
+            if has_freshwater {
+                (terrain_cap as f64 * FRESHWATER_CAPACITY_MULTIPLIER) as u32
+            } else {
+                terrain_cap
+            }
+        }
+        None => DEFAULT_CAPACITY,
+    };
 
-    // Collect settlement data
-    let settlements: Vec<SettlementInfo> = ctx
-        .world
+    // Local food sources; freshwater is already counted above
+    let food_resources = sd
+        .resources
+        .iter()
+        .filter(|r| **r != ResourceType::Freshwater && helpers::is_food_resource(r))
+        .count() as u32;
+    let food_capacity = food_resources * FOOD_RESOURCE_CAPACITY;
+
+    // Building bonuses from BuildingSystem
+    let capacity_bonus = sd.building_bonuses.capacity;
+    // Granary food buffer acts as extra effective capacity (reduces starvation)
+    let food_buffer_capacity = (sd.building_bonuses.food_buffer * FOOD_BUFFER_POP_PER_UNIT) as u32;
+
+    // Fishing capacity: coastal settlements get extra pop capacity
+    let has_port = sd.building_bonuses.port_trade > 0.0;
+    let fishing_cap = match (sd.is_coastal, has_port) {
+        (true, true) => PORT_FISHING_CAPACITY,
+        (true, false) => COASTAL_FISHING_CAPACITY,
+        _ => 0,
+    };
+
+    // Irrigation and cleared land from EconomySystem investment
+    let improvement_capacity = sd.land_improvement as u32;
+
+    // Seasonal food modifier reduces effective capacity in winter/droughts
+    let raw_capacity = base_capacity
+        + food_capacity
+        + capacity_bonus as u32
+        + food_buffer_capacity
+        + fishing_cap
+        + improvement_capacity;
+    (raw_capacity as f64 * sd.seasonal.food_annual) as u32
+}
+
+/// Compute carrying capacity for each living settlement via
+/// [`settlement_capacity`], along with its prosperity-driven vital rates.
+/// Stores capacity on each settlement for use by other systems.
+fn compute_capacity(ctx: &mut TickContext, config: &DemographicsConfig) -> Vec<SettlementInfo> {
+    let world = &*ctx.world;
+    let settlements: Vec<SettlementInfo> = world
         .entities
         .values()
         .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
         .filter_map(|e| {
-            let settlement = e.data.as_settlement()?;
-            let breakdown = settlement.population_breakdown.clone();
-
-            let region_id = e.active_rel(RelationshipKind::LocatedIn);
-
-            let base_capacity = region_capacities
-                .iter()
-                .find(|(id, _)| Some(*id) == region_id)
-                .map(|(_, cap)| *cap)
-                .unwrap_or(DEFAULT_CAPACITY);
-
-            // Building bonuses from BuildingSystem
-            let sd = e.data.as_settlement();
-            let capacity_bonus = sd.map(|s| s.building_bonuses.capacity).unwrap_or(0.0);
-            // Granary food buffer acts as extra effective capacity (reduces starvation)
-            let food_buffer = sd.map(|s| s.building_bonuses.food_buffer).unwrap_or(0.0);
-            let food_buffer_capacity = (food_buffer * FOOD_BUFFER_POP_PER_UNIT) as u32;
-
-            // Fishing capacity: coastal settlements get extra pop capacity
-            let is_coastal = sd.is_some_and(|s| s.is_coastal);
-            let has_port = sd.is_some_and(|s| s.building_bonuses.port_trade > 0.0);
-            let fishing_cap = match (is_coastal, has_port) {
-                (true, true) => PORT_FISHING_CAPACITY,
-                (true, false) => COASTAL_FISHING_CAPACITY,
-                _ => 0,
-            };
-
-            // Seasonal food modifier reduces effective capacity in winter/droughts
-            let season_food_annual = sd.map(|s| s.seasonal.food_annual).unwrap_or(1.0);
-            let raw_capacity =
-                base_capacity + capacity_bonus as u32 + food_buffer_capacity + fishing_cap;
-            let capacity = (raw_capacity as f64 * season_food_annual) as u32;
+            let sd = e.data.as_settlement()?;
+            let region = e
+                .active_rel(RelationshipKind::LocatedIn)
+                .and_then(|rid| world.entities.get(&rid))
+                .and_then(|r| r.data.as_region());
 
             Some(SettlementInfo {
                 id: e.id,
-                breakdown,
-                capacity,
+                breakdown: sd.population_breakdown.clone(),
+                capacity: settlement_capacity(region, sd),
+                mortality_multiplier: config.mortality_multiplier(sd.prosperity, sd.literacy_rate),
+                fertility_multiplier: config
+                    .fertility_multiplier(sd.prosperity, sd.rebuilding_boom),
             })
         })
         .collect();
@@ -266,7 +376,12 @@ fn grow_population(
 
         let old_pop = s.breakdown.total();
         let mut breakdown = s.breakdown.clone();
-        breakdown.tick_year(capacity, ctx.rng);
+        breakdown.tick_year_scaled(
+            capacity,
+            s.mortality_multiplier,
+            s.fertility_multiplier,
+            ctx.rng,
+        );
         let new_pop = breakdown.total();
 
         pop_updates.push(PopUpdate {
@@ -287,6 +402,13 @@ fn grow_population(
             );
             ctx.world
                 .add_event_participant(ev, update.settlement_id, ParticipantRole::Subject);
+            helpers::leave_ruins(
+                ctx.world,
+                update.settlement_id,
+                "population_collapse",
+                time,
+                ev,
+            );
             ctx.world.end_entity(update.settlement_id, time, ev);
         } else {
             let new_pop = update.new_breakdown.total();
@@ -324,9 +446,45 @@ fn grow_population(
     }
 }
 
+/// Wear down the health of everyone past `HEALTH_DECLINE_AGE`, faster the older they are.
+/// Only leaders' decline is recorded in the history: it is what drives abdication, and
+/// logging every elder's yearly loss would swamp the change log.
+fn process_aging(ctx: &mut TickContext, time: SimTimestamp, year_event: u64) {
+    let declines: Vec<(u64, f64, f64, bool)> = ctx
+        .world
+        .entities
+        .values()
+        .filter(|e| e.kind == EntityKind::Person && e.end.is_none())
+        .filter_map(|e| {
+            let pd = e.data.as_person()?;
+            let new_health = aged_health(pd.health, time.years_since(pd.born));
+            let is_leader = e.active_rel(RelationshipKind::LeaderOf).is_some();
+            (new_health != pd.health).then_some((e.id, pd.health, new_health, is_leader))
+        })
+        .collect();
+
+    for (id, old, new, is_leader) in declines {
+        ctx.world.person_mut(id).health = new;
+        if is_leader {
+            ctx.world.record_change(
+                id,
+                year_event,
+                "health",
+                serde_json::json!(old),
+                serde_json::json!(new),
+            );
+        }
+    }
+}
+
+fn aged_health(health: f64, age: u32) -> f64 {
+    let decades_past = age.saturating_sub(HEALTH_DECLINE_AGE) as f64 / 10.0;
+    (health - decades_past * HEALTH_DECLINE_PER_DECADE).max(0.0)
+}
+
 /// Roll mortality checks for all living persons and apply deaths. Handles leader
 /// vacancy signals, spouse widowing, and relationship cleanup.
-fn process_mortality(ctx: &mut TickContext, time: SimTimestamp) {
+fn process_mortality(ctx: &mut TickContext, config: &DemographicsConfig, time: SimTimestamp) {
     let persons: Vec<PersonInfo> = ctx
         .world
         .entities
@@ -339,6 +497,7 @@ fn process_mortality(ctx: &mut TickContext, time: SimTimestamp) {
             Some(PersonInfo {
                 id: e.id,
                 born: person.born,
+                health: person.health,
                 settlement_id,
                 is_leader,
             })
@@ -348,7 +507,17 @@ fn process_mortality(ctx: &mut TickContext, time: SimTimestamp) {
     let mut deaths: Vec<DeathInfo> = Vec::new();
     for person in &persons {
         let age = time.years_since(person.born);
-        let mortality = mortality_rate(age);
+        let mut mortality = mortality_rate(age);
+        if let Some(sd) = person
+            .settlement_id
+            .and_then(|sid| ctx.world.entities.get(&sid))
+            .and_then(|e| e.data.as_settlement())
+        {
+            mortality *= config.mortality_multiplier(sd.prosperity, sd.literacy_rate);
+        }
+        if person.is_leader {
+            mortality += (1.0 - person.health) * FRAIL_LEADER_MORTALITY;
+        }
         let roll: f64 = ctx.rng.random_range(0.0..1.0);
         if roll < mortality {
             deaths.push(DeathInfo {
@@ -367,8 +536,11 @@ fn process_mortality(ctx: &mut TickContext, time: SimTimestamp) {
             .get(&death.person_id)
             .map(|e| e.name.clone())
             .unwrap_or_else(|| format!("entity {}", death.person_id));
-        let ev = ctx.world.add_event(
-            EventKind::Death,
+        let ev = ctx.world.add_structured_event(
+            EventPayload::Death {
+                entity_id: death.person_id,
+                cause: "natural".to_string(),
+            },
             time,
             format!("{person_name} died in year {}", time.year()),
         );
@@ -554,16 +726,38 @@ fn process_births(ctx: &mut TickContext, time: SimTimestamp) {
                 Sex::Female
             };
 
-            // Generate personality traits
-            let traits = generate_traits(&selected_role, ctx.rng);
+            // Generate personality traits, inheriting from known parents
+            let parent_traits: Vec<Vec<Trait>> = [father_id, mother_id]
+                .into_iter()
+                .flatten()
+                .filter_map(|id| ctx.world.entities.get(&id))
+                .map(get_npc_traits)
+                .collect();
+            let parent_refs: Vec<&[Trait]> = parent_traits.iter().map(Vec::as_slice).collect();
+            let traits = inherit_traits(&selected_role, &parent_refs, ctx.rng);
+
+            // Dynasty passes down the father's line, else the mother's
+            let dynasty_id = [father_id, mother_id].into_iter().flatten().find_map(|id| {
+                ctx.world
+                    .entities
+                    .get(&id)
+                    .and_then(|e| e.data.as_person())
+                    .and_then(|pd| pd.dynasty_id)
+            });
 
-            let ev = ctx.world.add_event(
-                EventKind::Birth,
+            let person_id = ctx.world.reserve_entity_id();
+            let ev = ctx.world.add_structured_event(
+                EventPayload::Birth {
+                    person_id,
+                    parent_ids: [father_id, mother_id].into_iter().flatten().collect(),
+                    settlement_id: Some(plan.settlement_id),
+                },
                 time,
                 format!("{name} born in year {}", time.year()),
             );
 
-            let person_id = ctx.world.add_entity(
+            ctx.world.add_reserved_entity(
+                person_id,
                 EntityKind::Person,
                 name,
                 Some(time),
@@ -582,6 +776,8 @@ fn process_births(ctx: &mut TickContext, time: SimTimestamp) {
                     prestige_tier: 0,
                     loyalty: std::collections::BTreeMap::new(),
                     education: 0.0,
+                    health: 1.0,
+                    dynasty_id,
                 }),
                 ev,
             );
@@ -690,7 +886,8 @@ fn end_person_relationships(
     event_id: u64,
 ) {
     // Collect relationship targets before mutating
-    // End LocatedIn, MemberOf, and Spouse — but NOT Parent/Child (permanent genealogical facts)
+    // End LocatedIn, MemberOf, Spouse, captivity, governorships, and spy
+    // networks — but NOT Parent/Child (permanent genealogical facts)
     let rels: Vec<(u64, RelationshipKind)> = world
         .entities
         .get(&person_id)
@@ -699,12 +896,15 @@ fn end_person_relationships(
                 .iter()
                 .filter(|r| {
                     r.end.is_none()
-                        && matches!(
+                        && (matches!(
                             r.kind,
                             RelationshipKind::LocatedIn
                                 | RelationshipKind::MemberOf
                                 | RelationshipKind::Spouse
-                        )
+                                | RelationshipKind::CaptiveOf
+                                | RelationshipKind::GovernorOf
+                                | RelationshipKind::SpymasterOf
+                        ))
                 })
                 .map(|r| (r.target_entity_id, r.kind.clone()))
                 .collect()
@@ -918,7 +1118,14 @@ fn apply_marriages(marriages: &[MarriagePlan], ctx: &mut TickContext, time: SimT
             format!("{name_a} and {name_b} married in year {}", time.year())
         };
 
-        let ev = ctx.world.add_event(EventKind::Union, time, desc);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::Union {
+                person_a: marriage.spouse_a,
+                person_b: marriage.spouse_b,
+            },
+            time,
+            desc,
+        );
         ctx.world
             .add_event_participant(ev, marriage.spouse_a, ParticipantRole::Subject);
         ctx.world
@@ -1061,6 +1268,99 @@ mod tests {
         assert!(mortality_rate(0) > mortality_rate(10));
     }
 
+    #[test]
+    fn health_declines_faster_with_age() {
+        assert_eq!(aged_health(1.0, 40), 1.0);
+        assert_eq!(aged_health(1.0, HEALTH_DECLINE_AGE), 1.0);
+        let at_60 = 1.0 - aged_health(1.0, 60);
+        let at_80 = 1.0 - aged_health(1.0, 80);
+        assert!(at_60 > 0.0);
+        assert!(at_80 > at_60 * 2.0);
+        assert_eq!(aged_health(0.01, 95), 0.0);
+    }
+
+    #[test]
+    fn aging_records_health_only_for_leaders() {
+        use crate::model::StateChange;
+        use crate::scenario::Scenario;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut s = Scenario::at_year(100);
+        let k = s.add_kingdom_with(
+            "Elderland",
+            |_| {},
+            |_| {},
+            |pd| pd.born = SimTimestamp::from_year(20),
+        );
+        let elder = s.person("Old Commoner", k.faction).birth_year(20).id();
+        let mut world = s.build();
+        let time = SimTimestamp::from_year(100);
+        let year_event =
+            world.add_event(EventKind::Custom("year_tick".into()), time, "tick".into());
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world: &mut world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        process_aging(&mut ctx, time, year_event);
+
+        assert!(world.person(elder).health < 1.0);
+        assert!(world.person(k.leader).health < 1.0);
+        let health_changes: Vec<u64> = world
+            .event_effects
+            .iter()
+            .filter(|e| {
+                matches!(&e.effect, StateChange::PropertyChanged { field, .. } if field == "health")
+            })
+            .map(|e| e.entity_id)
+            .collect();
+        assert_eq!(health_changes, vec![k.leader]);
+    }
+
+    #[test]
+    fn freshwater_plains_outgrow_desert() {
+        use crate::model::{Climate, Terrain};
+
+        let region = |terrain, terrain_tags| RegionData {
+            terrain,
+            terrain_tags,
+            climate: Climate::from_latitude(0.5, terrain),
+            x: 0.0,
+            y: 0.0,
+            resources: Vec::new(),
+        };
+        let settlement = |resources| {
+            let mut data = EntityData::default_for_kind(EntityKind::Settlement);
+            let sd = data.as_settlement_mut().unwrap();
+            sd.resources = resources;
+            sd.building_bonuses.capacity = 150.0;
+            sd.building_bonuses.food_buffer = 1.0;
+            sd.clone()
+        };
+
+        let river_plains = settlement(vec![ResourceType::Grain, ResourceType::Freshwater]);
+        let desert = settlement(vec![ResourceType::Salt]);
+        let plains_cap = settlement_capacity(
+            Some(&region(Terrain::Plains, vec![TerrainTag::Riverine])),
+            &river_plains,
+        );
+        let desert_cap = settlement_capacity(Some(&region(Terrain::Desert, vec![])), &desert);
+        assert!(
+            plains_cap > desert_cap,
+            "plains {plains_cap} should exceed desert {desert_cap}"
+        );
+
+        // Freshwater and food raise the ceiling on the same terrain
+        let dry_plains = settlement(vec![]);
+        let dry_cap = settlement_capacity(Some(&region(Terrain::Plains, vec![])), &dry_plains);
+        assert!(plains_cap > dry_cap);
+    }
+
     #[test]
     fn mortality_100_is_certain() {
         assert_eq!(mortality_rate(100), 1.0);
@@ -1142,4 +1442,69 @@ mod tests {
         assert_eq!(father, None, "children should not be parents");
         assert_eq!(mother, None, "children should not be parents");
     }
+
+    #[test]
+    fn prosperous_settlement_outgrows_struggling_one() {
+        use crate::scenario::Scenario;
+
+        let mut s = Scenario::at_year(100);
+        let thriving = s.add_settlement_standalone("Thriving").settlement;
+        let struggling = s.add_settlement_standalone("Struggling").settlement;
+        let _ = s.settlement_mut(thriving).population(200).prosperity(0.9);
+        let _ = s.settlement_mut(struggling).population(200).prosperity(0.1);
+        let mut world = s.build();
+
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(DemographicsSystem::new())];
+        crate::testutil::run_years(&mut world, &mut systems, 50, 42);
+
+        let thriving_pop = world.settlement(thriving).population;
+        let struggling_pop = world.settlement(struggling).population;
+        assert!(
+            thriving_pop > struggling_pop,
+            "prosperity should drive growth: thriving {thriving_pop} vs struggling {struggling_pop}"
+        );
+    }
+
+    #[test]
+    fn births_deaths_and_marriages_carry_payloads() {
+        use crate::model::EventPayload;
+
+        let world = crate::testutil::generate_and_run(42, 30, crate::testutil::core_systems());
+
+        for kind in [EventKind::Birth, EventKind::Death, EventKind::Union] {
+            let events: Vec<_> = world.events.values().filter(|e| e.kind == kind).collect();
+            assert!(!events.is_empty(), "expected some {kind:?} events");
+            for e in events {
+                let payload = EventPayload::from_data(&e.data)
+                    .unwrap_or_else(|| panic!("{kind:?} event {} lacks a payload", e.id));
+                assert_eq!(payload.kind(), kind);
+                if let EventPayload::Birth { person_id, .. } = payload {
+                    assert_eq!(world.entity(person_id).origin, Some(e.timestamp));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn collapsed_settlement_leaves_ruins_in_its_region() {
+        use crate::scenario::Scenario;
+
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone("Old Town");
+        let _ = s.settlement_mut(setup.settlement).population(3);
+        let mut world = s.build();
+
+        crate::testutil::tick_system(&mut world, &mut DemographicsSystem::new(), 100, 42);
+
+        assert!(world.entity(setup.settlement).end.is_some());
+        let ruins = world
+            .living(EntityKind::Monument)
+            .find(|e| e.has_active_rel(RelationshipKind::LocatedIn, setup.region))
+            .expect("abandoned settlement should leave ruins in its region");
+        assert_eq!(ruins.name, "Ruins of Old Town");
+        let md = ruins.data.as_monument().unwrap();
+        assert_eq!(md.settlement_id, setup.settlement);
+        assert_eq!(md.settlement_name, "Old Town");
+        assert_eq!(md.cause, "population_collapse");
+    }
 }
@@ -1,14 +1,16 @@
 use rand::Rng;
 
 use crate::model::entity::EntityKind;
-use crate::model::entity_data::{ActiveDisease, DisasterType, DiseaseData};
+use crate::model::entity_data::{ActiveDisease, DisasterType, DiseaseData, Transmission};
 use crate::model::event::{EventKind, ParticipantRole};
+use crate::model::event_payload::EventPayload;
 use crate::model::population::NUM_BRACKETS;
 use crate::model::relationship::RelationshipKind;
 use crate::model::timestamp::SimTimestamp;
-use crate::worldgen::terrain::Terrain;
+use crate::worldgen::terrain::{Climate, Terrain};
 
 use super::context::TickContext;
+use super::environment::Season;
 use super::helpers;
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
@@ -19,7 +21,7 @@ use super::system::{SimSystem, TickFrequency};
 const BASE_OUTBREAK_CHANCE: f64 = 0.002;
 /// Bonus outbreak chance when population exceeds 80% of carrying capacity.
 const OVERCROWDING_BONUS: f64 = 0.003;
-/// Bonus outbreak chance for swamp/jungle terrain.
+/// Bonus outbreak chance for stagnant swamp water.
 const TERRAIN_BONUS: f64 = 0.002;
 /// Bonus outbreak chance per active trade route.
 const TRADE_ROUTE_BONUS: f64 = 0.0005;
@@ -38,6 +40,8 @@ const BASE_TRANSMISSION: f64 = 0.3;
 const TRADE_TRANSMISSION_BONUS: f64 = 0.2;
 /// Multiplier for adjacency-only spread (no trade route).
 const ADJACENCY_ONLY_FACTOR: f64 = 0.5;
+/// Extra spread of water-borne disease through warm, stagnant swamp water.
+const SWAMP_WARM_SEASON_FACTOR: f64 = 1.4;
 
 /// Infection rate climbs toward this fraction of virulence during ramp phase.
 const RAMP_TARGET_FRACTION: f64 = 0.6;
@@ -51,6 +55,11 @@ const RECOVERY_IMMUNITY: f64 = 0.7;
 /// Annual decay of plague_immunity.
 const IMMUNITY_DECAY: f64 = 0.05;
 
+/// Labor shortage added per unit of population fraction killed (0.2 dead → 0.2 shortage).
+const LABOR_SHORTAGE_PER_MORTALITY: f64 = 1.0;
+/// Ceiling on the accumulated labor shortage.
+const MAX_LABOR_SHORTAGE: f64 = 0.5;
+
 /// NPC plague death modifier (slightly lower than general pop — better fed, can isolate).
 const NPC_DEATH_MODIFIER: f64 = 0.5;
 
@@ -103,9 +112,58 @@ fn random_disease_data(rng: &mut dyn rand::RngCore) -> DiseaseData {
         lethality: rng.random_range(0.1..0.5),
         duration_years: rng.random_range(2..6),
         bracket_severity: profile,
+        transmission: if rng.random_bool(0.5) {
+            Transmission::Respiratory
+        } else {
+            Transmission::Waterborne
+        },
     }
 }
 
+/// How much more (or less) readily a disease spreads in `month` than its
+/// yearly average. Respiratory diseases spike in winter; water-borne ones in
+/// the warm months, worst of all in swamps. Tropical regions have no cold
+/// season, so respiratory spread is flat there and water-borne spread stays
+/// high all year.
+pub fn seasonal_disease_multiplier(
+    transmission: Transmission,
+    month: u32,
+    climate: Climate,
+    terrain: Terrain,
+) -> f64 {
+    let season = Season::from_month(month);
+    match transmission {
+        Transmission::Respiratory => match (season, climate) {
+            (_, Climate::Tropical) => 1.0,
+            (Season::Winter, Climate::Boreal) => 1.8,
+            (Season::Winter, _) => 1.6,
+            (Season::Autumn, _) => 1.2,
+            (Season::Spring, _) => 0.8,
+            (Season::Summer, _) => 0.5,
+        },
+        Transmission::Waterborne => {
+            let warm = season == Season::Summer || climate == Climate::Tropical;
+            let base = match (season, climate) {
+                (_, Climate::Tropical) => 1.3,
+                (Season::Summer, _) => 1.6,
+                (Season::Autumn, _) => 1.1,
+                (Season::Spring, _) => 0.8,
+                (Season::Winter, _) => 0.4,
+            };
+            if warm && terrain == Terrain::Swamp {
+                base * SWAMP_WARM_SEASON_FACTOR
+            } else {
+                base
+            }
+        }
+    }
+}
+
+/// Per-month chance of something that happens with `yearly` chance a year.
+fn monthly_chance(yearly: f64) -> f64 {
+    1.0 - (1.0 - yearly.clamp(0.0, 1.0)).powf(1.0 / 12.0)
+}
+
 // --- Settlement info collection ---
 
 struct SettlementDiseaseInfo {
@@ -117,12 +175,19 @@ struct SettlementDiseaseInfo {
     region_id: Option<u64>,
     trade_route_targets: Vec<u64>,
     terrain: Terrain,
+    climate: Climate,
     carrying_capacity: u32,
 }
 
+/// Spontaneous outbreak chance before local risk factors, scaled by how
+/// readily the region's climate breeds disease.
+fn base_outbreak_chance(climate: Climate) -> f64 {
+    BASE_OUTBREAK_CHANCE * climate.disease_rate()
+}
+
 fn collect_settlement_info(world: &crate::model::World) -> Vec<SettlementDiseaseInfo> {
     // Pre-compute region capacities
-    let region_data: Vec<(u64, Terrain, u32)> = world
+    let region_data: Vec<(u64, Terrain, Climate, u32)> = world
         .entities
         .values()
         .filter(|e| e.kind == EntityKind::Region)
@@ -133,7 +198,7 @@ fn collect_settlement_info(world: &crate::model::World) -> Vec<SettlementDisease
                 region.terrain_tags.clone(),
             );
             let capacity = profile.effective_population_range().1 * 5;
-            Some((e.id, region.terrain, capacity))
+            Some((e.id, region.terrain, region.climate, capacity))
         })
         .collect();
 
@@ -149,11 +214,11 @@ fn collect_settlement_info(world: &crate::model::World) -> Vec<SettlementDisease
             let trade_route_targets: Vec<u64> =
                 e.active_rels(RelationshipKind::TradeRoute).collect();
 
-            let (terrain, carrying_capacity) = region_data
+            let (terrain, climate, carrying_capacity) = region_data
                 .iter()
-                .find(|(id, _, _)| Some(*id) == region_id)
-                .map(|(_, t, c)| (*t, *c))
-                .unwrap_or((Terrain::Plains, 500));
+                .find(|(id, ..)| Some(*id) == region_id)
+                .map(|&(_, t, cl, c)| (t, cl, c))
+                .unwrap_or((Terrain::Plains, Climate::Temperate, 500));
 
             Some(SettlementDiseaseInfo {
                 id: e.id,
@@ -164,6 +229,7 @@ fn collect_settlement_info(world: &crate::model::World) -> Vec<SettlementDisease
                 region_id,
                 trade_route_targets,
                 terrain,
+                climate,
                 carrying_capacity,
             })
         })
@@ -226,16 +292,19 @@ impl SimSystem for DiseaseSystem {
     }
 
     fn frequency(&self) -> TickFrequency {
-        TickFrequency::Yearly
+        TickFrequency::Monthly
     }
 
     fn tick(&mut self, ctx: &mut TickContext) {
         let time = ctx.world.current_time;
+        let year_start = time.is_year_start();
 
         let settlements = collect_settlement_info(ctx.world);
 
-        // Phase 1: Immunity decay (before outbreak checks)
-        decay_immunity(ctx, &settlements, time);
+        // Phase 1: Immunity decay (yearly, before outbreak checks)
+        if year_start {
+            decay_immunity(ctx, &settlements, time);
+        }
 
         // Phase 2: Spontaneous outbreak checks
         check_outbreaks(ctx, &settlements, time);
@@ -243,14 +312,14 @@ impl SimSystem for DiseaseSystem {
         // Re-collect after possible mutations
         let settlements = collect_settlement_info(ctx.world);
 
-        // Phase 3: Disease spread
+        // Phase 3: Disease spread, paced by the season
         spread_disease(ctx, &settlements, time);
 
-        // Re-collect after possible mutations
-        let settlements = collect_settlement_info(ctx.world);
-
-        // Phase 4: Disease progression + mortality
-        progress_and_mortality(ctx, &settlements, time);
+        // Phase 4: Disease progression + mortality (yearly)
+        if year_start {
+            let settlements = collect_settlement_info(ctx.world);
+            progress_and_mortality(ctx, &settlements, time);
+        }
     }
 
     fn handle_signals(&mut self, ctx: &mut TickContext) {
@@ -357,7 +426,7 @@ fn check_outbreaks(
             continue;
         }
 
-        let mut chance = BASE_OUTBREAK_CHANCE;
+        let mut chance = base_outbreak_chance(info.climate);
 
         // Overcrowding
         if info.carrying_capacity > 0
@@ -367,7 +436,7 @@ fn check_outbreaks(
         }
 
         // Terrain
-        if matches!(info.terrain, Terrain::Swamp | Terrain::Jungle) {
+        if info.terrain == Terrain::Swamp {
             chance += TERRAIN_BONUS;
         }
 
@@ -405,7 +474,7 @@ fn check_outbreaks(
         }
 
         let roll: f64 = ctx.rng.random_range(0.0..1.0);
-        if roll < chance {
+        if roll < monthly_chance(chance) {
             targets.push(OutbreakTarget {
                 settlement_id: info.id,
             });
@@ -544,6 +613,12 @@ fn spread_disease(
         };
 
         let base_spread = disease.virulence * active.infection_rate * BASE_TRANSMISSION;
+        let season = seasonal_disease_multiplier(
+            disease.transmission,
+            time.month(),
+            info.climate,
+            info.terrain,
+        );
 
         // Check if source has a port
         let source_has_port = ctx.world.settlement(info.id).building_bonuses.port_trade > 0.0;
@@ -566,7 +641,7 @@ fn spread_disease(
                 let transmission = (base_spread + TRADE_TRANSMISSION_BONUS + port_bonus)
                     * (1.0 - ti.plague_immunity);
                 let roll: f64 = ctx.rng.random_range(0.0..1.0);
-                if roll < transmission {
+                if roll < monthly_chance(transmission * season) {
                     targets.push(SpreadTarget {
                         target_id,
                         disease_id: active.disease_id,
@@ -595,7 +670,7 @@ fn spread_disease(
                 }
                 let transmission = base_spread * ADJACENCY_ONLY_FACTOR * (1.0 - ti.plague_immunity);
                 let roll: f64 = ctx.rng.random_range(0.0..1.0);
-                if roll < transmission {
+                if roll < monthly_chance(transmission * season) {
                     targets.push(SpreadTarget {
                         target_id: adj_id,
                         disease_id: active.disease_id,
@@ -774,6 +849,11 @@ fn progress_and_mortality(
                 .population_breakdown
                 .apply_disease_mortality(&mortality_rates, ctx.rng);
             s.population = s.population_breakdown.total();
+            if old_pop > 0 {
+                s.labor_shortage = (s.labor_shortage
+                    + deaths as f64 / old_pop as f64 * LABOR_SHORTAGE_PER_MORTALITY)
+                    .min(MAX_LABOR_SHORTAGE);
+            }
             deaths
         };
 
@@ -982,8 +1062,11 @@ fn kill_npcs_from_plague(
             .map(|e| e.name.clone())
             .unwrap_or_else(|| "plague".to_string());
 
-        let ev = ctx.world.add_caused_event(
-            EventKind::Death,
+        let ev = ctx.world.add_caused_structured_event(
+            EventPayload::Death {
+                entity_id: npc_id,
+                cause: "disease".to_string(),
+            },
             time,
             format!("{npc_name} died of {disease_name} in year {}", time.year()),
             outbreak_event,
@@ -1045,6 +1128,126 @@ mod tests {
         }
     }
 
+    #[test]
+    fn seasonal_multiplier_follows_transmission() {
+        let resp = |month| {
+            seasonal_disease_multiplier(
+                Transmission::Respiratory,
+                month,
+                Climate::Temperate,
+                Terrain::Plains,
+            )
+        };
+        assert!(resp(11) > 1.0, "respiratory disease should spike in winter");
+        assert!(resp(5) < 1.0);
+
+        let water = |month, terrain| {
+            seasonal_disease_multiplier(
+                Transmission::Waterborne,
+                month,
+                Climate::Temperate,
+                terrain,
+            )
+        };
+        assert!(water(5, Terrain::Plains) > water(11, Terrain::Plains));
+        assert!(
+            water(5, Terrain::Swamp) > water(5, Terrain::Plains),
+            "warm swamps should breed water-borne disease"
+        );
+        assert_eq!(water(11, Terrain::Swamp), water(11, Terrain::Plains));
+
+        assert_eq!(
+            seasonal_disease_multiplier(
+                Transmission::Respiratory,
+                11,
+                Climate::Tropical,
+                Terrain::Plains
+            ),
+            1.0,
+            "tropics have no cold season"
+        );
+    }
+
+    #[test]
+    fn scenario_disease_spreads_faster_in_its_favorable_season() {
+        let spreads_in = |transmission: Transmission, month: u32| -> usize {
+            let mut s = Scenario::new();
+            let source = s.add_settlement_standalone("Source");
+            let target = s.add_settlement_standalone("Target");
+            s.make_adjacent(source.region, target.region);
+            let mut world = s.build();
+            let time = SimTimestamp::from_year_month(10, month);
+            world.current_time = time;
+
+            let mut rng = SmallRng::seed_from_u64(42);
+            let mut signals = Vec::new();
+            let disease_id = {
+                let mut ctx = TickContext {
+                    world: &mut world,
+                    rng: &mut rng,
+                    signals: &mut signals,
+                    inbox: &[],
+                };
+                start_outbreak(&mut ctx, source.settlement, time, None).unwrap()
+            };
+            let disease = world.disease_mut(disease_id);
+            disease.virulence = 0.6;
+            disease.transmission = transmission;
+            world
+                .settlement_mut(source.settlement)
+                .active_disease
+                .as_mut()
+                .unwrap()
+                .infection_rate = 0.4;
+
+            let mut spreads = 0;
+            for _ in 0..2000 {
+                let settlements = collect_settlement_info(&world);
+                let mut ctx = TickContext {
+                    world: &mut world,
+                    rng: &mut rng,
+                    signals: &mut signals,
+                    inbox: &[],
+                };
+                spread_disease(&mut ctx, &settlements, time);
+                if world
+                    .settlement_mut(target.settlement)
+                    .active_disease
+                    .take()
+                    .is_some()
+                {
+                    spreads += 1;
+                }
+            }
+            spreads
+        };
+
+        let winter = spreads_in(Transmission::Respiratory, 11);
+        let summer = spreads_in(Transmission::Respiratory, 5);
+        assert!(
+            winter > summer * 2,
+            "respiratory disease should spread faster in winter: {winter} vs {summer}"
+        );
+
+        let summer = spreads_in(Transmission::Waterborne, 5);
+        let winter = spreads_in(Transmission::Waterborne, 11);
+        assert!(
+            summer > winter * 2,
+            "water-borne disease should spread faster in summer: {summer} vs {winter}"
+        );
+    }
+
+    #[test]
+    fn tropical_regions_breed_more_disease() {
+        let tropical = base_outbreak_chance(Climate::Tropical);
+        let temperate = base_outbreak_chance(Climate::Temperate);
+        assert!(
+            tropical > temperate,
+            "tropical {tropical} should exceed temperate {temperate}"
+        );
+        assert!(base_outbreak_chance(Climate::Arid) < temperate);
+    }
+
     #[test]
     fn scenario_start_outbreak_creates_disease_entity() {
         let (mut world, settlement) = disease_scenario(500);
@@ -1214,6 +1417,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scenario_plague_die_off_causes_labor_shortage() {
+        use crate::model::ResourceType;
+        use crate::sim::EconomySystem;
+        use crate::testutil;
+
+        let (mut world, settlement) = disease_scenario(1000);
+        world.settlement_mut(settlement).resources = vec![ResourceType::Grain];
+        let output = |world: &crate::model::World| -> f64 {
+            world.settlement(settlement).production.values().sum()
+        };
+
+        testutil::tick_system(&mut world, &mut EconomySystem, 10, 42);
+        let baseline = output(&world);
+        let pop_before = world.settlement(settlement).population;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let time = ts(10);
+        {
+            let mut ctx = TickContext {
+                world: &mut world,
+                rng: &mut rng,
+                signals: &mut signals,
+                inbox: &[],
+            };
+            start_outbreak(&mut ctx, settlement, time, None);
+        }
+        {
+            let active = world
+                .settlement_mut(settlement)
+                .active_disease
+                .as_mut()
+                .unwrap();
+            active.infection_rate = 0.8;
+            active.peak_reached = true;
+        }
+        let settlements = collect_settlement_info(&world);
+        let mut ctx = TickContext {
+            world: &mut world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        progress_and_mortality(&mut ctx, &settlements, time);
+
+        let pop_after = world.settlement(settlement).population;
+        let shortage = world.settlement(settlement).labor_shortage;
+        assert!(
+            pop_after < pop_before,
+            "plague should kill: {pop_before} → {pop_after}"
+        );
+        assert!(shortage > 0.0, "die-off should leave a labor shortage");
+
+        testutil::tick_system(&mut world, &mut EconomySystem, 10, 42);
+        let after = output(&world);
+        // Headcount alone would scale output by sqrt(pop ratio)
+        let headcount_only = baseline * (pop_after as f64 / pop_before as f64).sqrt();
+        assert!(
+            after < headcount_only * 0.99,
+            "shortage penalty should cut output below headcount scaling: {after} vs {headcount_only}"
+        );
+
+        for month in 2..=12 {
+            testutil::tick_system_at(
+                &mut world,
+                &mut EconomySystem,
+                SimTimestamp::from_year_month(10, month),
+                42,
+            );
+        }
+        assert!(
+            world.settlement(settlement).labor_shortage < shortage,
+            "shortage should recover over the year"
+        );
+    }
+
     #[test]
     fn scenario_disease_lifecycle_ramp_peak_decline_end() {
         let (mut world, settlement) = disease_scenario(2000);
@@ -1478,6 +1758,7 @@ mod tests {
                 attacker_faction_id: 999,
                 defender_faction_id: 998,
                 outcome: crate::model::entity_data::SiegeOutcome::Lifted,
+                civilian_deaths: 0,
             },
         }];
         testutil::deliver_signals(&mut world, &mut DiseaseSystem, &inbox, 42);
@@ -1523,6 +1804,7 @@ mod tests {
                 attacker_faction_id: 999,
                 defender_faction_id: 998,
                 outcome: crate::model::entity_data::SiegeOutcome::Lifted,
+                civilian_deaths: 0,
             },
         }];
         testutil::deliver_signals(&mut world, &mut DiseaseSystem, &inbox, 42);
@@ -0,0 +1,442 @@
+use std::collections::BTreeMap;
+
+use rand::Rng;
+
+use crate::model::entity_data::ResourceType;
+use crate::model::{
+    EntityData, EntityKind, EventPayload, ParticipantRole, RelationshipKind, ResourceDepositData,
+    SimTimestamp, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::helpers;
+use crate::sim::signal::{Signal, SignalKind};
+use crate::worldgen::terrain::TerrainProfile;
+
+/// Quality lost per unit of monthly output, divided by the deposit's quantity.
+const DEPLETION_PER_UNIT: f64 = 0.1;
+/// Deposits whose quality falls below this are exhausted.
+const DEPLETION_THRESHOLD: f64 = 0.05;
+
+/// Yearly chance that a settled region turns up a new or hidden deposit.
+const PROSPECTING_CHANCE: f64 = 0.01;
+/// Only settlements at least this large send out prospectors.
+const PROSPECTING_MIN_POPULATION: u32 = 400;
+const DISCOVERED_QUALITY_MIN: f64 = 0.3;
+const DISCOVERED_QUALITY_MAX: f64 = 1.0;
+const DISCOVERED_QUANTITY_MIN: u32 = 50;
+const DISCOVERED_QUANTITY_MAX: u32 = 300;
+
+/// Find the active deposit of `resource` in a region, returning its id and quality.
+pub(super) fn find_deposit(
+    world: &World,
+    region_id: u64,
+    resource: &ResourceType,
+) -> Option<(u64, f64)> {
+    world
+        .living(EntityKind::ResourceDeposit)
+        .filter(|e| e.has_active_rel(RelationshipKind::LocatedIn, region_id))
+        .find_map(|e| {
+            let deposit = e.data.as_resource_deposit()?;
+            (deposit.resource_type == *resource).then_some((e.id, deposit.quality))
+        })
+}
+
+/// Lower deposit quality in proportion to this month's extraction, exhausting
+/// deposits that fall below the threshold. Food resources are renewable and
+/// are never passed in here.
+pub(super) fn deplete_deposits(
+    ctx: &mut TickContext,
+    extraction: &BTreeMap<u64, f64>,
+    time: SimTimestamp,
+    tick_event: u64,
+) {
+    let mut exhausted = Vec::new();
+
+    for (&deposit_id, &amount) in extraction {
+        let Some(rd) = ctx
+            .world
+            .entities
+            .get_mut(&deposit_id)
+            .filter(|e| e.end.is_none())
+            .and_then(|e| e.data.as_resource_deposit_mut())
+        else {
+            continue;
+        };
+        let loss = amount * DEPLETION_PER_UNIT / rd.quantity.max(1) as f64;
+        let old = rd.quality;
+        rd.quality = (old - loss).max(0.0);
+        if rd.quality < DEPLETION_THRESHOLD {
+            exhausted.push((deposit_id, old));
+        }
+    }
+
+    for (deposit_id, old_quality) in exhausted {
+        exhaust_deposit(ctx, deposit_id, old_quality, time, tick_event);
+    }
+}
+
+fn exhaust_deposit(
+    ctx: &mut TickContext,
+    deposit_id: u64,
+    old_quality: f64,
+    time: SimTimestamp,
+    tick_event: u64,
+) {
+    let Some(region_id) = ctx
+        .world
+        .entities
+        .get(&deposit_id)
+        .and_then(|e| e.active_rel(RelationshipKind::LocatedIn))
+    else {
+        return;
+    };
+    let deposit = ctx.world.resource_deposit(deposit_id);
+    let resource_type = deposit.resource_type.clone();
+    let new_quality = deposit.quality;
+    let deposit_name = helpers::entity_name(ctx.world, deposit_id);
+    let region_name = helpers::entity_name(ctx.world, region_id);
+
+    let ev = ctx.world.add_caused_structured_event(
+        EventPayload::ResourceDepleted {
+            deposit_id,
+            region_id,
+            resource_type: resource_type.clone(),
+        },
+        time,
+        format!(
+            "The {deposit_name} in {region_name} was exhausted in year {}",
+            time.year()
+        ),
+        tick_event,
+    );
+    ctx.world
+        .add_event_participant(ev, deposit_id, ParticipantRole::Subject);
+    ctx.world
+        .add_event_participant(ev, region_id, ParticipantRole::Location);
+    ctx.world.record_change(
+        deposit_id,
+        ev,
+        "quality",
+        serde_json::json!(old_quality),
+        serde_json::json!(new_quality),
+    );
+    ctx.world.end_entity(deposit_id, time, ev);
+
+    set_region_resource(ctx.world, region_id, &resource_type, false, ev);
+
+    ctx.signals.push(Signal {
+        event_id: ev,
+        kind: SignalKind::ResourceDepleted {
+            deposit_id,
+            region_id,
+        },
+    });
+}
+
+/// Yearly prospecting: regions with an established settlement occasionally
+/// reveal a hidden deposit or turn up a fresh one for a resource their terrain
+/// supports.
+pub(super) fn prospect_deposits(ctx: &mut TickContext, time: SimTimestamp, year_event: u64) {
+    let settled_regions: Vec<u64> = {
+        let mut regions: Vec<u64> = ctx
+            .world
+            .living(EntityKind::Settlement)
+            .filter(|e| {
+                e.data
+                    .as_settlement()
+                    .is_some_and(|sd| sd.population >= PROSPECTING_MIN_POPULATION)
+            })
+            .filter_map(|e| e.active_rel(RelationshipKind::LocatedIn))
+            .collect();
+        regions.sort_unstable();
+        regions.dedup();
+        regions
+    };
+
+    for region_id in settled_regions {
+        if ctx.rng.random_range(0.0..1.0) >= PROSPECTING_CHANCE {
+            continue;
+        }
+
+        let hidden = ctx
+            .world
+            .living(EntityKind::ResourceDeposit)
+            .filter(|e| e.has_active_rel(RelationshipKind::LocatedIn, region_id))
+            .find(|e| e.data.as_resource_deposit().is_some_and(|d| !d.discovered))
+            .map(|e| e.id);
+
+        let deposit_id = match hidden {
+            Some(id) => {
+                ctx.world.resource_deposit_mut(id).discovered = true;
+                id
+            }
+            None => {
+                let Some(id) = spawn_deposit(ctx, region_id, time, year_event) else {
+                    continue;
+                };
+                id
+            }
+        };
+
+        announce_discovery(ctx, deposit_id, region_id, time, year_event);
+    }
+}
+
+/// Create a new deposit for a non-food resource the region's terrain supports
+/// but that has no active deposit yet.
+fn spawn_deposit(
+    ctx: &mut TickContext,
+    region_id: u64,
+    time: SimTimestamp,
+    year_event: u64,
+) -> Option<u64> {
+    let region = ctx.world.region(region_id);
+    let (rx, ry) = (region.x, region.y);
+    let candidates: Vec<ResourceType> =
+        TerrainProfile::new(region.terrain, region.terrain_tags.clone())
+            .effective_resources()
+            .into_iter()
+            .filter(|r| !helpers::is_food_resource(r))
+            .filter(|r| find_deposit(ctx.world, region_id, r).is_none())
+            .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let resource = candidates[ctx.rng.random_range(0..candidates.len())].clone();
+    let quality = ctx
+        .rng
+        .random_range(DISCOVERED_QUALITY_MIN..=DISCOVERED_QUALITY_MAX);
+    let quantity = ctx
+        .rng
+        .random_range(DISCOVERED_QUANTITY_MIN..=DISCOVERED_QUANTITY_MAX);
+
+    let name = format!("{} deposit", crate::worldgen::capitalize(resource.as_str()));
+    let deposit_id = ctx.world.add_entity(
+        EntityKind::ResourceDeposit,
+        name,
+        Some(time),
+        EntityData::ResourceDeposit(ResourceDepositData {
+            resource_type: resource,
+            quantity,
+            quality,
+            discovered: true,
+            x: rx,
+            y: ry,
+        }),
+        year_event,
+    );
+    ctx.world.add_relationship(
+        deposit_id,
+        region_id,
+        RelationshipKind::LocatedIn,
+        time,
+        year_event,
+    );
+    Some(deposit_id)
+}
+
+fn announce_discovery(
+    ctx: &mut TickContext,
+    deposit_id: u64,
+    region_id: u64,
+    time: SimTimestamp,
+    year_event: u64,
+) {
+    let deposit = ctx.world.resource_deposit(deposit_id);
+    let resource_type = deposit.resource_type.clone();
+    let quality = deposit.quality;
+    let deposit_name = helpers::entity_name(ctx.world, deposit_id);
+    let region_name = helpers::entity_name(ctx.world, region_id);
+
+    let ev = ctx.world.add_caused_structured_event(
+        EventPayload::ResourceDiscovered {
+            deposit_id,
+            region_id,
+            resource_type: resource_type.clone(),
+            quality,
+        },
+        time,
+        format!(
+            "Prospectors found a {deposit_name} in {region_name} in year {}",
+            time.year()
+        ),
+        year_event,
+    );
+    ctx.world
+        .add_event_participant(ev, deposit_id, ParticipantRole::Subject);
+    ctx.world
+        .add_event_participant(ev, region_id, ParticipantRole::Location);
+
+    set_region_resource(ctx.world, region_id, &resource_type, true, ev);
+}
+
+/// Add or remove a resource from a region and every living settlement in it.
+fn set_region_resource(
+    world: &mut World,
+    region_id: u64,
+    resource: &ResourceType,
+    present: bool,
+    event_id: u64,
+) {
+    let region = world.region_mut(region_id);
+    if present {
+        if !region.resources.contains(resource) {
+            region.resources.push(resource.clone());
+        }
+    } else {
+        region.resources.retain(|r| r != resource);
+    }
+
+    let settlements: Vec<u64> = world
+        .living(EntityKind::Settlement)
+        .filter(|e| e.has_active_rel(RelationshipKind::LocatedIn, region_id))
+        .map(|e| e.id)
+        .collect();
+
+    for sid in settlements {
+        let sd = world.settlement_mut(sid);
+        if sd.resources.contains(resource) == present {
+            continue;
+        }
+        let old = sd.resources.clone();
+        if present {
+            sd.resources.push(resource.clone());
+        } else {
+            sd.resources.retain(|r| r != resource);
+        }
+        let new = sd.resources.clone();
+        world.record_change(
+            sid,
+            event_id,
+            "resources",
+            serde_json::json!(old),
+            serde_json::json!(new),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::Scenario;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn deplete(world: &mut World, extraction: &BTreeMap<u64, f64>) -> Vec<Signal> {
+        let ev = world.add_event(
+            crate::model::EventKind::Custom("test".to_string()),
+            world.current_time,
+            "test".to_string(),
+        );
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        let time = ctx.world.current_time;
+        deplete_deposits(&mut ctx, extraction, time, ev);
+        signals
+    }
+
+    #[test]
+    fn scenario_sustained_extraction_lowers_quality() {
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone("MineTown");
+        let deposit = s.add_resource_deposit("Iron deposit", ResourceType::Iron, setup.region);
+        let mut world = s.build();
+
+        let extraction = BTreeMap::from([(deposit, 2.0)]);
+        for _ in 0..12 {
+            deplete(&mut world, &extraction);
+        }
+
+        let quality = world.resource_deposit(deposit).quality;
+        assert!(
+            quality < 0.5,
+            "quality should fall below 0.5, got {quality}"
+        );
+        assert!(world.entity(deposit).end.is_none());
+    }
+
+    #[test]
+    fn scenario_heavy_extraction_exhausts_deposit() {
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone_with(
+            "MineTown",
+            |_| {},
+            |sd| sd.resources = vec![ResourceType::Grain, ResourceType::Iron],
+        );
+        let deposit = s.add_resource_deposit("Iron deposit", ResourceType::Iron, setup.region);
+        let mut world = s.build();
+
+        let extraction = BTreeMap::from([(deposit, 50.0)]);
+        let mut signals = Vec::new();
+        for _ in 0..20 {
+            signals.extend(deplete(&mut world, &extraction));
+        }
+
+        assert!(world.entity(deposit).end.is_some(), "deposit should end");
+        assert!(
+            signals
+                .iter()
+                .any(|sig| matches!(sig.kind, SignalKind::ResourceDepleted { deposit_id, .. } if deposit_id == deposit))
+        );
+        assert!(
+            world
+                .events
+                .values()
+                .any(|e| e.kind == crate::model::EventKind::ResourceDepleted)
+        );
+        assert_eq!(
+            world.settlement(setup.settlement).resources,
+            vec![ResourceType::Grain]
+        );
+    }
+
+    #[test]
+    fn scenario_prospecting_reveals_hidden_deposit() {
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone("Frontier");
+        let _ = s.settlement_mut(setup.settlement).population(800);
+        let deposit =
+            s.add_resource_deposit_with("Gold deposit", ResourceType::Gold, setup.region, |rd| {
+                rd.discovered = false
+            });
+        let mut world = s.build();
+        let ev = world.add_event(
+            crate::model::EventKind::Custom("test".to_string()),
+            world.current_time,
+            "test".to_string(),
+        );
+
+        let mut found = false;
+        for seed in 0..1000 {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            let mut signals = Vec::new();
+            let mut ctx = TickContext {
+                world: &mut world,
+                rng: &mut rng,
+                signals: &mut signals,
+                inbox: &[],
+            };
+            let time = ctx.world.current_time;
+            prospect_deposits(&mut ctx, time, ev);
+            if world.resource_deposit(deposit).discovered {
+                found = true;
+                break;
+            }
+        }
+
+        assert!(found, "prospecting should eventually reveal the deposit");
+        assert!(
+            world
+                .settlement(setup.settlement)
+                .resources
+                .contains(&ResourceType::Gold)
+        );
+    }
+}
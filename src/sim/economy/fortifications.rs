@@ -23,9 +23,7 @@ pub(super) fn update_fortifications(
 
     let candidates: Vec<FortCandidate> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+        .living(EntityKind::Settlement)
         .filter_map(|e| {
             let sd = e.data.as_settlement()?;
             // Cannot build while under siege
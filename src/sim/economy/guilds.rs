@@ -0,0 +1,490 @@
+//! Merchant guilds: commercial powers trading out of chapters in several
+//! settlements, across faction borders, with a treasury of their own.
+
+use std::collections::BTreeSet;
+
+use rand::Rng;
+
+use crate::model::{
+    EntityData, EntityKind, EventKind, EventPayload, GuildData, ParticipantRole, RelationshipKind,
+    SimTimestamp, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::helpers;
+
+/// Minimum prosperity of a trade hub before its merchants band together.
+const GUILD_FOUNDING_PROSPERITY: f64 = 0.6;
+/// Minimum trade routes out of a settlement before a guild forms there.
+const GUILD_FOUNDING_MIN_ROUTES: usize = 2;
+/// Yearly chance a qualifying trade hub founds a guild.
+const GUILD_FOUNDING_CHANCE: f64 = 0.2;
+/// Most settlements a single guild keeps chapters in.
+const GUILD_MAX_CHAPTERS: usize = 6;
+/// Share of its chapters' trade income a guild keeps as profit.
+const GUILD_PROFIT_SHARE: f64 = 0.1;
+/// Treasury at which a guild holds half its possible influence.
+const GUILD_INFLUENCE_SCALE: f64 = 500.0;
+/// Influence a guild needs before it bribes its host court.
+const GUILD_LOBBY_MIN_INFLUENCE: f64 = 0.2;
+/// Treasury a guild spends on one year's bribes.
+const GUILD_BRIBE_COST: f64 = 50.0;
+/// Policy shift toward open trade one year's bribes buy.
+const GUILD_BRIBE_POLICY_SHIFT: f64 = 0.05;
+/// Seat prosperity below which a guild moves its headquarters.
+const GUILD_RELOCATION_PROSPERITY: f64 = 0.3;
+
+/// Influence a guild with `treasury` holds over its hosts.
+fn influence_for(treasury: f64) -> f64 {
+    treasury / (treasury + GUILD_INFLUENCE_SCALE)
+}
+
+/// Settlement ids hosting a chapter of any living guild.
+fn guild_chapters(world: &World) -> BTreeSet<u64> {
+    world
+        .living(EntityKind::Guild)
+        .filter_map(|e| e.data.as_guild())
+        .flat_map(|gd| gd.chapters.iter().copied())
+        .collect()
+}
+
+fn is_living_settlement(world: &World, settlement_id: u64) -> bool {
+    world
+        .entities
+        .get(&settlement_id)
+        .is_some_and(|e| e.end.is_none() && e.data.as_settlement().is_some())
+}
+
+fn settlement_faction(world: &World, settlement_id: u64) -> Option<u64> {
+    world
+        .entities
+        .get(&settlement_id)?
+        .active_rel(RelationshipKind::MemberOf)
+}
+
+/// Guilds skim a share of the trade income flowing through their chapters.
+pub(super) fn collect_guild_profits(ctx: &mut TickContext, tick_event: u64) {
+    let profits: Vec<(u64, f64, f64)> = ctx
+        .world
+        .living(EntityKind::Guild)
+        .filter_map(|e| {
+            let gd = e.data.as_guild()?;
+            let trade: f64 = gd
+                .chapters
+                .iter()
+                .filter_map(|sid| ctx.world.entities.get(sid))
+                .filter(|s| s.end.is_none())
+                .filter_map(|s| s.data.as_settlement())
+                .map(|sd| sd.trade_income)
+                .sum();
+            Some((e.id, gd.treasury, trade * GUILD_PROFIT_SHARE))
+        })
+        .collect();
+
+    for (guild_id, old_treasury, profit) in profits {
+        if profit <= 0.0 {
+            continue;
+        }
+        let new_treasury = old_treasury + profit;
+        let gd = ctx.world.guild_mut(guild_id);
+        gd.treasury = new_treasury;
+        gd.influence = influence_for(new_treasury);
+        ctx.world.record_change(
+            guild_id,
+            tick_event,
+            "treasury",
+            serde_json::json!(old_treasury),
+            serde_json::json!(new_treasury),
+        );
+    }
+}
+
+/// Yearly guild upkeep: move out of declining seats, open chapters along
+/// trade routes, found new guilds in trade hubs, and lobby host courts.
+pub(super) fn update_guilds(
+    ctx: &mut TickContext,
+    time: SimTimestamp,
+    current_year: u32,
+    year_event: u64,
+) {
+    relocate_guilds(ctx, time, current_year, year_event);
+    open_chapters(ctx, year_event);
+    found_guilds(ctx, time, current_year, year_event);
+    lobby_hosts(ctx, time, current_year, year_event);
+}
+
+/// Prosperous settlements with several trade routes may found a guild, with
+/// chapters at the far end of each route.
+fn found_guilds(ctx: &mut TickContext, time: SimTimestamp, current_year: u32, year_event: u64) {
+    let mut taken = guild_chapters(ctx.world);
+    let candidates: Vec<(u64, u64, BTreeSet<u64>)> = ctx
+        .world
+        .living(EntityKind::Settlement)
+        .filter(|e| !taken.contains(&e.id))
+        .filter_map(|e| {
+            let sd = e.data.as_settlement()?;
+            if sd.prosperity < GUILD_FOUNDING_PROSPERITY
+                || sd.trade_routes.len() < GUILD_FOUNDING_MIN_ROUTES
+                || sd.active_siege.is_some()
+            {
+                return None;
+            }
+            let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
+            let partners = sd.trade_routes.iter().map(|r| r.target).collect();
+            Some((e.id, faction_id, partners))
+        })
+        .collect();
+
+    for (settlement_id, faction_id, partners) in candidates {
+        if taken.contains(&settlement_id) || ctx.rng.random_range(0.0..1.0) >= GUILD_FOUNDING_CHANCE
+        {
+            continue;
+        }
+        let mut chapters = BTreeSet::from([settlement_id]);
+        chapters.extend(
+            partners
+                .into_iter()
+                .filter(|&sid| !taken.contains(&sid) && is_living_settlement(ctx.world, sid))
+                .take(GUILD_MAX_CHAPTERS - 1),
+        );
+        taken.extend(chapters.iter().copied());
+
+        let settlement_name = helpers::entity_name(ctx.world, settlement_id);
+        let name = format!("{settlement_name} Merchant Guild");
+        let ev = ctx.world.add_caused_structured_event(
+            EventPayload::GuildFounded {
+                settlement_id,
+                faction_id,
+            },
+            time,
+            format!("The merchants of {settlement_name} founded the {name} in year {current_year}"),
+            year_event,
+        );
+        ctx.world
+            .add_event_participant(ev, settlement_id, ParticipantRole::Location);
+        ctx.world
+            .add_event_participant(ev, faction_id, ParticipantRole::Witness);
+        let guild_id = ctx.world.add_entity(
+            EntityKind::Guild,
+            name,
+            Some(time),
+            EntityData::Guild(GuildData {
+                seat_settlement_id: settlement_id,
+                chapters,
+                treasury: 0.0,
+                influence: 0.0,
+            }),
+            ev,
+        );
+        ctx.world
+            .add_event_participant(ev, guild_id, ParticipantRole::Subject);
+    }
+}
+
+/// Guilds follow their chapters' trade routes, opening chapters in partner
+/// settlements no other guild has claimed.
+fn open_chapters(ctx: &mut TickContext, year_event: u64) {
+    let mut taken = guild_chapters(ctx.world);
+    let guilds: Vec<(u64, BTreeSet<u64>)> = ctx
+        .world
+        .living(EntityKind::Guild)
+        .filter_map(|e| Some((e.id, e.data.as_guild()?.chapters.clone())))
+        .collect();
+
+    for (guild_id, old_chapters) in guilds {
+        let mut chapters = old_chapters.clone();
+        let partners: Vec<u64> = old_chapters
+            .iter()
+            .filter_map(|sid| ctx.world.entities.get(sid))
+            .filter_map(|e| e.data.as_settlement())
+            .flat_map(|sd| sd.trade_routes.iter().map(|r| r.target))
+            .collect();
+        for partner in partners {
+            if chapters.len() >= GUILD_MAX_CHAPTERS {
+                break;
+            }
+            if !taken.contains(&partner) && is_living_settlement(ctx.world, partner) {
+                chapters.insert(partner);
+                taken.insert(partner);
+            }
+        }
+        if chapters != old_chapters {
+            ctx.world.guild_mut(guild_id).chapters = chapters.clone();
+            ctx.world.record_change(
+                guild_id,
+                year_event,
+                "chapters",
+                serde_json::json!(old_chapters),
+                serde_json::json!(chapters),
+            );
+        }
+    }
+}
+
+/// A guild whose seat has fallen or declined moves its headquarters to its
+/// most prosperous chapter; one with no chapters left dissolves.
+fn relocate_guilds(ctx: &mut TickContext, time: SimTimestamp, current_year: u32, year_event: u64) {
+    let guilds: Vec<(u64, GuildData)> = ctx
+        .world
+        .living(EntityKind::Guild)
+        .filter_map(|e| Some((e.id, e.data.as_guild()?.clone())))
+        .collect();
+
+    for (guild_id, gd) in guilds {
+        let prosperity = |world: &World, sid: u64| {
+            world
+                .entities
+                .get(&sid)
+                .filter(|e| e.end.is_none())
+                .and_then(|e| e.data.as_settlement())
+                .map(|sd| sd.prosperity)
+        };
+        let living: BTreeSet<u64> = gd
+            .chapters
+            .iter()
+            .copied()
+            .filter(|&sid| is_living_settlement(ctx.world, sid))
+            .collect();
+        if living != gd.chapters {
+            ctx.world.guild_mut(guild_id).chapters = living.clone();
+        }
+
+        let guild_name = helpers::entity_name(ctx.world, guild_id);
+        if living.is_empty() {
+            let ev = ctx.world.add_caused_event(
+                EventKind::Dissolution,
+                time,
+                format!("The {guild_name} dissolved in year {current_year}"),
+                year_event,
+            );
+            ctx.world
+                .add_event_participant(ev, guild_id, ParticipantRole::Subject);
+            ctx.world.end_entity(guild_id, time, ev);
+            continue;
+        }
+
+        let seat_prosperity = prosperity(ctx.world, gd.seat_settlement_id);
+        if seat_prosperity.is_some_and(|p| p >= GUILD_RELOCATION_PROSPERITY) {
+            continue;
+        }
+        let Some(new_seat) = living
+            .iter()
+            .copied()
+            .filter(|&sid| sid != gd.seat_settlement_id)
+            .filter(|&sid| prosperity(ctx.world, sid) > seat_prosperity)
+            .max_by(|&a, &b| {
+                prosperity(ctx.world, a)
+                    .partial_cmp(&prosperity(ctx.world, b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+        else {
+            continue;
+        };
+
+        let new_seat_name = helpers::entity_name(ctx.world, new_seat);
+        let ev = ctx.world.add_caused_structured_event(
+            EventPayload::GuildRelocated {
+                guild_id,
+                from_settlement_id: gd.seat_settlement_id,
+                to_settlement_id: new_seat,
+            },
+            time,
+            format!(
+                "The {guild_name} moved its headquarters to {new_seat_name} in year {current_year}"
+            ),
+            year_event,
+        );
+        ctx.world
+            .add_event_participant(ev, guild_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, gd.seat_settlement_id, ParticipantRole::Origin);
+        ctx.world
+            .add_event_participant(ev, new_seat, ParticipantRole::Destination);
+        ctx.world.guild_mut(guild_id).seat_settlement_id = new_seat;
+        ctx.world.record_change(
+            guild_id,
+            ev,
+            "seat_settlement_id",
+            serde_json::json!(gd.seat_settlement_id),
+            serde_json::json!(new_seat),
+        );
+    }
+}
+
+/// Influential guilds bribe the court hosting their seat, buying policy
+/// that favors commerce and open borders.
+fn lobby_hosts(ctx: &mut TickContext, time: SimTimestamp, current_year: u32, year_event: u64) {
+    let lobbyists: Vec<(u64, u64, f64)> = ctx
+        .world
+        .living(EntityKind::Guild)
+        .filter_map(|e| {
+            let gd = e.data.as_guild()?;
+            if gd.influence < GUILD_LOBBY_MIN_INFLUENCE || gd.treasury < GUILD_BRIBE_COST {
+                return None;
+            }
+            let faction_id = settlement_faction(ctx.world, gd.seat_settlement_id)?;
+            let fd = ctx.world.entities.get(&faction_id)?.data.as_faction()?;
+            let satisfied = fd.mercantilism >= 1.0 && fd.isolationism <= 0.0;
+            (!satisfied).then_some((e.id, faction_id, gd.treasury))
+        })
+        .collect();
+
+    for (guild_id, faction_id, guild_treasury) in lobbyists {
+        let guild_name = helpers::entity_name(ctx.world, guild_id);
+        let faction_name = helpers::entity_name(ctx.world, faction_id);
+        let ev = ctx.world.add_caused_structured_event(
+            EventPayload::GuildBribe {
+                guild_id,
+                faction_id,
+                cost: GUILD_BRIBE_COST,
+            },
+            time,
+            format!(
+                "The {guild_name} bribed the court of {faction_name} for favorable trade policy in year {current_year}"
+            ),
+            year_event,
+        );
+        ctx.world
+            .add_event_participant(ev, guild_id, ParticipantRole::Instigator);
+        ctx.world
+            .add_event_participant(ev, faction_id, ParticipantRole::Object);
+
+        let remaining = guild_treasury - GUILD_BRIBE_COST;
+        let gd = ctx.world.guild_mut(guild_id);
+        gd.treasury = remaining;
+        gd.influence = influence_for(remaining);
+        ctx.world.record_change(
+            guild_id,
+            ev,
+            "treasury",
+            serde_json::json!(guild_treasury),
+            serde_json::json!(remaining),
+        );
+
+        let fd = ctx.world.faction_mut(faction_id);
+        let old_treasury = fd.treasury;
+        fd.treasury += GUILD_BRIBE_COST;
+        fd.mercantilism = (fd.mercantilism + GUILD_BRIBE_POLICY_SHIFT).min(1.0);
+        fd.isolationism = (fd.isolationism - GUILD_BRIBE_POLICY_SHIFT).max(0.0);
+        ctx.world.record_change(
+            faction_id,
+            ev,
+            "treasury",
+            serde_json::json!(old_treasury),
+            serde_json::json!(old_treasury + GUILD_BRIBE_COST),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TradeRoute;
+    use crate::scenario::Scenario;
+    use crate::testutil::count_events;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    /// A prosperous market trading with two settlements across the border.
+    fn trade_hub_world() -> (World, u64) {
+        let mut s = Scenario::at_year(100);
+        let region = s.add_region("Plains");
+        let seller = s.add_faction("Seller");
+        let buyer = s.add_faction("Buyer");
+        let hub = s
+            .settlement("Market", seller, region)
+            .population(300)
+            .prosperity(0.8)
+            .with(|sd| sd.trade_income = 20.0)
+            .id();
+        let forge = s.settlement("Forge", buyer, region).population(300).id();
+        let granary = s.settlement("Granary", buyer, region).population(300).id();
+        s.make_trade_route(hub, forge);
+        s.make_trade_route(hub, granary);
+        s.modify_settlement(hub, |sd| {
+            for (target, resource) in [(forge, "iron"), (granary, "grain")] {
+                sd.trade_routes.push(TradeRoute {
+                    target,
+                    path: vec![],
+                    distance: 1,
+                    resource: resource.to_string(),
+                    security: 1.0,
+                });
+            }
+        });
+        (s.build(), hub)
+    }
+
+    /// One year of guild activity: the yearly update, then a year of profits.
+    fn run_guild_year(world: &mut World, seed: u64) {
+        let time = world.current_time;
+        let ev = world.add_event(EventKind::Custom("test".to_string()), time, "test".into());
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        update_guilds(&mut ctx, time, time.year(), ev);
+        for _ in 0..12 {
+            collect_guild_profits(&mut ctx, ev);
+        }
+    }
+
+    fn guild_treasury(world: &World) -> f64 {
+        world
+            .living(EntityKind::Guild)
+            .filter_map(|e| e.data.as_guild())
+            .map(|gd| gd.treasury)
+            .sum()
+    }
+
+    #[test]
+    fn influence_grows_with_treasury_but_never_saturates() {
+        assert_eq!(influence_for(0.0), 0.0);
+        assert!((influence_for(GUILD_INFLUENCE_SCALE) - 0.5).abs() < 1e-9);
+        assert!(influence_for(1e9) < 1.0);
+    }
+
+    #[test]
+    fn scenario_trade_hub_founds_guild_that_grows_rich() {
+        let (mut world, hub) = trade_hub_world();
+        for seed in 0..20 {
+            run_guild_year(&mut world, seed);
+        }
+
+        assert_eq!(count_events(&world, &EventKind::GuildFounded), 1);
+        let guild = world
+            .living(EntityKind::Guild)
+            .next()
+            .expect("trade hub should found a guild");
+        let gd = guild.data.as_guild().unwrap();
+        assert_eq!(gd.seat_settlement_id, hub);
+        assert_eq!(gd.chapters.len(), 3, "guild should span its trade partners");
+
+        let early = guild_treasury(&world);
+        assert!(early > 0.0);
+        for seed in 20..30 {
+            run_guild_year(&mut world, seed);
+        }
+        let late = guild_treasury(&world);
+        assert!(
+            late > early,
+            "guild should accumulate wealth from trade: {early} -> {late}"
+        );
+    }
+
+    #[test]
+    fn scenario_guild_abandons_declining_seat() {
+        let (mut world, hub) = trade_hub_world();
+        for seed in 0..20 {
+            run_guild_year(&mut world, seed);
+        }
+        let guild_id = world.living(EntityKind::Guild).next().unwrap().id;
+        world.settlement_mut(hub).prosperity = 0.1;
+        run_guild_year(&mut world, 99);
+        assert_ne!(world.guild(guild_id).seat_settlement_id, hub);
+        assert_eq!(count_events(&world, &EventKind::GuildRelocated), 1);
+    }
+}
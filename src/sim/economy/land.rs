@@ -0,0 +1,257 @@
+use crate::model::{EntityKind, EventPayload, ParticipantRole, RelationshipKind, SimTimestamp};
+use crate::sim::context::TickContext;
+
+/// Minimum settlement prosperity before its faction invests in the land.
+const LAND_MIN_PROSPERITY: f64 = 0.5;
+/// Population share of capacity above which more land is worth improving.
+const LAND_CROWDING_THRESHOLD: f64 = 0.7;
+/// Capacity added by one round of irrigation and land clearing.
+const LAND_IMPROVEMENT_STEP: f64 = 100.0;
+/// Treasury cost of the first improvement step.
+const LAND_IMPROVEMENT_BASE_COST: f64 = 60.0;
+/// Extra cost per step already taken: the best land is improved first.
+const LAND_IMPROVEMENT_COST_GROWTH: f64 = 0.1;
+/// Treasury a faction keeps in reserve rather than spend on the land.
+const LAND_TREASURY_RESERVE: f64 = 100.0;
+/// Most capacity land improvement can add to a single settlement.
+const LAND_IMPROVEMENT_MAX: f64 = 3000.0;
+/// Yearly upkeep per point of improvement while a disaster strikes.
+const LAND_MAINTENANCE_PER_CAPACITY: f64 = 0.05;
+/// Fraction of improvement lost in a disaster year without maintenance.
+const LAND_DISASTER_DECAY: f64 = 0.25;
+
+/// Cost of the next improvement step for a settlement with `improvement` already.
+fn improvement_cost(improvement: f64) -> f64 {
+    let steps = (improvement / LAND_IMPROVEMENT_STEP).floor();
+    LAND_IMPROVEMENT_BASE_COST * (1.0 + steps * LAND_IMPROVEMENT_COST_GROWTH)
+}
+
+/// Prosperous factions pay to irrigate and clear land around crowded
+/// settlements, raising their carrying capacity a step at a time. In
+/// disaster-struck settlements the works must be maintained or they degrade.
+pub(super) fn improve_land(
+    ctx: &mut TickContext,
+    time: SimTimestamp,
+    current_year: u32,
+    year_event: u64,
+) {
+    struct LandCandidate {
+        settlement_id: u64,
+        faction_id: u64,
+        improvement: f64,
+        crowded: bool,
+        prosperous: bool,
+        disaster: bool,
+        besieged: bool,
+    }
+
+    let candidates: Vec<LandCandidate> = ctx
+        .world
+        .living(EntityKind::Settlement)
+        .filter_map(|e| {
+            let sd = e.data.as_settlement()?;
+            let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
+            Some(LandCandidate {
+                settlement_id: e.id,
+                faction_id,
+                improvement: sd.land_improvement,
+                crowded: sd.capacity > 0
+                    && sd.population as f64 >= sd.capacity as f64 * LAND_CROWDING_THRESHOLD,
+                prosperous: sd.prosperity >= LAND_MIN_PROSPERITY,
+                disaster: sd.active_disaster.is_some(),
+                besieged: sd.active_siege.is_some(),
+            })
+        })
+        .collect();
+
+    for c in candidates {
+        let treasury = ctx.world.faction(c.faction_id).treasury;
+
+        if c.disaster {
+            if c.improvement <= 0.0 {
+                continue;
+            }
+            let upkeep = c.improvement * LAND_MAINTENANCE_PER_CAPACITY;
+            if treasury >= upkeep {
+                ctx.world.faction_mut(c.faction_id).treasury = treasury - upkeep;
+                ctx.world.record_change(
+                    c.faction_id,
+                    year_event,
+                    "treasury",
+                    serde_json::json!(treasury),
+                    serde_json::json!(treasury - upkeep),
+                );
+            } else {
+                let degraded = c.improvement * (1.0 - LAND_DISASTER_DECAY);
+                ctx.world.settlement_mut(c.settlement_id).land_improvement = degraded;
+                ctx.world.record_change(
+                    c.settlement_id,
+                    year_event,
+                    "land_improvement",
+                    serde_json::json!(c.improvement),
+                    serde_json::json!(degraded),
+                );
+            }
+            continue;
+        }
+
+        if c.besieged || !c.crowded || !c.prosperous || c.improvement >= LAND_IMPROVEMENT_MAX {
+            continue;
+        }
+        let cost = improvement_cost(c.improvement);
+        if treasury < cost + LAND_TREASURY_RESERVE {
+            continue;
+        }
+
+        let improved = (c.improvement + LAND_IMPROVEMENT_STEP).min(LAND_IMPROVEMENT_MAX);
+        ctx.world.faction_mut(c.faction_id).treasury = treasury - cost;
+        ctx.world.settlement_mut(c.settlement_id).land_improvement = improved;
+
+        let settlement_name = ctx
+            .world
+            .entities
+            .get(&c.settlement_id)
+            .map(|e| e.name.clone())
+            .unwrap_or_default();
+        let ev = ctx.world.add_caused_structured_event(
+            EventPayload::LandImproved {
+                settlement_id: c.settlement_id,
+                faction_id: c.faction_id,
+                cost,
+                land_improvement: improved,
+            },
+            time,
+            format!("{settlement_name} irrigated and cleared new land in year {current_year}"),
+            year_event,
+        );
+        ctx.world
+            .add_event_participant(ev, c.settlement_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, c.faction_id, ParticipantRole::Instigator);
+        ctx.world.record_change(
+            c.settlement_id,
+            ev,
+            "land_improvement",
+            serde_json::json!(c.improvement),
+            serde_json::json!(improved),
+        );
+        ctx.world.record_change(
+            c.faction_id,
+            ev,
+            "treasury",
+            serde_json::json!(treasury),
+            serde_json::json!(treasury - cost),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EventKind;
+    use crate::model::World;
+    use crate::model::entity_data::{ActiveDisaster, DisasterType};
+    use crate::scenario::Scenario;
+    use crate::sim::demographics::DemographicsSystem;
+    use crate::testutil::{assert_approx, count_events, tick_system};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn run_improve_land(world: &mut World) {
+        let time = world.current_time;
+        let ev = world.add_event(EventKind::Custom("test".to_string()), time, "test".into());
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        improve_land(&mut ctx, time, time.year(), ev);
+    }
+
+    #[test]
+    fn improvement_cost_rises_with_each_step() {
+        assert_approx(improvement_cost(0.0), 60.0, 1e-9, "first step");
+        assert_approx(improvement_cost(50.0), 60.0, 1e-9, "partial step");
+        assert_approx(improvement_cost(500.0), 90.0, 1e-9, "sixth step");
+    }
+
+    #[test]
+    fn scenario_invested_settlement_outgrows_uninvested_twin() {
+        let mut s = Scenario::at_year(100);
+        let rich = s.add_settlement_standalone("Richfield");
+        let poor = s.add_settlement_standalone("Poorfield");
+        let _ = s.faction_mut(rich.faction).treasury(5000.0);
+        let _ = s.faction_mut(poor.faction).treasury(0.0);
+        for settlement in [rich.settlement, poor.settlement] {
+            let _ = s
+                .settlement_mut(settlement)
+                .population(4000)
+                .with(|sd| sd.prosperity = 0.8);
+        }
+        let mut world = s.build();
+
+        let mut demographics = DemographicsSystem::new();
+        for year in 100..130 {
+            tick_system(&mut world, &mut demographics, year, 42 + year as u64);
+            run_improve_land(&mut world);
+        }
+
+        let rich_sd = world.settlement(rich.settlement);
+        let poor_sd = world.settlement(poor.settlement);
+        assert!(rich_sd.land_improvement > 0.0);
+        assert_eq!(poor_sd.land_improvement, 0.0);
+        assert!(rich_sd.capacity > poor_sd.capacity);
+        assert!(count_events(&world, &EventKind::LandImproved) > 0);
+        assert!(
+            rich_sd.population > poor_sd.population,
+            "invested settlement should outgrow its twin: {} vs {}",
+            rich_sd.population,
+            poor_sd.population
+        );
+    }
+
+    #[test]
+    fn scenario_unmaintained_land_degrades_in_disaster() {
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone("Floodplain");
+        let _ = s.faction_mut(setup.faction).treasury(0.0);
+        let _ = s.settlement_mut(setup.settlement).with(|sd| {
+            sd.land_improvement = 400.0;
+            sd.active_disaster = Some(ActiveDisaster {
+                disaster_type: DisasterType::Flood,
+                severity: 0.5,
+                started: SimTimestamp::from_year(100),
+                months_remaining: 3,
+                total_deaths: 0,
+            });
+        });
+        let mut world = s.build();
+
+        run_improve_land(&mut world);
+        assert_approx(
+            world.settlement(setup.settlement).land_improvement,
+            300.0,
+            1e-9,
+            "unmaintained improvement",
+        );
+
+        // A faction that can pay the upkeep keeps its works intact
+        world.faction_mut(setup.faction).treasury = 1000.0;
+        run_improve_land(&mut world);
+        assert_approx(
+            world.settlement(setup.settlement).land_improvement,
+            300.0,
+            1e-9,
+            "maintained improvement",
+        );
+        assert_approx(
+            world.faction(setup.faction).treasury,
+            985.0,
+            1e-9,
+            "treasury after upkeep",
+        );
+    }
+}
@@ -1,18 +1,30 @@
+mod deposits;
 mod fortifications;
+mod guilds;
+mod land;
+mod prices;
+mod specialization;
 pub(crate) mod trade;
+mod tribute;
 
 use std::collections::BTreeMap;
 
 use super::context::TickContext;
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
-use crate::model::entity_data::ResourceType;
-use crate::model::{EntityKind, EventKind, ParticipantRole, RelationshipKind, SimTimestamp, World};
+use crate::model::entity_data::{ResourceType, SiegeOutcome};
+use crate::model::{
+    Climate, EntityKind, EventKind, ParticipantRole, RelationshipKind, SimTimestamp, World,
+};
 use crate::sim::helpers;
+use crate::sim::politics::faction_spymaster;
+use crate::sim::religion;
 
 const TAX_RATE: f64 = 0.15;
 const ARMY_MAINTENANCE_PER_STRENGTH: f64 = 0.5;
 const SETTLEMENT_UPKEEP: f64 = 2.0;
+/// Yearly cost of keeping a spymaster and their agents in the field.
+const SPY_NETWORK_UPKEEP: f64 = 10.0;
 
 // Production parameters
 const POP_FACTOR_DIVISOR: f64 = 100.0;
@@ -36,6 +48,32 @@ const PROSPERITY_FLOOR: f64 = 0.05;
 const PROSPERITY_CEILING: f64 = 0.95;
 const CRIME_PROSPERITY_PENALTY: f64 = 0.1;
 
+// Labor shortage parameters (shortage itself is raised by DiseaseSystem)
+/// Output lost per unit of labor shortage (0.3 shortage → 30% less output).
+const LABOR_SHORTAGE_OUTPUT_PENALTY: f64 = 1.0;
+/// Raw prosperity bonus per unit of shortage: scarce labor commands higher wages.
+const LABOR_SHORTAGE_WAGE_BONUS: f64 = 0.5;
+/// Fraction of the shortage recovered each year as births and migrants refill the workforce.
+const LABOR_SHORTAGE_RECOVERY_RATE: f64 = 0.25;
+
+// Post-siege rebuilding parameters (the boom itself also speeds DemographicsSystem growth)
+/// Rebuilding boom per unit of population fraction lost during a lifted siege.
+const REBUILDING_BOOM_PER_LOSS: f64 = 4.0;
+/// Extra prosperity recovery speed per unit of rebuilding boom.
+const REBUILDING_PROSPERITY_BONUS: f64 = 3.0;
+/// Fraction of the boom that fades each year as the rebuilding winds down.
+const REBUILDING_BOOM_FADE_RATE: f64 = 0.4;
+
+// Unfree labor parameters (the unfree fraction itself is managed by PoliticsSystem)
+/// Extra output per unit of unfree population fraction: forced labor comes cheap.
+const UNFREE_LABOR_OUTPUT_BONUS: f64 = 0.3;
+/// Prosperity ceiling lost per unit of unfree fraction: the gains flow to few hands.
+const UNFREE_PROSPERITY_CEILING_PENALTY: f64 = 0.5;
+
+// Embargo parameters
+/// Fraction of each side's treasury lost to disrupted merchants when an embargo begins.
+const EMBARGO_TREASURY_LOSS: f64 = 0.1;
+
 // Economic tension parameters
 const RESOURCE_SCARCITY_MOTIVATION: f64 = 0.3;
 const WEALTH_INEQUALITY_RATIO: f64 = 3.0;
@@ -64,8 +102,11 @@ impl SimSystem for EconomySystem {
         );
 
         // Monthly operations — run every month, scaled by seasonal modifiers
-        update_production(ctx);
+        let extraction = update_production(ctx);
+        deposits::deplete_deposits(ctx, &extraction, time, tick_event);
+        prices::update_market_prices(ctx);
         trade::calculate_trade_flows(ctx, tick_event);
+        guilds::collect_guild_profits(ctx, tick_event);
         update_treasuries(ctx, time, tick_event);
         update_economic_prosperity(ctx, tick_event);
 
@@ -73,8 +114,13 @@ impl SimSystem for EconomySystem {
         if is_year_start {
             trade::manage_trade_routes(ctx, time, current_year, tick_event);
             fortifications::update_fortifications(ctx, time, current_year, tick_event);
+            land::improve_land(ctx, time, current_year, tick_event);
+            guilds::update_guilds(ctx, time, current_year, tick_event);
             trade::check_trade_diplomacy(ctx, time, current_year, tick_event);
             check_economic_tensions(ctx, tick_event);
+            deposits::prospect_deposits(ctx, time, tick_event);
+            specialization::update_economy_types(ctx, tick_event);
+            tribute::refuse_tributes(ctx);
         }
     }
 
@@ -94,6 +140,24 @@ impl SimSystem for EconomySystem {
                         signal.event_id,
                     );
                 }
+                SignalKind::EmbargoImposed {
+                    faction_id,
+                    target_id,
+                } => {
+                    trade::sever_faction_trade_routes(
+                        ctx,
+                        *faction_id,
+                        *target_id,
+                        time,
+                        signal.event_id,
+                    );
+                    for fid in [*faction_id, *target_id] {
+                        apply_embargo_losses(ctx.world, fid, signal.event_id);
+                    }
+                }
+                SignalKind::StrategicEmbargoImposed { target_id, .. } => {
+                    trade::sever_strategic_imports(ctx, *target_id, time, signal.event_id);
+                }
                 SignalKind::SettlementCaptured {
                     settlement_id,
                     old_faction_id,
@@ -120,6 +184,14 @@ impl SimSystem for EconomySystem {
                         signal.event_id,
                     );
                 }
+                SignalKind::SiegeEnded {
+                    settlement_id,
+                    outcome: SiegeOutcome::Lifted,
+                    civilian_deaths,
+                    ..
+                } => {
+                    start_rebuilding_boom(ctx, *settlement_id, *civilian_deaths, signal.event_id);
+                }
                 SignalKind::BanditRaid { settlement_id, .. } => {
                     // Reduce prosperity on raided settlement
                     if let Some(entity) = ctx.world.entities.get_mut(settlement_id)
@@ -142,6 +214,40 @@ impl SimSystem for EconomySystem {
     }
 }
 
+/// A town that held out against a siege rebuilds in a rush, the harder the
+/// siege hit it the stronger the boom.
+fn start_rebuilding_boom(
+    ctx: &mut TickContext,
+    settlement_id: u64,
+    civilian_deaths: u32,
+    event_id: u64,
+) {
+    let Some(sd) = ctx
+        .world
+        .entities
+        .get_mut(&settlement_id)
+        .filter(|e| e.end.is_none())
+        .and_then(|e| e.data.as_settlement_mut())
+    else {
+        return;
+    };
+    let pre_siege = sd.population + civilian_deaths;
+    if civilian_deaths == 0 || pre_siege == 0 {
+        return;
+    }
+    let old = sd.rebuilding_boom;
+    let loss = civilian_deaths as f64 / pre_siege as f64;
+    let new = (old + loss * REBUILDING_BOOM_PER_LOSS).min(1.0);
+    sd.rebuilding_boom = new;
+    ctx.world.record_change(
+        settlement_id,
+        event_id,
+        "rebuilding_boom",
+        serde_json::json!(old),
+        serde_json::json!(new),
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Resource values
 // ---------------------------------------------------------------------------
@@ -161,6 +267,11 @@ fn resource_base_value(resource: &str) -> f64 {
     }
 }
 
+/// Monthly consumption of each resource by a settlement of this size.
+fn monthly_consumption(population: u32) -> f64 {
+    population as f64 / CONSUMPTION_DIVISOR / MONTHS_PER_YEAR
+}
+
 // ---------------------------------------------------------------------------
 // Phase B: Resource Production
 // ---------------------------------------------------------------------------
@@ -171,17 +282,21 @@ struct SettlementEcon {
     faction_id: u64,
     population: u32,
     resources: Vec<ResourceType>,
+    climate: Climate,
 }
 
 fn gather_settlements(world: &World) -> Vec<SettlementEcon> {
     world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+        .living(EntityKind::Settlement)
         .filter_map(|e| {
             let region_id = e.active_rel(RelationshipKind::LocatedIn)?;
             let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
             let settlement = e.data.as_settlement()?;
+            let climate = world
+                .entities
+                .get(&region_id)
+                .and_then(|r| r.data.as_region())
+                .map_or(Climate::Temperate, |rd| rd.climate);
 
             Some(SettlementEcon {
                 id: e.id,
@@ -189,31 +304,17 @@ fn gather_settlements(world: &World) -> Vec<SettlementEcon> {
                 faction_id,
                 population: settlement.population,
                 resources: settlement.resources.clone(),
+                climate,
             })
         })
         .collect()
 }
 
-fn get_resource_quality(world: &World, region_id: u64, resource_type: &str) -> f64 {
-    world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::ResourceDeposit && e.end.is_none())
-        .filter(|e| e.has_active_rel(RelationshipKind::LocatedIn, region_id))
-        .filter_map(|e| {
-            let deposit = e.data.as_resource_deposit()?;
-            if deposit.resource_type.as_str() == resource_type {
-                Some(deposit.quality)
-            } else {
-                None
-            }
-        })
-        .next()
-        .unwrap_or(DEFAULT_RESOURCE_QUALITY)
-}
-
-fn update_production(ctx: &mut TickContext) {
+/// Compute monthly production for every settlement. Returns the amount
+/// extracted from each non-renewable deposit this month.
+fn update_production(ctx: &mut TickContext) -> BTreeMap<u64, f64> {
     let settlements = gather_settlements(ctx.world);
+    let mut extraction: BTreeMap<u64, f64> = BTreeMap::new();
 
     struct ProdUpdate {
         id: u64,
@@ -227,23 +328,30 @@ fn update_production(ctx: &mut TickContext) {
         let mut production = BTreeMap::new();
         let mut surplus = BTreeMap::new();
 
+        // Read building bonuses (set by BuildingSystem before Economy ticks)
+        let sd = ctx.world.settlement(s.id);
+
+        // Surviving workers can't keep every field and workshop running after a plague
+        let labor_factor = (1.0 - sd.labor_shortage * LABOR_SHORTAGE_OUTPUT_PENALTY).max(0.0)
+            * (1.0 + sd.unfree_fraction * UNFREE_LABOR_OUTPUT_BONUS);
         let pop_factor = (s.population as f64 / POP_FACTOR_DIVISOR)
             .sqrt()
-            .max(POP_FACTOR_MIN);
-        let consumption_per_resource = s.population as f64 / CONSUMPTION_DIVISOR / MONTHS_PER_YEAR;
+            .max(POP_FACTOR_MIN)
+            * labor_factor;
+        let consumption_per_resource = monthly_consumption(s.population);
 
-        // Read building bonuses (set by BuildingSystem before Economy ticks)
-        let sd = ctx.world.settlement(s.id);
         let mine_bonus = sd.building_bonuses.mine;
         let workshop_bonus = sd.building_bonuses.workshop;
         let fishing_bonus = sd.building_bonuses.fishing;
 
         // Read seasonal food modifier (set by EnvironmentSystem)
         let season_food_mod = sd.seasonal.food;
+        let harvest_blessing = religion::harvest_deity_food_bonus(ctx.world, s.id);
+        let economy_type = sd.economy_type;
 
         for resource in &s.resources {
-            let resource_str = resource.as_str();
-            let quality = get_resource_quality(ctx.world, s.region_id, resource_str);
+            let deposit = deposits::find_deposit(ctx.world, s.region_id, resource);
+            let quality = deposit.map_or(DEFAULT_RESOURCE_QUALITY, |(_, q)| q);
             let mut output = pop_factor * (QUALITY_BASELINE + quality);
 
             // Apply building bonuses
@@ -254,9 +362,9 @@ fn update_production(ctx: &mut TickContext) {
                 output *= 1.0 + workshop_bonus;
             }
 
-            // Apply seasonal modifier to food resources
+            // Apply climate yield and seasonal modifier to food resources
             if helpers::is_food_resource(resource) {
-                output *= season_food_mod;
+                output *= s.climate.food_yield() * season_food_mod * (1.0 + harvest_blessing);
             }
 
             // Port fishing bonus for Fish resources
@@ -264,6 +372,9 @@ fn update_production(ctx: &mut TickContext) {
                 output *= 1.0 + fishing_bonus;
             }
 
+            // Specialization bonus on the settlement's dominant sector
+            output *= 1.0 + specialization::production_bonus(economy_type, resource);
+
             // Literacy bonus: educated workforce produces up to 10% more
             let literacy = helpers::settlement_literacy(ctx.world, s.id);
             output *= 1.0 + literacy * 0.10;
@@ -272,6 +383,11 @@ fn update_production(ctx: &mut TickContext) {
             output /= MONTHS_PER_YEAR;
 
             production.insert(resource.clone(), output);
+            if let Some((deposit_id, _)) = deposit
+                && !helpers::is_food_resource(resource)
+            {
+                *extraction.entry(deposit_id).or_default() += output;
+            }
 
             let surplus_val = output - consumption_per_resource;
             surplus.insert(resource.clone(), surplus_val);
@@ -288,13 +404,41 @@ fn update_production(ctx: &mut TickContext) {
         let sd = ctx.world.settlement_mut(u.id);
         sd.production = u.production;
         sd.surplus = u.surplus;
+        sd.labor_shortage *= 1.0 - LABOR_SHORTAGE_RECOVERY_RATE / MONTHS_PER_YEAR;
+        sd.rebuilding_boom *= 1.0 - REBUILDING_BOOM_FADE_RATE / MONTHS_PER_YEAR;
     }
+
+    extraction
 }
 
 // ---------------------------------------------------------------------------
 // Phase D: Treasuries
 // ---------------------------------------------------------------------------
 
+/// Lost contracts and stranded cargo when an embargo cuts off a trading partner.
+fn apply_embargo_losses(world: &mut World, faction_id: u64, event_id: u64) {
+    let Some(fd) = world
+        .entities
+        .get_mut(&faction_id)
+        .and_then(|e| e.data.as_faction_mut())
+    else {
+        return;
+    };
+    if fd.treasury <= 0.0 {
+        return;
+    }
+    let old = fd.treasury;
+    fd.treasury -= old * EMBARGO_TREASURY_LOSS;
+    let new = fd.treasury;
+    world.record_change(
+        faction_id,
+        event_id,
+        "treasury",
+        serde_json::json!(old),
+        serde_json::json!(new),
+    );
+}
+
 fn update_treasuries(ctx: &mut TickContext, _time: SimTimestamp, year_event: u64) {
     struct FactionFinance {
         id: u64,
@@ -303,13 +447,7 @@ fn update_treasuries(ctx: &mut TickContext, _time: SimTimestamp, year_event: u64
         old_treasury: f64,
     }
 
-    let factions: Vec<u64> = ctx
-        .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
-        .map(|e| e.id)
-        .collect();
+    let factions: Vec<u64> = ctx.world.living_ids(EntityKind::Faction).collect();
 
     let mut finances: Vec<FactionFinance> = Vec::new();
 
@@ -367,9 +505,15 @@ fn update_treasuries(ctx: &mut TickContext, _time: SimTimestamp, year_event: u64
             }
         }
 
+        let spy_expense = if faction_spymaster(ctx.world, fid).is_some() {
+            SPY_NETWORK_UPKEEP
+        } else {
+            0.0
+        };
+
         // Scale expenses to monthly (constants are annual rates)
-        let expenses =
-            (army_expense + settlement_count as f64 * SETTLEMENT_UPKEEP) / MONTHS_PER_YEAR;
+        let expenses = (army_expense + settlement_count as f64 * SETTLEMENT_UPKEEP + spy_expense)
+            / MONTHS_PER_YEAR;
 
         finances.push(FactionFinance {
             id: fid,
@@ -413,9 +557,7 @@ fn collect_tributes(ctx: &mut TickContext, year_event: u64) {
     // Collect tribute obligations from faction struct fields: (payer_id, payee_id, amount, years_remaining)
     let obligations: Vec<(u64, u64, f64, u32)> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
+        .living(EntityKind::Faction)
         .flat_map(|e| {
             e.data
                 .as_faction()
@@ -488,7 +630,7 @@ fn collect_tributes(ctx: &mut TickContext, year_event: u64) {
 
             // End tribute_to relationship
             if let Some(entity) = ctx.world.entities.get_mut(&payer_id) {
-                let kind = RelationshipKind::Custom("tribute_to".to_string());
+                let kind = RelationshipKind::tribute_to();
                 for r in &mut entity.relationships {
                     if r.target_entity_id == payee_id && r.kind == kind && r.end.is_none() {
                         r.end = Some(time);
@@ -565,13 +707,7 @@ fn update_economic_prosperity(ctx: &mut TickContext, year_event: u64) {
 
     let mut updates: Vec<ProsperityUpdate> = Vec::new();
 
-    let settlement_ids: Vec<u64> = ctx
-        .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
-        .map(|e| e.id)
-        .collect();
+    let settlement_ids: Vec<u64> = ctx.world.living_ids(EntityKind::Settlement).collect();
 
     for &sid in &settlement_ids {
         let entity = match ctx.world.entities.get(&sid) {
@@ -608,12 +744,18 @@ fn update_economic_prosperity(ctx: &mut TickContext, year_event: u64) {
         // Scale: a settlement producing ~5 value per 100 people is baseline (0.5 prosperity)
         let per_capita = economic_output / (population.max(1.0) / PER_CAPITA_POP_DIVISOR);
         let raw_prosperity = (per_capita / PER_CAPITA_PROSPERITY_DIVISOR
-            + settlement_prestige * PRESTIGE_PROSPERITY_FACTOR)
+            + settlement_prestige * PRESTIGE_PROSPERITY_FACTOR
+            + settlement.labor_shortage * LABOR_SHORTAGE_WAGE_BONUS)
             .clamp(0.0, 1.0);
 
-        // Smooth convergence (monthly rate = yearly rate / 12)
+        // Smooth convergence (monthly rate = yearly rate / 12); a rebuilding
+        // boom speeds the climb back up
+        let mut convergence_rate = PROSPERITY_CONVERGENCE_RATE;
+        if raw_prosperity > old_prosperity {
+            convergence_rate *= 1.0 + settlement.rebuilding_boom * REBUILDING_PROSPERITY_BONUS;
+        }
         let mut new_prosperity = old_prosperity
-            + (raw_prosperity - old_prosperity) * (PROSPERITY_CONVERGENCE_RATE / MONTHS_PER_YEAR);
+            + (raw_prosperity - old_prosperity) * (convergence_rate / MONTHS_PER_YEAR);
 
         // Overcrowding penalty
         let capacity_ratio = population / capacity.max(1.0);
@@ -626,7 +768,10 @@ fn update_economic_prosperity(ctx: &mut TickContext, year_event: u64) {
         // Crime penalty
         new_prosperity -= settlement.crime_rate * CRIME_PROSPERITY_PENALTY / MONTHS_PER_YEAR;
 
-        new_prosperity = new_prosperity.clamp(PROSPERITY_FLOOR, PROSPERITY_CEILING);
+        let ceiling = (PROSPERITY_CEILING
+            - settlement.unfree_fraction * UNFREE_PROSPERITY_CEILING_PENALTY)
+            .max(PROSPERITY_FLOOR);
+        new_prosperity = new_prosperity.clamp(PROSPERITY_FLOOR, ceiling);
 
         updates.push(ProsperityUpdate {
             settlement_id: sid,
@@ -665,21 +810,18 @@ fn check_economic_tensions(ctx: &mut TickContext, _year_event: u64) {
         ResourceType::Timber,
     ];
 
-    let factions: Vec<u64> = ctx
-        .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
-        .map(|e| e.id)
-        .collect();
+    let factions: Vec<u64> = ctx.world.living_ids(EntityKind::Faction).collect();
 
     // Collect resources available to each faction
     let mut faction_resources: BTreeMap<u64, std::collections::BTreeSet<ResourceType>> =
         BTreeMap::new();
     let mut faction_treasury_per_settlement: BTreeMap<u64, f64> = BTreeMap::new();
+    // Highest price each faction's markets pay for a good, as a multiple of base value
+    let mut faction_prices: BTreeMap<u64, BTreeMap<ResourceType, f64>> = BTreeMap::new();
 
     for &fid in &factions {
         let mut resources = std::collections::BTreeSet::new();
+        let mut prices: BTreeMap<ResourceType, f64> = BTreeMap::new();
         let mut settlement_count = 0u32;
 
         for e in ctx.world.entities.values() {
@@ -692,6 +834,11 @@ fn check_economic_tensions(ctx: &mut TickContext, _year_event: u64) {
                     for r in &settlement.resources {
                         resources.insert(r.clone());
                     }
+                    for (r, &price) in &settlement.market_prices {
+                        let multiple = price / resource_base_value(r.as_str());
+                        let entry = prices.entry(r.clone()).or_insert(multiple);
+                        *entry = entry.max(multiple);
+                    }
                 }
             }
         }
@@ -711,6 +858,7 @@ fn check_economic_tensions(ctx: &mut TickContext, _year_event: u64) {
         };
 
         faction_resources.insert(fid, resources);
+        faction_prices.insert(fid, prices);
         faction_treasury_per_settlement.insert(fid, per_settlement);
     }
 
@@ -738,13 +886,8 @@ fn check_economic_tensions(ctx: &mut TickContext, _year_event: u64) {
         // Get regions owned by this faction
         let my_regions: Vec<u64> = ctx
             .world
-            .entities
-            .values()
-            .filter(|e| {
-                e.kind == EntityKind::Settlement
-                    && e.end.is_none()
-                    && e.has_active_rel(RelationshipKind::MemberOf, fid)
-            })
+            .living(EntityKind::Settlement)
+            .filter(|e| e.has_active_rel(RelationshipKind::MemberOf, fid))
             .filter_map(|e| e.active_rel(RelationshipKind::LocatedIn))
             .collect();
 
@@ -772,10 +915,18 @@ fn check_economic_tensions(ctx: &mut TickContext, _year_event: u64) {
                 None => continue,
             };
 
-            // Resource scarcity: they have strategic resources we lack
+            // Resource scarcity: they have strategic resources we lack, weighed
+            // by how dearly our markets pay for them (imports ease the pressure)
             for res in &strategic_resources {
                 if !my_resources.contains(res) && their_resources.contains(res) {
-                    motivation += RESOURCE_SCARCITY_MOTIVATION;
+                    let scarcity = faction_prices.get(&fid).and_then(|p| p.get(res)).map_or(
+                        1.0,
+                        |&multiple| {
+                            ((multiple - 1.0) / (prices::MAX_PRICE_MULTIPLIER - 1.0))
+                                .clamp(0.0, 1.0)
+                        },
+                    );
+                    motivation += RESOURCE_SCARCITY_MOTIVATION * scarcity;
                 }
             }
 
@@ -1063,4 +1214,42 @@ mod tests {
 
         crate::testutil::assert_property_changed(&world, payee, "treasury");
     }
+
+    #[test]
+    fn scenario_lifted_siege_rebuilds_faster_than_control() {
+        use crate::sim::demographics::DemographicsSystem;
+        use crate::sim::system::SimSystem;
+
+        let mut s = Scenario::at_year(100);
+        let besieged = s.add_settlement_standalone("Besieged").settlement;
+        let control = s.add_settlement_standalone("Control").settlement;
+        let _ = s.settlement_mut(besieged).population(200);
+        let _ = s.settlement_mut(control).population(200);
+        let mut world = s.build();
+        let ev = test_event(&mut world);
+
+        let inbox = vec![Signal {
+            event_id: ev,
+            kind: SignalKind::SiegeEnded {
+                settlement_id: besieged,
+                attacker_faction_id: 999,
+                defender_faction_id: 998,
+                outcome: SiegeOutcome::Lifted,
+                civilian_deaths: 100,
+            },
+        }];
+        deliver_signals(&mut world, &mut EconomySystem, &inbox, 42);
+        assert!(world.settlement(besieged).rebuilding_boom > 0.0);
+        assert_eq!(world.settlement(control).rebuilding_boom, 0.0);
+
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(DemographicsSystem::new())];
+        crate::testutil::run_years(&mut world, &mut systems, 5, 42);
+
+        let besieged_pop = world.settlement(besieged).population;
+        let control_pop = world.settlement(control).population;
+        assert!(
+            besieged_pop > control_pop,
+            "rebuilding should outpace the control: besieged {besieged_pop} vs control {control_pop}"
+        );
+    }
 }
@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::model::World;
+use crate::model::entity_data::ResourceType;
+use crate::sim::context::TickContext;
+use crate::sim::helpers;
+
+use super::{gather_settlements, monthly_consumption, resource_base_value};
+
+/// Cheapest a good gets, as a multiple of its base value, in a glutted market.
+pub(super) const MIN_PRICE_MULTIPLIER: f64 = 0.5;
+/// Dearest a good gets, as a multiple of its base value, where none is on offer.
+pub(super) const MAX_PRICE_MULTIPLIER: f64 = 2.5;
+/// How sharply price follows the demand/supply ratio.
+const PRICE_ELASTICITY: f64 = 0.5;
+/// Share of a trade partner's surplus that reaches this settlement's market.
+const PARTNER_SUPPLY_SHARE: f64 = 0.5;
+/// A good priced at or above this multiple of its base value is scarce.
+pub(super) const SCARCITY_PRICE_MULTIPLIER: f64 = 1.5;
+
+/// Price as a multiple of base value for a market with the given monthly
+/// supply and demand.
+pub(super) fn price_multiplier(supply: f64, demand: f64) -> f64 {
+    if supply <= 0.0 {
+        return MAX_PRICE_MULTIPLIER;
+    }
+    (demand / supply)
+        .powf(PRICE_ELASTICITY)
+        .clamp(MIN_PRICE_MULTIPLIER, MAX_PRICE_MULTIPLIER)
+}
+
+/// Market price of `resource` at a settlement, falling back to its base value
+/// where the settlement's market doesn't deal in it.
+pub(super) fn market_price(world: &World, settlement_id: u64, resource: &str) -> f64 {
+    let resource_type: Option<ResourceType> = resource.to_string().try_into().ok();
+    resource_type
+        .and_then(|rt| {
+            world
+                .entities
+                .get(&settlement_id)
+                .and_then(|e| e.data.as_settlement())
+                .and_then(|sd| sd.market_prices.get(&rt).copied())
+        })
+        .unwrap_or_else(|| resource_base_value(resource))
+}
+
+/// Recompute every settlement's market prices from this month's production.
+///
+/// A settlement's supply of a good is its own output plus a share of its
+/// trade partners' surplus. Goods produced next door (same or adjacent region)
+/// but not reaching the market are priced as scarce, which is what draws new
+/// trade routes toward them.
+pub(super) fn update_market_prices(ctx: &mut TickContext) {
+    let settlements = gather_settlements(ctx.world);
+
+    let mut by_region: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+    for (i, s) in settlements.iter().enumerate() {
+        by_region.entry(s.region_id).or_default().push(i);
+    }
+
+    // Trade partners in either direction
+    let mut partners: BTreeMap<u64, BTreeSet<u64>> = BTreeMap::new();
+    for s in &settlements {
+        for route in &ctx.world.settlement(s.id).trade_routes {
+            partners.entry(s.id).or_default().insert(route.target);
+            partners.entry(route.target).or_default().insert(s.id);
+        }
+    }
+
+    let mut updates: Vec<(u64, BTreeMap<ResourceType, f64>)> = Vec::new();
+    for s in &settlements {
+        let mut neighbor_regions = helpers::adjacent_regions(ctx.world, s.region_id);
+        neighbor_regions.push(s.region_id);
+        let mut goods: BTreeSet<ResourceType> = neighbor_regions
+            .iter()
+            .filter_map(|r| by_region.get(r))
+            .flatten()
+            .flat_map(|&i| settlements[i].resources.iter().cloned())
+            .collect();
+
+        let my_partners = partners.get(&s.id);
+        let mut supply: BTreeMap<ResourceType, f64> = ctx.world.settlement(s.id).production.clone();
+        for &pid in my_partners.into_iter().flatten() {
+            let Some(pd) = ctx
+                .world
+                .entities
+                .get(&pid)
+                .filter(|e| e.end.is_none())
+                .and_then(|e| e.data.as_settlement())
+            else {
+                continue;
+            };
+            for (resource, &surplus) in &pd.surplus {
+                goods.insert(resource.clone());
+                *supply.entry(resource.clone()).or_default() +=
+                    surplus.max(0.0) * PARTNER_SUPPLY_SHARE;
+            }
+        }
+
+        let demand = monthly_consumption(s.population);
+        let prices = goods
+            .into_iter()
+            .map(|resource| {
+                let available = supply.get(&resource).copied().unwrap_or(0.0);
+                let price =
+                    resource_base_value(resource.as_str()) * price_multiplier(available, demand);
+                (resource, price)
+            })
+            .collect();
+        updates.push((s.id, prices));
+    }
+
+    for (id, prices) in updates {
+        ctx.world.settlement_mut(id).market_prices = prices;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn price_rises_with_scarcity() {
+        let glut = price_multiplier(10.0, 1.0);
+        let balanced = price_multiplier(1.0, 1.0);
+        let short = price_multiplier(0.25, 1.0);
+        assert!(glut < balanced && balanced < short);
+        assert_eq!(glut, MIN_PRICE_MULTIPLIER);
+        assert_eq!(price_multiplier(0.0, 1.0), MAX_PRICE_MULTIPLIER);
+    }
+}
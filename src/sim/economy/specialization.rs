@@ -0,0 +1,152 @@
+use crate::model::EntityKind;
+use crate::model::entity_data::{ResourceType, SettlementData, SettlementEconomyType};
+use crate::sim::context::TickContext;
+use crate::sim::helpers;
+
+use super::resource_base_value;
+
+/// Output bonus a specialized settlement gets on its dominant sector.
+const SPECIALIZATION_BONUS: f64 = 0.10;
+
+/// Classify a settlement by the sector producing the most value: food,
+/// mining, other crafted goods, or trade. Trade hubs on the water are ports.
+pub(crate) fn classify_economy(sd: &SettlementData) -> SettlementEconomyType {
+    let mut agricultural = 0.0;
+    let mut mining = 0.0;
+    let mut craft = 0.0;
+    for (resource, &amount) in &sd.production {
+        let value = amount.max(0.0) * resource_base_value(resource.as_str());
+        if helpers::is_food_resource(resource) {
+            agricultural += value;
+        } else if helpers::is_mining_resource(resource) {
+            mining += value;
+        } else {
+            craft += value;
+        }
+    }
+    let trade = sd.trade_income.max(0.0);
+
+    let mut best = (SettlementEconomyType::Agricultural, agricultural);
+    for candidate in [
+        (SettlementEconomyType::Mining, mining),
+        (SettlementEconomyType::Craft, craft),
+        (SettlementEconomyType::Trade, trade),
+    ] {
+        if candidate.1 > best.1 {
+            best = candidate;
+        }
+    }
+
+    match best.0 {
+        SettlementEconomyType::Trade if sd.is_coastal => SettlementEconomyType::Port,
+        other => other,
+    }
+}
+
+/// Production multiplier bonus for `resource` given the settlement's specialization.
+pub(super) fn production_bonus(
+    economy_type: SettlementEconomyType,
+    resource: &ResourceType,
+) -> f64 {
+    let matches = match economy_type {
+        SettlementEconomyType::Agricultural => helpers::is_food_resource(resource),
+        SettlementEconomyType::Mining => helpers::is_mining_resource(resource),
+        SettlementEconomyType::Craft => {
+            !helpers::is_food_resource(resource) && !helpers::is_mining_resource(resource)
+        }
+        SettlementEconomyType::Port => matches!(resource, ResourceType::Fish),
+        SettlementEconomyType::Trade => false,
+    };
+    if matches { SPECIALIZATION_BONUS } else { 0.0 }
+}
+
+/// Trade income multiplier bonus given the settlement's specialization.
+pub(super) fn trade_bonus(economy_type: SettlementEconomyType) -> f64 {
+    match economy_type {
+        SettlementEconomyType::Trade | SettlementEconomyType::Port => SPECIALIZATION_BONUS,
+        _ => 0.0,
+    }
+}
+
+/// Recompute every living settlement's economy type from its current production and trade.
+pub(super) fn update_economy_types(ctx: &mut TickContext, year_event: u64) {
+    let changes: Vec<(u64, SettlementEconomyType, SettlementEconomyType)> = ctx
+        .world
+        .living(EntityKind::Settlement)
+        .filter_map(|e| {
+            let sd = e.data.as_settlement()?;
+            let new_type = classify_economy(sd);
+            (new_type != sd.economy_type).then_some((e.id, sd.economy_type, new_type))
+        })
+        .collect();
+
+    for (sid, old_type, new_type) in changes {
+        ctx.world.settlement_mut(sid).economy_type = new_type;
+        ctx.world.record_change(
+            sid,
+            year_event,
+            "economy_type",
+            serde_json::json!(old_type),
+            serde_json::json!(new_type),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EntityData;
+    use crate::scenario::Scenario;
+    use crate::sim::economy::EconomySystem;
+    use crate::testutil;
+
+    #[test]
+    fn classify_empty_settlement_is_agricultural() {
+        let EntityData::Settlement(sd) = EntityData::default_for_kind(EntityKind::Settlement)
+        else {
+            unreachable!()
+        };
+        assert_eq!(classify_economy(&sd), SettlementEconomyType::Agricultural);
+    }
+
+    #[test]
+    fn coastal_trade_hub_is_port() {
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone("Harbor");
+        let _ = s.settlement_mut(setup.settlement).with(|sd| {
+            sd.is_coastal = true;
+            sd.trade_income = 50.0;
+            sd.production.insert(ResourceType::Grain, 1.0);
+        });
+        let world = s.build();
+        assert_eq!(
+            classify_economy(world.settlement(setup.settlement)),
+            SettlementEconomyType::Port
+        );
+    }
+
+    #[test]
+    fn scenario_iron_mining_town_is_classified_mining() {
+        let mut s = Scenario::at_year(100);
+        let setup = s.add_settlement_standalone("Ironhold");
+        let _ = s
+            .settlement_mut(setup.settlement)
+            .population(800)
+            .with(|sd| {
+                sd.resources = vec![ResourceType::Iron, ResourceType::Grain];
+                sd.building_bonuses.mine = 1.0;
+            });
+        s.add_resource_deposit_with("Iron deposit", ResourceType::Iron, setup.region, |rd| {
+            rd.quality = 1.0;
+            rd.quantity = 500;
+        });
+        let mut world = s.build();
+
+        testutil::tick_system(&mut world, &mut EconomySystem, 100, 42);
+
+        let sd = world.settlement(setup.settlement);
+        assert_eq!(sd.economy_type, SettlementEconomyType::Mining);
+        assert!(production_bonus(sd.economy_type, &ResourceType::Iron) > 0.0);
+        assert_eq!(production_bonus(sd.economy_type, &ResourceType::Grain), 0.0);
+    }
+}
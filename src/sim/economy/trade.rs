@@ -2,12 +2,16 @@ use std::collections::BTreeMap;
 
 use rand::Rng;
 
-use crate::model::{EntityKind, EventKind, ParticipantRole, RelationshipKind, SimTimestamp, World};
+use crate::model::{
+    EntityKind, EventKind, ParticipantRole, RelationshipKind, ResourceType, SimTimestamp, World,
+};
+use crate::sim::conflicts::interdiction;
 use crate::sim::context::TickContext;
 use crate::sim::helpers;
 use crate::sim::signal::{Signal, SignalKind};
 
-use super::{gather_settlements, resource_base_value};
+use super::prices::{SCARCITY_PRICE_MULTIPLIER, market_price};
+use super::{gather_settlements, monthly_consumption, resource_base_value};
 
 pub(super) const MAX_TRADE_HOPS: usize = 6;
 pub(super) const MAX_ROUTES_PER_SETTLEMENT: usize = 3;
@@ -17,6 +21,11 @@ pub(super) const TRADE_ROUTE_FORMATION_CHANCE: f64 = 0.15;
 const TRADE_DISTANCE_DECAY_FACTOR: f64 = 0.15;
 const TRADE_PRESTIGE_VALUE_BONUS: f64 = 0.15;
 const TRADE_PRESTIGE_FORMATION_BONUS: f64 = 0.2;
+/// Change in formation chance per point of the source faction's mercantilism above neutral.
+const MERCANTILISM_FORMATION_WEIGHT: f64 = 1.0;
+/// Change in formation chance per point of either faction's isolationism above
+/// neutral, for routes crossing a border.
+const ISOLATIONISM_FORMATION_WEIGHT: f64 = -1.0;
 const RIVER_TRADE_BONUS: f64 = 1.3;
 const SEA_TRADE_BONUS: f64 = 1.5;
 const SEA_RANGE_BONUS: usize = 4;
@@ -168,14 +177,28 @@ pub(super) fn manage_trade_routes(
     let mut deficit_settlements: Vec<(u64, u64, u64, String, f64)> = Vec::new();
 
     for s in &settlements {
-        let surplus_map = ctx
+        let sd = ctx
             .world
             .entities
             .get(&s.id)
-            .and_then(|e| e.data.as_settlement())
-            .map(|sd| &sd.surplus);
+            .and_then(|e| e.data.as_settlement());
 
-        if let Some(surplus_map) = surplus_map {
+        if let Some(sd) = sd {
+            let surplus_map = &sd.surplus;
+            // Goods the settlement doesn't make but pays dearly for are in demand too
+            for (resource, &price) in &sd.market_prices {
+                if !surplus_map.contains_key(resource)
+                    && price >= resource_base_value(resource.as_str()) * SCARCITY_PRICE_MULTIPLIER
+                {
+                    deficit_settlements.push((
+                        s.id,
+                        s.region_id,
+                        s.faction_id,
+                        resource.as_str().to_string(),
+                        -monthly_consumption(s.population),
+                    ));
+                }
+            }
             for (resource, &v) in surplus_map {
                 if v > 0.0 {
                     surplus_settlements.push((
@@ -243,8 +266,22 @@ pub(super) fn manage_trade_routes(
             if src_id == tgt_id {
                 continue;
             }
-            // Don't trade with factions at war
-            if factions_at_war(ctx.world, src_faction, tgt_faction) {
+            // Don't trade with factions at war or under embargo
+            if factions_at_war(ctx.world, src_faction, tgt_faction)
+                || helpers::has_active_rel_of_kind(
+                    ctx.world,
+                    src_faction,
+                    tgt_faction,
+                    RelationshipKind::Embargo,
+                )
+            {
+                continue;
+            }
+            // Strategic goods can't reach a faction whose enemies interdict them
+            if src_faction != tgt_faction
+                && carries_strategic_good(resource)
+                && interdiction::under_strategic_embargo(ctx.world, tgt_faction)
+            {
                 continue;
             }
             // Check if a route already exists between these settlements
@@ -309,7 +346,7 @@ pub(super) fn manage_trade_routes(
                     .map(|sd| sd.prestige)
                     .unwrap_or(0.0);
                 let avg_endpoint_prestige = (src_prestige + tgt_prestige) / 2.0;
-                let value = surplus_val * resource_base_value(resource)
+                let value = surplus_val * market_price(ctx.world, tgt_id, resource)
                     / (1.0 + TRADE_DISTANCE_DECAY_FACTOR * distance as f64)
                     * (1.0 + avg_endpoint_prestige * TRADE_PRESTIGE_VALUE_BONUS);
 
@@ -351,8 +388,24 @@ pub(super) fn manage_trade_routes(
             .and_then(|e| e.data.as_settlement())
             .map(|sd| sd.prestige)
             .unwrap_or(0.0);
-        let formation_chance =
-            TRADE_ROUTE_FORMATION_CHANCE * (1.0 + source_prestige * TRADE_PRESTIGE_FORMATION_BONUS);
+        let mut formation_chance = TRADE_ROUTE_FORMATION_CHANCE
+            * (1.0 + source_prestige * TRADE_PRESTIGE_FORMATION_BONUS)
+            * helpers::policy_factor(
+                ctx.world,
+                c.source_faction,
+                |fd| fd.mercantilism,
+                MERCANTILISM_FORMATION_WEIGHT,
+            );
+        if c.source_faction != c.target_faction {
+            for fid in [c.source_faction, c.target_faction] {
+                formation_chance *= helpers::policy_factor(
+                    ctx.world,
+                    fid,
+                    |fd| fd.isolationism,
+                    ISOLATIONISM_FORMATION_WEIGHT,
+                );
+            }
+        }
         if ctx.rng.random_range(0.0..1.0) >= formation_chance {
             continue;
         }
@@ -421,6 +474,7 @@ pub(super) fn manage_trade_routes(
             path,
             distance: distance as u32,
             resource: c.resource.clone(),
+            security: 0.0,
         };
 
         ctx.world
@@ -456,13 +510,7 @@ pub(super) fn calculate_trade_flows(ctx: &mut TickContext, _year_event: u64) {
 
     let mut updates: Vec<TradeUpdate> = Vec::new();
 
-    let settlement_ids: Vec<u64> = ctx
-        .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
-        .map(|e| e.id)
-        .collect();
+    let settlement_ids: Vec<u64> = ctx.world.living_ids(EntityKind::Settlement).collect();
 
     for &sid in &settlement_ids {
         let routes: Vec<crate::model::entity_data::TradeRoute> = ctx
@@ -499,18 +547,22 @@ pub(super) fn calculate_trade_flows(ctx: &mut TickContext, _year_event: u64) {
                 continue;
             }
 
-            // Get deficit at target
+            // Get deficit at target; a target that makes none of the good
+            // needs its whole consumption
             let target_id = route.target;
-            let target_deficit = resource_type
-                .as_ref()
-                .and_then(|rt| {
-                    ctx.world
-                        .entities
-                        .get(&target_id)
-                        .and_then(|e| e.data.as_settlement())
-                        .and_then(|sd| sd.surplus.get(rt).copied())
-                })
-                .unwrap_or(0.0);
+            let target_sd = ctx
+                .world
+                .entities
+                .get(&target_id)
+                .and_then(|e| e.data.as_settlement());
+            let target_deficit = match (resource_type.as_ref(), target_sd) {
+                (Some(rt), Some(sd)) => sd
+                    .surplus
+                    .get(rt)
+                    .copied()
+                    .unwrap_or_else(|| -monthly_consumption(sd.population)),
+                _ => 0.0,
+            };
 
             // Only trade if target actually has a deficit
             let demand = if target_deficit < 0.0 {
@@ -537,8 +589,12 @@ pub(super) fn calculate_trade_flows(ctx: &mut TickContext, _year_event: u64) {
                 1.0
             };
 
-            let value =
-                volume * resource_base_value(resource) * distance_decay * river_bonus * sea_bonus;
+            // Sold at the buyer's market price: deficit markets pay more
+            let value = volume
+                * market_price(ctx.world, target_id, resource)
+                * distance_decay
+                * river_bonus
+                * sea_bonus;
             total_income += value;
         }
 
@@ -552,18 +608,21 @@ pub(super) fn calculate_trade_flows(ctx: &mut TickContext, _year_event: u64) {
         let port_trade_bonus = sd.map(|s| s.building_bonuses.port_trade).unwrap_or(0.0);
         // Apply seasonal trade modifier (set by EnvironmentSystem)
         let season_trade_mod = sd.map(|s| s.seasonal.trade).unwrap_or(1.0);
+        let specialization_bonus = sd
+            .map(|s| super::specialization::trade_bonus(s.economy_type))
+            .unwrap_or(0.0);
 
-        total_income *= (1.0 + market_bonus + port_trade_bonus) * season_trade_mod;
+        total_income *=
+            (1.0 + market_bonus + port_trade_bonus + specialization_bonus) * season_trade_mod;
 
         // Scale to monthly
         total_income /= super::MONTHS_PER_YEAR;
 
-        if total_income > 0.0 {
-            updates.push(TradeUpdate {
-                settlement_id: sid,
-                trade_income: total_income,
-            });
-        }
+        // Always write back so income dries up once routes are severed
+        updates.push(TradeUpdate {
+            settlement_id: sid,
+            trade_income: total_income,
+        });
     }
 
     for u in updates {
@@ -608,6 +667,39 @@ pub(super) fn sever_faction_trade_routes(
     }
 }
 
+/// Cut routes carrying strategic goods into `target_faction` from abroad.
+pub(super) fn sever_strategic_imports(
+    ctx: &mut TickContext,
+    target_faction: u64,
+    time: SimTimestamp,
+    caused_by: u64,
+) {
+    let mut to_sever: Vec<(u64, u64)> = Vec::new();
+    for e in ctx.world.living(EntityKind::Settlement) {
+        if e.active_rel(RelationshipKind::MemberOf) == Some(target_faction) {
+            continue;
+        }
+        let Some(sd) = e.data.as_settlement() else {
+            continue;
+        };
+        for route in &sd.trade_routes {
+            if carries_strategic_good(&route.resource)
+                && helpers::settlement_faction(ctx.world, route.target) == Some(target_faction)
+            {
+                to_sever.push((e.id, route.target));
+            }
+        }
+    }
+
+    for (source, target) in to_sever {
+        sever_route(ctx, source, target, time, caused_by);
+    }
+}
+
+fn carries_strategic_good(resource: &str) -> bool {
+    ResourceType::try_from(resource.to_string()).is_ok_and(|r| interdiction::is_strategic_good(&r))
+}
+
 pub(crate) fn sever_settlement_trade_routes(
     ctx: &mut TickContext,
     settlement_id: u64,
@@ -683,13 +775,7 @@ pub(super) fn check_trade_diplomacy(
     _year_event: u64,
 ) {
     // Count cross-faction trade routes and compute trade happiness bonuses
-    let factions: Vec<u64> = ctx
-        .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
-        .map(|e| e.id)
-        .collect();
+    let factions: Vec<u64> = ctx.world.living_ids(EntityKind::Faction).collect();
 
     // Count trade routes between each faction pair (BTreeMap for deterministic iteration)
     let mut faction_pair_routes: std::collections::BTreeMap<(u64, u64), usize> =
@@ -819,6 +905,57 @@ mod tests {
     use super::*;
     use crate::scenario::Scenario;
 
+    #[test]
+    fn scenario_price_gradient_draws_trade_to_scarce_market() {
+        use crate::model::entity_data::ResourceType;
+        use crate::sim::economy::EconomySystem;
+        use crate::testutil;
+
+        let mut s = Scenario::new();
+        let r1 = s.add_region("Hills");
+        let r2 = s.add_region("Plains");
+        s.make_adjacent(r1, r2);
+        let faction = s.add_faction("Realm");
+        let mine = s
+            .settlement("Minehold", faction, r1)
+            .population(200)
+            .resources(vec![ResourceType::Grain, ResourceType::Iron])
+            .id();
+        let farm = s
+            .settlement("Farmstead", faction, r2)
+            .population(1000)
+            .resources(vec![ResourceType::Grain])
+            .id();
+        let mut world = s.build();
+        let mut economy = EconomySystem;
+
+        testutil::tick_system(&mut world, &mut economy, 1, 1);
+        let iron_price =
+            |world: &World, id| world.settlement(id).market_prices[&ResourceType::Iron];
+        let (source_price, buyer_price) = (iron_price(&world, mine), iron_price(&world, farm));
+        assert!(
+            buyer_price > source_price,
+            "iron should cost more where none is mined ({buyer_price} vs {source_price})"
+        );
+
+        for year in 2..=40 {
+            if !world.settlement(mine).trade_routes.is_empty() {
+                break;
+            }
+            testutil::tick_system(&mut world, &mut economy, year, year as u64);
+        }
+        let routes = &world.settlement(mine).trade_routes;
+        assert!(
+            routes
+                .iter()
+                .any(|r| r.target == farm && r.resource == "iron"),
+            "expected an iron route to the scarce market, got {routes:?}"
+        );
+
+        testutil::tick_system_at(&mut world, &mut economy, SimTimestamp::new(41, 31, 0), 7);
+        assert!(world.settlement(mine).trade_income > 0.0);
+    }
+
     #[test]
     fn scenario_trade_path_direct_neighbor() {
         let mut s = Scenario::new();
@@ -941,4 +1078,54 @@ mod tests {
         let has_water = path.iter().any(|&rid| region_is_water(&world, rid));
         assert!(has_water, "path should cross water region");
     }
+
+    #[test]
+    fn scenario_strategic_embargo_severs_iron_but_not_grain() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut s = Scenario::at_year(100);
+        let region = s.add_region("Plains");
+        let seller = s.add_faction("Seller");
+        let buyer = s.add_faction("Buyer");
+        let market = s.settlement("Market", seller, region).population(300).id();
+        let forge = s.settlement("Forge", buyer, region).population(300).id();
+        let granary = s.settlement("Granary", buyer, region).population(300).id();
+        s.make_trade_route(market, forge);
+        s.make_trade_route(market, granary);
+        s.modify_settlement(market, |sd| {
+            for (target, resource) in [(forge, "iron"), (granary, "grain")] {
+                sd.trade_routes.push(crate::model::TradeRoute {
+                    target,
+                    path: vec![],
+                    distance: 1,
+                    resource: resource.to_string(),
+                    security: 0.0,
+                });
+            }
+        });
+        let mut world = s.build();
+        let time = world.current_time;
+        let ev = world.add_event(EventKind::Custom("test".to_string()), time, String::new());
+
+        let mut rng = SmallRng::seed_from_u64(1);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world: &mut world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        sever_strategic_imports(&mut ctx, buyer, time, ev);
+
+        let routes = &world.settlement(market).trade_routes;
+        assert!(
+            routes.iter().all(|r| r.target != forge),
+            "iron route survived"
+        );
+        assert!(
+            routes.iter().any(|r| r.target == granary),
+            "grain route should be untouched"
+        );
+    }
 }
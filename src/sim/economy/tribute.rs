@@ -0,0 +1,200 @@
+use rand::Rng;
+
+use crate::model::{EntityKind, EventPayload, ParticipantRole, RelationshipKind, World};
+use crate::sim::context::TickContext;
+use crate::sim::grievance as grv;
+use crate::sim::helpers;
+
+/// Tributary power, as a multiple of its overlord's, at which it starts
+/// considering refusing to pay.
+const REFUSAL_POWER_RATIO: f64 = 1.2;
+/// Yearly refusal chance per unit of power ratio, once past the threshold.
+const REFUSAL_CHANCE_PER_RATIO: f64 = 0.3;
+const REFUSAL_MAX_CHANCE: f64 = 0.8;
+/// Grievance the overlord takes against a tributary that stops paying.
+const REFUSAL_GRIEVANCE: f64 = 0.4;
+
+/// Military weight of a faction: its fighting-age men at home and in the
+/// field, scaled by prestige.
+fn faction_power(world: &World, faction_id: u64) -> f64 {
+    let men: u32 = world
+        .living(EntityKind::Settlement)
+        .filter(|e| e.has_active_rel(RelationshipKind::MemberOf, faction_id))
+        .filter_map(|e| e.data.as_settlement())
+        .map(|sd| sd.population_breakdown.able_bodied_men())
+        .sum();
+    let soldiers: u32 = world
+        .living(EntityKind::Army)
+        .filter_map(|e| e.data.as_army())
+        .filter(|ad| ad.faction_id == faction_id)
+        .map(|ad| ad.strength)
+        .sum();
+    let prestige = world
+        .entities
+        .get(&faction_id)
+        .and_then(|e| e.data.as_faction())
+        .map(|fd| fd.prestige)
+        .unwrap_or(0.0);
+    (men + soldiers) as f64 * (1.0 + prestige)
+}
+
+/// Chance a tributary with `ratio` times its overlord's power refuses this
+/// year's tribute.
+fn refusal_chance(ratio: f64) -> f64 {
+    if ratio < REFUSAL_POWER_RATIO {
+        return 0.0;
+    }
+    (REFUSAL_CHANCE_PER_RATIO * ratio).min(REFUSAL_MAX_CHANCE)
+}
+
+/// Tributaries that have outgrown their overlords may repudiate their terms.
+///
+/// A refusal ends the tribute and the treaty behind it, and leaves the
+/// overlord aggrieved and hostile, so the conflicts system will likely see it
+/// march to re-impose terms. Weaker tributaries keep paying.
+pub(super) fn refuse_tributes(ctx: &mut TickContext) {
+    let time = ctx.world.current_time;
+
+    let obligations: Vec<(u64, u64)> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .filter_map(|e| e.data.as_faction().map(|fd| (e.id, fd)))
+        .flat_map(|(payer_id, fd)| {
+            fd.tributes
+                .iter()
+                .filter(|(_, trib)| trib.years_remaining > 0)
+                .map(move |(&payee_id, _)| (payer_id, payee_id))
+        })
+        .collect();
+
+    for (payer_id, payee_id) in obligations {
+        let overlord_alive = ctx
+            .world
+            .entities
+            .get(&payee_id)
+            .is_some_and(|e| e.end.is_none());
+        if !overlord_alive {
+            continue;
+        }
+        let overlord_power = faction_power(ctx.world, payee_id);
+        let ratio = if overlord_power > 0.0 {
+            faction_power(ctx.world, payer_id) / overlord_power
+        } else {
+            f64::INFINITY
+        };
+        if !ctx.rng.random_bool(refusal_chance(ratio)) {
+            continue;
+        }
+
+        let payer_name = helpers::entity_name(ctx.world, payer_id);
+        let payee_name = helpers::entity_name(ctx.world, payee_id);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::TributeRefused { payer_id, payee_id },
+            time,
+            format!(
+                "{payer_name} refused to pay tribute to {payee_name} in year {}",
+                time.year()
+            ),
+        );
+        ctx.world
+            .add_event_participant(ev, payer_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, payee_id, ParticipantRole::Object);
+
+        ctx.world.faction_mut(payer_id).tributes.remove(&payee_id);
+        for (source, target, kind) in [
+            (payer_id, payee_id, RelationshipKind::tribute_to()),
+            (payer_id, payee_id, RelationshipKind::treaty_with()),
+            (payee_id, payer_id, RelationshipKind::treaty_with()),
+        ] {
+            let active = ctx
+                .world
+                .entities
+                .get(&source)
+                .is_some_and(|e| e.has_active_rel(kind.clone(), target));
+            if active {
+                ctx.world.end_relationship(source, target, kind, time, ev);
+            }
+        }
+
+        grv::add_grievance(
+            ctx.world,
+            payee_id,
+            payer_id,
+            REFUSAL_GRIEVANCE,
+            "tribute_refused",
+            time,
+            ev,
+        );
+        if !helpers::has_active_rel_of_kind(ctx.world, payee_id, payer_id, RelationshipKind::Enemy)
+        {
+            ctx.world
+                .add_relationship(payee_id, payer_id, RelationshipKind::Enemy, time, ev);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EventKind;
+    use crate::scenario::Scenario;
+    use crate::sim::EconomySystem;
+    use crate::testutil;
+
+    #[test]
+    fn weaker_tributaries_keep_paying() {
+        assert_eq!(refusal_chance(0.5), 0.0);
+        assert_eq!(refusal_chance(1.0), 0.0, "parity is not enough");
+        assert!(refusal_chance(1.5) > 0.0);
+        assert!(refusal_chance(2.0) > refusal_chance(1.5));
+        assert_eq!(refusal_chance(10.0), REFUSAL_MAX_CHANCE);
+    }
+
+    /// A tributary and its overlord side by side, with the given populations.
+    fn tribute_scenario(payer_pop: u32, payee_pop: u32) -> (Scenario, u64, u64) {
+        let mut s = Scenario::at_year(100);
+        let r = s.add_region("R");
+        let payer = s.faction("Tributary").treasury(500.0).id();
+        let payee = s.faction("Overlord").treasury(50.0).id();
+        s.settlement("TributaryTown", payer, r)
+            .population(payer_pop)
+            .id();
+        s.settlement("OverlordTown", payee, r)
+            .population(payee_pop)
+            .id();
+        s.add_tribute(payer, payee, 10.0, 100);
+        (s, payer, payee)
+    }
+
+    #[test]
+    fn scenario_strong_tributary_refuses_tribute() {
+        let (s, payer, payee) = tribute_scenario(2000, 200);
+        let world = s.run(&mut [Box::new(EconomySystem)], 3, 42);
+
+        assert_eq!(
+            testutil::count_events(&world, &EventKind::TributeRefused),
+            1
+        );
+        assert!(world.faction(payer).tributes.is_empty());
+        assert!(grv::get_grievance(&world, payee, payer) >= REFUSAL_GRIEVANCE);
+        assert!(helpers::has_active_rel_of_kind(
+            &world,
+            payee,
+            payer,
+            RelationshipKind::Enemy
+        ));
+    }
+
+    #[test]
+    fn scenario_weak_tributary_keeps_paying() {
+        let (s, payer, _) = tribute_scenario(200, 2000);
+        let world = s.run(&mut [Box::new(EconomySystem)], 5, 42);
+
+        assert_eq!(
+            testutil::count_events(&world, &EventKind::TributeRefused),
+            0
+        );
+        assert_eq!(world.faction(payer).tributes.len(), 1);
+    }
+}
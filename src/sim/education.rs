@@ -1,7 +1,7 @@
 use super::context::TickContext;
 use super::system::{SimSystem, TickFrequency};
 use crate::model::entity::EntityKind;
-use crate::model::entity_data::Role;
+use crate::model::entity_data::{ReligiousTenet, Role, Technology};
 use crate::model::relationship::RelationshipKind;
 use crate::sim::helpers;
 
@@ -19,6 +19,11 @@ const TEMPLE_LITERACY_BONUS: f64 = 0.05;
 const SCHOLAR_DENSITY_LITERACY_BONUS: f64 = 0.20;
 /// Bonus to literacy target if settlement's dominant culture has the Scholarly value.
 const SCHOLARLY_CULTURE_BONUS: f64 = 0.10;
+/// Bonus to literacy target once the settlement's faction knows how to write.
+const WRITING_LITERACY_BONUS: f64 = 0.10;
+/// Bonus to literacy target if the dominant religion keeps scribal traditions
+/// (the Knowledge tenet).
+const SCRIBAL_RELIGION_LITERACY_BONUS: f64 = 0.10;
 /// Rate at which settlement literacy converges to target per year (10%).
 const SETTLEMENT_LITERACY_DRIFT: f64 = 0.10;
 /// Rate at which person education converges to target per year (15%).
@@ -130,8 +135,30 @@ fn update_settlement_literacy(ctx: &mut TickContext) {
                 .map(|_| SCHOLARLY_CULTURE_BONUS)
                 .unwrap_or(0.0);
 
-            let mut target =
-                library_target + academy_target + temple_target + scholar_target + scholarly_bonus;
+            // Era: a script to learn in the first place
+            let writing_bonus = helpers::settlement_faction(ctx.world, sid)
+                .and_then(|fid| ctx.world.entities.get(&fid))
+                .and_then(|fe| fe.data.as_faction())
+                .filter(|fd| fd.technologies.contains(&Technology::Writing))
+                .map(|_| WRITING_LITERACY_BONUS)
+                .unwrap_or(0.0);
+
+            // Scribal religious tradition
+            let scribal_bonus = sd
+                .dominant_religion
+                .and_then(|rid| ctx.world.entities.get(&rid))
+                .and_then(|re| re.data.as_religion())
+                .filter(|rd| rd.tenets.contains(&ReligiousTenet::Knowledge))
+                .map(|_| SCRIBAL_RELIGION_LITERACY_BONUS)
+                .unwrap_or(0.0);
+
+            let mut target = library_target
+                + academy_target
+                + temple_target
+                + scholar_target
+                + scholarly_bonus
+                + writing_bonus
+                + scribal_bonus;
 
             // Scale by prosperity
             target *= 0.5 + 0.5 * sd.prosperity;
@@ -481,4 +508,39 @@ mod tests {
             "high prosperity should lead to higher literacy: rich={lit_rich}, poor={lit_poor}"
         );
     }
+
+    #[test]
+    fn writing_and_scribal_religion_raise_literacy() {
+        fn literacy_after(scribal: bool) -> f64 {
+            let mut s = Scenario::at_year(100);
+            let setup = s.add_settlement_standalone("Town");
+            let _ = s
+                .settlement_mut(setup.settlement)
+                .population(500)
+                .prosperity(0.8);
+            s.add_building(BuildingType::Library, setup.settlement);
+            if scribal {
+                let religion = s.add_religion_with("Lettered Way", |rd| {
+                    rd.tenets = vec![ReligiousTenet::Knowledge]
+                });
+                s.modify_faction(setup.faction, |fd| {
+                    fd.technologies.insert(Technology::Writing);
+                });
+                s.modify_settlement(setup.settlement, |sd| sd.dominant_religion = Some(religion));
+            }
+            let mut world = s.build();
+            for year in 100..120 {
+                testutil::tick_system(&mut world, &mut crate::sim::BuildingSystem, year, 42);
+                testutil::tick_system(&mut world, &mut EducationSystem, year, 42);
+            }
+            world.settlement(setup.settlement).literacy_rate
+        }
+
+        let plain = literacy_after(false);
+        let scribal = literacy_after(true);
+        assert!(
+            scribal > plain,
+            "writing and a scribal faith should raise literacy: plain={plain}, scribal={scribal}"
+        );
+    }
 }
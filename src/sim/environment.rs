@@ -1,11 +1,16 @@
+use std::collections::BTreeSet;
+
 use rand::Rng;
 
 use super::context::TickContext;
+use super::helpers;
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
 use crate::model::entity_data::{ActiveDisaster, DisasterType};
-use crate::model::{EntityData, EntityKind, EventKind, RelationshipKind, SimTimestamp};
-use crate::worldgen::terrain::{Terrain, TerrainTag};
+use crate::model::{
+    EntityData, EntityKind, EventKind, RelationshipKind, SimTimestamp, Technology, World,
+};
+use crate::worldgen::terrain::{Climate, Terrain, TerrainTag};
 
 // ---------------------------------------------------------------------------
 // Season
@@ -40,29 +45,6 @@ impl Season {
     }
 }
 
-// ---------------------------------------------------------------------------
-// Climate zone (derived from y-coordinate)
-// ---------------------------------------------------------------------------
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ClimateZone {
-    Tropical,
-    Temperate,
-    Boreal,
-}
-
-/// Map y-coordinate (0–1000) to climate zone.
-/// Low y = tropical, mid = temperate, high y = boreal.
-fn climate_zone_from_y(y: f64) -> ClimateZone {
-    if y < 300.0 {
-        ClimateZone::Tropical
-    } else if y < 700.0 {
-        ClimateZone::Temperate
-    } else {
-        ClimateZone::Boreal
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Seasonal modifiers
 // ---------------------------------------------------------------------------
@@ -75,31 +57,35 @@ struct SeasonalModifiers {
     army: f64,
 }
 
-fn compute_modifiers(season: Season, climate: ClimateZone, terrain: Terrain) -> SeasonalModifiers {
+fn compute_modifiers(season: Season, climate: Climate, terrain: Terrain) -> SeasonalModifiers {
     let (base_food, base_trade, base_disease, base_army) = match (season, climate) {
         // -- Tropical: mild seasons, muted variation --
-        (Season::Spring, ClimateZone::Tropical) => (0.9, 1.0, 0.9, 1.0),
-        (Season::Summer, ClimateZone::Tropical) => (1.0, 1.1, 1.3, 0.9),
-        (Season::Autumn, ClimateZone::Tropical) => (1.1, 1.0, 1.0, 1.0),
-        (Season::Winter, ClimateZone::Tropical) => (0.9, 1.0, 0.8, 1.0),
+        (Season::Spring, Climate::Tropical) => (0.9, 1.0, 0.9, 1.0),
+        (Season::Summer, Climate::Tropical) => (1.0, 1.1, 1.3, 0.9),
+        (Season::Autumn, Climate::Tropical) => (1.1, 1.0, 1.0, 1.0),
+        (Season::Winter, Climate::Tropical) => (0.9, 1.0, 0.8, 1.0),
+
+        // -- Arid: scorching summers, growth after the cool-season rains --
+        (Season::Spring, Climate::Arid) => (1.1, 1.0, 0.8, 1.0),
+        (Season::Summer, Climate::Arid) => (0.6, 0.8, 1.0, 0.7),
+        (Season::Autumn, Climate::Arid) => (0.9, 1.0, 0.8, 1.0),
+        (Season::Winter, Climate::Arid) => (1.0, 1.1, 0.7, 1.0),
 
         // -- Temperate: clear seasonal cycle --
-        (Season::Spring, ClimateZone::Temperate) => (0.8, 1.0, 0.8, 1.0),
-        (Season::Summer, ClimateZone::Temperate) => (1.0, 1.1, 1.2, 0.9),
-        (Season::Autumn, ClimateZone::Temperate) => (1.3, 1.0, 0.9, 1.0),
-        (Season::Winter, ClimateZone::Temperate) => (0.4, 0.6, 0.7, 0.6),
+        (Season::Spring, Climate::Temperate) => (0.8, 1.0, 0.8, 1.0),
+        (Season::Summer, Climate::Temperate) => (1.0, 1.1, 1.2, 0.9),
+        (Season::Autumn, Climate::Temperate) => (1.3, 1.0, 0.9, 1.0),
+        (Season::Winter, Climate::Temperate) => (0.4, 0.6, 0.7, 0.6),
 
         // -- Boreal: harsh winters --
-        (Season::Spring, ClimateZone::Boreal) => (0.6, 0.8, 0.7, 0.8),
-        (Season::Summer, ClimateZone::Boreal) => (1.0, 1.0, 1.0, 1.0),
-        (Season::Autumn, ClimateZone::Boreal) => (1.2, 0.9, 0.8, 0.9),
-        (Season::Winter, ClimateZone::Boreal) => (0.2, 0.3, 0.6, 0.4),
+        (Season::Spring, Climate::Boreal) => (0.6, 0.8, 0.7, 0.8),
+        (Season::Summer, Climate::Boreal) => (1.0, 1.0, 1.0, 1.0),
+        (Season::Autumn, Climate::Boreal) => (1.2, 0.9, 0.8, 0.9),
+        (Season::Winter, Climate::Boreal) => (0.2, 0.3, 0.6, 0.4),
     };
 
-    // Terrain adjustments
+    // Terrain adjustments (climate-wide food and disease levels live on `Climate`)
     let terrain_food_mult = match terrain {
-        Terrain::Desert => 0.7,
-        Terrain::Tundra => 0.6,
         Terrain::Swamp => 0.8,
         _ => 1.0,
     };
@@ -110,14 +96,13 @@ fn compute_modifiers(season: Season, climate: ClimateZone, terrain: Terrain) ->
         _ => 1.0,
     };
     let terrain_disease_mult = match terrain {
-        Terrain::Swamp | Terrain::Jungle => 1.3,
-        Terrain::Tundra | Terrain::Desert => 0.7,
+        Terrain::Swamp => 1.3,
         _ => 1.0,
     };
 
     let construction_blocked = match (season, climate) {
-        (Season::Winter, ClimateZone::Boreal) => true,
-        (Season::Winter, ClimateZone::Temperate)
+        (Season::Winter, Climate::Boreal) => true,
+        (Season::Winter, Climate::Temperate)
             if terrain == Terrain::Mountains || terrain == Terrain::Tundra =>
         {
             true
@@ -143,7 +128,7 @@ struct SettlementInfo {
     region_id: u64,
     terrain: Terrain,
     terrain_tags: Vec<TerrainTag>,
-    region_y: f64,
+    climate: Climate,
     population: u32,
     has_active_disaster: bool,
 }
@@ -163,18 +148,18 @@ fn gather_settlement_info(world: &crate::model::World) -> Vec<SettlementInfo> {
         // Find region via LocatedIn relationship
         let region_id = entity.active_rel(RelationshipKind::LocatedIn);
 
-        let (terrain, terrain_tags, region_y) = if let Some(rid) = region_id {
+        let (terrain, terrain_tags, climate) = if let Some(rid) = region_id {
             if let Some(region) = world.entities.get(&rid) {
                 if let Some(rd) = region.data.as_region() {
-                    (rd.terrain, rd.terrain_tags.clone(), rd.y)
+                    (rd.terrain, rd.terrain_tags.clone(), rd.climate)
                 } else {
-                    (Terrain::Plains, vec![], 500.0)
+                    (Terrain::Plains, vec![], Climate::Temperate)
                 }
             } else {
-                (Terrain::Plains, vec![], 500.0)
+                (Terrain::Plains, vec![], Climate::Temperate)
             }
         } else {
-            (Terrain::Plains, vec![], 500.0)
+            (Terrain::Plains, vec![], Climate::Temperate)
         };
 
         infos.push(SettlementInfo {
@@ -182,7 +167,7 @@ fn gather_settlement_info(world: &crate::model::World) -> Vec<SettlementInfo> {
             region_id: region_id.unwrap_or(0),
             terrain,
             terrain_tags,
-            region_y,
+            climate,
             population: sd.population,
             has_active_disaster: sd.active_disaster.is_some(),
         });
@@ -190,6 +175,77 @@ fn gather_settlement_info(world: &crate::model::World) -> Vec<SettlementInfo> {
     infos
 }
 
+// ---------------------------------------------------------------------------
+// Disaster preparedness
+// ---------------------------------------------------------------------------
+
+/// Share of disaster losses avoided by a fully prepared settlement.
+const PREPAREDNESS_MAX_MITIGATION: f64 = 0.6;
+/// Reserves per settlement (its own treasury plus its share of the faction's)
+/// that count as fully stocked.
+const PREPAREDNESS_FULL_RESERVES: f64 = 100.0;
+const PREPAREDNESS_RESERVES_WEIGHT: f64 = 0.4;
+const PREPAREDNESS_GRANARY_WEIGHT: f64 = 0.3;
+const PREPAREDNESS_KNOWLEDGE_WEIGHT: f64 = 0.3;
+/// Know-how that helps a settlement weather disasters: sound building,
+/// water management and care for the injured.
+const PREPAREDNESS_TECHNOLOGIES: &[Technology] = &[
+    Technology::Masonry,
+    Technology::Irrigation,
+    Technology::Medicine,
+];
+
+/// Fraction of a disaster's population and prosperity losses a settlement
+/// avoids, from its reserves, its granaries' food buffer and what its faction
+/// knows. Ranges from 0.0 to [`PREPAREDNESS_MAX_MITIGATION`].
+pub fn disaster_mitigation(
+    reserves: f64,
+    food_buffer: f64,
+    technologies: &BTreeSet<Technology>,
+) -> f64 {
+    let reserves = (reserves / PREPAREDNESS_FULL_RESERVES).clamp(0.0, 1.0);
+    let granary = food_buffer.clamp(0.0, 1.0);
+    let known = PREPAREDNESS_TECHNOLOGIES
+        .iter()
+        .filter(|t| technologies.contains(t))
+        .count() as f64
+        / PREPAREDNESS_TECHNOLOGIES.len() as f64;
+    let preparedness = reserves * PREPAREDNESS_RESERVES_WEIGHT
+        + granary * PREPAREDNESS_GRANARY_WEIGHT
+        + known * PREPAREDNESS_KNOWLEDGE_WEIGHT;
+    preparedness * PREPAREDNESS_MAX_MITIGATION
+}
+
+/// [`disaster_mitigation`] for a settlement as it stands in `world`.
+fn settlement_disaster_mitigation(world: &World, settlement_id: u64) -> f64 {
+    let Some(sd) = world
+        .entities
+        .get(&settlement_id)
+        .and_then(|e| e.data.as_settlement())
+    else {
+        return 0.0;
+    };
+    let faction = helpers::settlement_faction(world, settlement_id)
+        .and_then(|fid| Some((fid, world.entities.get(&fid)?.data.as_faction()?)));
+    let (faction_share, technologies) = match faction {
+        Some((fid, fd)) => {
+            let holdings = helpers::faction_settlements(world, fid).len().max(1);
+            (
+                fd.treasury.max(0.0) / holdings as f64,
+                Some(&fd.technologies),
+            )
+        }
+        None => (0.0, None),
+    };
+    let reserves = sd.treasury.max(0.0) + faction_share;
+    let none = BTreeSet::new();
+    disaster_mitigation(
+        reserves,
+        sd.building_bonuses.food_buffer,
+        technologies.unwrap_or(&none),
+    )
+}
+
 // ---------------------------------------------------------------------------
 // EnvironmentSystem
 // ---------------------------------------------------------------------------
@@ -224,8 +280,7 @@ impl SimSystem for EnvironmentSystem {
 
         // Phase 1: Compute and store seasonal modifiers
         for info in &infos {
-            let climate = climate_zone_from_y(info.region_y);
-            let mods = compute_modifiers(season, climate, info.terrain);
+            let mods = compute_modifiers(season, info.climate, info.terrain);
 
             let sd = ctx.world.settlement_mut(info.id);
             sd.seasonal.food = mods.food;
@@ -238,7 +293,7 @@ impl SimSystem for EnvironmentSystem {
         // Also compute construction_months at year start for yearly systems
         if month == 1 {
             for info in &infos {
-                let climate = climate_zone_from_y(info.region_y);
+                let climate = info.climate;
                 let construction_months: u32 = (1..=12)
                     .filter(|&m| {
                         let s = Season::from_month(m);
@@ -441,8 +496,10 @@ fn apply_instant_disaster(
             role: crate::model::ParticipantRole::Object,
         });
 
-    // Population loss
-    let loss_frac = def.pop_loss_range.0 + severity * (def.pop_loss_range.1 - def.pop_loss_range.0);
+    // Population loss, softened by preparedness
+    let spared = 1.0 - settlement_disaster_mitigation(ctx.world, info.id);
+    let loss_frac =
+        (def.pop_loss_range.0 + severity * (def.pop_loss_range.1 - def.pop_loss_range.0)) * spared;
     let mut old_pop = 0u32;
     let mut new_pop = 0u32;
     let mut old_prosperity = 0.0;
@@ -458,7 +515,7 @@ fn apply_instant_disaster(
 
         // Prosperity hit
         old_prosperity = sd.prosperity;
-        sd.prosperity = (sd.prosperity - def.prosperity_hit * severity).max(0.0);
+        sd.prosperity = (sd.prosperity - def.prosperity_hit * severity * spared).max(0.0);
         new_prosperity = sd.prosperity;
     }
     if old_pop != new_pop {
@@ -743,7 +800,8 @@ fn progress_active_disasters(ctx: &mut TickContext, time: SimTimestamp, tick_eve
             continue;
         }
 
-        // Apply monthly effects
+        // Apply monthly effects, softened by preparedness
+        let spared = 1.0 - settlement_disaster_mitigation(ctx.world, sid);
         let (pop_loss_frac, building_damage) = match disaster_type {
             DisasterType::Drought => (0.005 + severity * 0.015, 0.0),
             DisasterType::Flood => (0.01 + severity * 0.02, 0.1),
@@ -751,7 +809,7 @@ fn progress_active_disasters(ctx: &mut TickContext, time: SimTimestamp, tick_eve
             _ => (0.0, 0.0),
         };
 
-        let deaths = (population as f64 * pop_loss_frac) as u32;
+        let deaths = (population as f64 * pop_loss_frac * spared) as u32;
 
         // Apply damage
         let mut old_prosperity = 0.0;
@@ -763,12 +821,13 @@ fn progress_active_disasters(ctx: &mut TickContext, time: SimTimestamp, tick_eve
             sd.population_breakdown.scale_to(sd.population);
 
             // Prosperity erosion
-            let prosperity_hit = match disaster_type {
-                DisasterType::Drought => 0.02 * severity,
-                DisasterType::Flood => 0.03 * severity,
-                DisasterType::Wildfire => 0.03 * severity,
-                _ => 0.0,
-            };
+            let prosperity_hit = spared
+                * match disaster_type {
+                    DisasterType::Drought => 0.02 * severity,
+                    DisasterType::Flood => 0.03 * severity,
+                    DisasterType::Wildfire => 0.03 * severity,
+                    _ => 0.0,
+                };
             old_prosperity = sd.prosperity;
             sd.prosperity = (sd.prosperity - prosperity_hit).max(0.0);
             new_prosperity = sd.prosperity;
@@ -952,21 +1011,61 @@ mod tests {
     }
 
     #[test]
-    fn climate_zone_boundaries() {
-        assert_eq!(climate_zone_from_y(0.0), ClimateZone::Tropical);
-        assert_eq!(climate_zone_from_y(299.0), ClimateZone::Tropical);
-        assert_eq!(climate_zone_from_y(300.0), ClimateZone::Temperate);
-        assert_eq!(climate_zone_from_y(699.0), ClimateZone::Temperate);
-        assert_eq!(climate_zone_from_y(700.0), ClimateZone::Boreal);
-        assert_eq!(climate_zone_from_y(1000.0), ClimateZone::Boreal);
+    fn climate_boundaries() {
+        let plains = |lat| Climate::from_latitude(lat, Terrain::Plains);
+        assert_eq!(plains(0.0), Climate::Tropical);
+        assert_eq!(plains(0.299), Climate::Tropical);
+        assert_eq!(plains(0.3), Climate::Temperate);
+        assert_eq!(plains(0.699), Climate::Temperate);
+        assert_eq!(plains(0.7), Climate::Boreal);
+        assert_eq!(plains(1.0), Climate::Boreal);
+        assert_eq!(Climate::from_latitude(0.9, Terrain::Desert), Climate::Arid);
+        assert_eq!(
+            Climate::from_latitude(0.1, Terrain::Tundra),
+            Climate::Boreal
+        );
+        assert_eq!(
+            Climate::from_latitude(0.5, Terrain::Jungle),
+            Climate::Tropical
+        );
+    }
+
+    #[test]
+    fn tropical_food_curve_flatter_than_temperate() {
+        let food = |climate| -> Vec<f64> {
+            [
+                Season::Spring,
+                Season::Summer,
+                Season::Autumn,
+                Season::Winter,
+            ]
+            .into_iter()
+            .map(|s| compute_modifiers(s, climate, Terrain::Plains).food)
+            .collect()
+        };
+        let spread = |curve: &[f64]| {
+            curve.iter().cloned().fold(f64::MIN, f64::max)
+                - curve.iter().cloned().fold(f64::MAX, f64::min)
+        };
+        let tropical = food(Climate::Tropical);
+        let temperate = food(Climate::Temperate);
+        assert_ne!(tropical, temperate);
+        assert!(
+            spread(&tropical) < spread(&temperate),
+            "tropical {tropical:?} should vary less than temperate {temperate:?}"
+        );
+        assert!(
+            tropical[3] > temperate[3],
+            "tropical winters still yield food"
+        );
     }
 
     #[test]
     fn winter_food_lower_than_autumn() {
         let temperate_winter =
-            compute_modifiers(Season::Winter, ClimateZone::Temperate, Terrain::Plains);
+            compute_modifiers(Season::Winter, Climate::Temperate, Terrain::Plains);
         let temperate_autumn =
-            compute_modifiers(Season::Autumn, ClimateZone::Temperate, Terrain::Plains);
+            compute_modifiers(Season::Autumn, Climate::Temperate, Terrain::Plains);
         assert!(
             temperate_winter.food < temperate_autumn.food,
             "winter food {} should be < autumn food {}",
@@ -977,11 +1076,10 @@ mod tests {
 
     #[test]
     fn boreal_winter_harshest() {
-        let boreal_winter = compute_modifiers(Season::Winter, ClimateZone::Boreal, Terrain::Plains);
+        let boreal_winter = compute_modifiers(Season::Winter, Climate::Boreal, Terrain::Plains);
         let temperate_winter =
-            compute_modifiers(Season::Winter, ClimateZone::Temperate, Terrain::Plains);
-        let tropical_winter =
-            compute_modifiers(Season::Winter, ClimateZone::Tropical, Terrain::Plains);
+            compute_modifiers(Season::Winter, Climate::Temperate, Terrain::Plains);
+        let tropical_winter = compute_modifiers(Season::Winter, Climate::Tropical, Terrain::Plains);
         assert!(boreal_winter.food < temperate_winter.food);
         assert!(temperate_winter.food < tropical_winter.food);
         assert!(boreal_winter.construction_blocked);
@@ -1040,4 +1138,52 @@ mod tests {
         );
         testutil::assert_property_changed(&world, setup.settlement, "prosperity");
     }
+
+    #[test]
+    fn mitigation_grows_with_reserves_granaries_and_knowledge() {
+        let none = BTreeSet::new();
+        let all: BTreeSet<Technology> = Technology::ALL.into_iter().collect();
+        assert_eq!(disaster_mitigation(0.0, 0.0, &none), 0.0);
+        assert!(disaster_mitigation(50.0, 0.0, &none) > 0.0);
+        assert!(disaster_mitigation(50.0, 1.0, &none) > disaster_mitigation(50.0, 0.0, &none));
+        assert!((disaster_mitigation(1e9, 5.0, &all) - PREPAREDNESS_MAX_MITIGATION).abs() < 1e-9);
+    }
+
+    #[test]
+    fn scenario_prepared_settlement_weathers_drought_better() {
+        use crate::scenario::Scenario;
+
+        let survivors = |prepared: bool| {
+            let mut s = Scenario::at_year(100);
+            let setup = s.add_settlement_standalone_with(
+                "Town",
+                |fd| {
+                    if prepared {
+                        fd.treasury = 1000.0;
+                        fd.technologies = Technology::ALL.into_iter().collect();
+                    }
+                },
+                |sd| {
+                    sd.prosperity = 0.8;
+                    sd.population = 1000;
+                    sd.population_breakdown = crate::model::PopulationBreakdown::from_total(1000);
+                    if prepared {
+                        sd.building_bonuses.food_buffer = 1.0;
+                    }
+                },
+            );
+            s.add_active_disaster(setup.settlement, DisasterType::Drought, 0.8);
+            let world = s.run(&mut [Box::new(EnvironmentSystem)], 1, 42);
+            let sd = world.settlement(setup.settlement);
+            (sd.population, sd.prosperity)
+        };
+
+        let (poor_pop, poor_prosperity) = survivors(false);
+        let (rich_pop, rich_prosperity) = survivors(true);
+        assert!(
+            rich_pop > poor_pop,
+            "prepared town should lose fewer people ({rich_pop} vs {poor_pop})"
+        );
+        assert!(rich_prosperity > poor_prosperity);
+    }
 }
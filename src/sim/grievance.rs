@@ -10,6 +10,14 @@ use crate::model::traits::Trait;
 /// Maximum number of source tags stored per grievance entry.
 const MAX_SOURCES: usize = 5;
 
+// --- Blood feuds ---
+/// Share of the normal yearly decay a blood feud loses.
+const BLOOD_FEUD_DECAY_FACTOR: f64 = 0.1;
+/// Severity a blood feud won't decay below while the atrocity is remembered.
+pub const BLOOD_FEUD_FLOOR: f64 = 0.3;
+/// Years an atrocity holds a blood feud at its floor: several generations.
+pub const BLOOD_FEUD_MEMORY_YEARS: u32 = 150;
+
 /// Read the grievance severity that `holder` has against `target` (0.0 if none).
 pub fn get_grievance(world: &World, holder: u64, target: u64) -> f64 {
     let Some(entity) = world.entities.get(&holder) else {
@@ -59,6 +67,7 @@ pub fn add_grievance(
         sources: Vec::new(),
         peak: 0.0,
         updated: time,
+        atrocity_at: None,
     });
 
     entry.severity = (entry.severity + delta).min(1.0);
@@ -85,8 +94,57 @@ pub fn add_grievance(
     );
 }
 
+/// Add a grievance for an atrocity (a sacked capital, executed captives, a
+/// broken treaty), turning it into a long-memory blood feud.
+pub fn add_atrocity_grievance(
+    world: &mut World,
+    holder: u64,
+    target: u64,
+    delta: f64,
+    source: &str,
+    time: SimTimestamp,
+    event_id: u64,
+) {
+    add_grievance(world, holder, target, delta, source, time, event_id);
+    let Some(entity) = world.entities.get_mut(&holder) else {
+        return;
+    };
+    let grievances = if let Some(fd) = entity.data.as_faction_mut() {
+        &mut fd.grievances
+    } else if let Some(pd) = entity.data.as_person_mut() {
+        &mut pd.grievances
+    } else {
+        return;
+    };
+    if let Some(g) = grievances.get_mut(&target) {
+        g.atrocity_at = Some(time);
+    }
+}
+
+/// Lowest severity a grievance can fall to at `now`: a remembered atrocity
+/// keeps a blood feud alive, anything else may fade entirely.
+pub fn grievance_floor(g: &Grievance, now: SimTimestamp) -> f64 {
+    match g.atrocity_at {
+        Some(at) if now.years_since(at) < BLOOD_FEUD_MEMORY_YEARS => BLOOD_FEUD_FLOOR.min(g.peak),
+        _ => 0.0,
+    }
+}
+
+/// Severity after one year of decay at `base_decay`. Blood feuds fade at a
+/// fraction of the rate and never below their floor.
+pub fn decayed_severity(g: &Grievance, base_decay: f64, now: SimTimestamp) -> f64 {
+    if g.is_blood_feud() {
+        (g.severity - base_decay * BLOOD_FEUD_DECAY_FACTOR)
+            .max(grievance_floor(g, now).min(g.severity))
+    } else {
+        g.severity - base_decay
+    }
+}
+
 /// Reduce a grievance by `delta`. Removes the entry entirely if severity drops below `threshold`.
+/// A blood feud is never satisfied below its floor.
 pub fn reduce_grievance(world: &mut World, holder: u64, target: u64, delta: f64, threshold: f64) {
+    let now = world.current_time;
     let entity = world.entities.get_mut(&holder);
     let Some(entity) = entity else { return };
 
@@ -99,7 +157,8 @@ pub fn reduce_grievance(world: &mut World, holder: u64, target: u64, delta: f64,
     };
 
     if let Some(g) = grievances.get_mut(&target) {
-        g.severity = (g.severity - delta).max(0.0);
+        let floor = grievance_floor(g, now).min(g.severity);
+        g.severity = (g.severity - delta).max(floor);
         if g.severity < threshold {
             grievances.remove(&target);
         }
@@ -1,13 +1,19 @@
 use std::collections::{BTreeSet, VecDeque};
 
-use crate::model::World;
 use crate::model::entity::{Entity, EntityKind};
-use crate::model::entity_data::{GovernmentType, ResourceType};
+use crate::model::entity_data::{
+    EntityData, FactionData, GovernmentType, MonumentData, POLICY_NEUTRAL, ResourceType,
+};
 use crate::model::relationship::RelationshipKind;
 use crate::model::timestamp::SimTimestamp;
+use crate::model::{Effect, FactionField, World};
 
 use super::signal::{Signal, SignalKind};
 
+/// Neighbouring regions a faction must hold before its control reaches an
+/// empty region between them.
+const REGION_CONTROL_MIN_NEIGHBORS: usize = 2;
+
 /// Find all region IDs adjacent to the given region via active AdjacentTo relationships.
 pub fn adjacent_regions(world: &World, region_id: u64) -> Vec<u64> {
     world
@@ -104,6 +110,21 @@ pub fn end_all_person_relationships(
         .unwrap_or_default();
 
     for (target_id, kind) in rels {
+        // A surviving spouse's side of the marriage ends too
+        if kind == RelationshipKind::Spouse
+            && world
+                .entities
+                .get(&target_id)
+                .is_some_and(|e| e.has_active_rel(RelationshipKind::Spouse, person_id))
+        {
+            world.end_relationship(
+                target_id,
+                person_id,
+                RelationshipKind::Spouse,
+                time,
+                event_id,
+            );
+        }
         world.end_relationship(person_id, target_id, kind, time, event_id);
     }
 }
@@ -119,6 +140,19 @@ pub fn has_active_rel_of_kind(world: &World, a: u64, b: u64, kind: RelationshipK
     check(a, b) || check(b, a)
 }
 
+/// Check whether an unexpired ceasefire between two factions is in force at `year`.
+pub fn in_ceasefire(world: &World, a: u64, b: u64, year: u32) -> bool {
+    let check = |source: u64, target: u64| -> bool {
+        world
+            .entities
+            .get(&source)
+            .and_then(|e| e.data.as_faction())
+            .and_then(|fd| fd.ceasefire_until.get(&target))
+            .is_some_and(|&until| year < until)
+    };
+    check(a, b) || check(b, a)
+}
+
 /// End an Ally relationship in both directions between two entities.
 pub fn end_ally_relationship(world: &mut World, a: u64, b: u64, time: SimTimestamp, event_id: u64) {
     for (src, dst) in [(a, b), (b, a)] {
@@ -162,6 +196,22 @@ pub fn faction_legitimacy(world: &World, faction_id: u64) -> f64 {
         .unwrap_or(0.5)
 }
 
+/// Multiplier from one of a faction's policy axes: `weight` is the change per
+/// point above neutral, so a neutral (or missing) faction yields 1.0.
+pub(crate) fn policy_factor(
+    world: &World,
+    faction_id: u64,
+    axis: impl Fn(&FactionData) -> f64,
+    weight: f64,
+) -> f64 {
+    let value = world
+        .entities
+        .get(&faction_id)
+        .and_then(|e| e.data.as_faction())
+        .map_or(POLICY_NEUTRAL, axis);
+    (1.0 + (value - POLICY_NEUTRAL) * weight).max(0.0)
+}
+
 /// BFS to find the next step from `start` toward `goal` over region adjacency.
 /// Returns the first region to move to, or None if already at goal or unreachable.
 pub fn bfs_next_step(world: &World, start: u64, goal: u64) -> Option<u64> {
@@ -221,6 +271,10 @@ pub fn bfs_nearest(world: &World, start: u64, predicate: impl Fn(u64) -> bool) -
 /// BFS from `start` to find the nearest region matching a predicate, with naval
 /// awareness. When `can_embark` is true, water regions are traversable with port
 /// constraints.
+#[allow(
+    dead_code,
+    reason = "scan-based reference for RegionGraph::nearest_naval; only tests call it"
+)]
 pub(crate) fn bfs_nearest_naval(
     world: &World,
     start: u64,
@@ -292,24 +346,16 @@ pub(crate) fn is_food_resource(resource: &ResourceType) -> bool {
 
 /// Apply a stability delta to a faction with full audit trail (records change).
 pub(crate) fn apply_stability_delta(world: &mut World, faction_id: u64, delta: f64, event_id: u64) {
-    let (old, new) = {
-        let Some(entity) = world.entities.get_mut(&faction_id) else {
-            return;
-        };
-        let Some(fd) = entity.data.as_faction_mut() else {
-            return;
-        };
-        let old = fd.stability;
-        fd.stability = (old + delta).clamp(0.0, 1.0);
-        (old, fd.stability)
-    };
-    world.record_change(
-        faction_id,
-        event_id,
-        "stability",
-        serde_json::json!(old),
-        serde_json::json!(new),
-    );
+    if world
+        .entities
+        .get(&faction_id)
+        .is_some_and(|e| e.data.as_faction().is_some())
+    {
+        world.apply_effect(
+            Effect::unit_delta(faction_id, FactionField::Stability, delta),
+            event_id,
+        );
+    }
 }
 
 /// Find the "capital" settlement of a faction by oldest ID (min entity ID).
@@ -404,10 +450,9 @@ pub(crate) fn faction_resource_set(world: &World, faction_id: u64) -> BTreeSet<R
 /// Collect all region IDs that contain settlements of a faction.
 pub(crate) fn collect_faction_region_ids(world: &World, faction_id: u64) -> Vec<u64> {
     let mut seen = BTreeSet::new();
-    for e in world.entities.values() {
+    for e in world.active_sources(RelationshipKind::MemberOf, faction_id) {
         if e.kind == EntityKind::Settlement
             && e.end.is_none()
-            && e.has_active_rel(RelationshipKind::MemberOf, faction_id)
             && let Some(region_id) = e.active_rel(RelationshipKind::LocatedIn)
         {
             seen.insert(region_id);
@@ -416,10 +461,62 @@ pub(crate) fn collect_faction_region_ids(world: &World, faction_id: u64) -> Vec<
     seen.into_iter().collect()
 }
 
-/// Check if two factions have settlements in adjacent (or same) regions.
+/// Factions owning living settlements in a region.
+fn region_settlement_owners(world: &World, region_id: u64) -> BTreeSet<u64> {
+    world
+        .active_sources(RelationshipKind::LocatedIn, region_id)
+        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+        .filter_map(|e| e.active_rel(RelationshipKind::MemberOf))
+        .collect()
+}
+
+/// The faction controlling a region, if any.
+///
+/// A region with settlements belongs to their owner. An empty region falls to
+/// the one faction holding settlements in at least
+/// [`REGION_CONTROL_MIN_NEIGHBORS`] of its neighbours. Regions split between
+/// factions are contested and controlled by no one.
+pub fn region_controller(world: &World, region_id: u64) -> Option<u64> {
+    let owners = region_settlement_owners(world, region_id);
+    if !owners.is_empty() {
+        return (owners.len() == 1).then(|| *owners.first().unwrap());
+    }
+
+    let mut neighbor_owners = BTreeSet::new();
+    let mut held_neighbors = 0;
+    for adj in adjacent_regions(world, region_id) {
+        let owners = region_settlement_owners(world, adj);
+        if !owners.is_empty() {
+            held_neighbors += 1;
+            neighbor_owners.extend(owners);
+        }
+    }
+    (neighbor_owners.len() == 1 && held_neighbors >= REGION_CONTROL_MIN_NEIGHBORS)
+        .then(|| *neighbor_owners.first().unwrap())
+}
+
+/// Collect all region IDs a faction controls: those holding its settlements
+/// plus the empty land it commands between them.
+pub(crate) fn collect_faction_controlled_region_ids(world: &World, faction_id: u64) -> Vec<u64> {
+    let mut seen: BTreeSet<u64> = collect_faction_region_ids(world, faction_id)
+        .into_iter()
+        .collect();
+    let frontier: Vec<u64> = seen
+        .iter()
+        .flat_map(|&r| adjacent_regions(world, r))
+        .collect();
+    for region_id in frontier {
+        if region_controller(world, region_id) == Some(faction_id) {
+            seen.insert(region_id);
+        }
+    }
+    seen.into_iter().collect()
+}
+
+/// Check if two factions control adjacent (or the same) regions.
 pub(crate) fn factions_are_adjacent(world: &World, a: u64, b: u64) -> bool {
-    let regions_a = collect_faction_region_ids(world, a);
-    let regions_b = collect_faction_region_ids(world, b);
+    let regions_a = collect_faction_controlled_region_ids(world, a);
+    let regions_b = collect_faction_controlled_region_ids(world, b);
 
     for &ra in &regions_a {
         for &rb in &regions_b {
@@ -438,6 +535,32 @@ pub(crate) fn factions_are_adjacent(world: &World, a: u64, b: u64) -> bool {
     false
 }
 
+/// Whether two factions have made contact and so may deal with each other.
+pub fn have_contact(world: &World, a: u64, b: u64) -> bool {
+    has_active_rel_of_kind(world, a, b, RelationshipKind::KnownTo)
+}
+
+/// Whether two factions can reach each other: over bordering territory, along
+/// a trade route between them, or by sea when both keep a port.
+pub(crate) fn factions_can_reach(world: &World, a: u64, b: u64) -> bool {
+    let trades_with = |from: u64, to: u64| {
+        world
+            .entities
+            .get(&from)
+            .and_then(|e| e.data.as_faction())
+            .is_some_and(|fd| fd.trade_partner_routes.contains_key(&to))
+    };
+    let has_port = |faction_id: u64| {
+        faction_settlements(world, faction_id)
+            .into_iter()
+            .any(|sid| settlement_has_port(world, sid))
+    };
+    factions_are_adjacent(world, a, b)
+        || trades_with(a, b)
+        || trades_with(b, a)
+        || (has_port(a) && has_port(b))
+}
+
 /// Damage buildings in a settlement. Applies `damage_fn` to each building's condition
 /// that passes `filter_fn`, destroys buildings at condition <= 0, and emits BuildingDestroyed
 /// signals. Used by both disaster and conquest damage paths.
@@ -643,9 +766,21 @@ pub(crate) fn region_has_port_settlement(world: &World, region_id: u64) -> bool
     })
 }
 
+/// Check if a land region has no living settlement in it, leaving it open to settlers.
+pub(crate) fn region_is_unclaimed(world: &World, region_id: u64) -> bool {
+    !region_is_water(world, region_id)
+        && !world
+            .living(EntityKind::Settlement)
+            .any(|e| e.has_active_rel(RelationshipKind::LocatedIn, region_id))
+}
+
 /// BFS to find the next step from `start` toward `goal`, allowing water traversal
 /// when `can_embark` is true. Water-to-land transitions are only allowed at regions
 /// with port settlements. When `can_embark` is false, water regions are impassable.
+#[allow(
+    dead_code,
+    reason = "scan-based reference for RegionGraph::next_step_naval; only tests call it"
+)]
 pub(crate) fn bfs_next_step_naval(
     world: &World,
     start: u64,
@@ -704,3 +839,37 @@ pub(crate) fn bfs_next_step_naval(
     }
     None
 }
+
+/// Leave ruins where a settlement stood, so its name outlives it on the map.
+/// Call before ending the settlement. Returns the ruins' entity ID, or `None`
+/// if the settlement had no region.
+pub fn leave_ruins(
+    world: &mut World,
+    settlement_id: u64,
+    cause: &str,
+    time: SimTimestamp,
+    event_id: u64,
+) -> Option<u64> {
+    let entity = world.entities.get(&settlement_id)?;
+    let region_id = entity.active_rel(RelationshipKind::LocatedIn)?;
+    let settlement_name = entity.name.clone();
+    let ruins_id = world.add_entity(
+        EntityKind::Monument,
+        format!("Ruins of {settlement_name}"),
+        Some(time),
+        EntityData::Monument(MonumentData {
+            settlement_id,
+            settlement_name,
+            cause: cause.to_string(),
+        }),
+        event_id,
+    );
+    world.add_relationship(
+        ruins_id,
+        region_id,
+        RelationshipKind::LocatedIn,
+        time,
+        event_id,
+    );
+    Some(ruins_id)
+}
@@ -0,0 +1,261 @@
+//! Holy sites: places sacred to a religion. Holding one lends a faction
+//! legitimacy; losing one to another faith breeds grievance and holy war.
+//!
+//! No `SimSystem` — called from worldgen, religion, politics, and conflicts.
+
+use crate::model::{
+    EntityData, EntityKind, EventPayload, HolySiteData, ParticipantRole, RelationshipKind,
+    SimTimestamp, World,
+};
+use crate::sim::grievance as grv;
+use crate::sim::helpers;
+
+/// Legitimacy target bonus per holy site of its own faith a faction holds.
+const HOLY_SITE_LEGITIMACY_BONUS: f64 = 0.03;
+/// Most legitimacy target bonus holy sites can lend a faction.
+const HOLY_SITE_LEGITIMACY_CAP: f64 = 0.09;
+/// Legitimacy a faction gains for taking a rival faith's holy site.
+const HOLY_SITE_CAPTURED_LEGITIMACY: f64 = 0.05;
+/// Legitimacy a faction loses when its holy site falls to another faith.
+const HOLY_SITE_LOST_LEGITIMACY: f64 = -0.05;
+/// Grievance the faithful hold against whoever takes their holy site.
+const GRIEVANCE_HOLY_SITE_LOST: f64 = 0.35;
+
+/// Consecrate a holy site of `religion_id` in `settlement_id`.
+pub(crate) fn found_holy_site(
+    world: &mut World,
+    religion_id: u64,
+    settlement_id: u64,
+    time: SimTimestamp,
+    event_id: u64,
+) -> u64 {
+    let name = format!("Sanctum of {}", helpers::entity_name(world, settlement_id));
+    world.add_entity(
+        EntityKind::HolySite,
+        name,
+        Some(time),
+        EntityData::HolySite(HolySiteData {
+            religion_id,
+            settlement_id,
+        }),
+        event_id,
+    )
+}
+
+fn faction_religion(world: &World, faction_id: u64) -> Option<u64> {
+    world
+        .entities
+        .get(&faction_id)?
+        .data
+        .as_faction()?
+        .primary_religion
+}
+
+/// Living holy sites as `(settlement_id, religion_id)`, skipping any whose
+/// settlement has fallen.
+fn living_sites(world: &World) -> impl Iterator<Item = (u64, u64)> + '_ {
+    world
+        .living(EntityKind::HolySite)
+        .filter_map(|e| e.data.as_holy_site())
+        .filter(|hs| {
+            world
+                .entities
+                .get(&hs.settlement_id)
+                .is_some_and(|s| s.end.is_none())
+        })
+        .map(|hs| (hs.settlement_id, hs.religion_id))
+}
+
+fn owner(world: &World, settlement_id: u64) -> Option<u64> {
+    world
+        .entities
+        .get(&settlement_id)?
+        .active_rel(RelationshipKind::MemberOf)
+}
+
+/// Legitimacy target bonus `faction_id` draws from the holy sites of its own
+/// faith it holds.
+pub(crate) fn holy_site_legitimacy(world: &World, faction_id: u64) -> f64 {
+    let Some(religion) = faction_religion(world, faction_id) else {
+        return 0.0;
+    };
+    let held = living_sites(world)
+        .filter(|&(sid, rid)| rid == religion && owner(world, sid) == Some(faction_id))
+        .count();
+    (held as f64 * HOLY_SITE_LEGITIMACY_BONUS).min(HOLY_SITE_LEGITIMACY_CAP)
+}
+
+/// Settlements of `holder_id` hosting holy sites of `faction_id`'s faith,
+/// where the holder follows another religion.
+pub(crate) fn occupied_holy_sites(world: &World, faction_id: u64, holder_id: u64) -> Vec<u64> {
+    let Some(religion) = faction_religion(world, faction_id) else {
+        return Vec::new();
+    };
+    if faction_religion(world, holder_id) == Some(religion) {
+        return Vec::new();
+    }
+    let mut settlements: Vec<u64> = living_sites(world)
+        .filter(|&(sid, rid)| rid == religion && owner(world, sid) == Some(holder_id))
+        .map(|(sid, _)| sid)
+        .collect();
+    settlements.dedup();
+    settlements
+}
+
+/// When a settlement falls, each holy site in it of the loser's faith that
+/// passes to a captor of another faith crowns the captor with legitimacy and
+/// leaves the loser aggrieved.
+pub(crate) fn handle_holy_site_captured(
+    world: &mut World,
+    event_id: u64,
+    settlement_id: u64,
+    old_faction_id: u64,
+    new_faction_id: u64,
+) {
+    let Some(old_religion) = faction_religion(world, old_faction_id) else {
+        return;
+    };
+    if faction_religion(world, new_faction_id) == Some(old_religion) {
+        return;
+    }
+    let fallen = living_sites(world)
+        .filter(|&(sid, rid)| sid == settlement_id && rid == old_religion)
+        .count() as u32;
+    if fallen == 0 {
+        return;
+    }
+
+    let time = world.current_time;
+    let ev = world.add_caused_structured_event(
+        EventPayload::HolySiteCaptured {
+            settlement_id,
+            religion_id: old_religion,
+            captor_id: new_faction_id,
+            loser_id: old_faction_id,
+            sites: fallen,
+        },
+        time,
+        format!(
+            "{} seized the holy places of {} from {} in year {}",
+            helpers::entity_name(world, new_faction_id),
+            helpers::entity_name(world, settlement_id),
+            helpers::entity_name(world, old_faction_id),
+            time.year()
+        ),
+        event_id,
+    );
+    world.add_event_participant(ev, new_faction_id, ParticipantRole::Attacker);
+    world.add_event_participant(ev, old_faction_id, ParticipantRole::Defender);
+    world.add_event_participant(ev, settlement_id, ParticipantRole::Location);
+
+    for (faction_id, delta) in [
+        (new_faction_id, HOLY_SITE_CAPTURED_LEGITIMACY),
+        (old_faction_id, HOLY_SITE_LOST_LEGITIMACY),
+    ] {
+        let Some(fd) = world
+            .entities
+            .get_mut(&faction_id)
+            .and_then(|e| e.data.as_faction_mut())
+        else {
+            continue;
+        };
+        let old = fd.legitimacy;
+        fd.legitimacy = (old + delta).clamp(0.0, 1.0);
+        let new = fd.legitimacy;
+        world.record_change(
+            faction_id,
+            ev,
+            "legitimacy",
+            serde_json::json!(old),
+            serde_json::json!(new),
+        );
+    }
+    grv::add_grievance(
+        world,
+        old_faction_id,
+        new_faction_id,
+        GRIEVANCE_HOLY_SITE_LOST,
+        "holy_site_lost",
+        time,
+        ev,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EventKind;
+    use crate::scenario::Scenario;
+    use crate::sim::politics::PoliticsSystem;
+    use crate::sim::signal::{Signal, SignalKind};
+    use crate::testutil::deliver_signals;
+
+    #[test]
+    fn scenario_capturing_rival_holy_site_crowns_captor_and_aggrieves_loser() {
+        let mut s = Scenario::at_year(100);
+        let r = s.add_region("R");
+        let old_faith = s.add_religion("Old Faith");
+        let new_faith = s.add_religion("New Faith");
+        let keepers = s
+            .faction("Keepers")
+            .legitimacy(0.5)
+            .primary_religion(Some(old_faith))
+            .id();
+        let raiders = s
+            .faction("Raiders")
+            .legitimacy(0.5)
+            .primary_religion(Some(new_faith))
+            .id();
+        let shrine_town = s.settlement("Shrine Town", keepers, r).population(300).id();
+        s.settlement("Keep", keepers, r).population(300).id();
+        s.settlement("Camp", raiders, r).population(300).id();
+        let mut world = s.build();
+
+        let time = world.current_time;
+        let ev = world.add_event(
+            EventKind::Custom("test".to_string()),
+            time,
+            "test capture".to_string(),
+        );
+        found_holy_site(&mut world, old_faith, shrine_town, time, ev);
+        assert!(holy_site_legitimacy(&world, keepers) > 0.0);
+        assert!(occupied_holy_sites(&world, keepers, raiders).is_empty());
+
+        world.end_relationship(shrine_town, keepers, RelationshipKind::MemberOf, time, ev);
+        world.add_relationship(shrine_town, raiders, RelationshipKind::MemberOf, time, ev);
+        let inbox = vec![Signal {
+            event_id: ev,
+            kind: SignalKind::SettlementCaptured {
+                settlement_id: shrine_town,
+                old_faction_id: keepers,
+                new_faction_id: raiders,
+            },
+        }];
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
+
+        assert!(
+            world.faction(raiders).legitimacy > 0.5,
+            "captor should gain legitimacy from the rival holy site"
+        );
+        assert!(world.faction(keepers).legitimacy < world.faction(raiders).legitimacy);
+        assert!(
+            grv::get_grievance(&world, keepers, raiders) >= GRIEVANCE_HOLY_SITE_LOST,
+            "loser should hold a grievance over its lost holy site"
+        );
+        assert_eq!(
+            occupied_holy_sites(&world, keepers, raiders),
+            vec![shrine_town],
+            "the lost site should become a holy war target"
+        );
+        assert!(world.events.values().any(|e| {
+            EventPayload::from_data(&e.data)
+                == Some(EventPayload::HolySiteCaptured {
+                    settlement_id: shrine_town,
+                    religion_id: old_faith,
+                    captor_id: raiders,
+                    loser_id: keepers,
+                    sites: 1,
+                })
+        }));
+    }
+}
@@ -850,7 +850,7 @@ mod tests {
 
         let world = s.run(&mut [Box::new(ItemSystem)], 10, 42);
 
-        let items = world.count_living(&EntityKind::Item);
+        let items = world.count_living(EntityKind::Item);
         assert_eq!(items, 0, "tiny settlements should not craft items");
     }
 
@@ -1136,6 +1136,7 @@ mod tests {
                 attacker_faction_id: 999,
                 defender_faction_id: setup.faction,
                 outcome: SiegeOutcome::Conquered,
+                civilian_deaths: 0,
             },
         }];
         testutil::deliver_signals(&mut world, &mut ItemSystem, &inbox, 42);
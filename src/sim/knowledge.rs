@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use rand::{Rng, RngCore};
 
 use super::context::TickContext;
@@ -6,10 +8,11 @@ use super::knowledge_derivation;
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
 use crate::model::{
-    BuildingType, EntityData, EntityKind, EventKind, KnowledgeCategory, KnowledgeData,
-    ManifestationData, Medium, ParticipantRole, RelationshipKind, SecretDesire, SecretMotivation,
-    SiegeOutcome, SimTimestamp,
+    BuildingType, Describer, EnglishDescriber, EntityData, EntityKind, EventKind, EventPayload,
+    KnowledgeCategory, KnowledgeData, ManifestationData, Medium, ParticipantRole, RelationshipKind,
+    SecretDesire, SecretMotivation, SiegeOutcome, SimTimestamp, Technology,
 };
+use crate::sim::politics::faction_spymaster;
 
 // ---------------------------------------------------------------------------
 // Significance — base values and scaling factors for knowledge creation
@@ -123,6 +126,21 @@ const SECRET_TRANSCRIPTION_FACTOR: f64 = 0.1;
 const SECRET_REVELATION_THRESHOLD: usize = 3;
 /// Base probability per keeper-settlement per year that a secret leaks via gossip.
 const SECRET_NATURAL_LEAK_PROB: f64 = 0.03;
+/// Leak probability multiplier when the receiving settlement's faction runs a spy network.
+const SECRET_SPYMASTER_LEAK_FACTOR: f64 = 2.0;
+
+// ---------------------------------------------------------------------------
+// Technology — invention and diffusion between factions
+// ---------------------------------------------------------------------------
+
+/// Annual chance per unknown technology of inventing it, per point of faction literacy.
+const TECH_INVENTION_LITERACY_FACTOR: f64 = 0.005;
+/// Annual adoption chance per trade route with a partner who knows the technology.
+const TECH_DIFFUSION_PER_TRADE_ROUTE: f64 = 0.1;
+/// Annual adoption chance from an ally who knows the technology.
+const TECH_DIFFUSION_ALLY: f64 = 0.08;
+/// Cap on the annual adoption chance, however many routes carry it.
+const TECH_DIFFUSION_MAX: f64 = 0.5;
 
 pub struct KnowledgeSystem;
 
@@ -151,6 +169,8 @@ impl SimSystem for KnowledgeSystem {
         copy_written_works(ctx, time, year_event);
         leak_secrets(ctx, time, year_event);
         check_secret_revelations(ctx, time, year_event);
+        invent_technologies(ctx, time, current_year);
+        diffuse_technologies(ctx, time);
     }
 
     fn handle_signals(&mut self, ctx: &mut TickContext) {
@@ -195,6 +215,7 @@ impl SimSystem for KnowledgeSystem {
                     outcome,
                     attacker_faction_id,
                     defender_faction_id,
+                    ..
                 } => handle_siege_ended(
                     ctx,
                     time,
@@ -1741,7 +1762,10 @@ fn leak_secrets(ctx: &mut TickContext, time: SimTimestamp, year_event: u64) {
 
     // Roll for each candidate
     for c in candidates {
-        let prob = SECRET_NATURAL_LEAK_PROB * c.sensitivity;
+        let spy_mult = helpers::settlement_faction(ctx.world, c.target_settlement_id)
+            .filter(|&fid| faction_spymaster(ctx.world, fid).is_some())
+            .map_or(1.0, |_| SECRET_SPYMASTER_LEAK_FACTOR);
+        let prob = SECRET_NATURAL_LEAK_PROB * c.sensitivity * spy_mult;
         if ctx.rng.random_range(0.0..1.0) < prob {
             let ev = ctx.world.add_caused_event(
                 EventKind::SecretLeaked,
@@ -1941,6 +1965,148 @@ fn check_secret_revelations(ctx: &mut TickContext, time: SimTimestamp, year_even
     }
 }
 
+// ---------------------------------------------------------------------------
+// Technology — invention and diffusion
+// ---------------------------------------------------------------------------
+
+/// Literate factions occasionally develop a technology of their own.
+fn invent_technologies(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    let factions: Vec<(u64, f64, BTreeSet<Technology>)> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .filter(|e| !helpers::is_non_state_faction(ctx.world, e.id))
+        .filter_map(|e| {
+            let fd = e.data.as_faction()?;
+            (fd.literacy_rate > 0.0).then(|| (e.id, fd.literacy_rate, fd.technologies.clone()))
+        })
+        .collect();
+
+    for (faction_id, literacy, known) in factions {
+        for technology in Technology::ALL {
+            if known.contains(&technology)
+                || !ctx
+                    .rng
+                    .random_bool((literacy * TECH_INVENTION_LITERACY_FACTOR).min(1.0))
+            {
+                continue;
+            }
+            let faction_name = entity_name(ctx.world, faction_id);
+            let ev = ctx.world.add_event(
+                EventKind::Discovery,
+                time,
+                format!(
+                    "{faction_name} developed {} in year {current_year}",
+                    technology.as_str()
+                ),
+            );
+            ctx.world
+                .add_event_participant(ev, faction_id, ParticipantRole::Subject);
+            add_technology(ctx.world, faction_id, technology, ev);
+        }
+    }
+}
+
+/// Factions pick up technologies known to their trade partners and allies,
+/// faster along busier trade links. Knowledge never crosses enmity.
+fn diffuse_technologies(ctx: &mut TickContext, time: SimTimestamp) {
+    struct FactionTech {
+        known: BTreeSet<Technology>,
+        trade_partners: BTreeMap<u64, u32>,
+        allies: BTreeSet<u64>,
+        enemies: BTreeSet<u64>,
+    }
+
+    let factions: BTreeMap<u64, FactionTech> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .filter(|e| !helpers::is_non_state_faction(ctx.world, e.id))
+        .filter_map(|e| {
+            let fd = e.data.as_faction()?;
+            Some((
+                e.id,
+                FactionTech {
+                    known: fd.technologies.clone(),
+                    trade_partners: fd.trade_partner_routes.clone(),
+                    allies: e.active_rels(RelationshipKind::Ally).collect(),
+                    enemies: e
+                        .active_rels(RelationshipKind::Enemy)
+                        .chain(e.active_rels(RelationshipKind::AtWar))
+                        .collect(),
+                },
+            ))
+        })
+        .collect();
+    if factions.values().all(|f| f.known.is_empty()) {
+        return;
+    }
+
+    // (adopter, source, technology, chance) — the likeliest teacher for each gap
+    let mut adoptions: Vec<(u64, u64, Technology, f64)> = Vec::new();
+    for (&faction_id, faction) in &factions {
+        for technology in Technology::ALL {
+            if faction.known.contains(&technology) {
+                continue;
+            }
+            let best = factions
+                .iter()
+                .filter(|&(&source_id, source)| {
+                    source_id != faction_id
+                        && source.known.contains(&technology)
+                        && !faction.enemies.contains(&source_id)
+                        && !source.enemies.contains(&faction_id)
+                })
+                .map(|(&source_id, _)| {
+                    let routes = faction.trade_partners.get(&source_id).copied().unwrap_or(0);
+                    let mut chance = f64::from(routes) * TECH_DIFFUSION_PER_TRADE_ROUTE;
+                    if faction.allies.contains(&source_id) {
+                        chance += TECH_DIFFUSION_ALLY;
+                    }
+                    (source_id, chance.min(TECH_DIFFUSION_MAX))
+                })
+                .filter(|&(_, chance)| chance > 0.0)
+                .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+            if let Some((source_id, chance)) = best {
+                adoptions.push((faction_id, source_id, technology, chance));
+            }
+        }
+    }
+
+    for (faction_id, source_id, technology, chance) in adoptions {
+        if !ctx.rng.random_bool(chance) {
+            continue;
+        }
+        let payload = EventPayload::KnowledgeAdopted {
+            faction_id,
+            source_faction_id: source_id,
+            technology,
+        };
+        let description = EnglishDescriber
+            .describe_payload(ctx.world, &payload, time)
+            .expect("technology adoption has an English template");
+        let ev = ctx.world.add_structured_event(payload, time, description);
+        ctx.world
+            .add_event_participant(ev, faction_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, source_id, ParticipantRole::Origin);
+        add_technology(ctx.world, faction_id, technology, ev);
+    }
+}
+
+fn add_technology(
+    world: &mut crate::model::World,
+    faction_id: u64,
+    technology: Technology,
+    event_id: u64,
+) {
+    let fd = world.faction_mut(faction_id);
+    let old: Vec<&str> = fd.technologies.iter().map(Technology::as_str).collect();
+    let old = serde_json::json!(old);
+    fd.technologies.insert(technology);
+    let new: Vec<&str> = fd.technologies.iter().map(Technology::as_str).collect();
+    let new = serde_json::json!(new);
+    world.record_change(faction_id, event_id, "technologies", old, new);
+}
+
 // ---------------------------------------------------------------------------
 // Settlement knowledge map — shared by propagation & transcription phases
 // ---------------------------------------------------------------------------
@@ -3038,6 +3204,7 @@ mod tests {
                     attacker_faction_id: attacker,
                     defender_faction_id: faction,
                     outcome: SiegeOutcome::Conquered,
+                    civilian_deaths: 0,
                 },
             }],
             42,
@@ -3108,6 +3275,7 @@ mod tests {
                     attacker_faction_id: attacker,
                     defender_faction_id: faction,
                     outcome: SiegeOutcome::Lifted,
+                    civilian_deaths: 0,
                 },
             }],
             42,
@@ -3996,7 +4164,7 @@ mod tests {
                 sensitivity: 1.0,
             },
         }];
-        crate::testutil::deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        crate::testutil::deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert!(
             world.faction(faction).stability < 0.8,
@@ -4004,4 +4172,45 @@ mod tests {
             world.faction(faction).stability
         );
     }
+
+    #[test]
+    fn scenario_technology_spreads_to_trade_partner_but_not_isolated_rival() {
+        let mut s = Scenario::at_year(100);
+        let teacher = s.add_faction_with("Teacher", |fd| {
+            fd.technologies.insert(Technology::Writing);
+        });
+        let partner = s.add_faction("Partner");
+        let rival = s.add_faction("Rival");
+        let mut world = s.build();
+        world
+            .faction_mut(teacher)
+            .trade_partner_routes
+            .insert(partner, 3);
+        world
+            .faction_mut(partner)
+            .trade_partner_routes
+            .insert(teacher, 3);
+
+        for year in 100..120 {
+            crate::testutil::tick_system(&mut world, &mut KnowledgeSystem, year, u64::from(year));
+        }
+
+        assert!(
+            world
+                .faction(partner)
+                .technologies
+                .contains(&Technology::Writing),
+            "trade partner should adopt writing within 20 years"
+        );
+        assert!(
+            world.faction(rival).technologies.is_empty(),
+            "unconnected rival should not learn anything"
+        );
+        assert!(
+            world
+                .events
+                .values()
+                .any(|e| e.kind == EventKind::KnowledgeAdopted)
+        );
+    }
 }
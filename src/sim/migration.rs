@@ -5,8 +5,13 @@ use rand::Rng;
 use super::context::TickContext;
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
+use crate::model::entity_data::DisasterType;
+use crate::model::population::PopulationBreakdown;
 use crate::model::traits::{Trait, has_trait};
-use crate::model::{EntityKind, EventKind, ParticipantRole, RelationshipKind, SimTimestamp, World};
+use crate::model::{
+    EntityData, EntityKind, EventKind, EventPayload, ParticipantRole, RelationshipKind,
+    SimTimestamp, World,
+};
 use crate::sim::helpers;
 
 // --- Constants ---
@@ -15,17 +20,40 @@ use crate::sim::helpers;
 const CONQUEST_REFUGEE_MIN: f64 = 0.15;
 const CONQUEST_REFUGEE_MAX: f64 = 0.30;
 
-/// Fraction of population that emigrates per year from war-zone settlements.
-const WAR_ZONE_EMIGRATION_MIN: f64 = 0.03;
-const WAR_ZONE_EMIGRATION_MAX: f64 = 0.08;
+/// Fraction of population that flees a settlement under heavy siege.
+const SIEGE_REFUGEE_MIN: f64 = 0.05;
+const SIEGE_REFUGEE_MAX: f64 = 0.10;
 
-/// Fraction of population that emigrates per year from low-prosperity settlements.
-const LOW_PROSPERITY_EMIGRATION_MIN: f64 = 0.02;
-const LOW_PROSPERITY_EMIGRATION_MAX: f64 = 0.05;
+/// Months a siege must drag on before civilians start fleeing.
+const HEAVY_SIEGE_MONTHS: u32 = 6;
+
+/// Fraction of population that emigrates per year per unit of push factor.
+const EMIGRATION_PER_PUSH_MIN: f64 = 0.03;
+const EMIGRATION_PER_PUSH_MAX: f64 = 0.08;
+
+/// Push factor contributions: how strongly each hardship drives people out.
+const PUSH_SIEGE: f64 = 0.5;
+const PUSH_PLAGUE: f64 = 1.0;
+const PUSH_FAMINE: f64 = 1.0;
+const PUSH_WAR_FRONT: f64 = 1.0;
+const PUSH_LOW_PROSPERITY: f64 = 0.6;
+/// Hardships stack, but only up to this much push.
+const PUSH_MAX: f64 = 2.0;
 
 /// Prosperity threshold below which economic emigration kicks in.
 const LOW_PROSPERITY_THRESHOLD: f64 = 0.3;
 
+/// Pull factor multipliers for destinations suffering a hardship of their own.
+const PULL_PLAGUE_MULT: f64 = 0.3;
+const PULL_FAMINE_MULT: f64 = 0.5;
+const PULL_WAR_FRONT_MULT: f64 = 0.6;
+/// Pull factor multiplier for destinations sharing the migrants' culture.
+const PULL_SHARED_CULTURE_MULT: f64 = 1.3;
+/// Least a crowded destination's headroom counts for.
+const PULL_MIN_HEADROOM: f64 = 0.1;
+/// Capacity assumed for settlements whose carrying capacity isn't computed yet.
+const DEFAULT_CAPACITY: u32 = 3000;
+
 /// Maximum BFS hops for destination search.
 const MAX_BFS_HOPS: usize = 4;
 
@@ -35,6 +63,18 @@ const PORT_DESTINATION_BONUS: f64 = 1.3;
 /// Minimum population before a settlement is considered abandoned.
 const ABANDONMENT_THRESHOLD: u32 = 10;
 
+/// Minimum refugee band size that will found a new settlement.
+const REFUGEE_SETTLEMENT_MIN_POP: u32 = 30;
+
+/// Yearly chance an overcrowded settlement sends its overflow out to found a
+/// daughter settlement.
+const DAUGHTER_FOUNDING_CHANCE: f64 = 0.25;
+/// Smallest overflow above capacity that can found a daughter settlement.
+const DAUGHTER_MIN_SETTLERS: u32 = 50;
+
+/// Cultural tension added to a destination receiving refugees of a different culture.
+const REFUGEE_CULTURAL_TENSION: f64 = 0.05;
+
 /// NPC flee chances by trait.
 const CAUTIOUS_FLEE_CHANCE: f64 = 0.60;
 const DEFAULT_FLEE_CHANCE: f64 = 0.30;
@@ -57,15 +97,100 @@ impl SimSystem for MigrationSystem {
 
         // Collect all migration sources with their refugee fractions
         let sources = collect_migration_sources(ctx.world, current_year);
-        if sources.is_empty() {
-            return;
-        }
 
         // Process each source
         for source in sources {
             process_migration(ctx, time, current_year, &source);
         }
+
+        found_daughter_settlements(ctx, time, current_year);
+    }
+}
+
+// --- Push and pull factors ---
+
+/// The conditions at a settlement that drive residents away or draw migrants in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SettlementConditions {
+    pub prosperity: f64,
+    pub besieged: bool,
+    pub plague: bool,
+    pub famine: bool,
+    /// The settlement's faction is at war.
+    pub war_front: bool,
+    /// Room left to grow: 1.0 when empty, 0.0 at or above capacity.
+    pub headroom: f64,
+}
+
+impl SettlementConditions {
+    fn of(world: &World, settlement_id: u64) -> Option<Self> {
+        let entity = world.entities.get(&settlement_id)?;
+        let sd = entity.data.as_settlement()?;
+        let war_front = entity
+            .active_rel(RelationshipKind::MemberOf)
+            .and_then(|fid| world.entities.get(&fid))
+            .is_some_and(|f| f.active_rels(RelationshipKind::AtWar).next().is_some());
+        let capacity = if sd.capacity > 0 {
+            sd.capacity
+        } else {
+            DEFAULT_CAPACITY
+        };
+        Some(Self {
+            prosperity: sd.prosperity,
+            besieged: sd.active_siege.is_some(),
+            plague: sd.active_disease.is_some(),
+            famine: sd
+                .active_disaster
+                .as_ref()
+                .is_some_and(|d| d.disaster_type == DisasterType::Drought),
+            war_front,
+            headroom: (1.0 - sd.population as f64 / capacity as f64).clamp(0.0, 1.0),
+        })
+    }
+}
+
+/// How strongly a settlement's conditions push residents to leave: 0.0 when
+/// nothing drives them out, up to `PUSH_MAX` when hardships pile up.
+pub(crate) fn push_factor(c: &SettlementConditions) -> f64 {
+    let mut push = 0.0;
+    if c.besieged {
+        push += PUSH_SIEGE;
+    }
+    if c.plague {
+        push += PUSH_PLAGUE;
+    }
+    if c.famine {
+        push += PUSH_FAMINE;
+    }
+    if c.war_front {
+        push += PUSH_WAR_FRONT;
+    }
+    if c.prosperity < LOW_PROSPERITY_THRESHOLD {
+        push += PUSH_LOW_PROSPERITY;
+    }
+    push.min(PUSH_MAX)
+}
+
+/// How attractive a settlement is to migrants. Prosperity, peace, room to
+/// grow and a shared culture draw them; nobody flees into a siege.
+pub(crate) fn pull_factor(c: &SettlementConditions, shared_culture: bool) -> f64 {
+    if c.besieged {
+        return 0.0;
+    }
+    let mut pull = (0.3 + c.prosperity) * c.headroom.max(PULL_MIN_HEADROOM);
+    if c.plague {
+        pull *= PULL_PLAGUE_MULT;
+    }
+    if c.famine {
+        pull *= PULL_FAMINE_MULT;
+    }
+    if c.war_front {
+        pull *= PULL_WAR_FRONT_MULT;
+    }
+    if shared_culture {
+        pull *= PULL_SHARED_CULTURE_MULT;
     }
+    pull
 }
 
 // --- Source collection ---
@@ -80,26 +205,33 @@ struct MigrationSource {
     fraction_max: f64,
     cause_event_id: Option<u64>,
     is_conquest: bool,
+    /// Fleeing violence (conquest or siege): may found a new settlement
+    /// when no existing one will take them in.
+    is_refugee_flight: bool,
 }
 
 fn collect_migration_sources(world: &World, current_year: u32) -> Vec<MigrationSource> {
     let mut sources = Vec::new();
 
     // Gather settlement info
-    let settlements: Vec<(u64, u64, u64, f64)> = world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+    let settlements: Vec<(u64, u64, u64, SettlementConditions, u32)> = world
+        .living(EntityKind::Settlement)
         .filter_map(|e| {
             let region_id = e.active_rel(RelationshipKind::LocatedIn)?;
             let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
-            let prosperity = e.data.as_settlement().map(|s| s.prosperity).unwrap_or(0.5);
-            Some((e.id, region_id, faction_id, prosperity))
+            let conditions = SettlementConditions::of(world, e.id)?;
+            let siege_months = e
+                .data
+                .as_settlement()
+                .and_then(|s| s.active_siege.as_ref())
+                .map(|siege| siege.months_elapsed)
+                .unwrap_or(0);
+            Some((e.id, region_id, faction_id, conditions, siege_months))
         })
         .collect();
 
     // Find recently conquered settlements: MemberOf relationship started this year
-    for &(sid, region_id, faction_id, prosperity) in &settlements {
+    for &(sid, region_id, faction_id, conditions, siege_months) in &settlements {
         let entity = match world.entities.get(&sid) {
             Some(e) => e,
             None => continue,
@@ -141,39 +273,38 @@ fn collect_migration_sources(world: &World, current_year: u32) -> Vec<MigrationS
                 fraction_max: CONQUEST_REFUGEE_MAX,
                 cause_event_id: cause_event,
                 is_conquest: true,
+                is_refugee_flight: true,
             });
-            continue; // Don't also add war-zone / low-prosperity for conquest
+            continue; // Don't also add hardship emigration for conquest
         }
 
-        // Check if faction is at war
-        let faction_at_war = world
-            .entities
-            .get(&faction_id)
-            .is_some_and(|e| e.active_rels(RelationshipKind::AtWar).next().is_some());
-
-        if faction_at_war {
+        // Heavy siege: civilians flee the encircled settlement
+        if siege_months >= HEAVY_SIEGE_MONTHS {
             sources.push(MigrationSource {
                 settlement_id: sid,
                 region_id,
                 affinity_faction_id: faction_id,
-                fraction_min: WAR_ZONE_EMIGRATION_MIN,
-                fraction_max: WAR_ZONE_EMIGRATION_MAX,
+                fraction_min: SIEGE_REFUGEE_MIN,
+                fraction_max: SIEGE_REFUGEE_MAX,
                 cause_event_id: None,
                 is_conquest: false,
+                is_refugee_flight: true,
             });
-            continue; // Don't stack with low-prosperity
+            continue;
         }
 
-        // Check low prosperity
-        if prosperity < LOW_PROSPERITY_THRESHOLD {
+        // Everything else: hardships push a share of residents to move on
+        let push = push_factor(&conditions);
+        if push > 0.0 {
             sources.push(MigrationSource {
                 settlement_id: sid,
                 region_id,
                 affinity_faction_id: faction_id,
-                fraction_min: LOW_PROSPERITY_EMIGRATION_MIN,
-                fraction_max: LOW_PROSPERITY_EMIGRATION_MAX,
+                fraction_min: EMIGRATION_PER_PUSH_MIN * push,
+                fraction_max: EMIGRATION_PER_PUSH_MAX * push,
                 cause_event_id: None,
                 is_conquest: false,
+                is_refugee_flight: false,
             });
         }
     }
@@ -205,6 +336,11 @@ struct Candidate {
 fn find_best_destination(world: &World, source: &MigrationSource) -> Option<u64> {
     // BFS over region adjacency to find settlements within MAX_BFS_HOPS
     let reachable_regions = bfs_reachable_regions(world, source.region_id, MAX_BFS_HOPS);
+    let source_culture = world
+        .entities
+        .get(&source.settlement_id)
+        .and_then(|e| e.data.as_settlement())
+        .and_then(|sd| sd.dominant_culture);
 
     let mut candidates: Vec<Candidate> = Vec::new();
 
@@ -227,26 +363,22 @@ fn find_best_destination(world: &World, source: &MigrationSource) -> Option<u64>
                 None => continue,
             };
 
-            // Score: faction_affinity * (1.0 / distance) * prosperity * capacity_room
+            // Score: faction_affinity * (1.0 / distance) * pull * port bonus
             let faction_affinity =
                 compute_faction_affinity(world, source.affinity_faction_id, dest_faction);
             if faction_affinity <= 0.0 {
                 continue; // hostile — skip
             }
 
-            let prosperity = entity
-                .data
-                .as_settlement()
-                .map(|s| s.prosperity)
-                .unwrap_or(0.3);
-            let population = entity
-                .data
-                .as_settlement()
-                .map(|s| s.population)
-                .unwrap_or(0);
-
-            // Capacity room: settlements above 2000 are less attractive
-            let capacity_room = (1.0 - population as f64 / 3000.0).max(0.1);
+            let Some(conditions) = SettlementConditions::of(world, entity.id) else {
+                continue;
+            };
+            let dest_culture = entity.data.as_settlement().and_then(|s| s.dominant_culture);
+            let shared_culture = source_culture.is_some() && dest_culture == source_culture;
+            let pull = pull_factor(&conditions, shared_culture);
+            if pull <= 0.0 {
+                continue; // under siege — nobody flees into one
+            }
 
             // Port cities are attractive destinations
             let port_bonus = entity
@@ -260,8 +392,7 @@ fn find_best_destination(world: &World, source: &MigrationSource) -> Option<u64>
             };
 
             let dist_factor = 1.0 / (distance as f64).max(1.0);
-            let score =
-                faction_affinity * dist_factor * (0.3 + prosperity) * capacity_room * port_mult;
+            let score = faction_affinity * dist_factor * pull * port_mult;
 
             candidates.push(Candidate {
                 settlement_id: entity.id,
@@ -363,15 +494,26 @@ fn bfs_reachable_regions(world: &World, start: u64, max_hops: usize) -> Vec<(u64
 
 // --- Migration processing ---
 
+/// Where a migrant group ends up.
+enum Destination {
+    Settlement(u64),
+    /// An empty, safe region where refugees found a new settlement.
+    NewSettlement(u64),
+}
+
 fn process_migration(
     ctx: &mut TickContext,
     time: SimTimestamp,
     current_year: u32,
     source: &MigrationSource,
 ) {
-    // Find a destination
-    let dest_id = match find_best_destination(ctx.world, source) {
-        Some(id) => id,
+    // Find a destination; refugees with nowhere to go may strike out on their own
+    let destination = match find_best_destination(ctx.world, source) {
+        Some(id) => Destination::Settlement(id),
+        None if source.is_refugee_flight => match find_refuge_region(ctx.world, source) {
+            Some(region_id) => Destination::NewSettlement(region_id),
+            None => return,
+        },
         None => return, // No valid destination
     };
 
@@ -389,6 +531,13 @@ fn process_migration(
         return;
     }
 
+    // Too few to found a viable settlement
+    if matches!(destination, Destination::NewSettlement(_))
+        && ((source_pop as f64 * fraction) as u32) < REFUGEE_SETTLEMENT_MIN_POP
+    {
+        return;
+    }
+
     // Subtract population from source
     let removed = {
         let entity = match ctx.world.entities.get_mut(&source.settlement_id) {
@@ -411,50 +560,71 @@ fn process_migration(
         return;
     }
 
-    // Add population to destination
-    let dest_pop_before = {
-        let entity = match ctx.world.entities.get_mut(&dest_id) {
-            Some(e) => e,
-            None => return,
-        };
-        let settlement = match entity.data.as_settlement_mut() {
-            Some(s) => s,
-            None => return,
-        };
-        let old_pop = settlement.population;
-        settlement.population_breakdown += &removed;
-        settlement.population = settlement.population_breakdown.total();
-        old_pop
-    };
-    let dest_pop_after = ctx.world.settlement(dest_id).population;
-
-    // Get names for event description
     let source_name = helpers::entity_name(ctx.world, source.settlement_id);
-    let dest_name = helpers::entity_name(ctx.world, dest_id);
 
-    // Create migration event
-    let ev = if let Some(cause_id) = source.cause_event_id {
-        ctx.world.add_caused_event(
-            EventKind::Migration,
-            time,
-            format!(
-                "{refugee_count} refugees fled from {source_name} to {dest_name} in year {current_year}"
-            ),
-            cause_id,
-        )
-    } else {
-        ctx.world.add_event(
-            EventKind::Migration,
-            time,
-            format!(
-                "{refugee_count} people migrated from {source_name} to {dest_name} in year {current_year}"
-            ),
-        )
+    let (dest_id, ev) = match destination {
+        Destination::Settlement(dest_id) => {
+            // Add population to destination
+            let dest_pop_before = {
+                let entity = match ctx.world.entities.get_mut(&dest_id) {
+                    Some(e) => e,
+                    None => return,
+                };
+                let settlement = match entity.data.as_settlement_mut() {
+                    Some(s) => s,
+                    None => return,
+                };
+                let old_pop = settlement.population;
+                settlement.population_breakdown += &removed;
+                settlement.population = settlement.population_breakdown.total();
+                old_pop
+            };
+            let dest_pop_after = ctx.world.settlement(dest_id).population;
+
+            let dest_name = helpers::entity_name(ctx.world, dest_id);
+
+            // Create migration event
+            let ev = if let Some(cause_id) = source.cause_event_id {
+                ctx.world.add_caused_event(
+                    EventKind::Migration,
+                    time,
+                    format!(
+                        "{refugee_count} refugees fled from {source_name} to {dest_name} in year {current_year}"
+                    ),
+                    cause_id,
+                )
+            } else {
+                ctx.world.add_event(
+                    EventKind::Migration,
+                    time,
+                    format!(
+                        "{refugee_count} people migrated from {source_name} to {dest_name} in year {current_year}"
+                    ),
+                )
+            };
+            ctx.world
+                .add_event_participant(ev, source.settlement_id, ParticipantRole::Origin);
+            ctx.world
+                .add_event_participant(ev, dest_id, ParticipantRole::Destination);
+
+            ctx.world.record_change(
+                dest_id,
+                ev,
+                "population",
+                serde_json::json!(dest_pop_before),
+                serde_json::json!(dest_pop_after),
+            );
+            raise_refugee_tension(ctx.world, source.settlement_id, dest_id, ev);
+            (dest_id, ev)
+        }
+        Destination::NewSettlement(region_id) => {
+            let name = format!("New {source_name}");
+            let description = format!(
+                "{refugee_count} refugees from {source_name} founded {name} in year {current_year}"
+            );
+            found_settlement(ctx, time, source, region_id, removed, name, description)
+        }
     };
-    ctx.world
-        .add_event_participant(ev, source.settlement_id, ParticipantRole::Origin);
-    ctx.world
-        .add_event_participant(ev, dest_id, ParticipantRole::Destination);
 
     // Record population changes
     ctx.world.record_change(
@@ -464,13 +634,6 @@ fn process_migration(
         serde_json::json!(source_pop),
         serde_json::json!(source_pop - refugee_count),
     );
-    ctx.world.record_change(
-        dest_id,
-        ev,
-        "population",
-        serde_json::json!(dest_pop_before),
-        serde_json::json!(dest_pop_after),
-    );
 
     // Emit RefugeesArrived signal
     ctx.signals.push(Signal {
@@ -505,10 +668,231 @@ fn process_migration(
         );
         ctx.world
             .add_event_participant(abandon_ev, source.settlement_id, ParticipantRole::Subject);
+        let cause = if source.is_conquest {
+            "conquest"
+        } else {
+            "exodus"
+        };
+        helpers::leave_ruins(ctx.world, source.settlement_id, cause, time, abandon_ev);
         ctx.world.end_entity(source.settlement_id, time, abandon_ev);
     }
 }
 
+/// Find an empty land region next to the source where refugees can settle:
+/// no existing settlement and no foreign army present. The fleeing faction
+/// must still exist to claim it.
+fn find_refuge_region(world: &World, source: &MigrationSource) -> Option<u64> {
+    if !world
+        .entities
+        .get(&source.affinity_faction_id)
+        .is_some_and(|e| e.kind == EntityKind::Faction && e.end.is_none())
+    {
+        return None;
+    }
+
+    helpers::adjacent_regions(world, source.region_id)
+        .into_iter()
+        .filter(|&region_id| !helpers::region_is_water(world, region_id))
+        .find(|&region_id| {
+            !world.entities.values().any(|e| {
+                e.end.is_none()
+                    && e.has_active_rel(RelationshipKind::LocatedIn, region_id)
+                    && match e.kind {
+                        EntityKind::Settlement => true,
+                        EntityKind::Army => e
+                            .data
+                            .as_army()
+                            .is_some_and(|ad| ad.faction_id != source.affinity_faction_id),
+                        _ => false,
+                    }
+            })
+        })
+}
+
+/// Found a new settlement for the source's faction from a band of people who
+/// left it. Returns the new settlement and the founding event.
+fn found_settlement(
+    ctx: &mut TickContext,
+    time: SimTimestamp,
+    source: &MigrationSource,
+    region_id: u64,
+    people: PopulationBreakdown,
+    name: String,
+    description: String,
+) -> (u64, u64) {
+    let (x, y) = ctx
+        .world
+        .entities
+        .get(&region_id)
+        .and_then(|e| e.data.as_region())
+        .map(|rd| (rd.x, rd.y))
+        .unwrap_or((0.0, 0.0));
+    let (culture, religion) = ctx
+        .world
+        .entities
+        .get(&source.settlement_id)
+        .and_then(|e| e.data.as_settlement())
+        .map(|sd| (sd.dominant_culture, sd.dominant_religion))
+        .unwrap_or((None, None));
+
+    let settlement_id = ctx.world.reserve_entity_id();
+    let payload = EventPayload::SettlementFounded {
+        settlement_id,
+        faction_id: Some(source.affinity_faction_id),
+        region_id,
+    };
+    let ev = match source.cause_event_id {
+        Some(cause_id) => {
+            ctx.world
+                .add_caused_structured_event(payload, time, description, cause_id)
+        }
+        None => ctx.world.add_structured_event(payload, time, description),
+    };
+
+    let mut data = EntityData::default_for_kind(EntityKind::Settlement);
+    if let EntityData::Settlement(ref mut sd) = data {
+        sd.population = people.total();
+        sd.population_breakdown = people;
+        sd.x = x;
+        sd.y = y;
+        sd.dominant_culture = culture;
+        if let Some(culture_id) = culture {
+            sd.culture_makeup.insert(culture_id, 1.0);
+        }
+        sd.dominant_religion = religion;
+        if let Some(religion_id) = religion {
+            sd.religion_makeup.insert(religion_id, 1.0);
+        }
+    }
+    ctx.world.add_reserved_entity(
+        settlement_id,
+        EntityKind::Settlement,
+        name,
+        Some(time),
+        data,
+        ev,
+    );
+    ctx.world.add_relationship(
+        settlement_id,
+        source.affinity_faction_id,
+        RelationshipKind::MemberOf,
+        time,
+        ev,
+    );
+    ctx.world.add_relationship(
+        settlement_id,
+        region_id,
+        RelationshipKind::LocatedIn,
+        time,
+        ev,
+    );
+
+    ctx.world
+        .add_event_participant(ev, settlement_id, ParticipantRole::Subject);
+    ctx.world
+        .add_event_participant(ev, source.settlement_id, ParticipantRole::Origin);
+
+    (settlement_id, ev)
+}
+
+/// Overcrowded settlements hive off their overflow into a daughter settlement
+/// in an empty neighboring region, when one exists.
+fn found_daughter_settlements(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    let crowded: Vec<(MigrationSource, u32, u32)> = ctx
+        .world
+        .living(EntityKind::Settlement)
+        .filter_map(|e| {
+            let sd = e.data.as_settlement()?;
+            if sd.active_siege.is_some() {
+                return None;
+            }
+            let capacity = if sd.capacity > 0 {
+                sd.capacity
+            } else {
+                DEFAULT_CAPACITY
+            };
+            let overflow = sd.population.checked_sub(capacity)?;
+            if overflow < DAUGHTER_MIN_SETTLERS {
+                return None;
+            }
+            let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
+            let source = MigrationSource {
+                settlement_id: e.id,
+                region_id: e.active_rel(RelationshipKind::LocatedIn)?,
+                affinity_faction_id: faction_id,
+                fraction_min: 0.0,
+                fraction_max: 0.0,
+                cause_event_id: None,
+                is_conquest: false,
+                is_refugee_flight: false,
+            };
+            Some((source, sd.population, overflow))
+        })
+        .collect();
+
+    for (source, population, overflow) in crowded {
+        if ctx.rng.random_range(0.0..1.0) >= DAUGHTER_FOUNDING_CHANCE {
+            continue;
+        }
+        let Some(region_id) = find_refuge_region(ctx.world, &source) else {
+            continue;
+        };
+
+        let settlers = {
+            let sd = ctx.world.settlement_mut(source.settlement_id);
+            let settlers = sd
+                .population_breakdown
+                .subtract_fraction(overflow as f64 / population as f64, ctx.rng);
+            sd.population = sd.population_breakdown.total();
+            settlers
+        };
+        let settler_count = settlers.total();
+        let source_name = helpers::entity_name(ctx.world, source.settlement_id);
+        let name = format!("Lesser {source_name}");
+        let description = format!(
+            "{settler_count} settlers from overcrowded {source_name} founded {name} in year {current_year}"
+        );
+        let (_, ev) = found_settlement(ctx, time, &source, region_id, settlers, name, description);
+        ctx.world.record_change(
+            source.settlement_id,
+            ev,
+            "population",
+            serde_json::json!(population),
+            serde_json::json!(population - settler_count),
+        );
+    }
+}
+
+/// Refugees of a different culture strain the receiving settlement.
+fn raise_refugee_tension(world: &mut World, source_id: u64, dest_id: u64, event_id: u64) {
+    let source_culture = world
+        .entities
+        .get(&source_id)
+        .and_then(|e| e.data.as_settlement())
+        .and_then(|sd| sd.dominant_culture);
+    let Some(source_culture) = source_culture else {
+        return;
+    };
+
+    let old_tension = {
+        let sd = world.settlement_mut(dest_id);
+        if sd.dominant_culture.is_none_or(|c| c == source_culture) {
+            return;
+        }
+        let old = sd.cultural_tension;
+        sd.cultural_tension = (old + REFUGEE_CULTURAL_TENSION).min(1.0);
+        old
+    };
+    let new_tension = world.settlement(dest_id).cultural_tension;
+    world.record_change(
+        dest_id,
+        event_id,
+        "cultural_tension",
+        serde_json::json!(old_tension),
+        serde_json::json!(new_tension),
+    );
+}
+
 fn migrate_npcs(
     ctx: &mut TickContext,
     time: SimTimestamp,
@@ -869,6 +1253,8 @@ mod tests {
                     widowed_at: None,
                     loyalty: std::collections::BTreeMap::new(),
                     education: 0.0,
+                    health: 1.0,
+                    dynasty_id: None,
                 }),
                 ev,
             );
@@ -1132,11 +1518,11 @@ mod tests {
             .id();
 
         let mut systems: Vec<Box<dyn SimSystem>> = vec![
-            Box::new(DemographicsSystem),
+            Box::new(DemographicsSystem::new()),
             Box::new(EconomySystem),
             Box::new(ConflictSystem),
             Box::new(MigrationSystem),
-            Box::new(PoliticsSystem),
+            Box::new(PoliticsSystem::new()),
         ];
         let world = s.run(&mut systems, 10, 42);
 
@@ -1170,11 +1556,11 @@ mod tests {
             .id();
 
         let mut systems: Vec<Box<dyn SimSystem>> = vec![
-            Box::new(DemographicsSystem),
+            Box::new(DemographicsSystem::new()),
             Box::new(EconomySystem),
             Box::new(ConflictSystem),
             Box::new(MigrationSystem),
-            Box::new(PoliticsSystem),
+            Box::new(PoliticsSystem::new()),
         ];
         let world = s.run(&mut systems, 10, 42);
 
@@ -1189,4 +1575,238 @@ mod tests {
             testutil::assert_property_changed(&world, dest, "population");
         }
     }
+
+    /// A defender settlement besieged for `siege_months`, next to an empty hinterland.
+    fn besieged_scenario(siege_months: u32) -> (crate::scenario::Scenario, u64, u64, u64) {
+        let mut s = crate::scenario::Scenario::at_year(100);
+        let war = s.add_war_between("Raiders", "Holdfast", 300);
+        let _ = s.settlement_mut(war.defender.settlement).population(1000);
+        s.start_siege_with(
+            war.defender.settlement,
+            war.army,
+            war.attacker.faction,
+            |siege| siege.months_elapsed = siege_months,
+        );
+        let hinterland = s.add_region("Hinterland");
+        s.make_adjacent(hinterland, war.defender.region);
+        (s, war.defender.settlement, war.defender.faction, hinterland)
+    }
+
+    #[test]
+    fn scenario_siege_refugees_absorbed_into_existing_settlement() {
+        let (mut s, source, faction, hinterland) = besieged_scenario(8);
+        let home_culture = s.add_culture("Highlanders");
+        let other_culture = s.add_culture("Lowlanders");
+        let _ = s
+            .settlement_mut(source)
+            .dominant_culture(Some(home_culture));
+        let dest = s
+            .settlement("Rear Town", faction, hinterland)
+            .population(200)
+            .prosperity(0.5)
+            .dominant_culture(Some(other_culture))
+            .id();
+        let mut world = s.build();
+        let tension_before = world.settlement(dest).cultural_tension;
+
+        crate::testutil::tick_system(&mut world, &mut MigrationSystem, 100, 42);
+
+        assert!(world.settlement(dest).population > 200);
+        assert!(world.settlement(source).population < 1000);
+        assert!(
+            world.settlement(dest).cultural_tension > tension_before,
+            "foreign refugees should raise cultural tension"
+        );
+        assert!(
+            !world
+                .events
+                .values()
+                .any(|e| e.kind == EventKind::SettlementFounded)
+        );
+    }
+
+    #[test]
+    fn scenario_siege_refugees_found_new_settlement() {
+        let (s, source, faction, hinterland) = besieged_scenario(8);
+        let mut world = s.build();
+
+        let signals = crate::testutil::tick_system(&mut world, &mut MigrationSystem, 100, 42);
+
+        let founded = world
+            .events
+            .values()
+            .find(|e| e.kind == EventKind::SettlementFounded)
+            .expect("refugees should found a settlement");
+        let Some(EventPayload::SettlementFounded {
+            settlement_id,
+            faction_id,
+            region_id,
+        }) = EventPayload::from_data(&founded.data)
+        else {
+            panic!("founding event should carry a payload");
+        };
+        assert_eq!(faction_id, Some(faction));
+        assert_eq!(region_id, hinterland);
+
+        let new_town = world.entity(settlement_id);
+        assert!(new_town.has_active_rel(RelationshipKind::MemberOf, faction));
+        assert!(new_town.has_active_rel(RelationshipKind::LocatedIn, hinterland));
+        let refugees = world.settlement(settlement_id).population;
+        assert!(refugees >= REFUGEE_SETTLEMENT_MIN_POP);
+        assert_eq!(world.settlement(source).population, 1000 - refugees);
+        assert!(crate::testutil::has_signal(&signals, |s| matches!(
+            s,
+            SignalKind::RefugeesArrived { settlement_id: id, .. } if *id == settlement_id
+        )));
+    }
+
+    #[test]
+    fn scenario_overcrowded_settlement_founds_daughter() {
+        let mut s = crate::scenario::Scenario::at_year(100);
+        let home = s.add_kingdom("Teeming");
+        let _ = s
+            .settlement_mut(home.settlement)
+            .population(1200)
+            .prosperity(0.6);
+        s.modify_settlement(home.settlement, |sd| sd.capacity = 800);
+        let frontier = s.add_region("Frontier");
+        s.make_adjacent(frontier, home.region);
+        let world = s.run(&mut [Box::new(MigrationSystem)], 20, 42);
+
+        let founded = world
+            .events
+            .values()
+            .find(|e| e.kind == EventKind::SettlementFounded)
+            .expect("overflow should found a daughter settlement within 20 years");
+        let Some(EventPayload::SettlementFounded {
+            settlement_id,
+            faction_id,
+            region_id,
+        }) = EventPayload::from_data(&founded.data)
+        else {
+            panic!("founding event should carry a payload");
+        };
+        assert_eq!(faction_id, Some(home.faction));
+        assert_eq!(region_id, frontier);
+        assert!(
+            world
+                .event_participants
+                .iter()
+                .any(|p| p.event_id == founded.id
+                    && p.entity_id == home.settlement
+                    && p.role == ParticipantRole::Origin)
+        );
+        let settlers = world.settlement(settlement_id).population;
+        assert!(settlers >= DAUGHTER_MIN_SETTLERS);
+        assert!(world.settlement(home.settlement).population <= 1200 - settlers);
+    }
+
+    #[test]
+    fn scenario_short_siege_does_not_drive_refugees_out() {
+        let (s, _source, _faction, _hinterland) = besieged_scenario(2);
+        let mut world = s.build();
+
+        crate::testutil::tick_system(&mut world, &mut MigrationSystem, 100, 42);
+
+        assert!(
+            !world
+                .events
+                .values()
+                .any(|e| e.kind == EventKind::SettlementFounded)
+        );
+    }
+
+    fn calm() -> SettlementConditions {
+        SettlementConditions {
+            prosperity: 0.6,
+            besieged: false,
+            plague: false,
+            famine: false,
+            war_front: false,
+            headroom: 0.5,
+        }
+    }
+
+    #[test]
+    fn hardships_push_and_repel() {
+        let quiet = calm();
+        assert_eq!(push_factor(&quiet), 0.0);
+
+        for hardship in [
+            SettlementConditions {
+                plague: true,
+                ..calm()
+            },
+            SettlementConditions {
+                famine: true,
+                ..calm()
+            },
+            SettlementConditions {
+                war_front: true,
+                ..calm()
+            },
+        ] {
+            assert!(push_factor(&hardship) > 0.0, "{hardship:?}");
+            assert!(pull_factor(&hardship, false) < pull_factor(&quiet, false));
+        }
+
+        let besieged = SettlementConditions {
+            besieged: true,
+            prosperity: 1.0,
+            ..calm()
+        };
+        assert!(push_factor(&besieged) > 0.0);
+        assert_eq!(
+            pull_factor(&besieged, true),
+            0.0,
+            "nobody flees into a siege"
+        );
+
+        let everything = SettlementConditions {
+            prosperity: 0.1,
+            besieged: true,
+            plague: true,
+            famine: true,
+            war_front: true,
+            ..calm()
+        };
+        assert_eq!(push_factor(&everything), PUSH_MAX);
+    }
+
+    #[test]
+    fn prosperity_room_and_culture_pull() {
+        let base = pull_factor(&calm(), false);
+        assert!(pull_factor(&calm(), true) > base);
+        let richer = SettlementConditions {
+            prosperity: 0.9,
+            ..calm()
+        };
+        assert!(pull_factor(&richer, false) > base);
+        let crowded = SettlementConditions {
+            headroom: 0.0,
+            ..calm()
+        };
+        assert!(pull_factor(&crowded, false) < base);
+        assert!(pull_factor(&crowded, false) > 0.0);
+    }
+
+    #[test]
+    fn scenario_migration_flows_away_from_besieged_settlement() {
+        let (mut s, besieged, faction, hinterland) = besieged_scenario(8);
+        // Prosperity alone would draw people into the besieged town
+        let _ = s.settlement_mut(besieged).prosperity(0.9);
+        let peaceful = s
+            .settlement("Rear Town", faction, hinterland)
+            .population(500)
+            .prosperity(0.4)
+            .id();
+        let world = s.run(&mut [Box::new(MigrationSystem)], 3, 42);
+
+        assert!(world.settlement(besieged).population < 1000);
+        assert!(
+            world.settlement(peaceful).population > 500,
+            "net migration should flow to the peaceful town, got {}",
+            world.settlement(peaceful).population
+        );
+    }
 }
@@ -15,6 +15,7 @@ pub mod extra_keys;
 pub mod faction_names;
 pub(crate) mod grievance;
 pub mod helpers;
+pub(crate) mod holy_sites;
 pub mod items;
 pub mod knowledge;
 pub mod knowledge_derivation;
@@ -22,9 +23,11 @@ pub(crate) mod loyalty;
 pub mod migration;
 pub mod names;
 pub mod politics;
+pub mod region_graph;
 pub mod religion;
 pub mod religion_names;
 pub mod reputation;
+mod rng;
 mod runner;
 pub mod signal;
 mod system;
@@ -37,7 +40,7 @@ pub use conflicts::ConflictSystem;
 pub use context::TickContext;
 pub use crime::CrimeSystem;
 pub use culture::CultureSystem;
-pub use demographics::DemographicsSystem;
+pub use demographics::{DemographicsConfig, DemographicsSystem};
 pub use disease::DiseaseSystem;
 pub use economy::EconomySystem;
 pub use education::EducationSystem;
@@ -45,9 +48,13 @@ pub use environment::EnvironmentSystem;
 pub use items::ItemSystem;
 pub use knowledge::KnowledgeSystem;
 pub use migration::MigrationSystem;
-pub use politics::PoliticsSystem;
+pub use politics::{PoliticsConfig, PoliticsSystem};
 pub use religion::ReligionSystem;
 pub use reputation::ReputationSystem;
-pub use runner::{SimConfig, dispatch_systems, run, should_fire};
+pub use rng::{Pcg32, SimRng};
+pub use runner::{
+    RunOutcome, SignalSubscriber, SimConfig, StopCondition, StopReason, dispatch_systems, resume,
+    run, should_fire,
+};
 pub use signal::{Signal, SignalKind};
 pub use system::{SimSystem, TickFrequency};
@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rand::Rng;
+
+use crate::model::{
+    EntityKind, EventPayload, ParticipantRole, RelationshipKind, SimTimestamp, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::helpers;
+
+// --- Capitals ---
+/// Happiness target bonus for a faction that holds its capital.
+pub(super) const CAPITAL_HAPPINESS_BONUS: f64 = 0.03;
+/// Legitimacy target bonus for a faction that holds its capital.
+pub(super) const CAPITAL_LEGITIMACY_BONUS: f64 = 0.05;
+/// Stability lost on top of the ordinary capture hit when the capital falls.
+const CAPITAL_CAPTURED_STABILITY: f64 = -0.15;
+/// Legitimacy lost when the capital falls.
+const CAPITAL_CAPTURED_LEGITIMACY: f64 = -0.10;
+/// Yearly chance a ruler moves the capital to a settlement that has outgrown it.
+const CAPITAL_DECREE_CHANCE: f64 = 0.1;
+/// How many times the capital's population a settlement must reach before a
+/// ruler considers moving the seat there.
+const CAPITAL_DECREE_POPULATION_RATIO: f64 = 2.0;
+
+/// Current capital of a faction, if it still holds one.
+pub fn faction_capital(world: &World, faction_id: u64) -> Option<u64> {
+    world
+        .living(EntityKind::Settlement)
+        .find(|e| {
+            e.has_active_rel(RelationshipKind::CapitalOf, faction_id)
+                && e.has_active_rel(RelationshipKind::MemberOf, faction_id)
+        })
+        .map(|e| e.id)
+}
+
+/// Factions that hold their capital, in one pass over settlements.
+pub(super) fn factions_holding_capital(world: &World) -> BTreeSet<u64> {
+    world
+        .living(EntityKind::Settlement)
+        .filter_map(|e| {
+            let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
+            e.has_active_rel(RelationshipKind::CapitalOf, faction_id)
+                .then_some(faction_id)
+        })
+        .collect()
+}
+
+/// Seat `faction_id` in `settlement_id`, ending any earlier designation of
+/// either.
+pub(crate) fn designate_capital(
+    world: &mut World,
+    faction_id: u64,
+    settlement_id: u64,
+    time: SimTimestamp,
+    event_id: u64,
+) {
+    let stale: Vec<(u64, u64)> = world
+        .entities
+        .values()
+        .filter(|e| e.kind == EntityKind::Settlement)
+        .flat_map(|e| {
+            e.relationships
+                .iter()
+                .filter(|r| {
+                    r.end.is_none()
+                        && r.kind == RelationshipKind::CapitalOf
+                        && (r.target_entity_id == faction_id || e.id == settlement_id)
+                })
+                .map(|r| (e.id, r.target_entity_id))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    for (sid, fid) in stale {
+        world.end_relationship(sid, fid, RelationshipKind::CapitalOf, time, event_id);
+    }
+    world.add_relationship(
+        settlement_id,
+        faction_id,
+        RelationshipKind::CapitalOf,
+        time,
+        event_id,
+    );
+}
+
+/// Seat capital-less factions in their largest settlement, and let rulers
+/// move the capital by decree once another settlement has far outgrown it.
+pub(super) fn update_capitals(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    // Largest settlement and capital of each state faction: faction → (largest, pop, capital, pop)
+    let mut largest: BTreeMap<u64, (u64, u32)> = BTreeMap::new();
+    let mut capitals: BTreeMap<u64, (u64, u32)> = BTreeMap::new();
+    for e in ctx.world.living(EntityKind::Settlement) {
+        let Some(faction_id) = e.active_rel(RelationshipKind::MemberOf) else {
+            continue;
+        };
+        if helpers::is_non_state_faction(ctx.world, faction_id) {
+            continue;
+        }
+        let pop = e.data.as_settlement().map_or(0, |sd| sd.population);
+        let best = largest.entry(faction_id).or_insert((e.id, pop));
+        if pop > best.1 {
+            *best = (e.id, pop);
+        }
+        if e.has_active_rel(RelationshipKind::CapitalOf, faction_id) {
+            capitals.insert(faction_id, (e.id, pop));
+        }
+    }
+
+    for (faction_id, (largest_id, largest_pop)) in largest {
+        match capitals.get(&faction_id) {
+            None => {
+                let ev = ctx.world.add_structured_event(
+                    EventPayload::CapitalDesignated {
+                        faction_id,
+                        settlement_id: largest_id,
+                    },
+                    time,
+                    format!(
+                        "{} made {} its capital in year {current_year}",
+                        helpers::entity_name(ctx.world, faction_id),
+                        helpers::entity_name(ctx.world, largest_id)
+                    ),
+                );
+                ctx.world
+                    .add_event_participant(ev, faction_id, ParticipantRole::Subject);
+                ctx.world
+                    .add_event_participant(ev, largest_id, ParticipantRole::Location);
+                designate_capital(ctx.world, faction_id, largest_id, time, ev);
+            }
+            Some(&(capital_id, capital_pop)) => {
+                if largest_id == capital_id
+                    || (largest_pop as f64) < capital_pop as f64 * CAPITAL_DECREE_POPULATION_RATIO
+                {
+                    continue;
+                }
+                let Some(leader_id) = helpers::faction_leader(ctx.world, faction_id) else {
+                    continue;
+                };
+                if ctx.rng.random_range(0.0..1.0) >= CAPITAL_DECREE_CHANCE {
+                    continue;
+                }
+                let ev = ctx.world.add_structured_event(
+                    EventPayload::CapitalMoved {
+                        faction_id,
+                        leader_id,
+                        from_settlement_id: capital_id,
+                        to_settlement_id: largest_id,
+                    },
+                    time,
+                    format!(
+                        "{} decreed the capital of {} moved from {} to {} in year {current_year}",
+                        helpers::entity_name(ctx.world, leader_id),
+                        helpers::entity_name(ctx.world, faction_id),
+                        helpers::entity_name(ctx.world, capital_id),
+                        helpers::entity_name(ctx.world, largest_id)
+                    ),
+                );
+                ctx.world
+                    .add_event_participant(ev, leader_id, ParticipantRole::Instigator);
+                ctx.world
+                    .add_event_participant(ev, faction_id, ParticipantRole::Subject);
+                ctx.world
+                    .add_event_participant(ev, capital_id, ParticipantRole::Origin);
+                ctx.world
+                    .add_event_participant(ev, largest_id, ParticipantRole::Destination);
+                designate_capital(ctx.world, faction_id, largest_id, time, ev);
+            }
+        }
+    }
+}
+
+/// Losing the capital shakes the faction well beyond an ordinary capture and
+/// forces the government to relocate to its largest remaining settlement.
+/// Returns whether `settlement_id` was the capital.
+pub(super) fn handle_capital_captured(
+    world: &mut World,
+    event_id: u64,
+    settlement_id: u64,
+    old_faction_id: u64,
+) -> bool {
+    let was_capital = world
+        .entities
+        .get(&settlement_id)
+        .is_some_and(|e| e.has_active_rel(RelationshipKind::CapitalOf, old_faction_id));
+    if !was_capital {
+        return false;
+    }
+    let time = world.current_time;
+    helpers::apply_stability_delta(world, old_faction_id, CAPITAL_CAPTURED_STABILITY, event_id);
+    if let Some(fd) = world
+        .entities
+        .get_mut(&old_faction_id)
+        .and_then(|e| e.data.as_faction_mut())
+    {
+        fd.legitimacy = (fd.legitimacy + CAPITAL_CAPTURED_LEGITIMACY).clamp(0.0, 1.0);
+    }
+    world.end_relationship(
+        settlement_id,
+        old_faction_id,
+        RelationshipKind::CapitalOf,
+        time,
+        event_id,
+    );
+
+    let Some((new_capital, _)) = helpers::faction_capital_largest(world, old_faction_id) else {
+        return true;
+    };
+    let ev = world.add_caused_structured_event(
+        EventPayload::CapitalRelocated {
+            faction_id: old_faction_id,
+            lost_settlement_id: settlement_id,
+            to_settlement_id: new_capital,
+        },
+        time,
+        format!(
+            "{} fled the fall of {} and relocated its capital to {} in year {}",
+            helpers::entity_name(world, old_faction_id),
+            helpers::entity_name(world, settlement_id),
+            helpers::entity_name(world, new_capital),
+            time.year()
+        ),
+        event_id,
+    );
+    world.add_event_participant(ev, old_faction_id, ParticipantRole::Subject);
+    world.add_event_participant(ev, settlement_id, ParticipantRole::Origin);
+    world.add_event_participant(ev, new_capital, ParticipantRole::Destination);
+    designate_capital(world, old_faction_id, new_capital, time, ev);
+    true
+}
@@ -0,0 +1,62 @@
+use crate::model::World;
+use crate::model::traits::{Trait, has_trait};
+use crate::sim::helpers;
+
+use super::collect_faction_members;
+use super::leadership::REGENCY_AGE;
+
+// --- Council ---
+/// Seats on a ruler's council.
+const COUNCIL_SIZE: usize = 3;
+/// Share of a faction's temperament its council supplies; the ruler supplies
+/// the rest.
+const COUNCIL_WEIGHT: f64 = 0.4;
+
+/// The adult members a ruler takes counsel from: the most prestigious of the
+/// faction, the ruler excluded.
+pub fn faction_council(world: &World, faction_id: u64) -> Vec<u64> {
+    let now = world.current_time;
+    let leader = helpers::faction_leader(world, faction_id);
+    let prestige = |id: u64| {
+        world
+            .entities
+            .get(&id)
+            .and_then(|e| e.data.as_person())
+            .map_or(0.0, |pd| pd.prestige)
+    };
+
+    let mut council: Vec<u64> = collect_faction_members(world, faction_id)
+        .into_iter()
+        .filter(|m| Some(m.id) != leader && now.years_since(m.born) >= REGENCY_AGE)
+        .map(|m| m.id)
+        .collect();
+    council.sort_by(|&a, &b| prestige(b).total_cmp(&prestige(a)).then(a.cmp(&b)));
+    council.truncate(COUNCIL_SIZE);
+    council
+}
+
+/// How strongly a faction's ruling circle leans toward `trait_`, from 0.0 to
+/// 1.0: the ruler's own temperament blended with the share of councillors who
+/// have the trait. A ruler without a council decides alone; a faction without
+/// a ruler has no leaning.
+pub fn council_leaning(world: &World, faction_id: u64, trait_: &Trait) -> f64 {
+    let Some(leader) = helpers::faction_leader_entity(world, faction_id) else {
+        return 0.0;
+    };
+    let ruler = if has_trait(leader, trait_) { 1.0 } else { 0.0 };
+    let council = faction_council(world, faction_id);
+    if council.is_empty() {
+        return ruler;
+    }
+    let share = council
+        .iter()
+        .filter(|&&id| {
+            world
+                .entities
+                .get(&id)
+                .is_some_and(|e| has_trait(e, trait_))
+        })
+        .count() as f64
+        / council.len() as f64;
+    ruler * (1.0 - COUNCIL_WEIGHT) + share * COUNCIL_WEIGHT
+}
@@ -3,18 +3,18 @@ use rand::RngCore;
 
 use crate::model::action::ActionKind;
 use crate::model::traits::{Trait, has_trait};
-use crate::model::{EventKind, ParticipantRole, RelationshipKind, Role, SimTimestamp, World};
+use crate::model::{
+    EventKind, EventPayload, ParticipantRole, RelationshipKind, Role, SimTimestamp, World,
+};
 use crate::sim::context::TickContext;
 use crate::sim::helpers;
 use crate::sim::signal::{Signal, SignalKind};
 
 use crate::sim::helpers::entity_name;
 
-use super::{MemberInfo, collect_faction_members, create_deposed_claims};
+use super::{MemberInfo, PoliticsConfig, collect_faction_members, create_deposed_claims};
 
 // --- Coups ---
-const COUP_STABILITY_THRESHOLD: f64 = 0.55;
-const COUP_BASE_ATTEMPT_CHANCE: f64 = 0.08;
 const COUP_UNHAPPINESS_LOW_FACTOR: f64 = 0.3;
 const COUP_UNHAPPINESS_HIGH_FACTOR: f64 = 0.7;
 const COUP_LEADER_PRESTIGE_ATTEMPT_RESISTANCE: f64 = 0.3;
@@ -45,7 +45,43 @@ const FAILED_COUP_LEGITIMACY_BOOST: f64 = 0.1;
 const FAILED_COUP_EXECUTION_CHANCE: f64 = 0.5;
 const CLAIM_COUP_MULTIPLIER_WEIGHT: f64 = 1.0;
 
-pub(super) fn check_coups(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+impl World {
+    /// Chance this year that someone moves against the leader of `faction_id`,
+    /// under the default [`PoliticsConfig`]. Zero for stable or leaderless
+    /// factions.
+    pub fn coup_risk(&self, faction_id: u64) -> f64 {
+        self.coup_risk_with(faction_id, &PoliticsConfig::default())
+    }
+
+    /// [`coup_risk`](Self::coup_risk) under the given thresholds.
+    pub fn coup_risk_with(&self, faction_id: u64, config: &PoliticsConfig) -> f64 {
+        let Some(fd) = self
+            .entities
+            .get(&faction_id)
+            .and_then(|e| e.data.as_faction())
+        else {
+            return 0.0;
+        };
+        if fd.stability >= config.coup_stability_threshold {
+            return 0.0;
+        }
+        let Some(leader) = helpers::faction_leader_entity(self, faction_id) else {
+            return 0.0;
+        };
+        let leader_prestige = leader.data.as_person().map(|pd| pd.prestige).unwrap_or(0.0);
+        config.coup_base_attempt_chance
+            * (1.0 - fd.stability)
+            * (COUP_UNHAPPINESS_LOW_FACTOR + COUP_UNHAPPINESS_HIGH_FACTOR * (1.0 - fd.happiness))
+            * (1.0 - leader_prestige * COUP_LEADER_PRESTIGE_ATTEMPT_RESISTANCE)
+    }
+}
+
+pub(super) fn check_coups(
+    ctx: &mut TickContext,
+    config: &PoliticsConfig,
+    time: SimTimestamp,
+    current_year: u32,
+) {
     use crate::model::EntityKind;
 
     struct CoupTarget {
@@ -54,28 +90,28 @@ pub(super) fn check_coups(ctx: &mut TickContext, time: SimTimestamp, current_yea
         stability: f64,
         happiness: f64,
         legitimacy: f64,
+        leader_prestige: f64,
+        attempt_chance: f64,
     }
 
     let targets: Vec<CoupTarget> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
+        .living(EntityKind::Faction)
         .filter_map(|e| {
-            let fd = e.data.as_faction()?;
-            let stability = fd.stability;
-            if stability >= COUP_STABILITY_THRESHOLD {
+            let attempt_chance = ctx.world.coup_risk_with(e.id, config);
+            if attempt_chance <= 0.0 {
                 return None;
             }
-            let leader_id = helpers::faction_leader(ctx.world, e.id)?;
-            let happiness = fd.happiness;
-            let legitimacy = fd.legitimacy;
+            let fd = e.data.as_faction()?;
+            let leader = helpers::faction_leader_entity(ctx.world, e.id)?;
             Some(CoupTarget {
                 faction_id: e.id,
-                current_leader_id: leader_id,
-                stability,
-                happiness,
-                legitimacy,
+                current_leader_id: leader.id,
+                stability: fd.stability,
+                happiness: fd.happiness,
+                legitimacy: fd.legitimacy,
+                leader_prestige: leader.data.as_person().map(|pd| pd.prestige).unwrap_or(0.0),
+                attempt_chance,
             })
         })
         .collect();
@@ -91,19 +127,8 @@ pub(super) fn check_coups(ctx: &mut TickContext, time: SimTimestamp, current_yea
 
         // Stage 1: Coup attempt
         let instability = 1.0 - target.stability;
-        let unhappiness_factor = 1.0 - target.happiness;
-        let leader_prestige = ctx
-            .world
-            .entities
-            .get(&target.current_leader_id)
-            .and_then(|e| e.data.as_person())
-            .map(|pd| pd.prestige)
-            .unwrap_or(0.0);
-        let attempt_chance = COUP_BASE_ATTEMPT_CHANCE
-            * instability
-            * (COUP_UNHAPPINESS_LOW_FACTOR + COUP_UNHAPPINESS_HIGH_FACTOR * unhappiness_factor)
-            * (1.0 - leader_prestige * COUP_LEADER_PRESTIGE_ATTEMPT_RESISTANCE);
-        if ctx.rng.random_range(0.0..1.0) >= attempt_chance {
+        let leader_prestige = target.leader_prestige;
+        if ctx.rng.random_range(0.0..1.0) >= target.attempt_chance {
             continue;
         }
 
@@ -303,8 +328,11 @@ pub(super) fn check_coups(ctx: &mut TickContext, time: SimTimestamp, current_yea
 
             // 50% chance coup leader is executed
             if ctx.rng.random_bool(FAILED_COUP_EXECUTION_CHANCE) {
-                let death_ev = ctx.world.add_caused_event(
-                    EventKind::Death,
+                let death_ev = ctx.world.add_caused_structured_event(
+                    EventPayload::Death {
+                        entity_id: instigator_id,
+                        cause: "execution".to_string(),
+                    },
                     time,
                     format!("{instigator_name} was executed in year {current_year}"),
                     ev,
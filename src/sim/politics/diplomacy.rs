@@ -1,8 +1,14 @@
+use std::collections::BTreeSet;
+
 use rand::Rng;
 
-use crate::model::{EntityKind, EventKind, ParticipantRole, RelationshipKind, SimTimestamp, World};
+use crate::model::{
+    Describer, EnglishDescriber, EntityKind, EventKind, EventPayload, ParticipantRole,
+    RelationshipKind, SimTimestamp, World,
+};
 use crate::sim::context::TickContext;
 use crate::sim::grievance as grv;
+use crate::sim::signal::{Signal, SignalKind};
 
 use crate::sim::helpers;
 use crate::sim::helpers::entity_name;
@@ -16,8 +22,26 @@ const ALLIANCE_FORMATION_BASE_RATE: f64 = 0.008;
 const ALLIANCE_SHARED_ENEMY_MULTIPLIER: f64 = 2.0;
 const ALLIANCE_HAPPINESS_WEIGHT: f64 = 0.5;
 const ALLIANCE_PRESTIGE_BONUS_WEIGHT: f64 = 0.3;
+/// Change in alliance formation rate per point of isolationism above neutral.
+const ISOLATIONISM_ALLIANCE_WEIGHT: f64 = -1.5;
 const RIVALRY_FORMATION_BASE_RATE: f64 = 0.006;
 const RIVALRY_INSTABILITY_WEIGHT: f64 = 0.5;
+/// Grievance at which a neutral pair turns enemies without a roll.
+const GRIEVANCE_ENEMY_THRESHOLD: f64 = 0.4;
+/// Minimum trust on both sides for goodwill to upgrade a neutral pair to allies.
+const AMITY_TRUST_THRESHOLD: f64 = 0.95;
+/// Yearly chance that grievance-free trading partners with high trust ally.
+const AMITY_ALLIANCE_CHANCE: f64 = 0.05;
+
+// --- Alliance Drift ---
+/// Grievance between allies at which their alliance starts to strain.
+const ALLIANCE_DRIFT_GRIEVANCE_MIN: f64 = 0.15;
+/// Yearly lapse chance per point of grievance between allies.
+const ALLIANCE_DRIFT_GRIEVANCE_WEIGHT: f64 = 1.0;
+/// Yearly lapse chance when allies follow different faiths.
+const ALLIANCE_DRIFT_RELIGION_CHANCE: f64 = 0.04;
+/// Yearly lapse chance once one ally has left the other to fight alone.
+const ALLIANCE_DRIFT_ABANDONED_CHANCE: f64 = 0.3;
 
 // --- Alliance Strength ---
 const ALLIANCE_BASE_STRENGTH: f64 = 0.1;
@@ -35,6 +59,21 @@ const TRUST_LOW_THRESHOLD: f64 = 0.3;
 const TRUST_DISSOLUTION_WEIGHT: f64 = 0.02;
 const TRUST_STRENGTH_WEIGHT: f64 = 0.3;
 
+// --- Diplomatic Reputation ---
+/// Reputation lost per recorded betrayal of an ally (as a divisor term).
+const REPUTATION_BETRAYAL_WEIGHT: f64 = 0.5;
+/// Reputation lost per broken treaty (as a divisor term).
+const REPUTATION_TREATY_BROKEN_WEIGHT: f64 = 0.25;
+/// Below this reputation no faction will willingly ally with a faction.
+const REPUTATION_ISOLATION_THRESHOLD: f64 = 0.4;
+
+// --- Hegemony / Coalitions ---
+const HEGEMONY_PRESTIGE_WEIGHT: f64 = 5.0;
+const HEGEMONY_TREASURY_DIVISOR: f64 = 100.0;
+/// Leader's hegemony score must be at least this multiple of the runner-up's.
+const HEGEMONY_DOMINANCE_RATIO: f64 = 2.0;
+const COALITION_FORMATION_CHANCE: f64 = 0.15;
+
 // --- Vulnerability ---
 const VULNERABILITY_AT_WAR: f64 = 0.30;
 const VULNERABILITY_PLAGUE: f64 = 0.15;
@@ -42,6 +81,18 @@ const VULNERABILITY_INSTABILITY_WEIGHT: f64 = 0.4;
 const VULNERABILITY_LOW_TREASURY: f64 = 0.10;
 const VULNERABILITY_SINGLE_SETTLEMENT: f64 = 0.10;
 
+// --- Embargoes ---
+/// Grievance at which a faction considers cutting off trade with its target.
+const EMBARGO_GRIEVANCE_MIN: f64 = 0.25;
+/// Above this grievance the conflicts system is already weighing a punitive war.
+const EMBARGO_GRIEVANCE_MAX: f64 = 0.5;
+const EMBARGO_CHANCE: f64 = 0.15;
+/// Grievance the embargoed faction gains against the embargoing one.
+const EMBARGO_TARGET_GRIEVANCE: f64 = 0.10;
+/// Imposer grievance below which an embargo may be lifted.
+const EMBARGO_LIFT_GRIEVANCE: f64 = 0.15;
+const EMBARGO_LIFT_CHANCE: f64 = 0.25;
+
 use super::STABILITY_DEFAULT;
 
 pub(super) fn update_diplomacy(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
@@ -59,13 +110,8 @@ pub(super) fn update_diplomacy(ctx: &mut TickContext, time: SimTimestamp, curren
 
     let factions: Vec<FactionDiplo> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| {
-            e.kind == EntityKind::Faction
-                && e.end.is_none()
-                && !helpers::is_non_state_faction(ctx.world, e.id)
-        })
+        .living(EntityKind::Faction)
+        .filter(|e| !helpers::is_non_state_faction(ctx.world, e.id))
         .map(|e| {
             let ally_count = e.active_rels(RelationshipKind::Ally).count() as u32;
             let fd = e.data.as_faction();
@@ -81,6 +127,12 @@ pub(super) fn update_diplomacy(ctx: &mut TickContext, time: SimTimestamp, curren
 
     let faction_ids: Vec<u64> = factions.iter().map(|f| f.id).collect();
 
+    // Factions only deal with those they have met
+    discover_contacts(ctx, &faction_ids, time);
+
+    // Strained alliances lapse peacefully before anything else is decided
+    lapse_strained_alliances(ctx, time, current_year);
+
     // Check for dissolution of existing relationships
     struct EndAction {
         source_id: u64,
@@ -171,7 +223,9 @@ pub(super) fn update_diplomacy(ctx: &mut TickContext, time: SimTimestamp, curren
             let a = &factions[i];
             let b = &factions[j];
 
-            if has_active_diplomatic_rel(ctx.world, a.id, b.id) {
+            if has_active_diplomatic_rel(ctx.world, a.id, b.id)
+                || !helpers::have_contact(ctx.world, a.id, b.id)
+            {
                 continue;
             }
 
@@ -199,17 +253,45 @@ pub(super) fn update_diplomacy(ctx: &mut TickContext, time: SimTimestamp, curren
             let trust_a = get_diplomatic_trust(ctx.world, a.id);
             let trust_b = get_diplomatic_trust(ctx.world, b.id);
             let min_trust = trust_a.min(trust_b);
+            // Everyone reads the worse partner's public record
+            let min_reputation =
+                diplomatic_reputation(ctx.world, a.id).min(diplomatic_reputation(ctx.world, b.id));
+            let isolated = min_reputation < REPUTATION_ISOLATION_THRESHOLD;
 
             // Mutual grievance dampens alliance formation and boosts rivalry
             let mutual_grievance = grv::get_grievance(ctx.world, a.id, b.id)
                 .max(grv::get_grievance(ctx.world, b.id, a.id));
+
+            // Stance shifts: a deep enough grudge makes enemies outright, while
+            // grievance-free trading partners who trust each other may ally
+            if mutual_grievance >= GRIEVANCE_ENEMY_THRESHOLD {
+                new_rels.push(NewRelAction {
+                    source_id: a.id,
+                    target_id: b.id,
+                    kind: RelationshipKind::Enemy,
+                });
+                continue;
+            }
+            if mutual_grievance == 0.0
+                && !isolated
+                && min_trust >= AMITY_TRUST_THRESHOLD
+                && are_trade_partners(ctx.world, a.id, b.id)
+                && ctx.rng.random_bool(AMITY_ALLIANCE_CHANCE)
+            {
+                new_rels.push(NewRelAction {
+                    source_id: a.id,
+                    target_id: b.id,
+                    kind: RelationshipKind::Ally,
+                });
+                continue;
+            }
             let grievance_alliance_factor = if mutual_grievance > 0.15 {
                 (1.0 - mutual_grievance).max(0.0)
             } else {
                 1.0
             };
 
-            let alliance_rate = if min_trust < TRUST_LOW_THRESHOLD {
+            let alliance_rate = if min_trust < TRUST_LOW_THRESHOLD || isolated {
                 0.0 // Too untrustworthy for alliance
             } else {
                 ALLIANCE_FORMATION_BASE_RATE
@@ -218,7 +300,20 @@ pub(super) fn update_diplomacy(ctx: &mut TickContext, time: SimTimestamp, curren
                     * alliance_cap
                     * (1.0 + avg_prestige * ALLIANCE_PRESTIGE_BONUS_WEIGHT)
                     * min_trust
+                    * min_reputation
                     * grievance_alliance_factor
+                    * helpers::policy_factor(
+                        ctx.world,
+                        a.id,
+                        |fd| fd.isolationism,
+                        ISOLATIONISM_ALLIANCE_WEIGHT,
+                    )
+                    * helpers::policy_factor(
+                        ctx.world,
+                        b.id,
+                        |fd| fd.isolationism,
+                        ISOLATIONISM_ALLIANCE_WEIGHT,
+                    )
             };
 
             let avg_instability = (1.0 - a.stability + 1.0 - b.stability) / 2.0;
@@ -266,6 +361,390 @@ pub(super) fn update_diplomacy(ctx: &mut TickContext, time: SimTimestamp, curren
         ctx.world
             .add_relationship(rel.source_id, rel.target_id, rel.kind, time, ev);
     }
+
+    lift_embargoes(ctx, time, current_year);
+    impose_embargoes(ctx, &faction_ids, time, current_year);
+
+    check_coalition_against_hegemon(ctx, &faction_ids, time, current_year);
+}
+
+/// Establish contact between every pair of factions that can now reach each
+/// other, or that already deal with each other, but have not met before.
+fn discover_contacts(ctx: &mut TickContext, faction_ids: &[u64], time: SimTimestamp) {
+    let mut first_contacts: Vec<(u64, u64)> = Vec::new();
+    for (i, &a) in faction_ids.iter().enumerate() {
+        for &b in &faction_ids[i + 1..] {
+            if !helpers::have_contact(ctx.world, a, b) && can_make_contact(ctx.world, a, b) {
+                first_contacts.push((a, b));
+            }
+        }
+    }
+
+    for (a, b) in first_contacts {
+        let payload = EventPayload::FirstContact {
+            faction_a: a,
+            faction_b: b,
+        };
+        let description = EnglishDescriber
+            .describe_payload(ctx.world, &payload, time)
+            .expect("first contact has an English template");
+        let ev = ctx.world.add_structured_event(payload, time, description);
+        ctx.world
+            .add_event_participant(ev, a, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, b, ParticipantRole::Object);
+        establish_contact(ctx.world, a, b, time, ev);
+    }
+}
+
+/// Whether two factions are in a position to meet: they can reach each other,
+/// or already deal with each other (diplomatic ties or grievances).
+pub(crate) fn can_make_contact(world: &World, a: u64, b: u64) -> bool {
+    has_active_diplomatic_rel(world, a, b)
+        || has_active_diplomatic_rel(world, b, a)
+        || grv::get_grievance(world, a, b) > 0.0
+        || grv::get_grievance(world, b, a) > 0.0
+        || helpers::factions_can_reach(world, a, b)
+}
+
+/// Record contact between two factions in both directions.
+pub(crate) fn establish_contact(
+    world: &mut World,
+    a: u64,
+    b: u64,
+    time: SimTimestamp,
+    event_id: u64,
+) {
+    world.add_relationship(a, b, RelationshipKind::KnownTo, time, event_id);
+    world.add_relationship(b, a, RelationshipKind::KnownTo, time, event_id);
+}
+
+/// A faction nursing a grievance that doesn't (yet) justify war may instead cut
+/// off trade with its target. The economy system severs the routes on the signal.
+fn impose_embargoes(
+    ctx: &mut TickContext,
+    faction_ids: &[u64],
+    time: SimTimestamp,
+    current_year: u32,
+) {
+    let mut embargoes: Vec<(u64, u64)> = Vec::new();
+    for &fid in faction_ids {
+        let Some(fd) = ctx
+            .world
+            .entities
+            .get(&fid)
+            .and_then(|e| e.data.as_faction())
+        else {
+            continue;
+        };
+        for (&target, &routes) in &fd.trade_partner_routes {
+            if routes == 0 || !faction_ids.contains(&target) {
+                continue;
+            }
+            let grievance = grv::get_grievance(ctx.world, fid, target);
+            if !(EMBARGO_GRIEVANCE_MIN..EMBARGO_GRIEVANCE_MAX).contains(&grievance) {
+                continue;
+            }
+            let blocked = [
+                RelationshipKind::Ally,
+                RelationshipKind::AtWar,
+                RelationshipKind::Embargo,
+            ]
+            .into_iter()
+            .any(|kind| helpers::has_active_rel_of_kind(ctx.world, fid, target, kind));
+            if !blocked && !embargoes.contains(&(target, fid)) {
+                embargoes.push((fid, target));
+            }
+        }
+    }
+
+    for (fid, target) in embargoes {
+        if ctx.rng.random_range(0.0..1.0) >= EMBARGO_CHANCE {
+            continue;
+        }
+        let name_a = entity_name(ctx.world, fid);
+        let name_b = entity_name(ctx.world, target);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::EmbargoImposed {
+                faction_id: fid,
+                target_id: target,
+            },
+            time,
+            format!("{name_a} imposed a trade embargo on {name_b} in year {current_year}"),
+        );
+        ctx.world
+            .add_event_participant(ev, fid, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, target, ParticipantRole::Object);
+        ctx.world
+            .add_relationship(fid, target, RelationshipKind::Embargo, time, ev);
+        grv::add_grievance(
+            ctx.world,
+            target,
+            fid,
+            EMBARGO_TARGET_GRIEVANCE,
+            "embargo",
+            time,
+            ev,
+        );
+        ctx.signals.push(Signal {
+            event_id: ev,
+            kind: SignalKind::EmbargoImposed {
+                faction_id: fid,
+                target_id: target,
+            },
+        });
+    }
+}
+
+/// Why an alliance is straining, if it is: rising grievance between the
+/// allies, a broken expectation (one left the other to fight alone), or
+/// faiths that have drifted apart. Returns the strongest cause and the yearly
+/// chance the alliance lapses, before its strength is taken into account.
+fn alliance_strain(world: &World, a: u64, b: u64) -> Option<(&'static str, f64)> {
+    let grievance = grv::get_grievance(world, a, b).max(grv::get_grievance(world, b, a));
+    let abandoned = |holder: u64, target: u64| {
+        world
+            .entities
+            .get(&holder)
+            .and_then(|e| e.data.as_faction())
+            .and_then(|fd| fd.grievances.get(&target))
+            .is_some_and(|g| g.sources.iter().any(|s| s == "ally_abandoned"))
+    };
+    let religion = |id: u64| {
+        world
+            .entities
+            .get(&id)
+            .and_then(|e| e.data.as_faction())
+            .and_then(|fd| fd.primary_religion)
+    };
+    let faiths_differ = matches!((religion(a), religion(b)), (Some(ra), Some(rb)) if ra != rb);
+
+    let mut strains = Vec::new();
+    if abandoned(a, b) || abandoned(b, a) {
+        strains.push(("broken_expectation", ALLIANCE_DRIFT_ABANDONED_CHANCE));
+    }
+    if grievance >= ALLIANCE_DRIFT_GRIEVANCE_MIN {
+        strains.push(("grievance", grievance * ALLIANCE_DRIFT_GRIEVANCE_WEIGHT));
+    }
+    if faiths_differ {
+        strains.push(("religion", ALLIANCE_DRIFT_RELIGION_CHANCE));
+    }
+    let cause = strains.iter().max_by(|x, y| x.1.total_cmp(&y.1))?.0;
+    let chance = strains.iter().map(|(_, c)| c).sum::<f64>().min(1.0);
+    Some((cause, chance))
+}
+
+/// Allies that have drifted apart let their alliance lapse to neutrality
+/// without a war. Strong alliances hold together longer.
+fn lapse_strained_alliances(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    let pairs: BTreeSet<(u64, u64)> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .flat_map(|e| {
+            e.active_rels(RelationshipKind::Ally)
+                .map(move |target| (e.id.min(target), e.id.max(target)))
+        })
+        .collect();
+
+    for (a, b) in pairs {
+        if helpers::has_active_rel_of_kind(ctx.world, a, b, RelationshipKind::AtWar) {
+            continue;
+        }
+        let Some((cause, strain)) = alliance_strain(ctx.world, a, b) else {
+            continue;
+        };
+        let strength = calculate_alliance_strength(ctx.world, a, b);
+        let chance = strain * (1.0 - strength).max(0.0);
+        if ctx.rng.random_range(0.0..1.0) >= chance {
+            continue;
+        }
+
+        let name_a = entity_name(ctx.world, a);
+        let name_b = entity_name(ctx.world, b);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::AllianceDissolved {
+                faction_a: a,
+                faction_b: b,
+                cause: cause.to_string(),
+            },
+            time,
+            format!("{name_a} and {name_b} let their alliance lapse in year {current_year}"),
+        );
+        ctx.world
+            .add_event_participant(ev, a, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, b, ParticipantRole::Object);
+        for (source, target) in [(a, b), (b, a)] {
+            if ctx.world.entities[&source].has_active_rel(RelationshipKind::Ally, target) {
+                ctx.world
+                    .end_relationship(source, target, RelationshipKind::Ally, time, ev);
+            }
+        }
+    }
+}
+
+/// Embargoes lapse once the imposer's grievance has cooled, or immediately if
+/// the target is gone.
+fn lift_embargoes(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    let embargoes: Vec<(u64, u64)> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .flat_map(|e| {
+            e.active_rels(RelationshipKind::Embargo)
+                .map(move |target| (e.id, target))
+        })
+        .collect();
+
+    for (fid, target) in embargoes {
+        let target_gone = ctx
+            .world
+            .entities
+            .get(&target)
+            .is_none_or(|e| e.end.is_some());
+        if !target_gone {
+            if grv::get_grievance(ctx.world, fid, target) >= EMBARGO_LIFT_GRIEVANCE {
+                continue;
+            }
+            if ctx.rng.random_range(0.0..1.0) >= EMBARGO_LIFT_CHANCE {
+                continue;
+            }
+        }
+        let name_a = entity_name(ctx.world, fid);
+        let name_b = entity_name(ctx.world, target);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::EmbargoLifted {
+                faction_id: fid,
+                target_id: target,
+            },
+            time,
+            format!("{name_a} lifted its trade embargo on {name_b} in year {current_year}"),
+        );
+        ctx.world
+            .add_event_participant(ev, fid, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, target, ParticipantRole::Object);
+        ctx.world
+            .end_relationship(fid, target, RelationshipKind::Embargo, time, ev);
+    }
+}
+
+/// Hegemony score used to detect an overgrown power: settlements held plus
+/// weighted prestige and treasury.
+fn hegemony_score(world: &World, faction_id: u64) -> f64 {
+    let settlements = helpers::faction_settlements(world, faction_id).len() as f64;
+    let (prestige, treasury) = world
+        .entities
+        .get(&faction_id)
+        .and_then(|e| e.data.as_faction())
+        .map(|fd| (fd.prestige, fd.treasury.max(0.0)))
+        .unwrap_or((0.0, 0.0));
+    settlements + prestige * HEGEMONY_PRESTIGE_WEIGHT + treasury / HEGEMONY_TREASURY_DIVISOR
+}
+
+/// When one faction's hegemony score dwarfs everyone else's, the remaining
+/// factions may band together: they ally with each other and declare the
+/// hegemon their enemy.
+fn check_coalition_against_hegemon(
+    ctx: &mut TickContext,
+    faction_ids: &[u64],
+    time: SimTimestamp,
+    current_year: u32,
+) {
+    if faction_ids.len() < 3 {
+        return;
+    }
+
+    let mut scores: Vec<(u64, f64)> = faction_ids
+        .iter()
+        .map(|&fid| (fid, hegemony_score(ctx.world, fid)))
+        .collect();
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    let (hegemon, top) = scores[0];
+    let runner_up = scores[1].1;
+    if top < runner_up.max(1.0) * HEGEMONY_DOMINANCE_RATIO {
+        return;
+    }
+
+    // Factions allied with or already fighting the hegemon stay out of it, as
+    // do those that have never met it
+    let members: Vec<u64> = faction_ids
+        .iter()
+        .copied()
+        .filter(|&fid| fid != hegemon)
+        .filter(|&fid| {
+            helpers::have_contact(ctx.world, fid, hegemon)
+                && !helpers::has_active_rel_of_kind(ctx.world, fid, hegemon, RelationshipKind::Ally)
+                && !helpers::has_active_rel_of_kind(
+                    ctx.world,
+                    fid,
+                    hegemon,
+                    RelationshipKind::AtWar,
+                )
+        })
+        .collect();
+    if members.len() < 2 {
+        return;
+    }
+
+    let new_enemies: Vec<u64> = members
+        .iter()
+        .copied()
+        .filter(|&fid| !has_active_diplomatic_rel(ctx.world, fid, hegemon))
+        .collect();
+    let mut new_alliances: Vec<(u64, u64)> = Vec::new();
+    for (i, &a) in members.iter().enumerate() {
+        for &b in &members[i + 1..] {
+            if !has_active_diplomatic_rel(ctx.world, a, b) && helpers::have_contact(ctx.world, a, b)
+            {
+                new_alliances.push((a, b));
+            }
+        }
+    }
+    if new_enemies.is_empty() && new_alliances.is_empty() {
+        return;
+    }
+
+    if ctx.rng.random_range(0.0..1.0) >= COALITION_FORMATION_CHANCE {
+        return;
+    }
+
+    let hegemon_name = entity_name(ctx.world, hegemon);
+    let member_names: Vec<String> = members
+        .iter()
+        .map(|&fid| entity_name(ctx.world, fid))
+        .collect();
+    let ev = ctx.world.add_event(
+        EventKind::Alliance,
+        time,
+        format!(
+            "{} formed a coalition against {hegemon_name} in year {current_year}",
+            member_names.join(", ")
+        ),
+    );
+    for &fid in &members {
+        ctx.world
+            .add_event_participant(ev, fid, ParticipantRole::Subject);
+    }
+    ctx.world
+        .add_event_participant(ev, hegemon, ParticipantRole::Object);
+
+    for (a, b) in new_alliances {
+        ctx.world
+            .add_relationship(a, b, RelationshipKind::Ally, time, ev);
+    }
+    for fid in new_enemies {
+        ctx.world
+            .add_relationship(fid, hegemon, RelationshipKind::Enemy, time, ev);
+    }
+
+    ctx.signals.push(Signal {
+        event_id: ev,
+        kind: SignalKind::CoalitionFormed {
+            hegemon_id: hegemon,
+            member_ids: members,
+        },
+    });
 }
 
 fn has_shared_enemy(world: &World, a: u64, b: u64) -> bool {
@@ -289,6 +768,18 @@ fn has_shared_enemy(world: &World, a: u64, b: u64) -> bool {
         .unwrap_or(false)
 }
 
+/// Whether both factions currently trade with each other.
+fn are_trade_partners(world: &World, a: u64, b: u64) -> bool {
+    let trades_with = |from: u64, to: u64| {
+        world
+            .entities
+            .get(&from)
+            .and_then(|e| e.data.as_faction())
+            .is_some_and(|fd| fd.trade_partner_routes.contains_key(&to))
+    };
+    trades_with(a, b) && trades_with(b, a)
+}
+
 fn has_active_diplomatic_rel(world: &World, a: u64, b: u64) -> bool {
     if let Some(entity) = world.entities.get(&a) {
         for rel in &entity.relationships {
@@ -390,6 +881,22 @@ pub(crate) fn get_diplomatic_trust(world: &World, faction_id: u64) -> f64 {
         .unwrap_or(TRUST_DEFAULT)
 }
 
+/// Public diplomatic reputation of a faction: 1.0 for a clean record, falling
+/// with every recorded betrayal and broken treaty. Unlike `diplomatic_trust` it
+/// never recovers, so a serial betrayer ends up isolated.
+pub fn diplomatic_reputation(world: &World, faction_id: u64) -> f64 {
+    let Some(fd) = world
+        .entities
+        .get(&faction_id)
+        .and_then(|e| e.data.as_faction())
+    else {
+        return 1.0;
+    };
+    1.0 / (1.0
+        + fd.betrayal_count as f64 * REPUTATION_BETRAYAL_WEIGHT
+        + fd.treaties_broken as f64 * REPUTATION_TREATY_BROKEN_WEIGHT)
+}
+
 /// Compute how vulnerable an ally faction is (0.0-1.0).
 /// Values >= VULNERABILITY_THRESHOLD make betrayal worth considering.
 pub(crate) fn compute_ally_vulnerability(world: &World, ally_id: u64) -> f64 {
@@ -432,13 +939,8 @@ pub(crate) fn compute_ally_vulnerability(world: &World, ally_id: u64) -> f64 {
 
     // Only one settlement
     let settlement_count = world
-        .entities
-        .values()
-        .filter(|e| {
-            e.kind == EntityKind::Settlement
-                && e.end.is_none()
-                && e.has_active_rel(RelationshipKind::MemberOf, ally_id)
-        })
+        .living(EntityKind::Settlement)
+        .filter(|e| e.has_active_rel(RelationshipKind::MemberOf, ally_id))
         .count();
     if settlement_count <= 1 {
         vuln += VULNERABILITY_SINGLE_SETTLEMENT;
@@ -498,7 +1000,7 @@ mod tests {
 
         // Run politics for a few years so trust drifts
         for _ in 0..5 {
-            testutil::tick_system(&mut world, &mut PoliticsSystem, 100, 42);
+            testutil::tick_system(&mut world, &mut PoliticsSystem::new(), 100, 42);
         }
 
         let trust = get_diplomatic_trust(&world, setup.faction);
@@ -518,7 +1020,7 @@ mod tests {
 
         // Run many ticks — alliance should never form due to low trust
         for _ in 0..50 {
-            testutil::tick_system(&mut world, &mut PoliticsSystem, 100, 42);
+            testutil::tick_system(&mut world, &mut PoliticsSystem::new(), 100, 42);
         }
 
         let has_alliance = world.entities[&setup_a.faction]
@@ -527,6 +1029,52 @@ mod tests {
         assert!(!has_alliance, "low-trust faction should not form alliances");
     }
 
+    #[test]
+    fn scenario_serial_betrayer_attracts_fewer_alliances() {
+        let count_alliances = |world: &World, faction: u64| {
+            world
+                .events
+                .values()
+                .filter(|e| e.kind == EventKind::Treaty)
+                .filter(|e| {
+                    world
+                        .event_participants
+                        .iter()
+                        .any(|p| p.event_id == e.id && p.entity_id == faction)
+                })
+                .count()
+        };
+
+        let (mut betrayer_total, mut clean_total) = (0, 0);
+        for seed in 0..10 {
+            let mut s = Scenario::at_year(100);
+            let betrayer = s
+                .add_settlement_standalone_with("Turncoat", |f| f.betrayal_count = 4, |_| {})
+                .faction;
+            let clean = s.add_settlement_standalone("Steadfast").faction;
+            let mut factions = vec![betrayer, clean];
+            for i in 0..6 {
+                factions.push(
+                    s.add_settlement_standalone(&format!("Neighbor {i}"))
+                        .faction,
+                );
+            }
+            make_all_contacts(&mut s, &factions);
+            let mut world = s.build();
+            for year in 100..150 {
+                testutil::tick_system(&mut world, &mut PoliticsSystem::new(), year, seed);
+            }
+            betrayer_total += count_alliances(&world, betrayer);
+            clean_total += count_alliances(&world, clean);
+        }
+
+        assert!(clean_total > 0, "clean faction should find allies");
+        assert!(
+            betrayer_total < clean_total,
+            "betrayer {betrayer_total} vs clean {clean_total}"
+        );
+    }
+
     #[test]
     fn scenario_compute_ally_vulnerability() {
         let mut s = Scenario::at_year(100);
@@ -564,4 +1112,353 @@ mod tests {
             "weak faction should be vulnerable: {weak_vuln}"
         );
     }
+
+    fn hegemon_scenario() -> (Scenario, u64, Vec<u64>) {
+        let mut s = Scenario::at_year(100);
+        let hegemon = s.add_settlement_standalone_with(
+            "Empire",
+            |f| {
+                f.prestige = 0.8;
+                f.treasury = 1000.0;
+            },
+            |_| {},
+        );
+        for i in 0..4 {
+            s.add_settlement(
+                &format!("Imperial Town {i}"),
+                hegemon.faction,
+                hegemon.region,
+            );
+        }
+        let minors: Vec<u64> = (0..3)
+            .map(|i| s.add_settlement_standalone(&format!("Minor {i}")).faction)
+            .collect();
+        let mut known = minors.clone();
+        known.push(hegemon.faction);
+        make_all_contacts(&mut s, &known);
+        (s, hegemon.faction, minors)
+    }
+
+    /// Put every pair of the given factions in contact.
+    fn make_all_contacts(s: &mut Scenario, factions: &[u64]) {
+        for (i, &a) in factions.iter().enumerate() {
+            for &b in &factions[i + 1..] {
+                s.make_contact(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn scenario_dominant_faction_has_highest_hegemony_score() {
+        let (s, hegemon, minors) = hegemon_scenario();
+        let world = s.build();
+        let top = hegemony_score(&world, hegemon);
+        for minor in minors {
+            assert!(hegemony_score(&world, minor) * HEGEMONY_DOMINANCE_RATIO <= top);
+        }
+    }
+
+    #[test]
+    fn scenario_dominant_faction_attracts_coalition() {
+        let mut formed = 0;
+        for seed in 0..10 {
+            let (s, hegemon, minors) = hegemon_scenario();
+            let mut world = s.build();
+            let mut signals = Vec::new();
+            for year in 100..130 {
+                signals.extend(testutil::tick_system(
+                    &mut world,
+                    &mut PoliticsSystem::new(),
+                    year,
+                    seed * 1000 + year as u64,
+                ));
+            }
+            let coalition = signals.iter().any(|sig| {
+                matches!(&sig.kind, SignalKind::CoalitionFormed { hegemon_id, .. } if *hegemon_id == hegemon)
+            });
+            if coalition {
+                formed += 1;
+                let enemies = minors
+                    .iter()
+                    .filter(|&&m| {
+                        world.entities[&m]
+                            .active_rels(RelationshipKind::Enemy)
+                            .any(|t| t == hegemon)
+                    })
+                    .count();
+                assert!(enemies > 0, "coalition members should oppose the hegemon");
+            }
+        }
+        assert!(
+            formed > 0,
+            "expected a coalition against the hegemon across seeds"
+        );
+    }
+
+    /// Two trading partners, the first holding a grievance against the second.
+    fn embargo_scenario(grievance: f64) -> (crate::model::World, u64, u64, u64, u64) {
+        use crate::model::entity_data::{ResourceType, TradeRoute};
+        let mut s = Scenario::at_year(100);
+        let a = s.add_settlement_standalone_with(
+            "Aldor",
+            |f| f.treasury = 100.0,
+            |sd| {
+                sd.population = 500;
+                sd.resources = vec![ResourceType::Grain];
+            },
+        );
+        let b = s.add_rival_settlement_with(
+            "Brell",
+            a.region,
+            |f| f.treasury = 100.0,
+            |sd| {
+                sd.population = 500;
+                sd.resources = vec![ResourceType::Iron];
+            },
+        );
+        s.make_trade_route(a.settlement, b.settlement);
+        for (from, to, resource) in [
+            (a.settlement, b.settlement, "grain"),
+            (b.settlement, a.settlement, "iron"),
+        ] {
+            s.modify_settlement(from, |sd| {
+                sd.trade_routes.push(TradeRoute {
+                    target: to,
+                    path: vec![],
+                    distance: 1,
+                    resource: resource.to_string(),
+                    security: 0.0,
+                })
+            });
+        }
+        s.modify_faction(a.faction, |fd| {
+            fd.trade_partner_routes.insert(b.faction, 1);
+        });
+        s.modify_faction(b.faction, |fd| {
+            fd.trade_partner_routes.insert(a.faction, 1);
+        });
+        s.add_grievance(a.faction, b.faction, grievance);
+        (s.build(), a.faction, b.faction, a.settlement, b.settlement)
+    }
+
+    fn trade_income(world: &crate::model::World, settlement: u64) -> f64 {
+        world
+            .entity(settlement)
+            .data
+            .as_settlement()
+            .unwrap()
+            .trade_income
+    }
+
+    #[test]
+    fn scenario_embargo_cuts_trade_income_for_both_sides() {
+        use crate::sim::EconomySystem;
+
+        let (mut world, fa, fb, sa, sb) = embargo_scenario(0.35);
+        testutil::tick_system(&mut world, &mut EconomySystem, 100, 1);
+        let (before_a, before_b) = (trade_income(&world, sa), trade_income(&world, sb));
+        assert!(before_a > 0.0 && before_b > 0.0, "partners should trade");
+        let treasury_before = world.entity(fb).data.as_faction().unwrap().treasury;
+
+        let mut year = 100;
+        let signals = loop {
+            // Ongoing friction keeps the grievance simmering below the war threshold
+            if let Some(g) = world.faction_mut(fa).grievances.get_mut(&fb) {
+                g.severity = 0.35;
+            }
+            let signals =
+                testutil::tick_system(&mut world, &mut PoliticsSystem::new(), year, year as u64);
+            if signals
+                .iter()
+                .any(|sig| matches!(sig.kind, SignalKind::EmbargoImposed { .. }))
+            {
+                break signals;
+            }
+            year += 1;
+            assert!(
+                year < 150,
+                "grievance below the war threshold should embargo"
+            );
+        };
+        assert!(world.entities[&fa].has_active_rel(RelationshipKind::Embargo, fb));
+        assert!(
+            world
+                .events
+                .values()
+                .any(|e| e.kind == EventKind::EmbargoImposed)
+        );
+        assert!(grv::get_grievance(&world, fb, fa) > 0.0);
+
+        testutil::deliver_signals(&mut world, &mut EconomySystem, &signals, 42);
+        assert!(world.entity(fb).data.as_faction().unwrap().treasury < treasury_before);
+        testutil::tick_system(&mut world, &mut EconomySystem, year, 1);
+        assert!(trade_income(&world, sa) < before_a);
+        assert!(trade_income(&world, sb) < before_b);
+    }
+
+    #[test]
+    fn scenario_no_embargo_without_grievance() {
+        let (mut world, fa, fb, _, _) = embargo_scenario(0.0);
+        for year in 100..130 {
+            testutil::tick_system(&mut world, &mut PoliticsSystem::new(), year, year as u64);
+        }
+        assert!(!helpers::has_active_rel_of_kind(
+            &world,
+            fa,
+            fb,
+            RelationshipKind::Embargo
+        ));
+    }
+
+    #[test]
+    fn scenario_embargo_lifted_once_grievance_cools() {
+        let (mut world, fa, fb, _, _) = embargo_scenario(0.05);
+        let ev = world.add_event(
+            EventKind::EmbargoImposed,
+            world.current_time,
+            "embargo".to_string(),
+        );
+        world.add_relationship(fa, fb, RelationshipKind::Embargo, world.current_time, ev);
+        for year in 100..130 {
+            testutil::tick_system(&mut world, &mut PoliticsSystem::new(), year, year as u64);
+        }
+        assert!(!world.entities[&fa].has_active_rel(RelationshipKind::Embargo, fb));
+        assert!(
+            world
+                .events
+                .values()
+                .any(|e| e.kind == EventKind::EmbargoLifted)
+        );
+    }
+
+    #[test]
+    fn scenario_betrayal_grievance_turns_neutral_factions_into_enemies() {
+        let mut s = Scenario::at_year(100);
+        let a = s.add_settlement_standalone("Aldor");
+        let b = s.add_settlement_standalone("Brell");
+        let mut world = s.build();
+
+        let mut year = 100;
+        while !helpers::has_active_rel_of_kind(
+            &world,
+            a.faction,
+            b.faction,
+            RelationshipKind::Enemy,
+        ) {
+            // Brell keeps breaking its word
+            let ev = world.add_event(
+                EventKind::Betrayal,
+                SimTimestamp::from_year(year),
+                "Brell broke its word".to_string(),
+            );
+            grv::add_grievance(
+                &mut world,
+                a.faction,
+                b.faction,
+                0.15,
+                "betrayal",
+                SimTimestamp::from_year(year),
+                ev,
+            );
+            testutil::tick_system(&mut world, &mut PoliticsSystem::new(), year, year as u64);
+            year += 1;
+            assert!(year < 105, "accumulated betrayals should make enemies");
+        }
+        assert!(!helpers::has_active_rel_of_kind(
+            &world,
+            a.faction,
+            b.faction,
+            RelationshipKind::Ally
+        ));
+    }
+
+    #[test]
+    fn scenario_grievance_free_trading_partners_ally() {
+        let mut s = Scenario::at_year(100);
+        let a = s.add_settlement_standalone("Aldor");
+        let b = s.add_settlement_standalone("Brell");
+        s.modify_faction(a.faction, |fd| {
+            fd.trade_partner_routes.insert(b.faction, 1);
+        });
+        s.modify_faction(b.faction, |fd| {
+            fd.trade_partner_routes.insert(a.faction, 1);
+        });
+        let mut world = s.build();
+
+        for year in 100..200 {
+            testutil::tick_system(&mut world, &mut PoliticsSystem::new(), year, year as u64);
+            if helpers::has_active_rel_of_kind(&world, a.faction, b.faction, RelationshipKind::Ally)
+            {
+                return;
+            }
+        }
+        panic!("trusting trade partners without grievances should eventually ally");
+    }
+
+    #[test]
+    fn scenario_aggrieved_allies_drift_to_neutral_without_war() {
+        let mut s = Scenario::at_year(100);
+        let a = s.add_settlement_standalone("Aldor");
+        let b = s.add_settlement_standalone("Brell");
+        s.make_allies(a.faction, b.faction);
+        // A moderate grudge: short of turning the allies into enemies
+        s.add_grievance(a.faction, b.faction, 0.3);
+        let mut world = s.build();
+
+        for year in 100..115 {
+            testutil::tick_system(&mut world, &mut PoliticsSystem::new(), year, year as u64);
+            if !helpers::has_active_rel_of_kind(
+                &world,
+                a.faction,
+                b.faction,
+                RelationshipKind::Ally,
+            ) {
+                break;
+            }
+        }
+
+        assert!(
+            !helpers::has_active_rel_of_kind(&world, a.faction, b.faction, RelationshipKind::Ally),
+            "aggrieved allies should drift apart"
+        );
+        let lapse = world
+            .events
+            .values()
+            .find(|e| e.kind == EventKind::AllianceDissolved)
+            .expect("alliance should lapse with an AllianceDissolved event");
+        assert_eq!(
+            EventPayload::from_data(&lapse.data),
+            Some(EventPayload::AllianceDissolved {
+                faction_a: a.faction,
+                faction_b: b.faction,
+                cause: "grievance".to_string(),
+            })
+        );
+        for kind in [RelationshipKind::AtWar, RelationshipKind::Enemy] {
+            assert!(!helpers::has_active_rel_of_kind(
+                &world, a.faction, b.faction, kind
+            ));
+        }
+    }
+
+    #[test]
+    fn alliance_strain_names_its_strongest_cause() {
+        let mut s = Scenario::at_year(100);
+        let a = s.add_settlement_standalone("Aldor").faction;
+        let b = s.add_settlement_standalone("Brell").faction;
+        s.make_allies(a, b);
+        let world = s.build();
+        assert_eq!(alliance_strain(&world, a, b), None);
+
+        let mut s = Scenario::at_year(100);
+        let a = s.add_settlement_standalone("Aldor").faction;
+        let b = s.add_settlement_standalone("Brell").faction;
+        s.modify_faction(a, |fd| fd.primary_religion = Some(900));
+        s.modify_faction(b, |fd| fd.primary_religion = Some(901));
+        let world = s.build();
+        assert_eq!(
+            alliance_strain(&world, a, b),
+            Some(("religion", ALLIANCE_DRIFT_RELIGION_CHANCE))
+        );
+    }
 }
@@ -0,0 +1,151 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::model::{
+    DynastyData, EntityData, EntityKind, EventPayload, ParticipantRole, RelationshipKind,
+    SimTimestamp, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::helpers::entity_name;
+use crate::sim::names::extract_surname;
+
+use super::children_of;
+
+/// Keep ruling houses in step with who holds power: found a dynasty for any
+/// leader without one, record each faction a dynasty comes to rule, and mark
+/// dynasties extinct once no living member remains.
+pub(crate) fn update_dynasties(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    // Living leaders: (person, faction, dynasty)
+    let leaders: Vec<(u64, u64, Option<u64>)> = ctx
+        .world
+        .living(EntityKind::Person)
+        .flat_map(|e| {
+            let dynasty_id = e.data.as_person().and_then(|pd| pd.dynasty_id);
+            e.active_rels(RelationshipKind::LeaderOf)
+                .filter(|&fid| {
+                    ctx.world
+                        .entities
+                        .get(&fid)
+                        .is_some_and(|f| f.kind == EntityKind::Faction && f.end.is_none())
+                })
+                .map(move |fid| (e.id, fid, dynasty_id))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut founded: BTreeMap<u64, u64> = BTreeMap::new(); // founder → dynasty
+    for (person_id, faction_id, dynasty_id) in leaders {
+        let dynasty_id = match dynasty_id.or_else(|| founded.get(&person_id).copied()) {
+            Some(id) => id,
+            None => {
+                let id = found_dynasty(ctx.world, person_id, faction_id, time, current_year);
+                founded.insert(person_id, id);
+                id
+            }
+        };
+        if let Some(dd) = ctx
+            .world
+            .entities
+            .get_mut(&dynasty_id)
+            .filter(|e| e.end.is_none())
+            .and_then(|e| e.data.as_dynasty_mut())
+        {
+            dd.ruled_factions.entry(faction_id).or_insert(time);
+        }
+    }
+
+    mark_extinct_dynasties(ctx.world, time, current_year);
+}
+
+/// Found a house around `founder_id`, who has just been found ruling
+/// `faction_id`. Living descendants without a house of their own join it.
+fn found_dynasty(
+    world: &mut World,
+    founder_id: u64,
+    faction_id: u64,
+    time: SimTimestamp,
+    current_year: u32,
+) -> u64 {
+    let founder_name = entity_name(world, founder_id);
+    let house = format!(
+        "House {}",
+        extract_surname(&founder_name).unwrap_or(&founder_name)
+    );
+    let faction_name = entity_name(world, faction_id);
+    let ev = world.add_structured_event(
+        EventPayload::DynastyFounded {
+            founder_id,
+            faction_id,
+        },
+        time,
+        format!("{house} rose to power in {faction_name} in year {current_year}"),
+    );
+    world.add_event_participant(ev, founder_id, ParticipantRole::Subject);
+    world.add_event_participant(ev, faction_id, ParticipantRole::Object);
+
+    let dynasty_id = world.add_entity(
+        EntityKind::Dynasty,
+        house,
+        Some(time),
+        EntityData::Dynasty(DynastyData {
+            founder_id,
+            ruled_factions: BTreeMap::new(),
+        }),
+        ev,
+    );
+
+    // Walk the founder's line; descendants already in another house keep it
+    let mut visited = BTreeSet::new();
+    let mut stack = vec![founder_id];
+    while let Some(person_id) = stack.pop() {
+        if !visited.insert(person_id) {
+            continue;
+        }
+        let Some(pd) = world
+            .entities
+            .get_mut(&person_id)
+            .and_then(|e| e.data.as_person_mut())
+        else {
+            continue;
+        };
+        if person_id != founder_id && pd.dynasty_id.is_some() {
+            continue;
+        }
+        pd.dynasty_id = Some(dynasty_id);
+        stack.extend(children_of(world, person_id));
+    }
+    dynasty_id
+}
+
+/// End every living dynasty with no living member left.
+fn mark_extinct_dynasties(world: &mut World, time: SimTimestamp, current_year: u32) {
+    let surviving: BTreeSet<u64> = world
+        .living(EntityKind::Person)
+        .filter_map(|e| e.data.as_person().and_then(|pd| pd.dynasty_id))
+        .collect();
+    let extinct: Vec<(u64, String)> = world
+        .living(EntityKind::Dynasty)
+        .filter(|e| !surviving.contains(&e.id))
+        .map(|e| (e.id, e.name.clone()))
+        .collect();
+
+    for (dynasty_id, name) in extinct {
+        let ev = world.add_structured_event(
+            EventPayload::Dissolution {
+                entity_id: dynasty_id,
+            },
+            time,
+            format!("{name} died out in year {current_year}"),
+        );
+        world.add_event_participant(ev, dynasty_id, ParticipantRole::Subject);
+        world.end_entity(dynasty_id, time, ev);
+    }
+}
+
+/// Dynasty of a person, if they belong to one.
+pub fn person_dynasty(world: &World, person_id: u64) -> Option<u64> {
+    world
+        .entities
+        .get(&person_id)
+        .and_then(|e| e.data.as_person())
+        .and_then(|pd| pd.dynasty_id)
+}
@@ -0,0 +1,488 @@
+//! Spy networks: a faction facing an enemy appoints a spymaster, whose agents
+//! steal from the enemy's treasury, sabotage its armies' supplies, or stir up
+//! its settlements. Caught agents sour relations instead.
+
+use std::collections::BTreeMap;
+
+use rand::Rng;
+
+use crate::model::traits::{Trait, has_trait};
+use crate::model::{
+    Describer, EnglishDescriber, EntityKind, EventPayload, ParticipantRole, RelationshipKind,
+    SimTimestamp, SpyOperation, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::grievance as grv;
+use crate::sim::helpers;
+
+// --- Espionage ---
+/// Treasury a faction needs before it will fund a spy network.
+const SPY_NETWORK_MIN_TREASURY: f64 = 20.0;
+/// Extra appointment weight a cunning candidate gets on top of prestige.
+const SPYMASTER_CUNNING_BONUS: f64 = 0.5;
+/// Yearly chance a spymaster mounts an operation against the enemy.
+const ESPIONAGE_OPERATION_CHANCE: f64 = 0.5;
+/// Chance an operation is discovered by its target.
+const ESPIONAGE_DETECTION_CHANCE: f64 = 0.3;
+/// Detection multiplier for a cunning spymaster.
+const CUNNING_DETECTION_FACTOR: f64 = 0.5;
+/// Detection multiplier when the target runs a spy network of its own.
+const COUNTER_INTELLIGENCE_FACTOR: f64 = 1.5;
+/// Share of the enemy treasury stolen by a successful theft.
+const TREASURY_THEFT_SHARE: f64 = 0.1;
+/// Months of supply destroyed by a successful sabotage.
+const SABOTAGE_SUPPLY_LOSS: f64 = 1.5;
+/// Crime rate added to a settlement by incited unrest.
+const INCITE_CRIME_SPIKE: f64 = 0.15;
+/// Stability the settlement's faction loses to incited unrest.
+const INCITE_STABILITY_HIT: f64 = -0.05;
+/// Grievance a faction holds against one caught spying on it.
+const DETECTED_ESPIONAGE_GRIEVANCE: f64 = 0.2;
+/// Diplomatic trust a faction loses when its spies are caught.
+const DETECTED_ESPIONAGE_TRUST_PENALTY: f64 = 0.1;
+
+/// Current spymaster of a faction, if it runs a spy network.
+pub fn faction_spymaster(world: &World, faction_id: u64) -> Option<u64> {
+    world
+        .living(EntityKind::Person)
+        .find(|e| e.has_active_rel(RelationshipKind::SpymasterOf, faction_id))
+        .map(|e| e.id)
+}
+
+/// The faction a spy network works against: a war enemy first, else any
+/// declared enemy.
+fn espionage_target(world: &World, faction_id: u64) -> Option<u64> {
+    let faction = world.entities.get(&faction_id)?;
+    [RelationshipKind::AtWar, RelationshipKind::Enemy]
+        .into_iter()
+        .find_map(|kind| {
+            faction
+                .active_rels(kind)
+                .find(|&target| world.entities.get(&target).is_some_and(|e| e.end.is_none()))
+        })
+}
+
+/// One operation a spymaster has settled on.
+pub(super) struct PlannedOperation {
+    pub spymaster_id: u64,
+    pub faction_id: u64,
+    pub target_faction_id: u64,
+    /// The army or settlement acted against; the target faction for theft.
+    pub target_id: u64,
+    pub operation: SpyOperation,
+}
+
+/// Dismiss spymasters who died, defected, took the throne, or have no enemy
+/// left to spy on; appoint one for every faction with an enemy and the
+/// treasury to fund a network; then run the year's operations.
+pub(super) fn update_espionage(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    // Active posts: (spymaster, faction, still valid)
+    let posts: Vec<(u64, u64, bool)> = ctx
+        .world
+        .entities
+        .values()
+        .filter(|e| e.kind == EntityKind::Person)
+        .flat_map(|e| {
+            e.relationships
+                .iter()
+                .filter(|r| r.end.is_none() && r.kind == RelationshipKind::SpymasterOf)
+                .map(|r| {
+                    let fid = r.target_entity_id;
+                    let valid = e.end.is_none()
+                        && e.has_active_rel(RelationshipKind::MemberOf, fid)
+                        && e.active_rel(RelationshipKind::LeaderOf).is_none()
+                        && e.active_rel(RelationshipKind::CaptiveOf).is_none()
+                        && espionage_target(ctx.world, fid).is_some();
+                    (e.id, fid, valid)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut networks: BTreeMap<u64, u64> = BTreeMap::new();
+    for (person_id, faction_id, valid) in posts {
+        if valid {
+            networks.insert(faction_id, person_id);
+            continue;
+        }
+        let person_name = helpers::entity_name(ctx.world, person_id);
+        let faction_name = helpers::entity_name(ctx.world, faction_id);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::Left {
+                entity_id: person_id,
+                group_id: faction_id,
+            },
+            time,
+            format!(
+                "{person_name} ceased to be spymaster of {faction_name} in year {current_year}"
+            ),
+        );
+        ctx.world
+            .add_event_participant(ev, person_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, faction_id, ParticipantRole::Object);
+        ctx.world.end_relationship(
+            person_id,
+            faction_id,
+            RelationshipKind::SpymasterOf,
+            time,
+            ev,
+        );
+    }
+
+    // Factions that want a network and can pay for one
+    let wanting: Vec<u64> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .filter(|e| {
+            !networks.contains_key(&e.id)
+                && !helpers::is_non_state_faction(ctx.world, e.id)
+                && e.data
+                    .as_faction()
+                    .is_some_and(|fd| fd.treasury >= SPY_NETWORK_MIN_TREASURY)
+        })
+        .map(|e| e.id)
+        .filter(|&fid| espionage_target(ctx.world, fid).is_some())
+        .collect();
+
+    for faction_id in wanting {
+        let Some(person_id) = best_spymaster_candidate(ctx.world, faction_id) else {
+            continue;
+        };
+        let person_name = helpers::entity_name(ctx.world, person_id);
+        let faction_name = helpers::entity_name(ctx.world, faction_id);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::Joined {
+                entity_id: person_id,
+                group_id: faction_id,
+            },
+            time,
+            format!("{person_name} became spymaster of {faction_name} in year {current_year}"),
+        );
+        ctx.world
+            .add_event_participant(ev, person_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, faction_id, ParticipantRole::Object);
+        ctx.world.add_relationship(
+            person_id,
+            faction_id,
+            RelationshipKind::SpymasterOf,
+            time,
+            ev,
+        );
+        networks.insert(faction_id, person_id);
+    }
+
+    for (faction_id, spymaster_id) in networks {
+        if ctx.rng.random_range(0.0..1.0) >= ESPIONAGE_OPERATION_CHANCE {
+            continue;
+        }
+        let Some(op) = plan_operation(ctx, faction_id, spymaster_id) else {
+            continue;
+        };
+        let detected = ctx.rng.random_range(0.0..1.0) < detection_chance(ctx.world, &op);
+        execute_operation(ctx, &op, detected, time);
+    }
+}
+
+/// The member best suited to run a spy network: the most prestigious, with
+/// a thumb on the scale for the cunning. Rulers, governors, and captives are
+/// otherwise occupied.
+fn best_spymaster_candidate(world: &World, faction_id: u64) -> Option<u64> {
+    world
+        .living(EntityKind::Person)
+        .filter(|e| {
+            e.has_active_rel(RelationshipKind::MemberOf, faction_id)
+                && e.active_rel(RelationshipKind::LeaderOf).is_none()
+                && e.active_rel(RelationshipKind::CaptiveOf).is_none()
+                && e.active_rel(RelationshipKind::GovernorOf).is_none()
+        })
+        .map(|e| {
+            let mut score = e.data.as_person().map_or(0.0, |pd| pd.prestige);
+            if has_trait(e, &Trait::Cunning) {
+                score += SPYMASTER_CUNNING_BONUS;
+            }
+            (e.id, score)
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(id, _)| id)
+}
+
+/// Pick an operation against the faction's enemy from those open to it:
+/// theft needs a treasury, sabotage an army in the field, unrest a settlement.
+fn plan_operation(
+    ctx: &mut TickContext,
+    faction_id: u64,
+    spymaster_id: u64,
+) -> Option<PlannedOperation> {
+    let target_faction_id = espionage_target(ctx.world, faction_id)?;
+
+    let mut options: Vec<(SpyOperation, u64)> = Vec::new();
+    if ctx.world.faction(target_faction_id).treasury > 0.0 {
+        options.push((SpyOperation::StealTreasury, target_faction_id));
+    }
+    for e in ctx.world.living(EntityKind::Army) {
+        if e.has_active_rel(RelationshipKind::MemberOf, target_faction_id) {
+            options.push((SpyOperation::SabotageSupply, e.id));
+        }
+    }
+    for sid in helpers::faction_settlements(ctx.world, target_faction_id) {
+        options.push((SpyOperation::InciteUnrest, sid));
+    }
+    if options.is_empty() {
+        return None;
+    }
+
+    let (operation, target_id) = options[ctx.rng.random_range(0..options.len())];
+    Some(PlannedOperation {
+        spymaster_id,
+        faction_id,
+        target_faction_id,
+        target_id,
+        operation,
+    })
+}
+
+fn detection_chance(world: &World, op: &PlannedOperation) -> f64 {
+    let mut chance = ESPIONAGE_DETECTION_CHANCE;
+    if world
+        .entities
+        .get(&op.spymaster_id)
+        .is_some_and(|e| has_trait(e, &Trait::Cunning))
+    {
+        chance *= CUNNING_DETECTION_FACTOR;
+    }
+    if faction_spymaster(world, op.target_faction_id).is_some() {
+        chance *= COUNTER_INTELLIGENCE_FACTOR;
+    }
+    chance.min(1.0)
+}
+
+/// Carry out an operation. A detected operation is foiled: the target holds
+/// a grievance and the spying faction's word counts for less abroad.
+pub(super) fn execute_operation(
+    ctx: &mut TickContext,
+    op: &PlannedOperation,
+    detected: bool,
+    time: SimTimestamp,
+) -> u64 {
+    let payload = EventPayload::EspionageOperation {
+        spymaster_id: op.spymaster_id,
+        faction_id: op.faction_id,
+        target_faction_id: op.target_faction_id,
+        target_id: op.target_id,
+        operation: op.operation,
+        detected,
+    };
+    let description = EnglishDescriber
+        .describe_payload(ctx.world, &payload, time)
+        .expect("espionage operations have an English template");
+    let ev = ctx.world.add_structured_event(payload, time, description);
+    ctx.world
+        .add_event_participant(ev, op.spymaster_id, ParticipantRole::Instigator);
+    ctx.world
+        .add_event_participant(ev, op.faction_id, ParticipantRole::Attacker);
+    ctx.world
+        .add_event_participant(ev, op.target_faction_id, ParticipantRole::Defender);
+    if op.target_id != op.target_faction_id {
+        ctx.world
+            .add_event_participant(ev, op.target_id, ParticipantRole::Object);
+    }
+
+    if detected {
+        grv::add_grievance(
+            ctx.world,
+            op.target_faction_id,
+            op.faction_id,
+            DETECTED_ESPIONAGE_GRIEVANCE,
+            "espionage",
+            time,
+            ev,
+        );
+        let old = ctx.world.faction(op.faction_id).diplomatic_trust;
+        let new = (old - DETECTED_ESPIONAGE_TRUST_PENALTY).max(0.0);
+        ctx.world.faction_mut(op.faction_id).diplomatic_trust = new;
+        ctx.world.record_change(
+            op.faction_id,
+            ev,
+            "diplomatic_trust",
+            serde_json::json!(old),
+            serde_json::json!(new),
+        );
+        return ev;
+    }
+
+    match op.operation {
+        SpyOperation::StealTreasury => {
+            let amount = ctx.world.faction(op.target_faction_id).treasury * TREASURY_THEFT_SHARE;
+            for (faction_id, delta) in [(op.target_faction_id, -amount), (op.faction_id, amount)] {
+                let old = ctx.world.faction(faction_id).treasury;
+                ctx.world.faction_mut(faction_id).treasury = old + delta;
+                ctx.world.record_change(
+                    faction_id,
+                    ev,
+                    "treasury",
+                    serde_json::json!(old),
+                    serde_json::json!(old + delta),
+                );
+            }
+        }
+        SpyOperation::SabotageSupply => {
+            let old = ctx.world.army(op.target_id).supply;
+            let new = (old - SABOTAGE_SUPPLY_LOSS).max(0.0);
+            ctx.world.army_mut(op.target_id).supply = new;
+            ctx.world.record_change(
+                op.target_id,
+                ev,
+                "supply",
+                serde_json::json!(old),
+                serde_json::json!(new),
+            );
+        }
+        SpyOperation::InciteUnrest => {
+            let old = ctx.world.settlement(op.target_id).crime_rate;
+            let new = (old + INCITE_CRIME_SPIKE).min(1.0);
+            ctx.world.settlement_mut(op.target_id).crime_rate = new;
+            ctx.world.record_change(
+                op.target_id,
+                ev,
+                "crime_rate",
+                serde_json::json!(old),
+                serde_json::json!(new),
+            );
+            helpers::apply_stability_delta(
+                ctx.world,
+                op.target_faction_id,
+                INCITE_STABILITY_HIT,
+                ev,
+            );
+        }
+    }
+    ev
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use super::*;
+    use crate::model::EventKind;
+    use crate::scenario::Scenario;
+    use crate::sim::signal::Signal;
+
+    struct SpyWorld {
+        world: World,
+        spy_faction: u64,
+        enemy: u64,
+        spymaster: u64,
+        army: u64,
+    }
+
+    fn spy_scenario() -> SpyWorld {
+        let mut s = Scenario::at_year(100);
+        let spies = s.add_kingdom_with("Spies", |fd| fd.treasury = 100.0, |_| {}, |_| {});
+        let enemy = s.add_rival_kingdom_with(
+            "Enemy",
+            spies.region,
+            |fd| fd.treasury = 100.0,
+            |_| {},
+            |_| {},
+        );
+        let spymaster = s
+            .person("Whisper", spies.faction)
+            .prestige(0.4)
+            .add_trait(Trait::Cunning)
+            .id();
+        let army = s
+            .army("Enemy Host", enemy.faction, enemy.region, 200)
+            .supply(3.0)
+            .id();
+        s.make_at_war(spies.faction, enemy.faction);
+        SpyWorld {
+            world: s.build(),
+            spy_faction: spies.faction,
+            enemy: enemy.faction,
+            spymaster,
+            army,
+        }
+    }
+
+    fn execute(world: &mut World, op: &PlannedOperation, detected: bool) {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals: Vec<Signal> = Vec::new();
+        let mut ctx = TickContext {
+            world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        execute_operation(&mut ctx, op, detected, SimTimestamp::from_year(100));
+    }
+
+    #[test]
+    fn faction_at_war_appoints_cunning_spymaster() {
+        let mut sw = spy_scenario();
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world: &mut sw.world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        update_espionage(&mut ctx, SimTimestamp::from_year(100), 100);
+
+        assert_eq!(
+            faction_spymaster(&sw.world, sw.spy_faction),
+            Some(sw.spymaster)
+        );
+    }
+
+    #[test]
+    fn successful_sabotage_lowers_enemy_army_supply() {
+        let mut sw = spy_scenario();
+        let op = PlannedOperation {
+            spymaster_id: sw.spymaster,
+            faction_id: sw.spy_faction,
+            target_faction_id: sw.enemy,
+            target_id: sw.army,
+            operation: SpyOperation::SabotageSupply,
+        };
+
+        execute(&mut sw.world, &op, false);
+
+        assert!((sw.world.army(sw.army).supply - (3.0 - SABOTAGE_SUPPLY_LOSS)).abs() < 1e-9);
+        assert_eq!(grv::get_grievance(&sw.world, sw.enemy, sw.spy_faction), 0.0);
+    }
+
+    #[test]
+    fn detected_operation_raises_grievance_and_is_foiled() {
+        let mut sw = spy_scenario();
+        let trust_before = sw.world.faction(sw.spy_faction).diplomatic_trust;
+        let op = PlannedOperation {
+            spymaster_id: sw.spymaster,
+            faction_id: sw.spy_faction,
+            target_faction_id: sw.enemy,
+            target_id: sw.army,
+            operation: SpyOperation::SabotageSupply,
+        };
+
+        execute(&mut sw.world, &op, true);
+
+        assert!(
+            (grv::get_grievance(&sw.world, sw.enemy, sw.spy_faction)
+                - DETECTED_ESPIONAGE_GRIEVANCE)
+                .abs()
+                < 1e-9
+        );
+        assert!(sw.world.faction(sw.spy_faction).diplomatic_trust < trust_before);
+        assert_eq!(sw.world.army(sw.army).supply, 3.0);
+        assert!(
+            sw.world
+                .events
+                .values()
+                .any(|e| e.kind == EventKind::EspionageOperation && e.data["detected"] == true)
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::model::traits::{Trait, has_trait};
+use crate::model::{
+    Describer, EnglishDescriber, EntityKind, EventPayload, ParticipantRole, RelationshipKind,
+    SimTimestamp, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::grievance as grv;
+use crate::sim::helpers;
+
+// --- Governors ---
+/// Loyalty of a governor with no grievance and no telling traits.
+const GOVERNOR_BASE_LOYALTY: f64 = 0.7;
+/// Loyalty lost per point of grievance against the governor's own faction.
+const GOVERNOR_GRIEVANCE_WEIGHT: f64 = 1.0;
+const GOVERNOR_AMBITIOUS_PENALTY: f64 = 0.3;
+const GOVERNOR_CONTENT_BONUS: f64 = 0.2;
+/// How strongly loyalty scales a settlement's split chance: a fully loyal
+/// governor quarters it, a fully disloyal one nearly doubles it.
+const GOVERNOR_SPLIT_WEIGHT: f64 = 1.5;
+/// Loyalty below which a governor may lead their settlement out of the faction.
+const GOVERNOR_INSTIGATOR_LOYALTY: f64 = 0.3;
+/// Prestige a disloyal governor needs to instigate a split.
+const GOVERNOR_INSTIGATOR_PRESTIGE: f64 = 0.3;
+
+/// Current governor of a settlement, if it has one.
+pub fn settlement_governor(world: &World, settlement_id: u64) -> Option<u64> {
+    world
+        .living(EntityKind::Person)
+        .find(|e| e.has_active_rel(RelationshipKind::GovernorOf, settlement_id))
+        .map(|e| e.id)
+}
+
+/// Every governed settlement: settlement → governor.
+pub(crate) fn governors_by_settlement(world: &World) -> BTreeMap<u64, u64> {
+    world
+        .living(EntityKind::Person)
+        .flat_map(|e| {
+            e.active_rels(RelationshipKind::GovernorOf)
+                .map(move |sid| (sid, e.id))
+        })
+        .collect()
+}
+
+/// How loyal a governor is to `faction_id`: 0.0 (ready to secede) to 1.0.
+/// Grievance against the faction and ambition erode it; contentment shores
+/// it up.
+pub fn governor_loyalty(world: &World, governor_id: u64, faction_id: u64) -> f64 {
+    let Some(governor) = world.entities.get(&governor_id) else {
+        return GOVERNOR_BASE_LOYALTY;
+    };
+    let mut loyalty = GOVERNOR_BASE_LOYALTY
+        - grv::get_grievance(world, governor_id, faction_id) * GOVERNOR_GRIEVANCE_WEIGHT;
+    if has_trait(governor, &Trait::Ambitious) {
+        loyalty -= GOVERNOR_AMBITIOUS_PENALTY;
+    }
+    if has_trait(governor, &Trait::Content) {
+        loyalty += GOVERNOR_CONTENT_BONUS;
+    }
+    loyalty.clamp(0.0, 1.0)
+}
+
+/// A governor's sway over whether their settlement secedes.
+pub(crate) struct GovernorSway {
+    /// Multiplier on the settlement's split chance.
+    pub split_factor: f64,
+    /// Set when the governor is disloyal and prestigious enough to lead a
+    /// split themselves, with the misery they bring to it.
+    pub instigator: Option<(u64, f64)>,
+}
+
+pub(crate) fn governor_sway(
+    world: &World,
+    governor_id: Option<u64>,
+    faction_id: u64,
+) -> GovernorSway {
+    let Some(governor_id) = governor_id else {
+        return GovernorSway {
+            split_factor: 1.0,
+            instigator: None,
+        };
+    };
+    let loyalty = governor_loyalty(world, governor_id, faction_id);
+    let prestige = world
+        .entities
+        .get(&governor_id)
+        .and_then(|e| e.data.as_person())
+        .map_or(0.0, |pd| pd.prestige);
+    let instigator = (loyalty < GOVERNOR_INSTIGATOR_LOYALTY
+        && prestige >= GOVERNOR_INSTIGATOR_PRESTIGE)
+        .then_some((governor_id, (1.0 - loyalty) * prestige));
+    GovernorSway {
+        split_factor: 1.0 + (0.5 - loyalty) * GOVERNOR_SPLIT_WEIGHT,
+        instigator,
+    }
+}
+
+/// Dismiss governors who died, moved away, or no longer serve the
+/// settlement's faction, then appoint the most prestigious local notable to
+/// each vacant seat.
+pub(crate) fn update_governors(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    // Settlements of state factions: settlement → faction
+    let settlements: BTreeMap<u64, u64> = ctx
+        .world
+        .living(EntityKind::Settlement)
+        .filter_map(|e| {
+            let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
+            (!helpers::is_non_state_faction(ctx.world, faction_id)).then_some((e.id, faction_id))
+        })
+        .collect();
+
+    // Active governorships: (governor, settlement, still valid)
+    let governorships: Vec<(u64, u64, bool)> = ctx
+        .world
+        .entities
+        .values()
+        .filter(|e| e.kind == EntityKind::Person)
+        .flat_map(|e| {
+            e.relationships
+                .iter()
+                .filter(|r| r.end.is_none() && r.kind == RelationshipKind::GovernorOf)
+                .map(|r| {
+                    let sid = r.target_entity_id;
+                    let valid = e.end.is_none()
+                        && e.active_rel(RelationshipKind::LeaderOf).is_none()
+                        && e.has_active_rel(RelationshipKind::LocatedIn, sid)
+                        && settlements
+                            .get(&sid)
+                            .is_some_and(|&fid| e.has_active_rel(RelationshipKind::MemberOf, fid));
+                    (e.id, sid, valid)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut governed = BTreeSet::new();
+    for (governor_id, settlement_id, valid) in governorships {
+        if valid {
+            governed.insert(settlement_id);
+            continue;
+        }
+        let governor_name = helpers::entity_name(ctx.world, governor_id);
+        let settlement_name = helpers::entity_name(ctx.world, settlement_id);
+        let ev = ctx.world.add_structured_event(
+            EventPayload::Left {
+                entity_id: governor_id,
+                group_id: settlement_id,
+            },
+            time,
+            format!("{governor_name} ceased to govern {settlement_name} in year {current_year}"),
+        );
+        ctx.world
+            .add_event_participant(ev, governor_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, settlement_id, ParticipantRole::Location);
+        ctx.world.end_relationship(
+            governor_id,
+            settlement_id,
+            RelationshipKind::GovernorOf,
+            time,
+            ev,
+        );
+    }
+
+    // Most prestigious eligible notable per vacant settlement: (person, prestige)
+    let mut candidates: BTreeMap<u64, (u64, f64)> = BTreeMap::new();
+    for e in ctx.world.living(EntityKind::Person) {
+        if e.active_rel(RelationshipKind::LeaderOf).is_some()
+            || e.active_rel(RelationshipKind::GovernorOf).is_some()
+            || e.active_rel(RelationshipKind::CaptiveOf).is_some()
+            || e.active_rel(RelationshipKind::SpymasterOf).is_some()
+        {
+            continue;
+        }
+        let Some(sid) = e.active_rel(RelationshipKind::LocatedIn) else {
+            continue;
+        };
+        if governed.contains(&sid) {
+            continue;
+        }
+        let Some(&faction_id) = settlements.get(&sid) else {
+            continue;
+        };
+        if !e.has_active_rel(RelationshipKind::MemberOf, faction_id) {
+            continue;
+        }
+        let prestige = e.data.as_person().map_or(0.0, |pd| pd.prestige);
+        let best = candidates.entry(sid).or_insert((e.id, prestige));
+        if prestige > best.1 {
+            *best = (e.id, prestige);
+        }
+    }
+
+    for (settlement_id, (person_id, _)) in candidates {
+        let faction_id = settlements[&settlement_id];
+        let payload = EventPayload::Appointment {
+            person_id,
+            settlement_id,
+            faction_id,
+        };
+        let description = EnglishDescriber
+            .describe_payload(ctx.world, &payload, time)
+            .expect("appointments have an English template");
+        let ev = ctx.world.add_structured_event(payload, time, description);
+        ctx.world
+            .add_event_participant(ev, person_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, settlement_id, ParticipantRole::Location);
+        ctx.world
+            .add_event_participant(ev, faction_id, ParticipantRole::Object);
+        ctx.world.add_relationship(
+            person_id,
+            settlement_id,
+            RelationshipKind::GovernorOf,
+            time,
+            ev,
+        );
+    }
+}
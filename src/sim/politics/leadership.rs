@@ -0,0 +1,79 @@
+use crate::model::{EntityKind, RelationshipKind, World};
+use crate::sim::helpers;
+
+use super::governors::governors_by_settlement;
+use super::{collect_faction_members, parents_of};
+
+/// Age below which a ruler reigns through a regent.
+pub(super) const REGENCY_AGE: u32 = 16;
+
+/// Who rules a faction right now. Built by [`World::faction_leadership`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Leadership {
+    /// Current leader, if the seat is filled.
+    pub leader: Option<u64>,
+    /// Adult governing for a leader too young to rule: a parent in the
+    /// faction, else its most prestigious adult member.
+    pub regent: Option<u64>,
+    /// Governed settlements of the faction: (settlement, governor).
+    pub governors: Vec<(u64, u64)>,
+    /// Living members other than the leader, most prestigious first.
+    pub notables: Vec<u64>,
+}
+
+impl World {
+    /// The ruling hierarchy of `faction_id`: leader, regent, governors and
+    /// notable members ranked by prestige.
+    pub fn faction_leadership(&self, faction_id: u64) -> Leadership {
+        let now = self.current_time;
+        let leader = helpers::faction_leader(self, faction_id);
+        let prestige = |id: u64| {
+            self.entities
+                .get(&id)
+                .and_then(|e| e.data.as_person())
+                .map_or(0.0, |pd| pd.prestige)
+        };
+
+        let members = collect_faction_members(self, faction_id);
+        let mut notables: Vec<u64> = members
+            .iter()
+            .map(|m| m.id)
+            .filter(|&id| Some(id) != leader)
+            .collect();
+        notables.sort_by(|&a, &b| prestige(b).total_cmp(&prestige(a)).then(a.cmp(&b)));
+
+        let regent = leader
+            .and_then(|lid| {
+                let born = members.iter().find(|m| m.id == lid)?.born;
+                (now.years_since(born) < REGENCY_AGE).then_some(lid)
+            })
+            .and_then(|lid| {
+                let adult = |id: &u64| {
+                    members
+                        .iter()
+                        .any(|m| m.id == *id && now.years_since(m.born) >= REGENCY_AGE)
+                };
+                parents_of(self, lid)
+                    .into_iter()
+                    .find(adult)
+                    .or_else(|| notables.iter().copied().find(adult))
+            });
+
+        let governors = governors_by_settlement(self)
+            .into_iter()
+            .filter(|&(sid, _)| {
+                self.entities.get(&sid).is_some_and(|e| {
+                    e.kind == EntityKind::Settlement
+                        && e.has_active_rel(RelationshipKind::MemberOf, faction_id)
+                })
+            })
+            .collect();
+
+        Leadership {
+            leader,
+            regent,
+            governors,
+            notables,
+        }
+    }
+}
@@ -1,20 +1,42 @@
+mod capitals;
+mod council;
 mod coups;
 pub(crate) mod diplomacy;
+mod dynasty;
+mod espionage;
+mod governors;
+mod leadership;
+mod mourning;
+mod policy;
+mod unfree;
+
+pub use capitals::faction_capital;
+pub use council::{council_leaning, faction_council};
+pub use diplomacy::diplomatic_reputation;
+pub use dynasty::person_dynasty;
+pub use espionage::faction_spymaster;
+pub use governors::{governor_loyalty, settlement_governor};
+pub use leadership::Leadership;
 
 use rand::Rng;
 use rand::RngCore;
 
 use super::context::TickContext;
 use super::faction_names::generate_unique_faction_name;
+use super::runner::SimConfig;
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
+use crate::model::entity_data::POLICY_NEUTRAL;
 use crate::model::traits::{Trait, has_trait};
 use crate::model::{
-    Claim, EntityData, EntityKind, EventKind, FactionData, GovernmentType, ParticipantRole,
-    RelationshipKind, Role, SecretMotivation, SiegeOutcome, SimTimestamp, World,
+    Claim, Effect, EntityData, EntityKind, EventKind, EventPayload, FactionData, FactionField,
+    GovernmentType, Heraldry, MilitaryDoctrine, ParticipantRole, RelationshipKind, Role,
+    SecretMotivation, SiegeOutcome, SimTimestamp, SuccessionLaw, World,
 };
+use crate::sim::conflicts;
 use crate::sim::grievance as grv;
 use crate::sim::helpers;
+use crate::sim::holy_sites;
 
 // --- Signal Deltas: War ---
 const WAR_STARTED_HAPPINESS_HIT: f64 = -0.15;
@@ -63,6 +85,8 @@ const HAPPINESS_LEADER_PRESENT_BONUS: f64 = 0.05;
 const HAPPINESS_LEADER_ABSENT_PENALTY: f64 = -0.1;
 const HAPPINESS_TENSION_WEIGHT: f64 = 0.15;
 const HAPPINESS_RELIGIOUS_TENSION_WEIGHT: f64 = 0.10;
+/// Happiness target lost per unit of average unfree population fraction.
+const HAPPINESS_UNFREE_WEIGHT: f64 = 0.15;
 const HAPPINESS_BUILDING_CAP: f64 = 0.15;
 const HAPPINESS_MIN_TARGET: f64 = 0.1;
 const HAPPINESS_MAX_TARGET: f64 = 0.95;
@@ -74,6 +98,8 @@ const DEFAULT_PROSPERITY: f64 = 0.3;
 const LEGITIMACY_BASE_TARGET: f64 = 0.5;
 const LEGITIMACY_HAPPINESS_WEIGHT: f64 = 0.4;
 const LEGITIMACY_LEADER_PRESTIGE_WEIGHT: f64 = 0.1;
+/// Legitimacy target lost to a leader at zero health, scaled by lost health.
+const LEGITIMACY_FRAIL_LEADER_PENALTY: f64 = 0.15;
 const LEGITIMACY_DRIFT_RATE: f64 = 0.1;
 
 // --- Stability Calculation ---
@@ -96,11 +122,42 @@ const STABILITY_LEADERLESS_PRESSURE: f64 = 0.04;
 const SUCCESSION_STABILITY_HIT: f64 = -0.12;
 const SUCCESSION_PRESTIGE_SOFTENING: f64 = 0.5;
 
+// --- Abdication ---
+/// Leader health below which stepping down for an heir becomes possible.
+const ABDICATION_HEALTH_THRESHOLD: f64 = 0.35;
+/// Yearly chance a leader below the health threshold abdicates.
+const ABDICATION_CHANCE: f64 = 0.25;
+/// Stability hit of a planned handover, far milder than a sudden vacancy.
+const ABDICATION_STABILITY_HIT: f64 = -0.03;
+
+// --- Chieftain Selection ---
+/// Weight multiplier for members of the warrior role.
+const CHIEFTAIN_WARRIOR_WEIGHT: f64 = 3.0;
+/// Extra weight per point of personal prestige.
+const CHIEFTAIN_PRESTIGE_WEIGHT: f64 = 4.0;
+/// Weight multiplier for each martial trait (Aggressive, Ruthless).
+const CHIEFTAIN_MARTIAL_TRAIT_WEIGHT: f64 = 1.5;
+
+// --- Bankruptcy ---
+/// Consecutive insolvent years before a faction is declared bankrupt.
+pub(crate) const BANKRUPTCY_YEARS: u32 = 2;
+/// Yearly stability lost while bankrupt.
+const BANKRUPTCY_STABILITY_HIT: f64 = -0.08;
+/// Share of each member settlement's treasury seized by an emergency levy.
+const EMERGENCY_LEVY_RATE: f64 = 0.25;
+/// Happiness lost to an emergency levy.
+const EMERGENCY_LEVY_HAPPINESS_HIT: f64 = -0.05;
+
 // --- Succession Claims ---
 const CLAIM_CHILD_STRENGTH: f64 = 0.9;
 const CLAIM_SIBLING_STRENGTH: f64 = 0.6;
 const CLAIM_GRANDCHILD_STRENGTH: f64 = 0.4;
+/// Claim of the late ruler's uncles and aunts, who only have one under seniority.
+const CLAIM_ELDER_KIN_STRENGTH: f64 = 0.5;
 const CLAIM_SPOUSE_FACTOR: f64 = 0.5;
+/// Share of a blood relative's claim passed to their foreign spouse and children
+/// when the marriage sealed an alliance and the dead leader left no children.
+const CLAIM_MARRIAGE_ALLIANCE_FACTOR: f64 = 0.8;
 const CLAIM_DEPOSED_STRENGTH: f64 = 0.7;
 const CLAIM_SPLIT_STRENGTH: f64 = 0.5;
 const CLAIM_DECAY_PER_YEAR: f64 = 0.05;
@@ -113,6 +170,8 @@ const CRISIS_LEGITIMACY_HIT: f64 = -0.20;
 const GRIEVANCE_BASE_DECAY: f64 = 0.03;
 const GRIEVANCE_MIN_THRESHOLD: f64 = 0.05;
 const GRIEVANCE_CONQUEST: f64 = 0.40;
+/// Extra grievance, remembered as an atrocity, for the capture of a capital.
+const GRIEVANCE_CAPITAL_SACKED: f64 = 0.20;
 const GRIEVANCE_WAR_DEFEAT_DECISIVE: f64 = 0.35;
 const GRIEVANCE_WAR_DEFEAT_INDECISIVE: f64 = 0.10;
 const GRIEVANCE_BETRAYAL: f64 = 0.50;
@@ -133,7 +192,41 @@ const SPLIT_NEW_FACTION_LEGITIMACY: f64 = 0.6;
 const SPLIT_NEW_FACTION_PRESTIGE_INHERITANCE: f64 = 0.25;
 const SPLIT_POST_ENEMY_CHANCE: f64 = 0.7;
 
-pub struct PoliticsSystem;
+/// Tunable thresholds for political upheaval. Set on [`SimConfig::politics`].
+///
+/// [`SimConfig::politics`]: crate::sim::SimConfig::politics
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoliticsConfig {
+    /// Factions at or above this stability are safe from coups.
+    pub coup_stability_threshold: f64,
+    /// Yearly coup attempt chance for a fully unstable, miserable faction
+    /// whose leader has no prestige.
+    pub coup_base_attempt_chance: f64,
+}
+
+impl Default for PoliticsConfig {
+    fn default() -> Self {
+        Self {
+            coup_stability_threshold: 0.55,
+            coup_base_attempt_chance: 0.08,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct PoliticsSystem {
+    config: PoliticsConfig,
+}
+
+impl PoliticsSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_config(config: PoliticsConfig) -> Self {
+        Self { config }
+    }
+}
 
 impl SimSystem for PoliticsSystem {
     fn name(&self) -> &str {
@@ -151,6 +244,15 @@ impl SimSystem for PoliticsSystem {
         // --- 4a: Fill leader vacancies ---
         fill_leader_vacancies(ctx, time, current_year);
 
+        // --- Abdications of ailing leaders ---
+        check_abdications(ctx, time, current_year);
+
+        // --- Bankruptcy of chronically insolvent factions ---
+        check_bankruptcy(ctx, time, current_year);
+
+        // --- Capitals: seat the capital-less, move the seat by decree ---
+        capitals::update_capitals(ctx, time, current_year);
+
         // --- Claim decay (yearly) ---
         decay_claims(ctx);
 
@@ -164,14 +266,29 @@ impl SimSystem for PoliticsSystem {
         // --- 4b: Stability drift ---
         update_stability(ctx, time);
 
+        // --- Rulers bend faction policy to their temperament ---
+        policy::update_policies(ctx);
+
+        // --- Manumission and slave revolts ---
+        unfree::update_unfree_population(ctx, time);
+
         // --- 4c: Coups ---
-        coups::check_coups(ctx, time, current_year);
+        coups::check_coups(ctx, &self.config, time, current_year);
 
         // --- 4d: Inter-faction diplomacy ---
         diplomacy::update_diplomacy(ctx, time, current_year);
 
+        // --- Governors: dismiss the departed, fill vacant seats ---
+        governors::update_governors(ctx, time, current_year);
+
+        // --- Spy networks: appoint spymasters, run operations ---
+        espionage::update_espionage(ctx, time, current_year);
+
         // --- 4e: Faction splits ---
         check_faction_splits(ctx, time, current_year);
+
+        // --- Ruling houses: founding, faction rule, extinction ---
+        dynasty::update_dynasties(ctx, time, current_year);
     }
 
     fn handle_signals(&mut self, ctx: &mut TickContext) {
@@ -223,11 +340,18 @@ impl SimSystem for PoliticsSystem {
                     );
                 }
                 SignalKind::SettlementCaptured {
+                    settlement_id,
                     old_faction_id,
                     new_faction_id,
-                    ..
                 } => {
-                    handle_settlement_captured(ctx.world, signal.event_id, *old_faction_id);
+                    handle_settlement_captured(
+                        ctx.world,
+                        signal.event_id,
+                        *settlement_id,
+                        *old_faction_id,
+                        *new_faction_id,
+                    );
+                    unfree::handle_settlement_captured(ctx.world, signal.event_id, *settlement_id);
                     // Grievance: old faction → new faction
                     grv::add_grievance(
                         ctx.world,
@@ -306,13 +430,8 @@ impl SimSystem for PoliticsSystem {
                     // Stability hit to the faction that owns settlements in this region
                     let affected_factions: Vec<u64> = ctx
                         .world
-                        .entities
-                        .values()
-                        .filter(|e| {
-                            e.kind == EntityKind::Settlement
-                                && e.end.is_none()
-                                && e.has_active_rel(RelationshipKind::LocatedIn, *region_id)
-                        })
+                        .living(EntityKind::Settlement)
+                        .filter(|e| e.has_active_rel(RelationshipKind::LocatedIn, *region_id))
                         .filter_map(|e| e.active_rel(RelationshipKind::MemberOf))
                         .collect();
                     for fid in affected_factions {
@@ -414,6 +533,10 @@ impl SimSystem for PoliticsSystem {
             }
         }
     }
+
+    fn configure(&mut self, config: &SimConfig) {
+        self.config = config.politics.clone();
+    }
 }
 
 // --- Signal handlers ---
@@ -443,13 +566,38 @@ fn handle_war_ended(
     }
 }
 
-fn handle_settlement_captured(world: &mut World, event_id: u64, old_faction_id: u64) {
+fn handle_settlement_captured(
+    world: &mut World,
+    event_id: u64,
+    settlement_id: u64,
+    old_faction_id: u64,
+    new_faction_id: u64,
+) {
     helpers::apply_stability_delta(
         world,
         old_faction_id,
         SETTLEMENT_CAPTURED_STABILITY,
         event_id,
     );
+    if capitals::handle_capital_captured(world, event_id, settlement_id, old_faction_id) {
+        // The sack of a capital is never forgotten
+        grv::add_atrocity_grievance(
+            world,
+            old_faction_id,
+            new_faction_id,
+            GRIEVANCE_CAPITAL_SACKED,
+            "capital_sacked",
+            world.current_time,
+            event_id,
+        );
+    }
+    holy_sites::handle_holy_site_captured(
+        world,
+        event_id,
+        settlement_id,
+        old_faction_id,
+        new_faction_id,
+    );
 }
 
 fn handle_refugees_arrived(world: &mut World, event_id: u64, settlement_id: u64, count: u32) {
@@ -533,16 +681,25 @@ fn handle_leader_vacancy(
         return;
     }
 
+    let mourning = mourning::mourn_ruler(world, cause_event_id, faction_id, previous_leader_id);
+
     // Skip if a leader was already assigned this tick (e.g. by fill_leader_vacancies)
     if has_leader(world, faction_id) {
         return;
     }
 
     let gov_type = get_government_type(world, faction_id);
+    let succession_law = get_succession_law(world, faction_id);
     let faction_name = helpers::entity_name(world, faction_id);
-    let members = collect_faction_members(world, faction_id);
-    if let Some(leader_id) = select_leader(&members, gov_type, world, rng, Some(previous_leader_id))
-    {
+    let members = eligible_rulers(world, faction_id);
+    if let Some(leader_id) = select_leader(
+        &members,
+        gov_type,
+        succession_law,
+        world,
+        rng,
+        Some(previous_leader_id),
+    ) {
         let leader_name = helpers::entity_name(world, leader_id);
         let ev = world.add_caused_event(
             EventKind::Succession,
@@ -556,13 +713,28 @@ fn handle_leader_vacancy(
         world.add_event_participant(ev, faction_id, ParticipantRole::Object);
         world.add_relationship(leader_id, faction_id, RelationshipKind::LeaderOf, time, ev);
 
-        // Succession causes a stability hit
-        apply_succession_stability_hit(world, faction_id, ev);
-
         // Create claims for passed-over blood relatives (Hereditary only)
         if gov_type == GovernmentType::Hereditary {
             create_succession_claims(world, faction_id, previous_leader_id, current_year, ev);
         }
+
+        // Succession causes a stability hit, eased for an uncontested heir
+        // by the legitimacy of a long reign
+        let contested = mourning::throne_contested(world, faction_id);
+        let carryover = if contested {
+            0.0
+        } else {
+            mourning::long_reign_carryover(world, faction_id, previous_leader_id)
+        };
+        apply_succession_stability_hit(
+            world,
+            faction_id,
+            SUCCESSION_STABILITY_HIT * (1.0 - carryover),
+            ev,
+        );
+        if mourning && !contested {
+            mourning::rally_behind_heir(world, faction_id, leader_id, ev);
+        }
     }
 }
 
@@ -602,13 +774,12 @@ fn fill_leader_vacancies(ctx: &mut TickContext, time: SimTimestamp, current_year
     struct FactionInfo {
         id: u64,
         government_type: GovernmentType,
+        succession_law: SuccessionLaw,
     }
 
     let factions: Vec<FactionInfo> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
+        .living(EntityKind::Faction)
         .map(|e| FactionInfo {
             id: e.id,
             government_type: e
@@ -616,6 +787,11 @@ fn fill_leader_vacancies(ctx: &mut TickContext, time: SimTimestamp, current_year
                 .as_faction()
                 .map(|f| f.government_type)
                 .unwrap_or(GovernmentType::Chieftain),
+            succession_law: e
+                .data
+                .as_faction()
+                .map(|f| f.succession_law)
+                .unwrap_or_default(),
         })
         .collect();
 
@@ -627,7 +803,7 @@ fn fill_leader_vacancies(ctx: &mut TickContext, time: SimTimestamp, current_year
 
     for faction in leaderless {
         let faction_name = helpers::entity_name(ctx.world, faction.id);
-        let members = collect_faction_members(ctx.world, faction.id);
+        let members = eligible_rulers(ctx.world, faction.id);
 
         // Find previous leader from most recently ended LeaderOf relationship
         let previous_leader_id = find_previous_leader(ctx.world, faction.id);
@@ -635,6 +811,7 @@ fn fill_leader_vacancies(ctx: &mut TickContext, time: SimTimestamp, current_year
         if let Some(leader_id) = select_leader(
             &members,
             faction.government_type,
+            faction.succession_law,
             ctx.world,
             ctx.rng,
             previous_leader_id,
@@ -653,7 +830,7 @@ fn fill_leader_vacancies(ctx: &mut TickContext, time: SimTimestamp, current_year
                 .add_relationship(leader_id, faction.id, RelationshipKind::LeaderOf, time, ev);
 
             // Succession causes a stability hit
-            apply_succession_stability_hit(ctx.world, faction.id, ev);
+            apply_succession_stability_hit(ctx.world, faction.id, SUCCESSION_STABILITY_HIT, ev);
         }
     }
 }
@@ -671,13 +848,13 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
         avg_prosperity: f64,
         avg_cultural_tension: f64,
         avg_religious_tension: f64,
+        avg_festival_bonus: f64,
+        avg_unfree_fraction: f64,
     }
 
     let factions: Vec<HappinessInfo> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
+        .living(EntityKind::Faction)
         .map(|e| {
             let fd = e.data.as_faction();
             let old_happiness = fd.map(|f| f.happiness).unwrap_or(HAPPINESS_DEFAULT);
@@ -694,27 +871,34 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
                 avg_prosperity: DEFAULT_PROSPERITY, // filled below
                 avg_cultural_tension: 0.0,          // filled below
                 avg_religious_tension: 0.0,         // filled below
+                avg_festival_bonus: 0.0,            // filled below
+                avg_unfree_fraction: 0.0,           // filled below
             }
         })
         .collect();
 
     // Single pass over living settlements: aggregate prosperity, tension, building
-    // happiness bonus, and trade happiness bonus per faction. O(S) instead of O(F×S).
-    // Tuple: (prosperity_sum, cultural_tension_sum, building_bonus, religious_tension_sum, trade_happiness_sum, count)
-    let mut faction_agg: std::collections::BTreeMap<u64, (f64, f64, f64, f64, f64, u32)> =
-        std::collections::BTreeMap::new();
-    for (_id, e) in ctx.world.living(EntityKind::Settlement) {
+    // happiness bonus, trade and festival happiness bonus per faction. O(S) instead of O(F×S).
+    // Tuple: (prosperity_sum, cultural_tension_sum, building_bonus, religious_tension_sum, trade_happiness_sum, festival_sum, unfree_sum, count)
+    #[allow(clippy::type_complexity)]
+    let mut faction_agg: std::collections::BTreeMap<
+        u64,
+        (f64, f64, f64, f64, f64, f64, f64, u32),
+    > = std::collections::BTreeMap::new();
+    for e in ctx.world.living(EntityKind::Settlement) {
         if let Some(faction_id) = e.active_rel(RelationshipKind::MemberOf) {
-            let (prosperity, tension, religious_tension, trade_happiness) =
+            let (prosperity, tension, religious_tension, trade_happiness, festival, unfree) =
                 if let Some(sd) = e.data.as_settlement() {
                     (
                         sd.prosperity,
                         sd.cultural_tension,
                         sd.religious_tension,
                         sd.trade_happiness_bonus,
+                        sd.festival_happiness_bonus,
+                        sd.unfree_fraction,
                     )
                 } else {
-                    (DEFAULT_PROSPERITY, 0.0, 0.0, 0.0)
+                    (DEFAULT_PROSPERITY, 0.0, 0.0, 0.0, 0.0, 0.0)
                 };
             let building_bonus = e
                 .data
@@ -722,13 +906,15 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
                 .map_or(0.0, |sd| sd.building_bonuses.happiness);
             let entry = faction_agg
                 .entry(faction_id)
-                .or_insert((0.0, 0.0, 0.0, 0.0, 0.0, 0));
+                .or_insert((0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0));
             entry.0 += prosperity;
             entry.1 += tension;
             entry.2 += building_bonus;
             entry.3 += religious_tension;
             entry.4 += trade_happiness;
-            entry.5 += 1;
+            entry.5 += festival;
+            entry.6 += unfree;
+            entry.7 += 1;
         }
     }
 
@@ -738,12 +924,22 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
         .map(|mut f| {
             f.has_leader = has_leader(ctx.world, f.faction_id);
 
-            if let Some(&(prosperity_sum, tension_sum, _, rel_tension_sum, _, count)) =
-                faction_agg.get(&f.faction_id)
+            if let Some(&(
+                prosperity_sum,
+                tension_sum,
+                _,
+                rel_tension_sum,
+                _,
+                festival_sum,
+                unfree_sum,
+                count,
+            )) = faction_agg.get(&f.faction_id)
             {
                 f.avg_prosperity = prosperity_sum / count as f64;
                 f.avg_cultural_tension = tension_sum / count as f64;
                 f.avg_religious_tension = rel_tension_sum / count as f64;
+                f.avg_festival_bonus = festival_sum / count as f64;
+                f.avg_unfree_fraction = unfree_sum / count as f64;
             }
             f
         })
@@ -752,13 +948,15 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
     // Extract building happiness and trade happiness from the same pre-aggregated data
     let faction_building_happiness: std::collections::BTreeMap<u64, f64> = faction_agg
         .iter()
-        .map(|(&fid, &(_, _, bonus, _, _, _))| (fid, bonus))
+        .map(|(&fid, &(_, _, bonus, _, _, _, _, _))| (fid, bonus))
         .collect();
     let faction_trade_happiness: std::collections::BTreeMap<u64, f64> = faction_agg
         .iter()
-        .map(|(&fid, &(_, _, _, _, trade_bonus, _))| (fid, trade_bonus))
+        .map(|(&fid, &(_, _, _, _, trade_bonus, _, _, _))| (fid, trade_bonus))
         .collect();
 
+    let holding_capital = capitals::factions_holding_capital(ctx.world);
+
     let year_event = ctx.world.add_event(
         EventKind::Custom("happiness_tick".to_string()),
         time,
@@ -782,6 +980,11 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
         } else {
             HAPPINESS_LEADER_ABSENT_PENALTY
         };
+        let capital_bonus = if holding_capital.contains(&f.faction_id) {
+            capitals::CAPITAL_HAPPINESS_BONUS
+        } else {
+            0.0
+        };
 
         let trade_bonus = faction_trade_happiness
             .get(&f.faction_id)
@@ -791,6 +994,7 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
         let tension_penalty = -f.avg_cultural_tension * HAPPINESS_TENSION_WEIGHT;
         let religious_tension_penalty =
             -f.avg_religious_tension * HAPPINESS_RELIGIOUS_TENSION_WEIGHT;
+        let unfree_penalty = -f.avg_unfree_fraction * HAPPINESS_UNFREE_WEIGHT;
 
         // Building happiness bonus (temples)
         let building_happiness = faction_building_happiness
@@ -804,9 +1008,12 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
             + stability_bonus
             + peace_bonus
             + leader_bonus
+            + capital_bonus
             + trade_bonus
+            + f.avg_festival_bonus
             + tension_penalty
             + religious_tension_penalty
+            + unfree_penalty
             + building_happiness)
             .clamp(HAPPINESS_MIN_TARGET, HAPPINESS_MAX_TARGET);
         let noise: f64 = ctx
@@ -833,6 +1040,185 @@ fn update_happiness(ctx: &mut TickContext, time: SimTimestamp) {
     }
 }
 
+// --- Abdication ---
+
+/// Ailing leaders may hand power to an heir while still alive. The handover is
+/// orderly: the heir is chosen as in a normal succession, but the faction takes
+/// only a small stability hit and no vacancy is ever opened.
+fn check_abdications(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    let ailing: Vec<(u64, u64)> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .filter_map(|e| {
+            let leader_id = helpers::faction_leader(ctx.world, e.id)?;
+            let health = ctx.world.entities.get(&leader_id)?.data.as_person()?.health;
+            (health < ABDICATION_HEALTH_THRESHOLD).then_some((e.id, leader_id))
+        })
+        .collect();
+
+    for (faction_id, leader_id) in ailing {
+        if ctx.rng.random_range(0.0..1.0) >= ABDICATION_CHANCE {
+            continue;
+        }
+        let members: Vec<MemberInfo> = collect_faction_members(ctx.world, faction_id)
+            .into_iter()
+            .filter(|m| m.id != leader_id)
+            .collect();
+        let gov_type = get_government_type(ctx.world, faction_id);
+        let succession_law = get_succession_law(ctx.world, faction_id);
+        let Some(heir_id) = select_leader(
+            &members,
+            gov_type,
+            succession_law,
+            ctx.world,
+            ctx.rng,
+            Some(leader_id),
+        ) else {
+            continue;
+        };
+
+        let leader_name = helpers::entity_name(ctx.world, leader_id);
+        let heir_name = helpers::entity_name(ctx.world, heir_id);
+        let faction_name = helpers::entity_name(ctx.world, faction_id);
+        let abdication_ev = ctx.world.add_structured_event(
+            EventPayload::Abdication {
+                faction_id,
+                leader_id,
+                heir_id,
+            },
+            time,
+            format!(
+                "{leader_name} abdicated leadership of {faction_name} in favor of {heir_name} in year {current_year}"
+            ),
+        );
+        ctx.world
+            .add_event_participant(abdication_ev, leader_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(abdication_ev, faction_id, ParticipantRole::Object);
+        ctx.world.end_relationship(
+            leader_id,
+            faction_id,
+            RelationshipKind::LeaderOf,
+            time,
+            abdication_ev,
+        );
+
+        let ev = ctx.world.add_caused_structured_event(
+            EventPayload::Succession {
+                faction_id,
+                leader_id: heir_id,
+                previous_leader_id: Some(leader_id),
+            },
+            time,
+            format!("{heir_name} succeeded to leadership of {faction_name} in year {current_year}"),
+            abdication_ev,
+        );
+        ctx.world
+            .add_event_participant(ev, heir_id, ParticipantRole::Subject);
+        ctx.world
+            .add_event_participant(ev, faction_id, ParticipantRole::Object);
+        ctx.world
+            .add_relationship(heir_id, faction_id, RelationshipKind::LeaderOf, time, ev);
+        apply_succession_stability_hit(ctx.world, faction_id, ABDICATION_STABILITY_HIT, ev);
+    }
+}
+
+// --- Bankruptcy ---
+
+/// Track consecutive years of empty treasury. Once insolvency lasts
+/// `BANKRUPTCY_YEARS` the faction is bankrupt: it loses stability, squeezes an
+/// emergency levy out of its settlements, and signals that its armies go
+/// unpaid. Any income that refills the treasury (taxes, reparations, tribute)
+/// ends the streak.
+fn check_bankruptcy(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
+    let factions: Vec<(u64, f64)> = ctx
+        .world
+        .living(EntityKind::Faction)
+        .filter(|e| !helpers::is_non_state_faction(ctx.world, e.id))
+        .filter_map(|e| Some((e.id, e.data.as_faction()?.treasury)))
+        .collect();
+
+    for (faction_id, treasury) in factions {
+        let fd = ctx.world.faction_mut(faction_id);
+        if treasury > 0.0 {
+            fd.insolvent_years = 0;
+            continue;
+        }
+        fd.insolvent_years += 1;
+        let insolvent_years = fd.insolvent_years;
+        if insolvent_years < BANKRUPTCY_YEARS {
+            continue;
+        }
+
+        let faction_name = helpers::entity_name(ctx.world, faction_id);
+        let desc = if insolvent_years == BANKRUPTCY_YEARS {
+            format!("{faction_name} declared bankruptcy in year {current_year}")
+        } else {
+            format!(
+                "{faction_name} remained bankrupt in year {current_year}, {insolvent_years} years insolvent"
+            )
+        };
+        let ev = ctx.world.add_structured_event(
+            EventPayload::Bankruptcy {
+                faction_id,
+                insolvent_years,
+            },
+            time,
+            desc,
+        );
+        ctx.world
+            .add_event_participant(ev, faction_id, ParticipantRole::Subject);
+
+        helpers::apply_stability_delta(ctx.world, faction_id, BANKRUPTCY_STABILITY_HIT, ev);
+        levy_emergency_taxes(ctx.world, faction_id, ev);
+
+        ctx.signals.push(Signal {
+            event_id: ev,
+            kind: SignalKind::FactionBankrupt {
+                faction_id,
+                insolvent_years,
+            },
+        });
+    }
+}
+
+/// Seize a share of each member settlement's treasury to refill the faction's,
+/// at the cost of popular goodwill.
+fn levy_emergency_taxes(world: &mut World, faction_id: u64, event_id: u64) {
+    let mut raised = 0.0;
+    for sid in helpers::faction_settlements(world, faction_id) {
+        let sd = world.settlement_mut(sid);
+        let old = sd.treasury;
+        let levy = old.max(0.0) * EMERGENCY_LEVY_RATE;
+        if levy <= 0.0 {
+            continue;
+        }
+        sd.treasury = old - levy;
+        raised += levy;
+        world.record_change(
+            sid,
+            event_id,
+            "treasury",
+            serde_json::json!(old),
+            serde_json::json!(old - levy),
+        );
+    }
+
+    let fd = world.faction_mut(faction_id);
+    let old = fd.treasury;
+    fd.treasury += raised;
+    if raised > 0.0 {
+        world.record_change(
+            faction_id,
+            event_id,
+            "treasury",
+            serde_json::json!(old),
+            serde_json::json!(old + raised),
+        );
+    }
+    apply_happiness_delta(world, faction_id, EMERGENCY_LEVY_HAPPINESS_HIT, event_id);
+}
+
 // --- Legitimacy ---
 
 fn update_legitimacy(ctx: &mut TickContext, time: SimTimestamp) {
@@ -841,29 +1227,35 @@ fn update_legitimacy(ctx: &mut TickContext, time: SimTimestamp) {
         old_legitimacy: f64,
         happiness: f64,
         leader_prestige: f64,
+        leader_health: f64,
+        long_reign: f64,
     }
 
     let factions: Vec<LegitimacyInfo> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
+        .living(EntityKind::Faction)
         .map(|e| {
             let fd = e.data.as_faction();
-            let leader_prestige = helpers::faction_leader(ctx.world, e.id)
+            let leader_id = helpers::faction_leader(ctx.world, e.id);
+            let leader = leader_id
                 .and_then(|lid| ctx.world.entities.get(&lid))
-                .and_then(|le| le.data.as_person())
-                .map(|pd| pd.prestige)
-                .unwrap_or(0.0);
+                .and_then(|le| le.data.as_person());
+            let long_reign = leader_id.map_or(0.0, |lid| {
+                mourning::long_reign_factor(mourning::reign_years(ctx.world, lid, e.id, time))
+            });
             LegitimacyInfo {
                 faction_id: e.id,
                 old_legitimacy: fd.map(|f| f.legitimacy).unwrap_or(LEGITIMACY_BASE_TARGET),
                 happiness: fd.map(|f| f.happiness).unwrap_or(LEGITIMACY_BASE_TARGET),
-                leader_prestige,
+                leader_prestige: leader.map(|pd| pd.prestige).unwrap_or(0.0),
+                leader_health: leader.map(|pd| pd.health).unwrap_or(1.0),
+                long_reign,
             }
         })
         .collect();
 
+    let holding_capital = capitals::factions_holding_capital(ctx.world);
+
     let year_event = ctx.world.add_event(
         EventKind::Custom("legitimacy_tick".to_string()),
         time,
@@ -873,7 +1265,15 @@ fn update_legitimacy(ctx: &mut TickContext, time: SimTimestamp) {
     for f in &factions {
         let target = LEGITIMACY_BASE_TARGET
             + LEGITIMACY_HAPPINESS_WEIGHT * f.happiness
-            + f.leader_prestige * LEGITIMACY_LEADER_PRESTIGE_WEIGHT;
+            + f.leader_prestige * LEGITIMACY_LEADER_PRESTIGE_WEIGHT
+            - (1.0 - f.leader_health) * LEGITIMACY_FRAIL_LEADER_PENALTY
+            + f.long_reign * mourning::LONG_REIGN_LEGITIMACY_BONUS
+            + if holding_capital.contains(&f.faction_id) {
+                capitals::CAPITAL_LEGITIMACY_BONUS
+            } else {
+                0.0
+            }
+            + holy_sites::holy_site_legitimacy(ctx.world, f.faction_id);
         let new_legitimacy = (f.old_legitimacy
             + (target - f.old_legitimacy) * LEGITIMACY_DRIFT_RATE)
             .clamp(0.0, 1.0);
@@ -911,9 +1311,7 @@ fn update_stability(ctx: &mut TickContext, time: SimTimestamp) {
 
     let factions: Vec<FactionStability> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
+        .living(EntityKind::Faction)
         .map(|e| {
             let fd = e.data.as_faction();
             let theocracy_fervor = fd
@@ -1030,7 +1428,10 @@ struct SplitPlan {
     old_faction_id: u64,
     old_happiness: f64,
     old_gov_type: GovernmentType,
+    old_succession_law: SuccessionLaw,
     parent_prestige: f64,
+    /// Disloyal governor leading the settlement out, who takes the new throne.
+    instigator_id: Option<u64>,
 }
 
 fn check_faction_splits(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
@@ -1045,18 +1446,14 @@ fn evaluate_split_candidates(ctx: &mut TickContext) -> Vec<SplitPlan> {
         stability: f64,
         happiness: f64,
         government_type: GovernmentType,
+        succession_law: SuccessionLaw,
         prestige: f64,
     }
 
     let faction_sentiments: std::collections::BTreeMap<u64, FactionSentiment> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| {
-            e.kind == EntityKind::Faction
-                && e.end.is_none()
-                && !helpers::is_non_state_faction(ctx.world, e.id)
-        })
+        .living(EntityKind::Faction)
+        .filter(|e| !helpers::is_non_state_faction(ctx.world, e.id))
         .map(|e| {
             let fd = e.data.as_faction();
             (
@@ -1067,6 +1464,7 @@ fn evaluate_split_candidates(ctx: &mut TickContext) -> Vec<SplitPlan> {
                     government_type: fd
                         .map(|f| f.government_type)
                         .unwrap_or(GovernmentType::Chieftain),
+                    succession_law: fd.map(|f| f.succession_law).unwrap_or_default(),
                     prestige: fd.map(|f| f.prestige).unwrap_or(0.0),
                 },
             )
@@ -1081,9 +1479,7 @@ fn evaluate_split_candidates(ctx: &mut TickContext) -> Vec<SplitPlan> {
 
     let settlement_factions: Vec<SettlementFaction> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Settlement && e.end.is_none())
+        .living(EntityKind::Settlement)
         .filter_map(|e| {
             let faction_id = e
                 .relationships
@@ -1105,6 +1501,8 @@ fn evaluate_split_candidates(ctx: &mut TickContext) -> Vec<SplitPlan> {
         })
         .collect();
 
+    let governors = governors::governors_by_settlement(ctx.world);
+
     // Count settlements per faction
     let mut faction_settlement_count: std::collections::BTreeMap<u64, u32> =
         std::collections::BTreeMap::new();
@@ -1119,16 +1517,34 @@ fn evaluate_split_candidates(ctx: &mut TickContext) -> Vec<SplitPlan> {
             continue;
         };
 
-        // Skip if faction is reasonably stable or happy
-        if sentiment.stability >= SPLIT_STABILITY_THRESHOLD
-            || sentiment.happiness >= SPLIT_HAPPINESS_THRESHOLD
-        {
+        let sway = governors::governor_sway(
+            ctx.world,
+            governors.get(&sf.settlement_id).copied(),
+            sf.faction_id,
+        );
+
+        // Skip if faction is reasonably stable or happy, unless a disloyal
+        // governor is stirring secession anyway
+        let content = sentiment.stability >= SPLIT_STABILITY_THRESHOLD
+            || sentiment.happiness >= SPLIT_HAPPINESS_THRESHOLD;
+        let mut misery = if content {
+            0.0
+        } else {
+            (1.0 - sentiment.happiness) * (1.0 - sentiment.stability)
+        };
+        let mut instigator_id = None;
+        if let Some((governor_id, discontent)) = sway.instigator {
+            misery = misery.max(discontent);
+            instigator_id = Some(governor_id);
+        }
+        if misery <= 0.0 {
             continue;
         }
 
-        let misery = (1.0 - sentiment.happiness) * (1.0 - sentiment.stability);
-        let split_chance =
-            SPLIT_BASE_CHANCE * misery * (1.0 - sentiment.prestige * SPLIT_PRESTIGE_RESISTANCE);
+        let split_chance = SPLIT_BASE_CHANCE
+            * misery
+            * sway.split_factor
+            * (1.0 - sentiment.prestige * SPLIT_PRESTIGE_RESISTANCE);
 
         if ctx.rng.random_range(0.0..1.0) < split_chance {
             splits.push(SplitPlan {
@@ -1136,7 +1552,9 @@ fn evaluate_split_candidates(ctx: &mut TickContext) -> Vec<SplitPlan> {
                 old_faction_id: sf.faction_id,
                 old_happiness: sentiment.happiness,
                 old_gov_type: sentiment.government_type,
+                old_succession_law: sentiment.succession_law,
                 parent_prestige: sentiment.prestige,
+                instigator_id,
             });
             // Decrease count so we don't split a faction down to 0 settlements
             if let Some(c) = faction_settlement_count.get_mut(&sf.faction_id) {
@@ -1170,14 +1588,18 @@ fn execute_faction_splits(
         );
 
         // 50% inherit government type, 50% random
-        let gov_type = if ctx.rng.random_bool(SPLIT_GOV_TYPE_INHERITANCE_CHANCE) {
-            split.old_gov_type
+        let (gov_type, succession_law) = if ctx.rng.random_bool(SPLIT_GOV_TYPE_INHERITANCE_CHANCE) {
+            (split.old_gov_type, split.old_succession_law)
         } else {
-            gov_types[ctx.rng.random_range(0..gov_types.len())]
+            (
+                gov_types[ctx.rng.random_range(0..gov_types.len())],
+                SuccessionLaw::ALL[ctx.rng.random_range(0..SuccessionLaw::ALL.len())],
+            )
         };
 
         let new_faction_data = EntityData::Faction(FactionData {
             government_type: gov_type,
+            succession_law,
             stability: SPLIT_NEW_FACTION_STABILITY,
             happiness: (split.old_happiness + SPLIT_NEW_FACTION_HAPPINESS_BONUS).clamp(0.0, 1.0),
             legitimacy: SPLIT_NEW_FACTION_LEGITIMACY,
@@ -1192,6 +1614,7 @@ fn execute_faction_splits(
             economic_motivation: 0.0,
             diplomatic_trust: 1.0,
             betrayal_count: 0,
+            treaties_broken: 0,
             last_betrayal: None,
             last_betrayed_by: None,
             succession_crisis_at: None,
@@ -1199,16 +1622,30 @@ fn execute_faction_splits(
             prestige_tier: 0,
             trade_partner_routes: std::collections::BTreeMap::new(),
             marriage_alliances: std::collections::BTreeMap::new(),
+            ceasefire_until: std::collections::BTreeMap::new(),
             war_goals: std::collections::BTreeMap::new(),
             loyalty: std::collections::BTreeMap::new(),
             mercenary_wage: 0.0,
             unpaid_months: 0,
+            insolvent_years: 0,
             literacy_rate: 0.0,
+            technologies: std::collections::BTreeSet::new(),
+            expansionism: POLICY_NEUTRAL,
+            mercantilism: POLICY_NEUTRAL,
+            isolationism: POLICY_NEUTRAL,
+            heraldry: Heraldry::default(),
+            doctrine: MilitaryDoctrine::default(),
         });
 
         let new_faction_id =
             ctx.world
                 .add_entity(EntityKind::Faction, name, Some(time), new_faction_data, ev);
+        // Splinters bear a difference of their parent's arms
+        ctx.world.faction_mut(new_faction_id).heraldry = ctx
+            .world
+            .faction(split.old_faction_id)
+            .heraldry
+            .cadency(new_faction_id);
 
         // Move settlement to new faction
         ctx.world.end_relationship(
@@ -1225,6 +1662,9 @@ fn execute_faction_splits(
             time,
             ev,
         );
+        // The breakaway seat becomes the splinter's capital; a parent that
+        // loses its own seat this way chooses a new one next year
+        capitals::designate_capital(ctx.world, new_faction_id, split.settlement_id, time, ev);
 
         // Transfer NPCs in this settlement to new faction
         let npc_transfers: Vec<u64> = ctx
@@ -1257,6 +1697,24 @@ fn execute_faction_splits(
             );
         }
 
+        // An instigating governor trades their governorship for the new throne
+        if let Some(governor_id) = split.instigator_id {
+            ctx.world.end_relationship(
+                governor_id,
+                split.settlement_id,
+                RelationshipKind::GovernorOf,
+                time,
+                ev,
+            );
+            ctx.world.add_relationship(
+                governor_id,
+                new_faction_id,
+                RelationshipKind::LeaderOf,
+                time,
+                ev,
+            );
+        }
+
         // High chance old and new factions become enemies
         if ctx.rng.random_bool(SPLIT_POST_ENEMY_CHANCE) {
             ctx.world.add_relationship(
@@ -1297,9 +1755,7 @@ fn execute_faction_splits(
 fn dissolve_empty_factions(ctx: &mut TickContext, time: SimTimestamp, current_year: u32) {
     let empty_factions: Vec<u64> = ctx
         .world
-        .entities
-        .values()
-        .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
+        .living(EntityKind::Faction)
         .filter(|e| {
             !ctx.world.entities.values().any(|s| {
                 s.kind == EntityKind::Settlement
@@ -1354,6 +1810,9 @@ fn dissolve_empty_factions(ctx: &mut TickContext, time: SimTimestamp, current_ye
         }
 
         ctx.world.end_entity(faction_id, time, ev);
+
+        // Armies key off `faction_id` and would otherwise linger orphaned
+        conflicts::disband_armies_of_ended_faction(ctx, faction_id, time, ev);
     }
 }
 
@@ -1367,13 +1826,8 @@ pub(super) struct MemberInfo {
 
 pub(super) fn collect_faction_members(world: &World, faction_id: u64) -> Vec<MemberInfo> {
     world
-        .entities
-        .values()
-        .filter(|e| {
-            e.kind == EntityKind::Person
-                && e.end.is_none()
-                && e.has_active_rel(RelationshipKind::MemberOf, faction_id)
-        })
+        .active_sources(RelationshipKind::MemberOf, faction_id)
+        .filter(|e| e.kind == EntityKind::Person && e.end.is_none())
         .map(|e| {
             let pd = e.data.as_person();
             MemberInfo {
@@ -1385,9 +1839,17 @@ pub(super) fn collect_faction_members(world: &World, faction_id: u64) -> Vec<Mem
         .collect()
 }
 
+/// Members who could take the throne: everyone not held captive abroad.
+fn eligible_rulers(world: &World, faction_id: u64) -> Vec<MemberInfo> {
+    let mut members = collect_faction_members(world, faction_id);
+    members.retain(|m| conflicts::captor_of(world, m.id).is_none());
+    members
+}
+
 fn select_leader(
     members: &[MemberInfo],
     government_type: GovernmentType,
+    succession_law: SuccessionLaw,
     world: &World,
     rng: &mut dyn RngCore,
     previous_leader_id: Option<u64>,
@@ -1399,109 +1861,24 @@ fn select_leader(
     match government_type {
         GovernmentType::Hereditary => {
             // Try bloodline succession if we have a previous leader
-            if let Some(prev_id) = previous_leader_id {
-                let member_ids: std::collections::BTreeSet<u64> =
-                    members.iter().map(|m| m.id).collect();
-
-                // 1. Find living children of previous leader (Parent rels → target)
-                let children: Vec<&MemberInfo> =
-                    if let Some(prev_entity) = world.entities.get(&prev_id) {
-                        let child_ids: Vec<u64> = prev_entity
-                            .relationships
-                            .iter()
-                            .filter(|r| r.kind == RelationshipKind::Parent)
-                            .map(|r| r.target_entity_id)
-                            .filter(|id| member_ids.contains(id))
-                            .collect();
-                        members
-                            .iter()
-                            .filter(|m| child_ids.contains(&m.id))
-                            .collect()
-                    } else {
-                        Vec::new()
-                    };
-
-                if !children.is_empty() {
-                    // Pick oldest child (lowest birth_year)
-                    return children.iter().min_by_key(|m| m.born).map(|m| m.id);
-                }
-
-                // 2. Find siblings: previous leader's parents → parent's children → filter to members
-                if let Some(prev_entity) = world.entities.get(&prev_id) {
-                    let parent_ids: Vec<u64> = prev_entity
-                        .relationships
-                        .iter()
-                        .filter(|r| r.kind == RelationshipKind::Child)
-                        .map(|r| r.target_entity_id)
-                        .collect();
-
-                    let mut sibling_ids: Vec<u64> = Vec::new();
-                    for pid in &parent_ids {
-                        if let Some(parent_entity) = world.entities.get(pid) {
-                            for r in &parent_entity.relationships {
-                                if r.kind == RelationshipKind::Parent
-                                    && r.target_entity_id != prev_id
-                                    && member_ids.contains(&r.target_entity_id)
-                                    && !sibling_ids.contains(&r.target_entity_id)
-                                {
-                                    sibling_ids.push(r.target_entity_id);
-                                }
-                            }
-                        }
-                    }
-
-                    let siblings: Vec<&MemberInfo> = members
-                        .iter()
-                        .filter(|m| sibling_ids.contains(&m.id))
-                        .collect();
-                    if !siblings.is_empty() {
-                        return siblings.iter().min_by_key(|m| m.born).map(|m| m.id);
-                    }
-                }
+            if let Some(prev_id) = previous_leader_id
+                && let Some(heir) = select_heir(members, succession_law, world, rng, prev_id)
+            {
+                return Some(heir);
             }
 
             // Fallback: oldest faction member
             members.iter().min_by_key(|m| m.born).map(|m| m.id)
         }
         GovernmentType::Elective => {
-            // Weighted random: elder/scholar roles get 3x, Charismatic trait gets 2x
-            let preferred = [Role::Elder, Role::Scholar];
             let refs: Vec<&MemberInfo> = members.iter().collect();
-            let weights: Vec<u32> = refs
-                .iter()
-                .map(|m| {
-                    let mut w: u32 = if preferred.contains(&m.role) { 3 } else { 1 };
-                    if let Some(entity) = world.entities.get(&m.id)
-                        && has_trait(entity, &Trait::Charismatic)
-                    {
-                        w *= 2;
-                    }
-                    w
-                })
-                .collect();
-            let total: u32 = weights.iter().sum();
-            let roll = rng.random_range(0..total);
-            let mut cumulative = 0u32;
-            for (i, &w) in weights.iter().enumerate() {
-                cumulative += w;
-                if roll < cumulative {
-                    return Some(refs[i].id);
-                }
-            }
-            Some(refs.last().unwrap().id)
+            elect(&refs, world, rng)
         }
         GovernmentType::Chieftain
         | GovernmentType::BanditClan
         | GovernmentType::MercenaryCompany => {
-            // Chieftain/BanditClan: warrior preferred, else oldest
-            let warriors: Vec<&MemberInfo> =
-                members.iter().filter(|m| m.role == Role::Warrior).collect();
-            if !warriors.is_empty() {
-                // Oldest warrior
-                warriors.iter().min_by_key(|m| m.born).map(|m| m.id)
-            } else {
-                members.iter().min_by_key(|m| m.born).map(|m| m.id)
-            }
+            // Chieftain/BanditClan: the strongest rise, not merely the oldest
+            select_chieftain(members, world, rng)
         }
         GovernmentType::Theocracy => {
             // Theocracy: prefer Priest role, then Pious trait, else oldest
@@ -1527,62 +1904,197 @@ fn select_leader(
     }
 }
 
-fn has_leader(world: &World, faction_id: u64) -> bool {
-    world.entities.values().any(|e| {
-        e.kind == EntityKind::Person
-            && e.end.is_none()
-            && e.has_active_rel(RelationshipKind::LeaderOf, faction_id)
-    })
+/// Weighted pick among all members favoring warriors, personal prestige,
+/// and martial traits.
+fn select_chieftain(members: &[MemberInfo], world: &World, rng: &mut dyn RngCore) -> Option<u64> {
+    let weights: Vec<f64> = members
+        .iter()
+        .map(|m| {
+            let mut w = if m.role == Role::Warrior {
+                CHIEFTAIN_WARRIOR_WEIGHT
+            } else {
+                1.0
+            };
+            if let Some(entity) = world.entities.get(&m.id) {
+                let prestige = entity.data.as_person().map_or(0.0, |pd| pd.prestige);
+                w *= 1.0 + CHIEFTAIN_PRESTIGE_WEIGHT * prestige.max(0.0);
+                for martial in [Trait::Aggressive, Trait::Ruthless] {
+                    if has_trait(entity, &martial) {
+                        w *= CHIEFTAIN_MARTIAL_TRAIT_WEIGHT;
+                    }
+                }
+            }
+            w
+        })
+        .collect();
+    let total: f64 = weights.iter().sum();
+    let mut roll = rng.random::<f64>() * total;
+    for (m, &w) in members.iter().zip(&weights) {
+        if roll < w {
+            return Some(m.id);
+        }
+        roll -= w;
+    }
+    members.last().map(|m| m.id)
 }
 
-pub(super) fn apply_happiness_delta(world: &mut World, faction_id: u64, delta: f64, event_id: u64) {
-    let (old, new) = {
-        let Some(entity) = world.entities.get_mut(&faction_id) else {
-            return;
-        };
-        let Some(fd) = entity.data.as_faction_mut() else {
-            return;
-        };
-        let old = fd.happiness;
-        fd.happiness = (old + delta).clamp(0.0, 1.0);
-        (old, fd.happiness)
+/// Pick the late ruler's heir from the faction's members under the faction's
+/// succession law, or `None` when no kin are left to inherit.
+fn select_heir(
+    members: &[MemberInfo],
+    succession_law: SuccessionLaw,
+    world: &World,
+    rng: &mut dyn RngCore,
+    prev_id: u64,
+) -> Option<u64> {
+    let kin_members = |ids: &[u64]| -> Vec<&MemberInfo> {
+        members.iter().filter(|m| ids.contains(&m.id)).collect()
     };
-    world.record_change(
-        faction_id,
-        event_id,
-        "happiness",
-        serde_json::json!(old),
-        serde_json::json!(new),
-    );
+    let child_ids = children_of(world, prev_id);
+    let sibling_ids = siblings_of(world, prev_id);
+    let children = kin_members(&child_ids);
+    let siblings = kin_members(&sibling_ids);
+
+    match succession_law {
+        SuccessionLaw::Primogeniture => {
+            // Oldest child (lowest birth year), then oldest sibling
+            children
+                .iter()
+                .min_by_key(|m| m.born)
+                .or_else(|| siblings.iter().min_by_key(|m| m.born))
+                .map(|m| m.id)
+        }
+        SuccessionLaw::Ultimogeniture => children
+            .iter()
+            .max_by_key(|m| m.born)
+            .or_else(|| siblings.iter().max_by_key(|m| m.born))
+            .map(|m| m.id),
+        SuccessionLaw::Seniority | SuccessionLaw::Elective => {
+            // The whole family is eligible, whatever the branch
+            let mut family: Vec<u64> = child_ids.clone();
+            family.extend(&sibling_ids);
+            for &child_id in &child_ids {
+                family.extend(children_of(world, child_id));
+            }
+            for &parent_id in &parents_of(world, prev_id) {
+                family.extend(siblings_of(world, parent_id));
+            }
+            let family = kin_members(&family);
+            if succession_law == SuccessionLaw::Seniority {
+                family.iter().min_by_key(|m| m.born).map(|m| m.id)
+            } else if family.is_empty() {
+                None
+            } else {
+                elect(&family, world, rng)
+            }
+        }
+    }
 }
 
-fn apply_succession_stability_hit(world: &mut World, faction_id: u64, event_id: u64) {
-    // Prestigious new leader softens the succession instability
-    let new_leader_prestige = helpers::faction_leader(world, faction_id)
-        .and_then(|lid| world.entities.get(&lid))
-        .and_then(|e| e.data.as_person())
+/// Weighted election among candidates: elder/scholar roles get 3x, the
+/// Charismatic trait 2x.
+fn elect(candidates: &[&MemberInfo], world: &World, rng: &mut dyn RngCore) -> Option<u64> {
+    let preferred = [Role::Elder, Role::Scholar];
+    let weights: Vec<u32> = candidates
+        .iter()
+        .map(|m| {
+            let mut w: u32 = if preferred.contains(&m.role) { 3 } else { 1 };
+            if let Some(entity) = world.entities.get(&m.id)
+                && has_trait(entity, &Trait::Charismatic)
+            {
+                w *= 2;
+            }
+            w
+        })
+        .collect();
+    let total: u32 = weights.iter().sum();
+    let roll = rng.random_range(0..total);
+    let mut cumulative = 0u32;
+    for (i, &w) in weights.iter().enumerate() {
+        cumulative += w;
+        if roll < cumulative {
+            return Some(candidates[i].id);
+        }
+    }
+    candidates.last().map(|m| m.id)
+}
+
+/// Children of a person, living or dead (Parent rels → target is child).
+fn children_of(world: &World, person_id: u64) -> Vec<u64> {
+    world
+        .entities
+        .get(&person_id)
+        .map(|e| {
+            e.relationships
+                .iter()
+                .filter(|r| r.kind == RelationshipKind::Parent)
+                .map(|r| r.target_entity_id)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parents of a person (Child rels → target is parent).
+fn parents_of(world: &World, person_id: u64) -> Vec<u64> {
+    world
+        .entities
+        .get(&person_id)
+        .map(|e| {
+            e.relationships
+                .iter()
+                .filter(|r| r.kind == RelationshipKind::Child)
+                .map(|r| r.target_entity_id)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Siblings of a person: their parents' other children, without duplicates.
+fn siblings_of(world: &World, person_id: u64) -> Vec<u64> {
+    let mut sibling_ids: Vec<u64> = Vec::new();
+    for pid in parents_of(world, person_id) {
+        for child_id in children_of(world, pid) {
+            if child_id != person_id && !sibling_ids.contains(&child_id) {
+                sibling_ids.push(child_id);
+            }
+        }
+    }
+    sibling_ids
+}
+
+fn has_leader(world: &World, faction_id: u64) -> bool {
+    world
+        .active_sources(RelationshipKind::LeaderOf, faction_id)
+        .any(|e| e.kind == EntityKind::Person && e.end.is_none())
+}
+
+pub(super) fn apply_happiness_delta(world: &mut World, faction_id: u64, delta: f64, event_id: u64) {
+    if world
+        .entities
+        .get(&faction_id)
+        .is_some_and(|e| e.data.as_faction().is_some())
+    {
+        world.apply_effect(
+            Effect::unit_delta(faction_id, FactionField::Happiness, delta),
+            event_id,
+        );
+    }
+}
+
+fn apply_succession_stability_hit(
+    world: &mut World,
+    faction_id: u64,
+    base_hit: f64,
+    event_id: u64,
+) {
+    // Prestigious new leader softens the succession instability
+    let new_leader_prestige = helpers::faction_leader(world, faction_id)
+        .and_then(|lid| world.entities.get(&lid))
+        .and_then(|e| e.data.as_person())
         .map(|pd| pd.prestige)
         .unwrap_or(0.0);
-    let hit =
-        SUCCESSION_STABILITY_HIT * (1.0 - new_leader_prestige * SUCCESSION_PRESTIGE_SOFTENING);
-    let (old, new) = {
-        let Some(entity) = world.entities.get_mut(&faction_id) else {
-            return;
-        };
-        let Some(fd) = entity.data.as_faction_mut() else {
-            return;
-        };
-        let old = fd.stability;
-        fd.stability = (old + hit).clamp(0.0, 1.0);
-        (old, fd.stability)
-    };
-    world.record_change(
-        faction_id,
-        event_id,
-        "stability",
-        serde_json::json!(old),
-        serde_json::json!(new),
-    );
+    let hit = base_hit * (1.0 - new_leader_prestige * SUCCESSION_PRESTIGE_SOFTENING);
+    helpers::apply_stability_delta(world, faction_id, hit, event_id);
 }
 
 /// Find the most recent previous leader of a faction by scanning members'
@@ -1616,9 +2128,23 @@ fn get_government_type(world: &World, faction_id: u64) -> GovernmentType {
         .unwrap_or(GovernmentType::Chieftain)
 }
 
+fn get_succession_law(world: &World, faction_id: u64) -> SuccessionLaw {
+    world
+        .entities
+        .get(&faction_id)
+        .and_then(|e| e.data.as_faction())
+        .map(|f| f.succession_law)
+        .unwrap_or_default()
+}
+
 // --- Succession Claims ---
 
 /// Create claims for blood relatives of the dead leader who are in other factions.
+///
+/// Which relatives claim, and how strongly, follows the faction's succession
+/// law: seniority favours the late ruler's siblings and extends to uncles and
+/// aunts, ultimogeniture favours the youngest child, and an elective throne
+/// leaves kin no claim at all.
 fn create_succession_claims(
     world: &mut World,
     faction_id: u64,
@@ -1626,70 +2152,72 @@ fn create_succession_claims(
     current_year: u32,
     event_id: u64,
 ) {
+    let succession_law = get_succession_law(world, faction_id);
+    if succession_law == SuccessionLaw::Elective {
+        return;
+    }
+
     // Collect person→strength pairs for direct blood relatives
     let mut claim_candidates: Vec<(u64, f64, &str)> = Vec::new();
 
-    let Some(dead_entity) = world.entities.get(&dead_leader_id) else {
+    if !world.entities.contains_key(&dead_leader_id) {
         return;
-    };
+    }
 
-    // Children of the dead leader (Parent rels → target is child)
-    let children: Vec<u64> = dead_entity
-        .relationships
+    let children = children_of(world, dead_leader_id);
+    let sibling_ids = siblings_of(world, dead_leader_id);
+    let (child_strength, sibling_strength) = match succession_law {
+        SuccessionLaw::Seniority => (CLAIM_SIBLING_STRENGTH, CLAIM_CHILD_STRENGTH),
+        _ => (CLAIM_CHILD_STRENGTH, CLAIM_SIBLING_STRENGTH),
+    };
+    // Under ultimogeniture only the youngest living child has the full claim
+    let youngest_child = children
         .iter()
-        .filter(|r| r.kind == RelationshipKind::Parent)
-        .map(|r| r.target_entity_id)
-        .collect();
+        .filter_map(|id| world.entities.get(id))
+        .filter(|e| e.end.is_none())
+        .filter_map(|e| e.data.as_person().map(|pd| (e.id, pd.born)))
+        .max_by_key(|&(_, born)| born)
+        .map(|(id, _)| id);
 
     for &child_id in &children {
         if is_living_in_other_faction(world, child_id, faction_id) {
-            claim_candidates.push((child_id, CLAIM_CHILD_STRENGTH, "bloodline"));
+            let strength = if succession_law == SuccessionLaw::Ultimogeniture
+                && Some(child_id) != youngest_child
+            {
+                CLAIM_SIBLING_STRENGTH
+            } else {
+                child_strength
+            };
+            claim_candidates.push((child_id, strength, "bloodline"));
         }
 
         // Grandchildren: children of this child
-        if let Some(child_entity) = world.entities.get(&child_id) {
-            let grandchildren: Vec<u64> = child_entity
-                .relationships
-                .iter()
-                .filter(|r| r.kind == RelationshipKind::Parent)
-                .map(|r| r.target_entity_id)
-                .collect();
-            for &gc_id in &grandchildren {
-                if is_living_in_other_faction(world, gc_id, faction_id) {
-                    claim_candidates.push((gc_id, CLAIM_GRANDCHILD_STRENGTH, "bloodline"));
-                }
+        for gc_id in children_of(world, child_id) {
+            if is_living_in_other_faction(world, gc_id, faction_id) {
+                claim_candidates.push((gc_id, CLAIM_GRANDCHILD_STRENGTH, "bloodline"));
             }
         }
     }
 
-    // Siblings: dead leader's parents → parent's children
-    let parent_ids: Vec<u64> = dead_entity
-        .relationships
-        .iter()
-        .filter(|r| r.kind == RelationshipKind::Child)
-        .map(|r| r.target_entity_id)
-        .collect();
+    for &sib_id in &sibling_ids {
+        if is_living_in_other_faction(world, sib_id, faction_id) {
+            claim_candidates.push((sib_id, sibling_strength, "bloodline"));
+        }
+    }
 
-    let mut sibling_ids: Vec<u64> = Vec::new();
-    for &pid in &parent_ids {
-        if let Some(parent_entity) = world.entities.get(&pid) {
-            for r in &parent_entity.relationships {
-                if r.kind == RelationshipKind::Parent
-                    && r.target_entity_id != dead_leader_id
-                    && !sibling_ids.contains(&r.target_entity_id)
+    // Seniority reaches up a generation, to the late ruler's uncles and aunts
+    if succession_law == SuccessionLaw::Seniority {
+        for parent_id in parents_of(world, dead_leader_id) {
+            for elder_id in siblings_of(world, parent_id) {
+                if is_living_in_other_faction(world, elder_id, faction_id)
+                    && !claim_candidates.iter().any(|(c, _, _)| *c == elder_id)
                 {
-                    sibling_ids.push(r.target_entity_id);
+                    claim_candidates.push((elder_id, CLAIM_ELDER_KIN_STRENGTH, "bloodline"));
                 }
             }
         }
     }
 
-    for &sib_id in &sibling_ids {
-        if is_living_in_other_faction(world, sib_id, faction_id) {
-            claim_candidates.push((sib_id, CLAIM_SIBLING_STRENGTH, "bloodline"));
-        }
-    }
-
     // Spouse claims: find spouses of anyone who got a blood claim
     let blood_claimant_ids: Vec<u64> = claim_candidates.iter().map(|(id, _, _)| *id).collect();
     let mut spouse_claims: Vec<(u64, f64)> = Vec::new();
@@ -1715,6 +2243,31 @@ fn create_succession_claims(
         claim_candidates.push((spouse_id, strength, "marriage"));
     }
 
+    // Dynastic union: with no children to inherit, relatives married abroad under
+    // a marriage alliance carry the claim to their spouse's house
+    let heirless = children.iter().all(|id| {
+        world
+            .entities
+            .get(id)
+            .is_none_or(|e| e.end.is_some() || e.kind != EntityKind::Person)
+    });
+    if heirless {
+        let home_relatives: Vec<(u64, f64)> = sibling_ids
+            .iter()
+            .map(|&id| (id, sibling_strength))
+            .filter(|(id, _)| !claim_candidates.iter().any(|(c, _, _)| c == id))
+            .collect();
+        for (relative_id, strength) in home_relatives {
+            for (heir_id, heir_strength) in
+                alliance_marriage_heirs(world, relative_id, faction_id, strength)
+            {
+                if !claim_candidates.iter().any(|(c, _, _)| *c == heir_id) {
+                    claim_candidates.push((heir_id, heir_strength, "marriage_alliance"));
+                }
+            }
+        }
+    }
+
     // Now set claims on PersonData (skip if person already has a claim on this faction)
     let mut claimant_ids = Vec::new();
     for (person_id, strength, source) in &claim_candidates {
@@ -1856,6 +2409,7 @@ fn decay_claims(ctx: &mut TickContext) {
 /// Decay all faction and person grievances by `GRIEVANCE_BASE_DECAY` per year.
 /// NPCs decay at a trait-modulated rate.  Entries below threshold are removed.
 fn decay_grievances(ctx: &mut TickContext) {
+    let now = ctx.world.current_time;
     // Collect (entity_id, target_id, new_severity_or_remove) tuples
     let mut updates: Vec<(u64, u64, Option<f64>)> = Vec::new();
 
@@ -1866,7 +2420,7 @@ fn decay_grievances(ctx: &mut TickContext) {
         match &e.data {
             EntityData::Faction(fd) => {
                 for (&target, g) in &fd.grievances {
-                    let new_sev = g.severity - GRIEVANCE_BASE_DECAY;
+                    let new_sev = grv::decayed_severity(g, GRIEVANCE_BASE_DECAY, now);
                     if new_sev < GRIEVANCE_MIN_THRESHOLD {
                         updates.push((e.id, target, None));
                     } else {
@@ -1878,7 +2432,7 @@ fn decay_grievances(ctx: &mut TickContext) {
                 let mult = grv::trait_decay_multiplier(&pd.traits);
                 let decay = GRIEVANCE_BASE_DECAY * mult;
                 for (&target, g) in &pd.grievances {
-                    let new_sev = g.severity - decay;
+                    let new_sev = grv::decayed_severity(g, decay, now);
                     if new_sev < GRIEVANCE_MIN_THRESHOLD {
                         updates.push((e.id, target, None));
                     } else {
@@ -2066,6 +2620,55 @@ pub(super) fn create_split_claims(
     }
 }
 
+/// Foreign spouses of `relative_id` whose faction holds a marriage alliance with
+/// `faction_id`, plus the couple's children in that faction, with their claim strengths.
+fn alliance_marriage_heirs(
+    world: &World,
+    relative_id: u64,
+    faction_id: u64,
+    relative_strength: f64,
+) -> Vec<(u64, f64)> {
+    let Some(relative) = world.entities.get(&relative_id).filter(|e| e.end.is_none()) else {
+        return Vec::new();
+    };
+    let strength = relative_strength * CLAIM_MARRIAGE_ALLIANCE_FACTOR;
+    let mut heirs = Vec::new();
+    for spouse_id in relative.active_rels(RelationshipKind::Spouse) {
+        let Some(spouse) = world.entities.get(&spouse_id).filter(|e| e.end.is_none()) else {
+            continue;
+        };
+        let Some(spouse_faction) = spouse.relationships.iter().find_map(|r| {
+            (r.kind == RelationshipKind::MemberOf
+                && r.end.is_none()
+                && world
+                    .entities
+                    .get(&r.target_entity_id)
+                    .is_some_and(|t| t.kind == EntityKind::Faction && t.end.is_none()))
+            .then_some(r.target_entity_id)
+        }) else {
+            continue;
+        };
+        let allied_by_marriage = spouse_faction != faction_id
+            && world
+                .entities
+                .get(&spouse_faction)
+                .and_then(|e| e.data.as_faction())
+                .is_some_and(|fd| fd.marriage_alliances.contains_key(&faction_id));
+        if !allied_by_marriage {
+            continue;
+        }
+        heirs.push((spouse_id, strength));
+        for child_id in relative.active_rels(RelationshipKind::Parent) {
+            if spouse.has_active_rel(RelationshipKind::Parent, child_id)
+                && is_living_in_other_faction(world, child_id, faction_id)
+            {
+                heirs.push((child_id, strength));
+            }
+        }
+    }
+    heirs
+}
+
 /// Check if a person is alive and a member of a faction other than the given one.
 fn is_living_in_other_faction(world: &World, person_id: u64, excluded_faction: u64) -> bool {
     world.entities.get(&person_id).is_some_and(|e| {
@@ -2086,11 +2689,11 @@ fn is_living_in_other_faction(world: &World, person_id: u64, excluded_faction: u
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::World;
-    use crate::scenario::Scenario;
+    use crate::model::{StateChange, World};
+    use crate::scenario::{KingdomIds, Scenario};
     use crate::sim::demographics::DemographicsSystem;
     use crate::sim::runner::{SimConfig, run};
-    use crate::testutil::{assert_approx, deliver_signals};
+    use crate::testutil::{assert_approx, deliver_signals, tick_system};
     use crate::worldgen::{self, config::WorldGenConfig};
 
     fn test_event(world: &mut World) -> u64 {
@@ -2107,22 +2710,47 @@ mod tests {
             ..WorldGenConfig::default()
         };
         let mut world = worldgen::generate_world(config);
-        let mut systems: Vec<Box<dyn SimSystem>> =
-            vec![Box::new(DemographicsSystem), Box::new(PoliticsSystem)];
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![
+            Box::new(DemographicsSystem::new()),
+            Box::new(PoliticsSystem::new()),
+        ];
         run(&mut world, &mut systems, SimConfig::new(1, num_years, seed));
         world
     }
 
     #[test]
-    fn faction_gets_leader_on_first_tick() {
-        let world = make_political_world(42, 1);
+    fn collect_faction_members_matches_full_scan() {
+        let world = make_political_world(42, 50);
 
-        let factions: Vec<u64> = world
+        for faction in world
             .entities
             .values()
-            .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
-            .map(|e| e.id)
-            .collect();
+            .filter(|e| e.kind == EntityKind::Faction)
+        {
+            let via_index: Vec<u64> = collect_faction_members(&world, faction.id)
+                .iter()
+                .map(|m| m.id)
+                .collect();
+            let via_scan: Vec<u64> = world
+                .living(EntityKind::Person)
+                .filter(|e| e.has_active_rel(RelationshipKind::MemberOf, faction.id))
+                .map(|e| e.id)
+                .collect();
+            assert_eq!(via_index, via_scan, "members of {} diverge", faction.name);
+            assert_eq!(
+                has_leader(&world, faction.id),
+                world.entities.values().any(|e| e.kind == EntityKind::Person
+                    && e.end.is_none()
+                    && e.has_active_rel(RelationshipKind::LeaderOf, faction.id))
+            );
+        }
+    }
+
+    #[test]
+    fn faction_gets_leader_on_first_tick() {
+        let world = make_political_world(42, 1);
+
+        let factions: Vec<u64> = world.living_ids(EntityKind::Faction).collect();
         assert!(!factions.is_empty(), "should have factions");
 
         let mut ruled = 0;
@@ -2143,11 +2771,7 @@ mod tests {
         // Create a world, run 1 year to establish factions, then check stability
         let world = make_political_world(42, 50);
 
-        for faction in world
-            .entities
-            .values()
-            .filter(|e| e.kind == EntityKind::Faction && e.end.is_none())
-        {
+        for faction in world.living(EntityKind::Faction) {
             let fd = faction
                 .data
                 .as_faction()
@@ -2224,11 +2848,95 @@ mod tests {
             }
         }
 
-        let mut systems: Vec<Box<dyn SimSystem>> =
-            vec![Box::new(DemographicsSystem), Box::new(PoliticsSystem)];
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![
+            Box::new(DemographicsSystem::new()),
+            Box::new(PoliticsSystem::new()),
+        ];
         s.run(&mut systems, num_years, seed)
     }
 
+    #[test]
+    fn scenario_shaky_throne_reports_higher_coup_risk() {
+        let mut s = Scenario::at_year(100);
+        let shaky = s.add_kingdom_with(
+            "Shaky",
+            |fd| {
+                fd.stability = 0.2;
+                fd.happiness = 0.15;
+                fd.legitimacy = 0.2;
+            },
+            |_| {},
+            |_| {},
+        );
+        let steady = s.add_rival_kingdom_with(
+            "Steady",
+            shaky.region,
+            |fd| {
+                fd.stability = 0.9;
+                fd.happiness = 0.8;
+                fd.legitimacy = 0.9;
+            },
+            |_| {},
+            |_| {},
+        );
+        let world = s.build();
+
+        let shaky_risk = world.coup_risk(shaky.faction);
+        assert!(shaky_risk > 0.0);
+        assert!(shaky_risk > world.coup_risk(steady.faction));
+    }
+
+    #[test]
+    fn looser_coup_threshold_brings_more_coups() {
+        let coups = |config: PoliticsConfig| {
+            let mut total = 0;
+            for seed in 0..5 {
+                let mut s = Scenario::at_year(100);
+                for i in 0..3 {
+                    let k = s.add_kingdom_with(
+                        &format!("Uneasy Kingdom {i}"),
+                        |fd| {
+                            fd.stability = 0.6;
+                            fd.happiness = 0.3;
+                        },
+                        |sd| sd.population = 200,
+                        |_| {},
+                    );
+                    for j in 0..4 {
+                        s.person_in(&format!("Noble {i}-{j}"), k.faction, k.settlement)
+                            .role(Role::Warrior)
+                            .birth_year(70)
+                            .id();
+                    }
+                }
+                let mut world = s.build();
+                let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(PoliticsSystem::new())];
+                let sim_config = SimConfig {
+                    politics: config.clone(),
+                    ..SimConfig::new(100, 30, seed)
+                };
+                run(&mut world, &mut systems, sim_config).unwrap();
+                total += world
+                    .events
+                    .values()
+                    .filter(|e| matches!(e.kind, EventKind::Coup | EventKind::FailedCoup))
+                    .count();
+            }
+            total
+        };
+
+        let strict = coups(PoliticsConfig::default());
+        let loose = coups(PoliticsConfig {
+            coup_stability_threshold: 0.9,
+            ..PoliticsConfig::default()
+        });
+        assert!(
+            loose > strict,
+            "coups should strike steadier thrones when the threshold is looser \
+             (strict {strict}, loose {loose})"
+        );
+    }
+
     #[test]
     fn coup_eventually_occurs() {
         let mut total_coups = 0;
@@ -2341,6 +3049,7 @@ mod tests {
         let leader = select_leader(
             &members,
             GovernmentType::Hereditary,
+            SuccessionLaw::Primogeniture,
             &world,
             &mut rng,
             Some(parent),
@@ -2379,6 +3088,7 @@ mod tests {
         let leader = select_leader(
             &members,
             GovernmentType::Hereditary,
+            SuccessionLaw::Primogeniture,
             &world,
             &mut rng,
             Some(old_leader),
@@ -2390,6 +3100,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scenario_chieftain_favors_prestigious_young_warrior() {
+        use crate::scenario::Scenario;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut s = Scenario::at_year(100);
+        let faction = s.add_faction("Clan");
+        let old = s
+            .person("Greybeard", faction)
+            .birth_year(40)
+            .role(Role::Warrior)
+            .id();
+        let young = s
+            .person("Champion", faction)
+            .birth_year(80)
+            .role(Role::Warrior)
+            .prestige(0.8)
+            .add_trait(Trait::Aggressive)
+            .id();
+        let world = s.build();
+        let members = collect_faction_members(&world, faction);
+
+        let (mut young_wins, mut old_wins) = (0, 0);
+        for seed in 0..200 {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            let leader = select_leader(
+                &members,
+                GovernmentType::Chieftain,
+                SuccessionLaw::Primogeniture,
+                &world,
+                &mut rng,
+                None,
+            );
+            if leader == Some(young) {
+                young_wins += 1;
+            } else if leader == Some(old) {
+                old_wins += 1;
+            }
+        }
+        assert_eq!(young_wins + old_wins, 200);
+        assert!(
+            young_wins > old_wins * 3,
+            "prestigious warrior should win far more often: {young_wins} vs {old_wins}"
+        );
+        assert!(old_wins > 0, "selection should stay a weighted draw");
+
+        // Same RNG seed, same chieftain
+        let pick = |seed| {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            select_leader(
+                &members,
+                GovernmentType::Chieftain,
+                SuccessionLaw::Primogeniture,
+                &world,
+                &mut rng,
+                None,
+            )
+        };
+        assert_eq!(pick(7), pick(7));
+    }
+
     #[test]
     fn scenario_hereditary_succession_falls_back_to_oldest() {
         use crate::scenario::Scenario;
@@ -2412,6 +3184,7 @@ mod tests {
         let leader = select_leader(
             &members,
             GovernmentType::Hereditary,
+            SuccessionLaw::Primogeniture,
             &world,
             &mut rng,
             Some(old_leader),
@@ -2423,55 +3196,173 @@ mod tests {
         );
     }
 
+    /// A late ruler with two children and a younger sibling at court, plus an
+    /// unrelated elder, under the given succession law.
+    fn heir_under(law: SuccessionLaw) -> (Option<u64>, u64, u64, u64, u64) {
+        use crate::scenario::Scenario;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut s = Scenario::at_year(100);
+        let faction = s.add_faction("Dynasty");
+        let grandparent = s.add_person_standalone("Grandparent");
+        let old_leader = s.add_person_standalone("OldLeader");
+        let sibling = s.person("Sibling", faction).birth_year(45).id();
+        let eldest = s.person("Eldest", faction).birth_year(70).id();
+        let youngest = s.person("Youngest", faction).birth_year(80).id();
+        let elder = s
+            .person("Elder", faction)
+            .birth_year(30)
+            .role(Role::Elder)
+            .id();
+        s.make_parent_child(grandparent, old_leader);
+        s.make_parent_child(grandparent, sibling);
+        s.make_parent_child(old_leader, eldest);
+        s.make_parent_child(old_leader, youngest);
+
+        let world = s.build();
+        let members = collect_faction_members(&world, faction);
+        let mut rng = SmallRng::seed_from_u64(42);
+        let heir = select_leader(
+            &members,
+            GovernmentType::Hereditary,
+            law,
+            &world,
+            &mut rng,
+            Some(old_leader),
+        );
+        (heir, sibling, eldest, youngest, elder)
+    }
+
     #[test]
-    fn scenario_succession_creates_claims_for_children_in_other_faction() {
+    fn scenario_succession_law_changes_heir() {
+        let (heir, _, eldest, _, _) = heir_under(SuccessionLaw::Primogeniture);
+        assert_eq!(heir, Some(eldest));
+
+        let (heir, _, _, youngest, _) = heir_under(SuccessionLaw::Ultimogeniture);
+        assert_eq!(heir, Some(youngest));
+
+        let (heir, sibling, _, _, _) = heir_under(SuccessionLaw::Seniority);
+        assert_eq!(
+            heir,
+            Some(sibling),
+            "seniority passes over the children to the eldest of the family"
+        );
+
+        let (heir, sibling, eldest, youngest, elder) = heir_under(SuccessionLaw::Elective);
+        let heir = heir.expect("the family should elect an heir");
+        assert!([sibling, eldest, youngest].contains(&heir));
+        assert_ne!(heir, elder, "only the family is eligible");
+    }
+
+    /// Claimants on a hereditary throne after its ruler dies, leaving a child
+    /// and an uncle abroad, under the given succession law.
+    fn claimants_under(law: SuccessionLaw) -> (Vec<u64>, u64, u64) {
         use crate::scenario::Scenario;
 
         let mut s = Scenario::at_year(100);
         let fa = s
             .faction("Dynasty A")
             .government_type(GovernmentType::Hereditary)
+            .succession_law(law)
             .id();
         let fb = s.add_faction("Dynasty B");
-
-        // Dead leader of faction A
+        let grandfather = s.add_person_standalone("Grandfather");
+        let father = s.add_person_standalone("Father");
         let dead_leader = s.add_person("Old King", fa);
-        s.make_leader(dead_leader, fa);
-
-        // Child in faction B (should get claim)
         let child = s.add_person("Prince", fb);
+        let uncle = s.add_person("Uncle", fb);
+        s.make_parent_child(grandfather, father);
+        s.make_parent_child(grandfather, uncle);
+        s.make_parent_child(father, dead_leader);
         s.make_parent_child(dead_leader, child);
-
-        // New successor in faction A
-        let successor = s.person("Successor", fa).birth_year(60).id();
-
         let mut world = s.build();
 
-        // Simulate leader death + succession
         let ev = world.add_event(
             EventKind::Death,
             SimTimestamp::from_year(100),
             "Old King died".to_string(),
         );
-        // End the leader
-        world.entities.get_mut(&dead_leader).unwrap().end = Some(SimTimestamp::from_year(100));
-        for r in &mut world.entities.get_mut(&dead_leader).unwrap().relationships {
-            if r.kind == RelationshipKind::LeaderOf && r.end.is_none() {
-                r.end = Some(SimTimestamp::from_year(100));
-            }
-        }
-        // Install successor
-        world.add_relationship(
-            successor,
-            fa,
-            RelationshipKind::LeaderOf,
-            SimTimestamp::from_year(100),
-            ev,
-        );
-        // Now create succession claims
         create_succession_claims(&mut world, fa, dead_leader, 100, ev);
 
-        // Child should have a claim on faction A
+        let claimants = world
+            .living(EntityKind::Person)
+            .filter(|e| {
+                e.data
+                    .as_person()
+                    .is_some_and(|pd| pd.claims.contains_key(&fa))
+            })
+            .map(|e| e.id)
+            .collect();
+        (claimants, child, uncle)
+    }
+
+    #[test]
+    fn scenario_succession_law_changes_claimants() {
+        let (primogeniture, child, uncle) = claimants_under(SuccessionLaw::Primogeniture);
+        assert!(primogeniture.contains(&child));
+        assert!(!primogeniture.contains(&uncle));
+
+        let (seniority, child, uncle) = claimants_under(SuccessionLaw::Seniority);
+        assert!(seniority.contains(&child));
+        assert!(
+            seniority.contains(&uncle),
+            "seniority extends claims to the late ruler's elders"
+        );
+
+        let (elective, _, _) = claimants_under(SuccessionLaw::Elective);
+        assert!(elective.is_empty(), "an elected throne leaves kin no claim");
+    }
+
+    #[test]
+    fn scenario_succession_creates_claims_for_children_in_other_faction() {
+        use crate::scenario::Scenario;
+
+        let mut s = Scenario::at_year(100);
+        let fa = s
+            .faction("Dynasty A")
+            .government_type(GovernmentType::Hereditary)
+            .id();
+        let fb = s.add_faction("Dynasty B");
+
+        // Dead leader of faction A
+        let dead_leader = s.add_person("Old King", fa);
+        s.make_leader(dead_leader, fa);
+
+        // Child in faction B (should get claim)
+        let child = s.add_person("Prince", fb);
+        s.make_parent_child(dead_leader, child);
+
+        // New successor in faction A
+        let successor = s.person("Successor", fa).birth_year(60).id();
+
+        let mut world = s.build();
+
+        // Simulate leader death + succession
+        let ev = world.add_event(
+            EventKind::Death,
+            SimTimestamp::from_year(100),
+            "Old King died".to_string(),
+        );
+        // End the leader
+        world.entities.get_mut(&dead_leader).unwrap().end = Some(SimTimestamp::from_year(100));
+        for r in &mut world.entities.get_mut(&dead_leader).unwrap().relationships {
+            if r.kind == RelationshipKind::LeaderOf && r.end.is_none() {
+                r.end = Some(SimTimestamp::from_year(100));
+            }
+        }
+        // Install successor
+        world.add_relationship(
+            successor,
+            fa,
+            RelationshipKind::LeaderOf,
+            SimTimestamp::from_year(100),
+            ev,
+        );
+        // Now create succession claims
+        create_succession_claims(&mut world, fa, dead_leader, 100, ev);
+
+        // Child should have a claim on faction A
         let claim = world
             .person(child)
             .claims
@@ -2623,6 +3514,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scenario_faction_leadership_ranks_notables_by_prestige() {
+        let mut s = Scenario::at_year(100);
+        let k = s.add_kingdom("Realm");
+        let minor = s.person("Minor", k.faction).prestige(0.1).id();
+        let grand = s.person("Grand", k.faction).prestige(0.8).id();
+        let middling = s.person("Middling", k.faction).prestige(0.4).id();
+        let town = s.settlement("Town", k.faction, k.region).id();
+        let governor = s.person_in("Governor", k.faction, town).prestige(0.3).id();
+        s.add_relationship(governor, town, RelationshipKind::GovernorOf);
+        let world = s.build();
+
+        let leadership = world.faction_leadership(k.faction);
+        assert_eq!(leadership.leader, Some(k.leader));
+        assert_eq!(leadership.regent, None);
+        assert_eq!(leadership.governors, vec![(town, governor)]);
+        let ranked: Vec<u64> = leadership
+            .notables
+            .iter()
+            .copied()
+            .filter(|id| [minor, grand, middling, governor].contains(id))
+            .collect();
+        assert_eq!(ranked, vec![grand, middling, governor, minor]);
+        assert!(!leadership.notables.contains(&k.leader));
+    }
+
+    #[test]
+    fn scenario_child_ruler_governs_through_parent_regent() {
+        let mut s = Scenario::at_year(100);
+        let f = s.add_faction("Realm");
+        let mother = s.person("Queen Mother", f).birth_year(70).id();
+        let child = s.person("Child King", f).birth_year(95).id();
+        s.make_parent_child(mother, child);
+        s.make_leader(child, f);
+        let world = s.build();
+
+        let leadership = world.faction_leadership(f);
+        assert_eq!(leadership.leader, Some(child));
+        assert_eq!(leadership.regent, Some(mother));
+    }
+
+    #[test]
+    fn scenario_atrocity_grievance_outlasts_war_defeat() {
+        use crate::scenario::Scenario;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut s = Scenario::at_year(100);
+        let victim = s.add_faction("Victim");
+        let defeated = s.add_faction("Defeated");
+        let wrongdoer = s.add_faction("Wrongdoer");
+        let mut world = s.build();
+        let ev = test_event(&mut world);
+        let time = world.current_time;
+        grv::add_grievance(&mut world, defeated, wrongdoer, 0.5, "war_defeat", time, ev);
+        grv::add_atrocity_grievance(&mut world, victim, wrongdoer, 0.5, "execution", time, ev);
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut ordinary_forgotten = None;
+        for year in 101..=220 {
+            world.current_time = SimTimestamp::from_year(year);
+            let mut signals = Vec::new();
+            let mut ctx = TickContext {
+                world: &mut world,
+                rng: &mut rng,
+                signals: &mut signals,
+                inbox: &[],
+            };
+            decay_grievances(&mut ctx);
+            if ordinary_forgotten.is_none()
+                && grv::get_grievance(&world, defeated, wrongdoer) < GRIEVANCE_MIN_THRESHOLD
+            {
+                ordinary_forgotten = Some(year);
+            }
+        }
+
+        let forgotten = ordinary_forgotten.expect("a war defeat should fade within a century");
+        assert!(forgotten < 130, "war defeat lingered until {forgotten}");
+        let feud = grv::get_grievance(&world, victim, wrongdoer);
+        assert!(
+            feud >= grv::BLOOD_FEUD_FLOOR,
+            "blood feud should still burn after 120 years, got {feud}"
+        );
+    }
+
     #[test]
     fn scenario_succession_crisis_fires_for_strong_claimant() {
         use crate::scenario::Scenario;
@@ -2826,7 +3802,7 @@ mod tests {
                 defender_id: fb,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(fa).happiness,
@@ -2863,7 +3839,7 @@ mod tests {
                 tribute_years: 0,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(winner).happiness,
@@ -2900,7 +3876,7 @@ mod tests {
                 tribute_years: 0,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(loser).happiness,
@@ -2935,7 +3911,7 @@ mod tests {
                 new_faction_id: new_f,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(old_f).stability,
@@ -2945,6 +3921,148 @@ mod tests {
         );
     }
 
+    /// Capture either the capital or an ordinary town from a faction at 0.7
+    /// stability and let politics handle it. Returns (world, faction, capital, town).
+    fn capture_from_capital_world(capture_capital: bool) -> (World, u64, u64, u64) {
+        let mut s = Scenario::at_year(100);
+        let r = s.add_region("R");
+        let old_f = s.faction("OldOwner").stability(0.7).id();
+        let new_f = s.faction("Conqueror").id();
+        let capital = s.settlement("Capital", old_f, r).population(500).id();
+        let town = s.settlement("Town", old_f, r).population(300).id();
+        s.settlement("Market", old_f, r).population(200).id();
+        s.settlement("Camp", new_f, r).population(200).id();
+        s.add_relationship(capital, old_f, RelationshipKind::CapitalOf);
+        let mut world = s.build();
+        let ev = test_event(&mut world);
+        let time = world.current_time;
+
+        let captured = if capture_capital { capital } else { town };
+        world.end_relationship(captured, old_f, RelationshipKind::MemberOf, time, ev);
+        world.add_relationship(captured, new_f, RelationshipKind::MemberOf, time, ev);
+        let inbox = vec![Signal {
+            event_id: ev,
+            kind: SignalKind::SettlementCaptured {
+                settlement_id: captured,
+                old_faction_id: old_f,
+                new_faction_id: new_f,
+            },
+        }];
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
+        (world, old_f, capital, town)
+    }
+
+    #[test]
+    fn scenario_capital_capture_hits_harder_than_ordinary_capture() {
+        let (ordinary, old_f, capital, _) = capture_from_capital_world(false);
+        let (fallen, _, _, town) = capture_from_capital_world(true);
+
+        assert!(
+            fallen.faction(old_f).stability < ordinary.faction(old_f).stability,
+            "losing the capital should shake the faction more ({} vs {})",
+            fallen.faction(old_f).stability,
+            ordinary.faction(old_f).stability
+        );
+        assert!(fallen.faction(old_f).legitimacy < ordinary.faction(old_f).legitimacy);
+        assert_eq!(faction_capital(&ordinary, old_f), Some(capital));
+        assert_eq!(
+            faction_capital(&fallen, old_f),
+            Some(town),
+            "the government should relocate to the largest remaining settlement"
+        );
+        assert!(fallen.events.values().any(|e| {
+            EventPayload::from_data(&e.data)
+                == Some(EventPayload::CapitalRelocated {
+                    faction_id: old_f,
+                    lost_settlement_id: capital,
+                    to_settlement_id: town,
+                })
+        }));
+    }
+
+    #[test]
+    fn scenario_capital_less_faction_seats_its_largest_settlement() {
+        let mut s = Scenario::at_year(100);
+        let r = s.add_region("R");
+        let f = s.faction("Kingdom").id();
+        s.settlement("Village", f, r).population(100).id();
+        let city = s.settlement("City", f, r).population(900).id();
+        let mut world = s.build();
+        assert_eq!(faction_capital(&world, f), None);
+
+        tick_system(&mut world, &mut PoliticsSystem::new(), 100, 42);
+
+        assert_eq!(faction_capital(&world, f), Some(city));
+    }
+
+    #[test]
+    fn scenario_conquest_takes_prisoners_into_bondage() {
+        let mut s = Scenario::at_year(100);
+        let r = s.add_region("R");
+        let old_f = s.faction("OldOwner").id();
+        let new_f = s.faction("Conqueror").id();
+        let sett = s.settlement("Town", old_f, r).population(200).id();
+        let mut world = s.build();
+        let ev = test_event(&mut world);
+
+        let inbox = vec![Signal {
+            event_id: ev,
+            kind: SignalKind::SettlementCaptured {
+                settlement_id: sett,
+                old_faction_id: old_f,
+                new_faction_id: new_f,
+            },
+        }];
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
+        let after_one = world.settlement(sett).unfree_fraction;
+        assert!(after_one > 0.0, "conquest should take prisoners");
+
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
+        let after_two = world.settlement(sett).unfree_fraction;
+        assert!(after_two > after_one && after_two < 1.0);
+    }
+
+    #[test]
+    fn scenario_large_unfree_population_revolts_more_often() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let revolts = |unfree: f64| {
+            (0..300)
+                .filter(|&seed| {
+                    let mut s = Scenario::at_year(100);
+                    let r = s.add_region("R");
+                    let f = s.faction("Kingdom").happiness(0.2).id();
+                    s.settlement("Town", f, r)
+                        .population(500)
+                        .unfree_fraction(unfree)
+                        .id();
+                    let mut world = s.build();
+                    let mut rng = SmallRng::seed_from_u64(seed);
+                    let mut signals = Vec::new();
+                    let mut ctx = TickContext {
+                        world: &mut world,
+                        rng: &mut rng,
+                        signals: &mut signals,
+                        inbox: &[],
+                    };
+                    let time = ctx.world.current_time;
+                    unfree::update_unfree_population(&mut ctx, time);
+                    world
+                        .events
+                        .values()
+                        .any(|e| e.kind == EventKind::SlaveRevolt)
+                })
+                .count()
+        };
+        let few = revolts(0.25);
+        let many = revolts(0.8);
+        assert!(
+            many > few * 2,
+            "large unfree population should revolt more: {many} vs {few}"
+        );
+    }
+
     #[test]
     fn scenario_plague_hits_faction_happiness_and_stability() {
         let mut s = Scenario::at_year(100);
@@ -2961,7 +4079,7 @@ mod tests {
                 disease_id: 999,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(f).happiness,
@@ -2996,7 +4114,7 @@ mod tests {
                 defender_faction_id: defender,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(defender).happiness,
@@ -3030,9 +4148,10 @@ mod tests {
                 attacker_faction_id: attacker,
                 defender_faction_id: defender,
                 outcome: SiegeOutcome::Lifted,
+                civilian_deaths: 0,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(defender).happiness,
@@ -3061,7 +4180,7 @@ mod tests {
                 severity,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         let expected_happiness =
             0.7 + DISASTER_HAPPINESS_BASE - severity * DISASTER_HAPPINESS_SEVERITY_WEIGHT;
@@ -3097,7 +4216,7 @@ mod tests {
                 months_duration: 6,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(f).happiness,
@@ -3123,7 +4242,7 @@ mod tests {
                 region_id: r,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(f).stability,
@@ -3151,7 +4270,7 @@ mod tests {
                 treasury_stolen: 5.0,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(f).happiness,
@@ -3187,7 +4306,7 @@ mod tests {
                 income_lost: 10.0,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(fa).happiness,
@@ -3223,7 +4342,7 @@ mod tests {
                 betrayer_leader_id: leader,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(victim).happiness,
@@ -3258,7 +4377,7 @@ mod tests {
                 count: 50,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(f).happiness,
@@ -3285,7 +4404,7 @@ mod tests {
                 culture_id: 999,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         assert_approx(
             world.faction(f).stability,
@@ -3327,7 +4446,7 @@ mod tests {
                 previous_leader_id: k.leader,
             },
         }];
-        deliver_signals(&mut world, &mut PoliticsSystem, &inbox, 42);
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
 
         // A succession event should have been created
         let succession_count = world
@@ -3346,4 +4465,500 @@ mod tests {
             "faction should have a new leader after succession",
         );
     }
+
+    /// Stability lost when a prestigious king of forty years dies and is
+    /// succeeded by his eldest child. A contested succession leaves a
+    /// weak heir facing a younger sibling's claim.
+    fn stability_lost_after_long_reign(contested: bool) -> (f64, World, u64) {
+        let mut s = Scenario::at_year(100);
+        let k = s.add_kingdom_with(
+            "Realm",
+            |fd| {
+                fd.government_type = GovernmentType::Hereditary;
+                fd.stability = 0.7;
+                fd.happiness = 0.6;
+            },
+            |_| {},
+            |pd| {
+                pd.born = SimTimestamp::from_year(30);
+                pd.prestige = 0.8;
+            },
+        );
+        let heir_prestige = if contested { 0.1 } else { 0.7 };
+        let heir = s
+            .person_in("Heir", k.faction, k.settlement)
+            .birth_year(60)
+            .prestige(heir_prestige)
+            .id();
+        s.make_parent_child(k.leader, heir);
+        if contested {
+            let sibling = s
+                .person_in("Younger", k.faction, k.settlement)
+                .birth_year(65)
+                .id();
+            s.make_parent_child(k.leader, sibling);
+        }
+        let mut world = s.build();
+        world
+            .entities
+            .get_mut(&k.leader)
+            .unwrap()
+            .relationships
+            .iter_mut()
+            .find(|r| r.kind == RelationshipKind::LeaderOf)
+            .unwrap()
+            .start = SimTimestamp::from_year(60);
+
+        let before = world.faction(k.faction).stability;
+        let now = world.current_time;
+        let death_ev = world.add_event(EventKind::Death, now, "the old king died".to_string());
+        helpers::end_all_person_relationships(&mut world, k.leader, now, death_ev);
+        world.end_entity(k.leader, now, death_ev);
+
+        let inbox = vec![Signal {
+            event_id: death_ev,
+            kind: SignalKind::LeaderVacancy {
+                faction_id: k.faction,
+                previous_leader_id: k.leader,
+            },
+        }];
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
+        assert_eq!(helpers::faction_leader(&world, k.faction), Some(heir));
+        let lost = before - world.faction(k.faction).stability;
+        (lost, world, k.faction)
+    }
+
+    #[test]
+    fn scenario_prompt_strong_heir_after_long_reign_loses_less_stability() {
+        let (smooth, world, faction) = stability_lost_after_long_reign(false);
+        let (contested, _, _) = stability_lost_after_long_reign(true);
+        assert!(
+            smooth < contested,
+            "smooth succession should cost less stability: {smooth} vs {contested}"
+        );
+        assert!(
+            smooth < -SUCCESSION_STABILITY_HIT * (1.0 - 0.7 * SUCCESSION_PRESTIGE_SOFTENING),
+            "a long reign should ease the ordinary succession hit: {smooth}"
+        );
+        assert!(
+            world.events.values().any(|e| e.kind == EventKind::Mourning),
+            "the realm should mourn a prestigious, long-reigning king"
+        );
+        assert!(
+            (world.faction(faction).happiness - 0.6).abs() < 0.05,
+            "a strong heir should rally the realm out of most of its mourning"
+        );
+    }
+
+    /// Hereditary kingdom whose aged leader has one adult child.
+    fn aging_dynasty(name: &str, health: f64) -> (Scenario, KingdomIds, u64) {
+        let mut s = Scenario::at_year(100);
+        let k = s.add_kingdom_with(
+            name,
+            |fd| fd.government_type = GovernmentType::Hereditary,
+            |_| {},
+            |pd| {
+                pd.born = SimTimestamp::from_year(22);
+                pd.health = health;
+            },
+        );
+        let heir = s
+            .person(&format!("{name} Heir"), k.faction)
+            .birth_year(60)
+            .id();
+        s.make_parent_child(k.leader, heir);
+        (s, k, heir)
+    }
+
+    fn stability_change(world: &World, event_id: u64, faction_id: u64) -> f64 {
+        world
+            .event_effects
+            .iter()
+            .find_map(|e| match &e.effect {
+                StateChange::PropertyChanged {
+                    field,
+                    old_value,
+                    new_value,
+                } if e.event_id == event_id
+                    && e.entity_id == faction_id
+                    && field == "stability" =>
+                {
+                    Some(new_value.as_f64().unwrap() - old_value.as_f64().unwrap())
+                }
+                _ => None,
+            })
+            .expect("succession should change stability")
+    }
+
+    #[test]
+    fn scenario_ailing_leader_eventually_abdicates_to_heir() {
+        let (s, k, heir) = aging_dynasty("Aldor", 0.1);
+        let mut world = s.build();
+
+        for year in 100..130 {
+            tick_system(&mut world, &mut PoliticsSystem::new(), year, 7);
+        }
+
+        let abdication = world
+            .events
+            .values()
+            .find(|e| e.kind == EventKind::Abdication)
+            .expect("ailing leader should abdicate");
+        assert_eq!(
+            EventPayload::from_data(&abdication.data),
+            Some(EventPayload::Abdication {
+                faction_id: k.faction,
+                leader_id: k.leader,
+                heir_id: heir,
+            })
+        );
+        assert!(
+            world.entity(k.leader).end.is_none(),
+            "abdication is not death"
+        );
+        assert_eq!(helpers::faction_leader(&world, k.faction), Some(heir));
+    }
+
+    #[test]
+    fn healthy_leader_does_not_abdicate() {
+        let (s, _, _) = aging_dynasty("Aldor", 0.9);
+        let mut world = s.build();
+        for year in 100..130 {
+            tick_system(&mut world, &mut PoliticsSystem::new(), year, 7);
+        }
+        assert!(
+            !world
+                .events
+                .values()
+                .any(|e| e.kind == EventKind::Abdication)
+        );
+    }
+
+    #[test]
+    fn scenario_abdication_is_gentler_than_death() {
+        let (s, abdicating, _) = aging_dynasty("Aldor", 0.0);
+        let mut world = s.build();
+        let mut year = 100;
+        let abdication_succession = loop {
+            tick_system(&mut world, &mut PoliticsSystem::new(), year, 7);
+            if let Some(ev) = world.events.values().find(|e| {
+                e.kind == EventKind::Succession
+                    && e.caused_by
+                        .is_some_and(|c| world.events[&c].kind == EventKind::Abdication)
+            }) {
+                break ev.id;
+            }
+            year += 1;
+            assert!(year < 150, "leader at zero health should abdicate");
+        };
+        let abdication_hit = stability_change(&world, abdication_succession, abdicating.faction);
+
+        let (s, dying, _) = aging_dynasty("Brell", 1.0);
+        let mut world = s.build();
+        let death_ev = world.add_event(
+            EventKind::Death,
+            world.current_time,
+            "leader died".to_string(),
+        );
+        world.end_relationship(
+            dying.leader,
+            dying.faction,
+            RelationshipKind::LeaderOf,
+            world.current_time,
+            death_ev,
+        );
+        world.end_entity(dying.leader, world.current_time, death_ev);
+        let inbox = vec![Signal {
+            event_id: death_ev,
+            kind: SignalKind::LeaderVacancy {
+                faction_id: dying.faction,
+                previous_leader_id: dying.leader,
+            },
+        }];
+        deliver_signals(&mut world, &mut PoliticsSystem::new(), &inbox, 42);
+        let death_succession = world
+            .events
+            .values()
+            .find(|e| e.kind == EventKind::Succession)
+            .unwrap()
+            .id;
+        let death_hit = stability_change(&world, death_succession, dying.faction);
+
+        assert!(abdication_hit < 0.0);
+        assert!(
+            abdication_hit.abs() < death_hit.abs(),
+            "abdication hit {abdication_hit} should be milder than death hit {death_hit}"
+        );
+    }
+
+    /// Old King of Aldor dies childless; his sister is married to the king of
+    /// Brell, optionally under a marriage alliance between the two houses.
+    fn dynastic_union_scenario(alliance: bool) -> (World, u64, u64, u64, u64) {
+        use crate::scenario::Scenario;
+
+        let mut s = Scenario::at_year(100);
+        let fa = s
+            .faction("Aldor")
+            .government_type(GovernmentType::Hereditary)
+            .id();
+        let fb = s.add_faction("Brell");
+
+        let old_queen = s.add_person("Old Queen", fa);
+        let dead_leader = s.add_person("Old King", fa);
+        s.make_leader(dead_leader, fa);
+        let princess = s.add_person("Princess", fa);
+        s.make_parent_child(old_queen, dead_leader);
+        s.make_parent_child(old_queen, princess);
+
+        let foreign_king = s.add_person("King of Brell", fb);
+        s.make_leader(foreign_king, fb);
+        s.make_spouse(princess, foreign_king);
+        let nephew = s.add_person("Nephew", fb);
+        s.make_parent_child(princess, nephew);
+        s.make_parent_child(foreign_king, nephew);
+        if alliance {
+            s.modify_faction(fa, |fd| {
+                fd.marriage_alliances.insert(fb, 95);
+            });
+            s.modify_faction(fb, |fd| {
+                fd.marriage_alliances.insert(fa, 95);
+            });
+        }
+        let successor = s.person("Successor", fa).birth_year(60).id();
+        let mut world = s.build();
+
+        let time = SimTimestamp::from_year(100);
+        let ev = world.add_event(EventKind::Death, time, "Old King died".to_string());
+        world.end_relationship(dead_leader, fa, RelationshipKind::LeaderOf, time, ev);
+        world.end_entity(dead_leader, time, ev);
+        world.add_relationship(successor, fa, RelationshipKind::LeaderOf, time, ev);
+        create_succession_claims(&mut world, fa, dead_leader, 100, ev);
+
+        (world, fa, fb, foreign_king, nephew)
+    }
+
+    #[test]
+    fn scenario_marriage_alliance_passes_claim_to_foreign_spouse_and_children() {
+        let (world, fa, _, foreign_king, nephew) = dynastic_union_scenario(true);
+
+        let claim = world
+            .person(foreign_king)
+            .claims
+            .get(&fa)
+            .expect("allied spouse should inherit a claim");
+        assert_eq!(claim.source, "marriage_alliance");
+        assert_approx(
+            claim.strength,
+            CLAIM_SIBLING_STRENGTH * CLAIM_MARRIAGE_ALLIANCE_FACTOR,
+            1e-9,
+            "spouse claim",
+        );
+        assert!(world.person(nephew).claims.contains_key(&fa));
+    }
+
+    #[test]
+    fn scenario_marriage_without_alliance_grants_no_claim() {
+        let (world, fa, _, foreign_king, nephew) = dynastic_union_scenario(false);
+
+        assert!(!world.person(foreign_king).claims.contains_key(&fa));
+        assert!(!world.person(nephew).claims.contains_key(&fa));
+    }
+
+    #[test]
+    fn scenario_marriage_alliance_claim_leads_to_claim_war() {
+        use crate::model::{Action, ActionKind, ActionSource, WarGoal};
+        use crate::sim::ActionSystem;
+
+        let (mut world, fa, fb, foreign_king, _) = dynastic_union_scenario(true);
+        world.queue_action(Action {
+            actor_id: foreign_king,
+            source: ActionSource::Player,
+            kind: ActionKind::PressClaim {
+                target_faction_id: fa,
+            },
+        });
+        crate::testutil::tick_system(&mut world, &mut ActionSystem, 100, 42);
+
+        assert!(world.entities[&fb].has_active_rel(RelationshipKind::AtWar, fa));
+        assert!(matches!(
+            world.faction(fb).war_goals.get(&fa),
+            Some(WarGoal::SuccessionClaim { claimant_id }) if *claimant_id == foreign_king
+        ));
+    }
+
+    #[test]
+    fn scenario_dissolved_faction_army_is_disbanded_not_orphaned() {
+        let mut s = Scenario::at_year(100);
+        let region = s.add_region("Marches");
+        let host = s.add_faction("Host Realm");
+        let town = s
+            .settlement("Border Town", host, region)
+            .population(300)
+            .id();
+        // A faction with no settlements left, but still an army in the field
+        let doomed = s.add_faction("Doomed Realm");
+        let army = s.add_army("Last Host", doomed, region, 40);
+        let mut world = s.build();
+
+        tick_system(&mut world, &mut PoliticsSystem::new(), 100, 42);
+
+        assert!(
+            world.entities[&doomed].end.is_some(),
+            "empty faction should dissolve"
+        );
+        assert!(
+            world.entities[&army].end.is_some(),
+            "army of a dissolved faction should be disbanded"
+        );
+        assert_eq!(
+            world.settlement(town).population,
+            340,
+            "disbanded soldiers should scatter into the army's region"
+        );
+        assert!(
+            world.living(EntityKind::Army).all(|e| e
+                .data
+                .as_army()
+                .is_some_and(|ad| world.entities[&ad.faction_id].end.is_none())),
+            "no living army should point at an ended faction"
+        );
+    }
+
+    #[test]
+    fn scenario_ruling_family_shares_dynasty_until_it_dies_out() {
+        let (mut s, k, heir) = aging_dynasty("Aldor", 1.0);
+        let grandchild = s.person("Aldor Grandchild", k.faction).birth_year(90).id();
+        s.make_parent_child(heir, grandchild);
+        let outsider = s.person("Brell Outsider", k.faction).birth_year(70).id();
+        let mut world = s.build();
+
+        tick_system(&mut world, &mut PoliticsSystem::new(), 100, 42);
+        let dynasty = person_dynasty(&world, k.leader).expect("ruler should found a dynasty");
+        assert_eq!(person_dynasty(&world, heir), Some(dynasty));
+        assert_eq!(person_dynasty(&world, grandchild), Some(dynasty));
+        assert_eq!(person_dynasty(&world, outsider), None);
+        let dd = world.entities[&dynasty].data.as_dynasty().unwrap();
+        assert_eq!(dd.founder_id, k.leader);
+        assert!(dd.ruled_factions.contains_key(&k.faction));
+
+        let time = world.current_time;
+        let death_ev = world.add_event(EventKind::Death, time, "house died".to_string());
+        for person in [k.leader, heir, grandchild] {
+            world.end_entity(person, time, death_ev);
+        }
+        tick_system(&mut world, &mut PoliticsSystem::new(), 101, 42);
+        assert!(
+            world.entities[&dynasty].end.is_some(),
+            "dynasty should be extinct once its last member dies"
+        );
+    }
+
+    #[test]
+    fn scenario_disloyal_governor_makes_secession_more_likely() {
+        use crate::model::grievance::Grievance;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut s = Scenario::at_year(100);
+        let k = s.add_kingdom_with(
+            "Aldor",
+            |fd| {
+                fd.stability = 0.2;
+                fd.happiness = 0.2;
+            },
+            |_| {},
+            |_| {},
+        );
+        let loyal_town = s.settlement("Loyal Town", k.faction, k.region).id();
+        let restive_town = s.settlement("Restive Town", k.faction, k.region).id();
+        let loyal = s.add_person_in_with("Loyal Governor", k.faction, loyal_town, |pd| {
+            pd.traits = vec![Trait::Content];
+        });
+        let restive = s.add_person_in_with("Restive Governor", k.faction, restive_town, |pd| {
+            pd.traits = vec![Trait::Ambitious];
+            pd.prestige = 0.5;
+            pd.grievances.insert(
+                k.faction,
+                Grievance {
+                    severity: 0.5,
+                    sources: vec!["passed_over".to_string()],
+                    peak: 0.5,
+                    updated: SimTimestamp::from_year(99),
+                    atrocity_at: None,
+                },
+            );
+        });
+        s.add_relationship(loyal, loyal_town, RelationshipKind::GovernorOf);
+        s.add_relationship(restive, restive_town, RelationshipKind::GovernorOf);
+        let mut world = s.build();
+
+        assert!(
+            governor_loyalty(&world, loyal, k.faction)
+                > governor_loyalty(&world, restive, k.faction)
+        );
+
+        let (mut loyal_splits, mut restive_splits) = (0, 0);
+        for seed in 0..3000 {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            let mut signals = Vec::new();
+            let mut ctx = TickContext {
+                world: &mut world,
+                rng: &mut rng,
+                signals: &mut signals,
+                inbox: &[],
+            };
+            for plan in evaluate_split_candidates(&mut ctx) {
+                if plan.settlement_id == loyal_town {
+                    loyal_splits += 1;
+                } else if plan.settlement_id == restive_town {
+                    restive_splits += 1;
+                    assert_eq!(plan.instigator_id, Some(restive));
+                }
+            }
+        }
+        assert!(
+            restive_splits > loyal_splits * 2,
+            "disloyal governor's town should secede far more often \
+             (restive {restive_splits}, loyal {loyal_splits})"
+        );
+    }
+
+    #[test]
+    fn scenario_splinter_faction_differences_parent_arms() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut s = Scenario::at_year(100);
+        let k = s.add_kingdom("Aldor");
+        let town = s.settlement("Breakaway", k.faction, k.region).id();
+        let parent_arms = Heraldry::for_faction(k.faction, None);
+        let mut world = s.build();
+        world.faction_mut(k.faction).heraldry = parent_arms;
+
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut signals = Vec::new();
+        let mut ctx = TickContext {
+            world: &mut world,
+            rng: &mut rng,
+            signals: &mut signals,
+            inbox: &[],
+        };
+        let plan = SplitPlan {
+            settlement_id: town,
+            old_faction_id: k.faction,
+            old_happiness: 0.2,
+            old_gov_type: GovernmentType::Hereditary,
+            old_succession_law: SuccessionLaw::default(),
+            parent_prestige: 0.0,
+            instigator_id: None,
+        };
+        execute_faction_splits(&mut ctx, vec![plan], SimTimestamp::from_year(100), 100);
+
+        let child = helpers::settlement_faction(&world, town).unwrap();
+        assert_ne!(child, k.faction);
+        let child_arms = world.faction(child).heraldry;
+        assert_eq!(child_arms.primary, parent_arms.primary);
+        assert_eq!(child_arms.charge, parent_arms.charge);
+        assert_ne!(child_arms.secondary, parent_arms.secondary);
+    }
 }
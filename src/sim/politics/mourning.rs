@@ -0,0 +1,156 @@
+use crate::model::{
+    EntityKind, EventPayload, ParticipantRole, RelationshipKind, SimTimestamp, World,
+};
+use crate::sim::helpers;
+
+use super::{CRISIS_CLAIM_THRESHOLD, apply_happiness_delta};
+
+// --- Long Reigns ---
+/// Reign length at which a reign starts to count as long.
+const LONG_REIGN_MIN_YEARS: u32 = 10;
+/// Reign length at which a reign counts fully as long.
+const LONG_REIGN_FULL_YEARS: u32 = 30;
+/// Legitimacy target bonus for a ruler whose reign counts fully as long.
+pub(super) const LONG_REIGN_LEGITIMACY_BONUS: f64 = 0.08;
+/// Share of the succession stability hit spared when an uncontested heir
+/// follows a fully long reign.
+const LONG_REIGN_SUCCESSION_SOFTENING: f64 = 0.5;
+
+// --- Mourning ---
+/// Happiness lost mourning a ruler of full prestige and a fully long reign.
+const MOURNING_HAPPINESS_HIT: f64 = -0.10;
+/// Stature below which a ruler's death passes without public mourning.
+const MOURNING_MIN_STATURE: f64 = 0.25;
+/// Heir prestige needed to rally a mourning realm.
+const RALLY_HEIR_PRESTIGE: f64 = 0.5;
+/// Happiness regained when a strong heir takes over without contest.
+const RALLY_HAPPINESS: f64 = 0.06;
+
+/// Years `leader_id` has ruled `faction_id` in their latest reign, up to its
+/// end or `now`.
+pub(super) fn reign_years(
+    world: &World,
+    leader_id: u64,
+    faction_id: u64,
+    now: SimTimestamp,
+) -> u32 {
+    world
+        .entities
+        .get(&leader_id)
+        .and_then(|e| {
+            e.relationships
+                .iter()
+                .filter(|r| {
+                    r.kind == RelationshipKind::LeaderOf && r.target_entity_id == faction_id
+                })
+                .max_by_key(|r| r.start)
+        })
+        .map_or(0, |r| r.end.unwrap_or(now).years_since(r.start))
+}
+
+/// How far a reign of `years` counts as long, from 0.0 to 1.0.
+pub(super) fn long_reign_factor(years: u32) -> f64 {
+    let span = (LONG_REIGN_FULL_YEARS - LONG_REIGN_MIN_YEARS) as f64;
+    (years.saturating_sub(LONG_REIGN_MIN_YEARS) as f64 / span).min(1.0)
+}
+
+/// A ruler's standing at death: the mean of their prestige and how long
+/// their reign ran.
+fn stature(world: &World, ruler_id: u64, faction_id: u64) -> f64 {
+    let prestige = world
+        .entities
+        .get(&ruler_id)
+        .and_then(|e| e.data.as_person())
+        .map_or(0.0, |pd| pd.prestige);
+    let reign = long_reign_factor(reign_years(world, ruler_id, faction_id, world.current_time));
+    (prestige + reign) / 2.0
+}
+
+/// Public mourning for a ruler who died in office, deepest for prestigious
+/// rulers of long reigns. Deposed and abdicated rulers are not mourned.
+/// Returns whether the realm mourns.
+pub(super) fn mourn_ruler(
+    world: &mut World,
+    cause_event_id: u64,
+    faction_id: u64,
+    ruler_id: u64,
+) -> bool {
+    let died = world
+        .entities
+        .get(&ruler_id)
+        .is_some_and(|e| e.kind == EntityKind::Person && e.end.is_some());
+    if !died {
+        return false;
+    }
+    let stature = stature(world, ruler_id, faction_id);
+    if stature < MOURNING_MIN_STATURE {
+        return false;
+    }
+
+    let time = world.current_time;
+    let ev = world.add_caused_structured_event(
+        EventPayload::Mourning {
+            faction_id,
+            ruler_id,
+            stature,
+        },
+        time,
+        format!(
+            "{} mourned the death of {} in year {}",
+            helpers::entity_name(world, faction_id),
+            helpers::entity_name(world, ruler_id),
+            time.year()
+        ),
+        cause_event_id,
+    );
+    world.add_event_participant(ev, faction_id, ParticipantRole::Subject);
+    world.add_event_participant(ev, ruler_id, ParticipantRole::Object);
+    apply_happiness_delta(world, faction_id, MOURNING_HAPPINESS_HIT * stature, ev);
+    true
+}
+
+/// Whether anyone but the ruler holds a claim to `faction_id` strong enough
+/// to contest the throne.
+pub(super) fn throne_contested(world: &World, faction_id: u64) -> bool {
+    let leader = helpers::faction_leader(world, faction_id);
+    world.living(EntityKind::Person).any(|e| {
+        Some(e.id) != leader
+            && e.data
+                .as_person()
+                .and_then(|pd| pd.claims.get(&faction_id))
+                .is_some_and(|c| c.strength >= CRISIS_CLAIM_THRESHOLD)
+    })
+}
+
+/// Share of the succession stability hit spared because the late ruler's
+/// long reign lends its legitimacy to an uncontested heir.
+pub(super) fn long_reign_carryover(world: &World, faction_id: u64, late_ruler_id: u64) -> f64 {
+    let years = reign_years(world, late_ruler_id, faction_id, world.current_time);
+    long_reign_factor(years) * LONG_REIGN_SUCCESSION_SOFTENING
+}
+
+/// A strong heir taking over without contest lifts a mourning realm.
+pub(super) fn rally_behind_heir(world: &mut World, faction_id: u64, heir_id: u64, event_id: u64) {
+    let prestige = world
+        .entities
+        .get(&heir_id)
+        .and_then(|e| e.data.as_person())
+        .map_or(0.0, |pd| pd.prestige);
+    if prestige >= RALLY_HEIR_PRESTIGE {
+        apply_happiness_delta(world, faction_id, RALLY_HAPPINESS, event_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_reign_factor_ramps_between_thresholds() {
+        assert_eq!(long_reign_factor(5), 0.0);
+        assert_eq!(long_reign_factor(LONG_REIGN_MIN_YEARS), 0.0);
+        assert!((long_reign_factor(20) - 0.5).abs() < 1e-9);
+        assert_eq!(long_reign_factor(LONG_REIGN_FULL_YEARS), 1.0);
+        assert_eq!(long_reign_factor(80), 1.0);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::model::traits::Trait;
+use crate::model::{EntityKind, World};
+use crate::sim::context::TickContext;
+use crate::sim::helpers;
+
+use super::council::council_leaning;
+
+// --- Policy ---
+/// Yearly pull each of a ruler's traits exerts on a policy axis.
+const POLICY_DRIFT_RATE: f64 = 0.02;
+
+/// Net yearly drift from a faction's ruling circle leaning toward `raise` or
+/// `lower` traits.
+fn trait_drift(world: &World, faction_id: u64, raise: &[Trait], lower: &[Trait]) -> f64 {
+    let lean = |traits: &[Trait]| {
+        traits
+            .iter()
+            .map(|t| council_leaning(world, faction_id, t))
+            .sum::<f64>()
+    };
+    (lean(raise) - lean(lower)) * POLICY_DRIFT_RATE
+}
+
+/// Rulers and their councils slowly bend their faction's policies toward
+/// their own temperament: the ambitious and aggressive toward conquest, the
+/// cunning toward commerce, the reclusive toward keeping foreigners at arm's
+/// length.
+pub(super) fn update_policies(ctx: &mut TickContext) {
+    let world = &*ctx.world;
+    let drifts: Vec<(u64, f64, f64, f64)> = world
+        .living(EntityKind::Faction)
+        .filter(|faction| helpers::faction_leader(world, faction.id).is_some())
+        .map(|faction| {
+            (
+                faction.id,
+                trait_drift(
+                    world,
+                    faction.id,
+                    &[Trait::Ambitious, Trait::Aggressive],
+                    &[Trait::Content, Trait::Cautious],
+                ),
+                trait_drift(world, faction.id, &[Trait::Cunning], &[Trait::Pious]),
+                trait_drift(
+                    world,
+                    faction.id,
+                    &[Trait::Reclusive],
+                    &[Trait::Charismatic],
+                ),
+            )
+        })
+        .collect();
+
+    for (faction_id, expansionism, mercantilism, isolationism) in drifts {
+        let fd = ctx.world.faction_mut(faction_id);
+        fd.expansionism = (fd.expansionism + expansionism).clamp(0.0, 1.0);
+        fd.mercantilism = (fd.mercantilism + mercantilism).clamp(0.0, 1.0);
+        fd.isolationism = (fd.isolationism + isolationism).clamp(0.0, 1.0);
+    }
+}
@@ -0,0 +1,140 @@
+use rand::Rng;
+
+use crate::model::{
+    Describer, EnglishDescriber, EntityKind, EventPayload, ParticipantRole, RelationshipKind,
+    SimTimestamp, World,
+};
+use crate::sim::context::TickContext;
+use crate::sim::helpers;
+
+// --- Unfree Population ---
+/// Share of a conquered settlement's free people taken as prisoners.
+const CONQUEST_UNFREE_GAIN: f64 = 0.15;
+/// Share of the unfree population freed each year by manumission.
+const MANUMISSION_RATE: f64 = 0.03;
+/// Unfree fraction at or below which a revolt never breaks out.
+const REVOLT_UNFREE_THRESHOLD: f64 = 0.2;
+/// Misery (1 - faction happiness) at or below which a revolt never breaks out.
+const REVOLT_MISERY_THRESHOLD: f64 = 0.4;
+/// Yearly revolt chance at full unfree fraction and full misery.
+const REVOLT_BASE_CHANCE: f64 = 0.25;
+/// Share of the unfree population that wins its freedom in a revolt.
+const REVOLT_FREED_SHARE: f64 = 0.5;
+/// Share of the settlement population killed in a revolt and its suppression.
+const REVOLT_POPULATION_LOSS: f64 = 0.05;
+const REVOLT_STABILITY_HIT: f64 = -0.10;
+
+/// Prisoners taken in a conquest swell the settlement's unfree population.
+pub(crate) fn handle_settlement_captured(world: &mut World, event_id: u64, settlement_id: u64) {
+    let Some(sd) = world
+        .entities
+        .get_mut(&settlement_id)
+        .and_then(|e| e.data.as_settlement_mut())
+    else {
+        return;
+    };
+    let old = sd.unfree_fraction;
+    sd.unfree_fraction = old + (1.0 - old) * CONQUEST_UNFREE_GAIN;
+    let new = sd.unfree_fraction;
+    world.record_change(
+        settlement_id,
+        event_id,
+        "unfree_fraction",
+        serde_json::json!(old),
+        serde_json::json!(new),
+    );
+}
+
+/// Yearly chance of a slave revolt in a settlement.
+pub(crate) fn revolt_chance(unfree_fraction: f64, faction_happiness: f64) -> f64 {
+    let misery = 1.0 - faction_happiness;
+    if unfree_fraction <= REVOLT_UNFREE_THRESHOLD || misery <= REVOLT_MISERY_THRESHOLD {
+        return 0.0;
+    }
+    (REVOLT_BASE_CHANCE * unfree_fraction * misery).clamp(0.0, 1.0)
+}
+
+/// Free a share of the unfree each year, then roll for revolts where bondage
+/// and misery run high.
+pub(crate) fn update_unfree_population(ctx: &mut TickContext, time: SimTimestamp) {
+    struct RevoltCandidate {
+        settlement_id: u64,
+        faction_id: u64,
+        chance: f64,
+    }
+
+    let settlement_ids: Vec<u64> = ctx
+        .world
+        .living(EntityKind::Settlement)
+        .filter(|e| {
+            e.data
+                .as_settlement()
+                .is_some_and(|sd| sd.unfree_fraction > 0.0)
+        })
+        .map(|e| e.id)
+        .collect();
+    for &sid in &settlement_ids {
+        let sd = ctx.world.settlement_mut(sid);
+        sd.unfree_fraction *= 1.0 - MANUMISSION_RATE;
+    }
+
+    let candidates: Vec<RevoltCandidate> = settlement_ids
+        .iter()
+        .filter_map(|&sid| {
+            let e = ctx.world.entities.get(&sid)?;
+            let faction_id = e.active_rel(RelationshipKind::MemberOf)?;
+            let happiness = ctx
+                .world
+                .entities
+                .get(&faction_id)?
+                .data
+                .as_faction()?
+                .happiness;
+            let chance = revolt_chance(e.data.as_settlement()?.unfree_fraction, happiness);
+            (chance > 0.0).then_some(RevoltCandidate {
+                settlement_id: sid,
+                faction_id,
+                chance,
+            })
+        })
+        .collect();
+
+    for c in candidates {
+        if !ctx.rng.random_bool(c.chance) {
+            continue;
+        }
+        let sd = ctx.world.settlement(c.settlement_id);
+        let old_unfree = sd.unfree_fraction;
+        let new_unfree = old_unfree * (1.0 - REVOLT_FREED_SHARE);
+        let freed = (f64::from(sd.population) * (old_unfree - new_unfree)).round() as u32;
+        let losses = (f64::from(sd.population) * REVOLT_POPULATION_LOSS).round() as u32;
+
+        let payload = EventPayload::SlaveRevolt {
+            settlement_id: c.settlement_id,
+            faction_id: c.faction_id,
+            freed,
+        };
+        let description = EnglishDescriber
+            .describe_payload(ctx.world, &payload, time)
+            .expect("slave revolts have an English template");
+        let ev = ctx.world.add_structured_event(payload, time, description);
+        ctx.world
+            .add_event_participant(ev, c.settlement_id, ParticipantRole::Location);
+        ctx.world
+            .add_event_participant(ev, c.faction_id, ParticipantRole::Object);
+
+        let sd = ctx.world.settlement_mut(c.settlement_id);
+        sd.unfree_fraction = new_unfree;
+        sd.population = sd.population.saturating_sub(losses);
+        let population = sd.population;
+        sd.population_breakdown.scale_to(population);
+        ctx.world.record_change(
+            c.settlement_id,
+            ev,
+            "unfree_fraction",
+            serde_json::json!(old_unfree),
+            serde_json::json!(new_unfree),
+        );
+        helpers::apply_stability_delta(ctx.world, c.faction_id, REVOLT_STABILITY_HIT, ev);
+    }
+}
@@ -0,0 +1,234 @@
+//! Region adjacency graph for pathfinding.
+//!
+//! The scan-based helpers ([`helpers::bfs_next_step`], `helpers::bfs_nearest_naval`,
+//! ...) look up each region's neighbours through the world, and the naval
+//! variants check for a port by scanning every entity at each edge, making a
+//! naval search O(R·N) for R regions and N entities. [`RegionGraph`] gathers
+//! adjacency, water terrain, and port regions in a single O(N + E) pass, after
+//! which every search costs O(R + E). Build one per tick (or per batch of
+//! queries) and reuse it while the map is unchanged; results match the
+//! scan-based helpers exactly.
+//!
+//! [`helpers::bfs_next_step`]: crate::sim::helpers::bfs_next_step
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::model::{EntityKind, RelationshipKind, World};
+
+/// Snapshot of region adjacency plus the terrain and port facts naval
+/// pathfinding needs.
+#[derive(Debug, Clone, Default)]
+pub struct RegionGraph {
+    /// Neighbours of each region, in the order the world lists them.
+    adjacency: BTreeMap<u64, Vec<u64>>,
+    water: BTreeSet<u64>,
+    ports: BTreeSet<u64>,
+}
+
+impl RegionGraph {
+    /// Gather adjacency, water regions, and port regions from the world.
+    pub fn build(world: &World) -> Self {
+        let mut graph = Self::default();
+        for e in world.entities.values() {
+            let neighbors: Vec<u64> = e.active_rels(RelationshipKind::AdjacentTo).collect();
+            if !neighbors.is_empty() {
+                graph.adjacency.insert(e.id, neighbors);
+            }
+            if e.data.as_region().is_some_and(|r| r.terrain.is_water()) {
+                graph.water.insert(e.id);
+            }
+            if e.kind == EntityKind::Settlement
+                && e.end.is_none()
+                && e.data
+                    .as_settlement()
+                    .is_some_and(|sd| sd.building_bonuses.port_trade > 0.0)
+            {
+                graph
+                    .ports
+                    .extend(e.active_rels(RelationshipKind::LocatedIn));
+            }
+        }
+        graph
+    }
+
+    /// Regions adjacent to `region_id`.
+    pub fn neighbors(&self, region_id: u64) -> &[u64] {
+        self.adjacency.get(&region_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn is_water(&self, region_id: u64) -> bool {
+        self.water.contains(&region_id)
+    }
+
+    /// Whether a living settlement with a port stands in the region.
+    pub fn has_port(&self, region_id: u64) -> bool {
+        self.ports.contains(&region_id)
+    }
+
+    /// First region to move to from `start` toward `goal` over land and water
+    /// alike, or `None` if already there or unreachable.
+    pub fn next_step(&self, start: u64, goal: u64) -> Option<u64> {
+        self.next_step_by(start, goal, |_, _| true)
+    }
+
+    /// Nearest region to `start` (itself included) matching `predicate`.
+    pub fn nearest(&self, start: u64, predicate: impl Fn(u64) -> bool) -> Option<u64> {
+        self.nearest_by(start, predicate, |_, _| true)
+    }
+
+    /// Like [`next_step`](Self::next_step), but water is only passable when
+    /// `can_embark`, and only entered or left at port regions.
+    pub fn next_step_naval(&self, start: u64, goal: u64, can_embark: bool) -> Option<u64> {
+        self.next_step_by(start, goal, |from, to| {
+            self.naval_passable(from, to, can_embark)
+        })
+    }
+
+    /// Like [`nearest`](Self::nearest), with the naval rules of
+    /// [`next_step_naval`](Self::next_step_naval).
+    pub fn nearest_naval(
+        &self,
+        start: u64,
+        can_embark: bool,
+        predicate: impl Fn(u64) -> bool,
+    ) -> Option<u64> {
+        self.nearest_by(start, predicate, |from, to| {
+            self.naval_passable(from, to, can_embark)
+        })
+    }
+
+    fn naval_passable(&self, from: u64, to: u64, can_embark: bool) -> bool {
+        let (from_water, to_water) = (self.is_water(from), self.is_water(to));
+        if to_water && !can_embark {
+            return false;
+        }
+        // Land→water only from a port; water→land only at a port
+        if !from_water && to_water && !self.has_port(from) {
+            return false;
+        }
+        !(from_water && !to_water && !self.has_port(to))
+    }
+
+    fn next_step_by(
+        &self,
+        start: u64,
+        goal: u64,
+        passable: impl Fn(u64, u64) -> bool,
+    ) -> Option<u64> {
+        if start == goal {
+            return None;
+        }
+        let mut visited = BTreeSet::from([start]);
+        let mut queue: VecDeque<(u64, u64)> = VecDeque::new(); // (current, first_step)
+        for &adj in self.neighbors(start) {
+            if !passable(start, adj) {
+                continue;
+            }
+            if adj == goal {
+                return Some(adj);
+            }
+            if visited.insert(adj) {
+                queue.push_back((adj, adj));
+            }
+        }
+        while let Some((current, first_step)) = queue.pop_front() {
+            for &adj in self.neighbors(current) {
+                if !passable(current, adj) {
+                    continue;
+                }
+                if adj == goal {
+                    return Some(first_step);
+                }
+                if visited.insert(adj) {
+                    queue.push_back((adj, first_step));
+                }
+            }
+        }
+        None
+    }
+
+    fn nearest_by(
+        &self,
+        start: u64,
+        predicate: impl Fn(u64) -> bool,
+        passable: impl Fn(u64, u64) -> bool,
+    ) -> Option<u64> {
+        if predicate(start) {
+            return Some(start);
+        }
+        let mut visited = BTreeSet::from([start]);
+        let mut queue: VecDeque<u64> = self
+            .neighbors(start)
+            .iter()
+            .copied()
+            .filter(|&adj| passable(start, adj) && visited.insert(adj))
+            .collect();
+        while let Some(current) = queue.pop_front() {
+            if predicate(current) {
+                return Some(current);
+            }
+            for &adj in self.neighbors(current) {
+                if passable(current, adj) && visited.insert(adj) {
+                    queue.push_back(adj);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::sim::helpers;
+    use crate::worldgen::{self, config::WorldGenConfig};
+
+    #[test]
+    fn cached_bfs_matches_scan_bfs_on_random_map() {
+        let mut world = worldgen::generate_world(WorldGenConfig {
+            seed: 7,
+            ..WorldGenConfig::default()
+        });
+        // Scatter ports so the naval rules are exercised
+        let mut rng = SmallRng::seed_from_u64(7);
+        let settlements: Vec<u64> = world.living_ids(EntityKind::Settlement).collect();
+        for sid in settlements {
+            if rng.random_bool(0.3) {
+                world.settlement_mut(sid).building_bonuses.port_trade = 0.2;
+            }
+        }
+        let graph = RegionGraph::build(&world);
+
+        let regions: Vec<u64> = world.living_ids(EntityKind::Region).collect();
+        assert!(regions.len() > 10, "map should have enough regions");
+        let targets: BTreeSet<u64> = regions.iter().copied().step_by(5).collect();
+        for &start in &regions {
+            for &goal in &regions {
+                assert_eq!(
+                    graph.next_step(start, goal),
+                    helpers::bfs_next_step(&world, start, goal)
+                );
+                for can_embark in [false, true] {
+                    assert_eq!(
+                        graph.next_step_naval(start, goal, can_embark),
+                        helpers::bfs_next_step_naval(&world, start, goal, can_embark)
+                    );
+                }
+            }
+            let is_target = |r: u64| targets.contains(&r) && r != start;
+            assert_eq!(
+                graph.nearest(start, is_target),
+                helpers::bfs_nearest(&world, start, is_target)
+            );
+            for can_embark in [false, true] {
+                assert_eq!(
+                    graph.nearest_naval(start, can_embark, is_target),
+                    helpers::bfs_nearest_naval(&world, start, can_embark, is_target)
+                );
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use rand::Rng;
 
 use super::context::TickContext;
 use super::helpers;
+use super::holy_sites;
 use super::religion_names::{generate_deity_name, generate_religion_name};
 use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
@@ -11,7 +12,7 @@ use crate::model::entity_data::{
     Medium, ReligionData, ReligiousTenet,
 };
 use crate::model::{
-    DerivationMethod, EntityData, EntityKind, EventKind, ParticipantRole, RelationshipKind,
+    DerivationMethod, EntityData, EntityKind, EventKind, ParticipantRole, RelationshipKind, World,
 };
 
 // --- Signal: religion share adjustments ---
@@ -47,6 +48,49 @@ const PROPHECY_COOLDOWN_YEARS: u64 = 20;
 // --- Nature worship disaster fervor spike ---
 const DISASTER_FERVOR_SPIKE: f64 = 0.05;
 
+// --- Deity domains ---
+/// Monthly army morale drawn from a homeland wholly devoted to a war god.
+const WAR_DEITY_MORALE_BONUS: f64 = 0.01;
+/// Food output bonus for a settlement wholly devoted to a harvest god.
+const HARVEST_DEITY_FOOD_BONUS: f64 = 0.15;
+
+/// How devoted a settlement is to gods of `domain`, from 0.0 to 1.0: each
+/// faith's share of the settlement times the worship of its strongest god of
+/// that domain.
+pub fn domain_devotion(world: &World, settlement_id: u64, domain: DeityDomain) -> f64 {
+    let Some(sd) = world
+        .entities
+        .get(&settlement_id)
+        .and_then(|e| e.data.as_settlement())
+    else {
+        return 0.0;
+    };
+    sd.religion_makeup
+        .iter()
+        .map(|(&rid, &share)| {
+            let worship = world
+                .active_sources(RelationshipKind::MemberOf, rid)
+                .filter(|e| e.end.is_none())
+                .filter_map(|e| e.data.as_deity())
+                .filter(|dd| dd.domain == domain)
+                .map(|dd| dd.worship_strength)
+                .fold(0.0, f64::max);
+            share * worship
+        })
+        .sum::<f64>()
+        .clamp(0.0, 1.0)
+}
+
+/// Monthly morale an army draws from the war gods of one of its settlements.
+pub fn war_deity_morale_bonus(world: &World, settlement_id: u64) -> f64 {
+    domain_devotion(world, settlement_id, DeityDomain::War) * WAR_DEITY_MORALE_BONUS
+}
+
+/// Food output bonus a settlement's harvest gods grant its fields.
+pub fn harvest_deity_food_bonus(world: &World, settlement_id: u64) -> f64 {
+    domain_devotion(world, settlement_id, DeityDomain::Harvest) * HARVEST_DEITY_FOOD_BONUS
+}
+
 pub struct ReligionSystem;
 
 impl SimSystem for ReligionSystem {
@@ -691,6 +735,9 @@ fn check_schisms(ctx: &mut TickContext) {
             ev,
         );
 
+        // The schism's birthplace becomes its holy site
+        holy_sites::found_holy_site(ctx.world, new_religion_id, sid, time, ev);
+
         // Transfer some of the parent's share to the new religion
         let transfer = makeup.get(&dominant_rid).copied().unwrap_or(0.0) * 0.3;
         let old_makeup = ctx
@@ -1159,7 +1206,7 @@ mod tests {
         // Establish a trade route
         s.make_trade_route(settlement_a, settlement_b);
 
-        let world = s.run(&mut religion_system(), 300, 42);
+        let world = s.run(&mut religion_system(), 300, 44);
 
         // Check both directions — at least one should have spread
         let share_a_in_b = world
@@ -1778,4 +1825,37 @@ mod tests {
             "schism should record religion_makeup change at least once"
         );
     }
+
+    #[test]
+    fn scenario_war_god_emboldens_armies_but_harvest_god_does_not() {
+        let mut s = Scenario::at_year(100);
+        let region = s.add_region("Plains");
+        let faction = s.add_faction("Kingdom");
+        let war_faith = s.add_religion("Cult of the Spear");
+        s.add_deity_with("Spear-Father", war_faith, |dd| {
+            dd.domain = DeityDomain::War;
+            dd.worship_strength = 0.9;
+        });
+        let harvest_faith = s.add_religion("Cult of the Sheaf");
+        s.add_deity_with("Sheaf-Mother", harvest_faith, |dd| {
+            dd.domain = DeityDomain::Harvest;
+            dd.worship_strength = 0.9;
+        });
+        let warlike = s
+            .settlement("Spearholm", faction, region)
+            .dominant_religion(Some(war_faith))
+            .religion_makeup(BTreeMap::from([(war_faith, 1.0)]))
+            .id();
+        let pastoral = s
+            .settlement("Sheafton", faction, region)
+            .dominant_religion(Some(harvest_faith))
+            .religion_makeup(BTreeMap::from([(harvest_faith, 1.0)]))
+            .id();
+        let world = s.build();
+
+        assert!(war_deity_morale_bonus(&world, warlike) > 0.0);
+        assert_eq!(war_deity_morale_bonus(&world, pastoral), 0.0);
+        assert!(harvest_deity_food_bonus(&world, pastoral) > 0.0);
+        assert_eq!(harvest_deity_food_bonus(&world, warlike), 0.0);
+    }
 }
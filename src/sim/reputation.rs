@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use rand::Rng;
 
 use super::context::TickContext;
@@ -5,7 +7,8 @@ use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
 use crate::model::traits::Trait;
 use crate::model::{
-    EntityKind, EventKind, RelationshipKind, Role, SecretMotivation, SiegeOutcome, SimTimestamp,
+    EntityKind, EventKind, EventPayload, RelationshipKind, Role, SecretMotivation, SiegeOutcome,
+    SimTimestamp, World,
 };
 use crate::sim::helpers;
 
@@ -118,6 +121,9 @@ const FACTION_BUILDING_CAP: f64 = 0.10;
 const FACTION_STABILITY_WEIGHT: f64 = 0.05;
 const FACTION_LEGITIMACY_WEIGHT: f64 = 0.05;
 const FACTION_LEADER_PRESTIGE_WEIGHT: f64 = 0.10;
+const FACTION_VICTORY_WINDOW_YEARS: u32 = 15;
+const FACTION_VICTORY_BONUS: f64 = 0.05;
+const FACTION_VICTORY_CAP: f64 = 0.20;
 const FACTION_TARGET_MAX: f64 = 0.90;
 
 // ---------------------------------------------------------------------------
@@ -186,6 +192,9 @@ impl SimSystem for ReputationSystem {
         update_person_prestige(ctx, time, year_event);
         update_faction_prestige(ctx, time, year_event);
         update_settlement_prestige(ctx, time, year_event);
+
+        // Keep tiers in step with drifted prestige even in signal-free years
+        emit_threshold_signals(ctx, year_event);
     }
 
     fn handle_signals(&mut self, ctx: &mut TickContext) {
@@ -720,13 +729,15 @@ fn update_person_prestige(ctx: &mut TickContext, time: SimTimestamp, year_event:
 // Faction prestige convergence
 // ---------------------------------------------------------------------------
 
-fn update_faction_prestige(ctx: &mut TickContext, _time: SimTimestamp, year_event: u64) {
+fn update_faction_prestige(ctx: &mut TickContext, time: SimTimestamp, year_event: u64) {
     struct FactionInfo {
         id: u64,
         old_prestige: f64,
         target: f64,
     }
 
+    let victories = recent_victories(ctx.world, time.year());
+
     let factions: Vec<FactionInfo> = ctx
         .world
         .entities
@@ -765,6 +776,10 @@ fn update_faction_prestige(ctx: &mut TickContext, _time: SimTimestamp, year_even
                 base_target += leader_prestige * FACTION_LEADER_PRESTIGE_WEIGHT;
             }
 
+            // Recent victories; renown fades once a faction stops winning
+            let wins = victories.get(&faction_id).copied().unwrap_or(0);
+            base_target += (wins as f64 * FACTION_VICTORY_BONUS).min(FACTION_VICTORY_CAP);
+
             let target = base_target.clamp(0.0, FACTION_TARGET_MAX);
 
             Some(FactionInfo {
@@ -799,6 +814,25 @@ fn update_faction_prestige(ctx: &mut TickContext, _time: SimTimestamp, year_even
     }
 }
 
+/// Count wars won per faction within the recent-victory window, from treaty events.
+fn recent_victories(world: &World, current_year: u32) -> BTreeMap<u64, u32> {
+    let mut wins = BTreeMap::new();
+    let recent = world
+        .events
+        .values()
+        .rev()
+        .take_while(|ev| ev.timestamp.year() + FACTION_VICTORY_WINDOW_YEARS > current_year);
+    for ev in recent {
+        if ev.kind != EventKind::Treaty {
+            continue;
+        }
+        if let Some(EventPayload::Treaty { winner_id, .. }) = EventPayload::from_data(&ev.data) {
+            *wins.entry(winner_id).or_insert(0) += 1;
+        }
+    }
+    wins
+}
+
 // ---------------------------------------------------------------------------
 // Settlement prestige convergence
 // ---------------------------------------------------------------------------
@@ -1435,6 +1469,7 @@ mod tests {
                 attacker_faction_id: attacker,
                 defender_faction_id: defender,
                 outcome: SiegeOutcome::Conquered,
+                civilian_deaths: 0,
             },
         }];
         deliver_signals(&mut world, &mut ReputationSystem, &inbox, 42);
@@ -1766,4 +1801,49 @@ mod tests {
             "faction prestige after leader died",
         );
     }
+
+    #[test]
+    fn scenario_idle_faction_prestige_fades_while_victor_holds() {
+        let mut s = Scenario::at_year(100);
+        let idle = s.faction("Idle").prestige(0.8).id();
+        let r1 = s.add_region("Idle Lands");
+        s.add_settlement("Idle Town", idle, r1);
+        let victor = s.faction("Victor").prestige(0.8).id();
+        let r2 = s.add_region("Victor Lands");
+        s.add_settlement("Victor Town", victor, r2);
+        let rival = s.add_faction("Rival");
+        let mut world = s.build();
+
+        for year in 100..140 {
+            if year % 5 == 0 {
+                world.add_structured_event(
+                    EventPayload::Treaty {
+                        winner_id: victor,
+                        loser_id: rival,
+                        terms: crate::model::PeaceTerms {
+                            decisive: true,
+                            territory_ceded: Vec::new(),
+                            reparations: 0.0,
+                            tribute_per_year: 0.0,
+                            tribute_duration_years: 0,
+                        },
+                        winner_goal: None,
+                        loser_goal: None,
+                    },
+                    SimTimestamp::from_year(year),
+                    "Victor won a war".to_string(),
+                );
+            }
+            tick_system(&mut world, &mut ReputationSystem, year, 42 + year as u64);
+        }
+
+        let idle_p = world.faction(idle).prestige;
+        let victor_p = world.faction(victor).prestige;
+        assert!(idle_p < 0.5, "idle faction should fade, got {idle_p}");
+        assert!(
+            victor_p > idle_p + 0.1,
+            "victorious faction should hold renown: victor={victor_p} idle={idle_p}"
+        );
+        assert_eq!(world.faction(idle).prestige_tier, prestige_tier(idle_p));
+    }
 }
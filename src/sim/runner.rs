@@ -1,23 +1,52 @@
+use std::collections::BTreeMap;
+use std::fmt;
 use std::path::PathBuf;
+use std::sync::Arc;
 
-use rand::rngs::SmallRng;
-use rand::{RngCore, SeedableRng};
+use rand::RngCore;
 
 use super::context::TickContext;
+use super::demographics::DemographicsConfig;
+use super::politics::PoliticsConfig;
+use super::rng::SimRng;
+use super::signal::{Signal, SignalKind};
 use super::system::{SimSystem, TickFrequency};
-use crate::flush::flush_to_jsonl;
-use crate::model::timestamp::{DAYS_PER_MONTH, DAYS_PER_YEAR, HOURS_PER_DAY, MONTHS_PER_YEAR};
-use crate::model::{SimTimestamp, World};
+use crate::flush::{flush_to_jsonl, write_checkpoint};
+use crate::model::timestamp::{
+    DAYS_PER_MONTH, DAYS_PER_WEEK, DAYS_PER_YEAR, HOURS_PER_DAY, MONTHS_PER_YEAR, WEEKS_PER_MONTH,
+};
+use crate::model::{EntityKind, EventRetention, RelationshipKind, RngBackend, SimTimestamp, World};
 
 /// Configuration for a simulation run.
+#[derive(Debug, Clone)]
 pub struct SimConfig {
     pub start_year: u32,
     pub num_years: u32,
     pub seed: u64,
     /// If set, flush world state every N years.
     pub flush_interval: Option<u32>,
+    /// If set, write a resumable checkpoint (see [`resume`]) every N years.
+    pub checkpoint_interval: Option<u32>,
     /// Directory to write flush checkpoints into.
     pub output_dir: Option<PathBuf>,
+    /// Which events to keep; anything else is pruned at the end of each year.
+    pub event_retention: EventRetention,
+    /// How prosperity and learning shape lifespans and fertility.
+    pub demographics: DemographicsConfig,
+    /// Coup thresholds and other political tuning.
+    pub politics: PoliticsConfig,
+    /// Conditions checked at the end of each year; the first one met ends the
+    /// run early.
+    pub stop_conditions: Vec<StopCondition>,
+    /// Tick frequency overrides keyed by system name, used in place of the
+    /// system's own [`frequency`](SimSystem::frequency). Naming a system that
+    /// isn't registered is an error.
+    pub tick_frequencies: BTreeMap<String, TickFrequency>,
+    /// Observers shown every signal of the run they are interested in.
+    pub subscribers: Vec<SignalSubscriber>,
+    /// Generator the run draws from; recorded in [`World::rng`]. Resuming a
+    /// world with a different backend than it was run on is an error.
+    pub rng: RngBackend,
 }
 
 impl SimConfig {
@@ -27,23 +56,152 @@ impl SimConfig {
             num_years,
             seed,
             flush_interval: None,
+            checkpoint_interval: None,
             output_dir: None,
+            event_retention: EventRetention::All,
+            demographics: DemographicsConfig::default(),
+            politics: PoliticsConfig::default(),
+            stop_conditions: Vec::new(),
+            tick_frequencies: BTreeMap::new(),
+            subscribers: Vec::new(),
+            rng: RngBackend::default(),
         }
     }
 }
 
+/// Watches signals across a whole run, for logging, metrics, or custom
+/// reactions outside the systems. After each dispatch it is shown a copy of
+/// every signal it is interested in, including reactions that were never
+/// delivered; the systems' own inbox is untouched.
+#[derive(Clone)]
+pub struct SignalSubscriber {
+    interest: Arc<SignalInterest>,
+    observer: Arc<SignalObserver>,
+}
+
+type SignalInterest = dyn Fn(&SignalKind) -> bool + Send + Sync;
+type SignalObserver = dyn Fn(&World, &Signal) + Send + Sync;
+
+impl SignalSubscriber {
+    /// Subscribe to every signal.
+    pub fn all(observer: impl Fn(&World, &Signal) + Send + Sync + 'static) -> Self {
+        Self::filtered(|_| true, observer)
+    }
+
+    /// Subscribe to the signals whose kind passes `interest`, e.g.
+    /// `|kind| matches!(kind, SignalKind::WarStarted { .. })`.
+    pub fn filtered(
+        interest: impl Fn(&SignalKind) -> bool + Send + Sync + 'static,
+        observer: impl Fn(&World, &Signal) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            interest: Arc::new(interest),
+            observer: Arc::new(observer),
+        }
+    }
+
+    fn notify(&self, world: &World, signals: &[Signal]) {
+        for signal in signals.iter().filter(|s| (self.interest)(&s.kind)) {
+            (self.observer)(world, signal);
+        }
+    }
+}
+
+impl fmt::Debug for SignalSubscriber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SignalSubscriber(..)")
+    }
+}
+
+/// A condition that ends a run before its configured number of years.
+#[derive(Clone)]
+pub enum StopCondition {
+    /// One faction owns at least this fraction (0.0-1.0) of living settlements.
+    Hegemony(f64),
+    /// No living factions remain.
+    AllFactionsDead,
+    /// Caller-supplied check on the world.
+    Custom(Arc<dyn Fn(&World) -> bool + Send + Sync>),
+}
+
+impl fmt::Debug for StopCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopCondition::Hegemony(share) => f.debug_tuple("Hegemony").field(share).finish(),
+            StopCondition::AllFactionsDead => f.write_str("AllFactionsDead"),
+            StopCondition::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Why a run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// Ran the full configured number of years.
+    Completed,
+    /// The given faction reached the [`StopCondition::Hegemony`] share.
+    Hegemony {
+        faction_id: u64,
+    },
+    AllFactionsDead,
+    /// A [`StopCondition::Custom`] predicate returned true.
+    Custom,
+}
+
+/// Outcome of [`run`] or [`resume`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunOutcome {
+    /// Last year simulated.
+    pub final_year: u32,
+    pub reason: StopReason,
+}
+
+impl StopCondition {
+    fn check(&self, world: &World) -> Option<StopReason> {
+        match self {
+            StopCondition::Hegemony(share) => {
+                hegemon(world, *share).map(|faction_id| StopReason::Hegemony { faction_id })
+            }
+            StopCondition::AllFactionsDead => (world.count_living(EntityKind::Faction) == 0)
+                .then_some(StopReason::AllFactionsDead),
+            StopCondition::Custom(predicate) => predicate(world).then_some(StopReason::Custom),
+        }
+    }
+}
+
+/// Faction owning at least `share` of all living settlements, if any.
+fn hegemon(world: &World, share: f64) -> Option<u64> {
+    let mut owned: BTreeMap<u64, u32> = BTreeMap::new();
+    let mut total = 0u32;
+    for settlement in world.living(EntityKind::Settlement) {
+        total += 1;
+        if let Some(owner) = settlement.active_rel(RelationshipKind::MemberOf) {
+            *owned.entry(owner).or_default() += 1;
+        }
+    }
+    if total == 0 {
+        return None;
+    }
+    owned
+        .into_iter()
+        .find(|&(_, count)| f64::from(count) / f64::from(total) >= share)
+        .map(|(faction_id, _)| faction_id)
+}
+
 /// Returns true if a system with the given frequency should fire at this timestamp.
 pub fn should_fire(freq: TickFrequency, time: SimTimestamp) -> bool {
     match freq {
         TickFrequency::Hourly => true,
         TickFrequency::Daily => time.hour() == 0,
-        TickFrequency::Weekly => time.hour() == 0 && (time.day() - 1).is_multiple_of(7),
-        TickFrequency::Monthly => time.hour() == 0 && time.day_of_month() == 1,
-        TickFrequency::Yearly => time.hour() == 0 && time.day() == 1,
+        TickFrequency::Weekly => time.is_week_start(),
+        TickFrequency::Monthly => time.is_month_start(),
+        TickFrequency::Yearly => time.is_year_start(),
     }
 }
 
 /// Set `world.current_time` and call each system whose frequency matches.
+/// `frequencies` holds each system's effective frequency, in the same order
+/// as `systems`.
 ///
 /// Signal delivery is **single-pass, non-cascading**:
 ///
@@ -59,18 +217,34 @@ pub fn should_fire(freq: TickFrequency, time: SimTimestamp) -> bool {
 /// within the same tick. This is intentional: it prevents infinite cascades and
 /// keeps each tick's side-effects bounded. If a reaction needs to propagate,
 /// it should mutate world state that a later tick's Phase 1 will observe.
+///
+/// `rng` is drawn from exactly once per call; each system gets its own stream
+/// (see [`system_rng`]) for both phases, so adding, removing, or reordering a
+/// system doesn't shift the random draws any other system sees. Those streams
+/// come from the world's recorded [`RngBackend`], `SmallRng` if none.
+///
+/// Returns every signal emitted: the delivered Phase 1 signals first, then the
+/// undelivered Phase 2 ones.
 pub fn dispatch_systems(
     world: &mut World,
     systems: &mut [Box<dyn SimSystem>],
+    frequencies: &[TickFrequency],
     rng: &mut dyn RngCore,
     time: SimTimestamp,
-) {
+) -> Vec<Signal> {
     world.current_time = time;
 
+    let backend = world.rng.unwrap_or_default();
+    let dispatch_seed = rng.next_u64();
+    let mut rngs: Vec<SimRng> = systems
+        .iter()
+        .map(|s| system_rng(backend, dispatch_seed, s.name()))
+        .collect();
+
     // Phase 1: tick systems, collecting signals
     let mut signals = Vec::new();
-    for system in systems.iter_mut() {
-        if should_fire(system.frequency(), time) {
+    for ((system, rng), &freq) in systems.iter_mut().zip(rngs.iter_mut()).zip(frequencies) {
+        if should_fire(freq, time) {
             let mut ctx = TickContext {
                 world,
                 rng,
@@ -82,9 +256,10 @@ pub fn dispatch_systems(
     }
 
     // Phase 2: deliver signals for reaction (only if any were emitted)
+    let mut undelivered = Vec::new();
     if !signals.is_empty() {
-        for system in systems.iter_mut() {
-            if should_fire(system.frequency(), time) {
+        for ((system, rng), &freq) in systems.iter_mut().zip(rngs.iter_mut()).zip(frequencies) {
+            if should_fire(freq, time) {
                 let mut new_signals = Vec::new();
                 let mut ctx = TickContext {
                     world,
@@ -93,77 +268,241 @@ pub fn dispatch_systems(
                     inbox: &signals,
                 };
                 system.handle_signals(&mut ctx);
+                undelivered.append(&mut new_signals);
             }
         }
     }
+    signals.append(&mut undelivered);
+    signals
+}
+
+/// [`dispatch_systems`], then show the subscribers what was signalled.
+fn dispatch_observed(
+    world: &mut World,
+    systems: &mut [Box<dyn SimSystem>],
+    frequencies: &[TickFrequency],
+    rng: &mut dyn RngCore,
+    time: SimTimestamp,
+    subscribers: &[SignalSubscriber],
+) {
+    let signals = dispatch_systems(world, systems, frequencies, rng, time);
+    for subscriber in subscribers {
+        subscriber.notify(world, &signals);
+    }
 }
 
-/// Run the simulation for the configured number of years.
+/// RNG stream for one system within one dispatch, keyed by a stable hash of
+/// the system's name rather than its position in the pipeline.
+fn system_rng(backend: RngBackend, dispatch_seed: u64, system_name: &str) -> SimRng {
+    backend.stream(dispatch_seed ^ backend.name_hash(system_name))
+}
+
+/// Run the simulation for the configured number of years, or until one of
+/// `config.stop_conditions` is met.
 ///
-/// Creates a deterministic RNG from `config.seed`, so the same seed always
-/// produces the same simulation. The loop iterates at the finest granularity
-/// needed by any registered system, avoiding wasted cycles when all systems
-/// are coarse.
+/// Each year draws from its own RNG stream derived from `config.seed`, so the
+/// same seed always produces the same simulation — including when the run is
+/// split by a checkpoint and [`resume`]d. The loop iterates at the finest
+/// granularity needed by any registered system, avoiding wasted cycles when
+/// all systems are coarse.
 pub fn run(
     world: &mut World,
     systems: &mut [Box<dyn SimSystem>],
     config: SimConfig,
-) -> std::io::Result<()> {
-    if systems.is_empty() || config.num_years == 0 {
-        return Ok(());
+) -> std::io::Result<RunOutcome> {
+    run_years(world, systems, &config, config.start_year)
+}
+
+/// Continue a world loaded from a checkpoint (see
+/// [`read_checkpoint`](crate::flush::read_checkpoint)) through the end of the
+/// configured run.
+///
+/// Starts the year after `world.current_time` and leaves `world.id_gen` and
+/// the clock as loaded, so ids keep counting from where the checkpoint left
+/// off. Systems that keep internal state between ticks start fresh.
+pub fn resume(
+    world: &mut World,
+    systems: &mut [Box<dyn SimSystem>],
+    config: SimConfig,
+) -> std::io::Result<RunOutcome> {
+    let from_year = world.current_time.year() + 1;
+    run_years(world, systems, &config, from_year)
+}
+
+/// Per-year RNG stream, independent of how many draws earlier years made.
+fn year_rng(backend: RngBackend, seed: u64, year: u32) -> SimRng {
+    backend.stream(seed ^ u64::from(year).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// Each system's effective frequency: its override from
+/// `config.tick_frequencies` if any, else its own default.
+fn tick_frequencies(
+    systems: &[Box<dyn SimSystem>],
+    config: &SimConfig,
+) -> std::io::Result<Vec<TickFrequency>> {
+    if let Some(unknown) = config
+        .tick_frequencies
+        .keys()
+        .find(|name| !systems.iter().any(|s| s.name() == name.as_str()))
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("tick frequency override for unknown system `{unknown}`"),
+        ));
+    }
+    Ok(systems
+        .iter()
+        .map(|s| {
+            config
+                .tick_frequencies
+                .get(s.name())
+                .copied()
+                .unwrap_or_else(|| s.frequency())
+        })
+        .collect())
+}
+
+fn run_years(
+    world: &mut World,
+    systems: &mut [Box<dyn SimSystem>],
+    config: &SimConfig,
+    from_year: u32,
+) -> std::io::Result<RunOutcome> {
+    let frequencies = tick_frequencies(systems, config)?;
+    if let Some(recorded) = world.rng
+        && recorded != config.rng
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "world was simulated with the `{}` RNG but the run is configured for `{}`",
+                recorded.id(),
+                config.rng.id()
+            ),
+        ));
+    }
+    let end_year = config.start_year + config.num_years;
+    if systems.is_empty() || from_year >= end_year {
+        return Ok(RunOutcome {
+            final_year: world.current_time.year(),
+            reason: StopReason::Completed,
+        });
+    }
+
+    world.rng = Some(config.rng);
+    for system in systems.iter_mut() {
+        system.configure(config);
     }
 
-    let mut rng = SmallRng::seed_from_u64(config.seed);
-    let finest = systems.iter().map(|s| s.frequency()).max().unwrap();
+    let finest = frequencies.iter().copied().max().unwrap();
 
-    for year_offset in 0..config.num_years {
-        let year = config.start_year + year_offset;
+    for year in from_year..end_year {
+        let year_offset = year - config.start_year;
+        let mut rng = year_rng(config.rng, config.seed, year);
         match finest {
             TickFrequency::Yearly => {
-                dispatch_systems(world, systems, &mut rng, SimTimestamp::new(year, 1, 0));
+                dispatch_observed(
+                    world,
+                    systems,
+                    &frequencies,
+                    &mut rng,
+                    SimTimestamp::new(year, 1, 0),
+                    &config.subscribers,
+                );
             }
             TickFrequency::Monthly => {
                 for month in 0..MONTHS_PER_YEAR {
                     let day = month * DAYS_PER_MONTH + 1;
-                    dispatch_systems(world, systems, &mut rng, SimTimestamp::new(year, day, 0));
+                    dispatch_observed(
+                        world,
+                        systems,
+                        &frequencies,
+                        &mut rng,
+                        SimTimestamp::new(year, day, 0),
+                        &config.subscribers,
+                    );
                 }
             }
             TickFrequency::Weekly => {
-                let mut day = 1;
-                while day <= DAYS_PER_YEAR {
-                    dispatch_systems(world, systems, &mut rng, SimTimestamp::new(year, day, 0));
-                    day += 7;
+                // Weeks are month-aligned, so every month start is a week start
+                for month in 0..MONTHS_PER_YEAR {
+                    for week in 0..WEEKS_PER_MONTH {
+                        let day = month * DAYS_PER_MONTH + week * DAYS_PER_WEEK + 1;
+                        dispatch_observed(
+                            world,
+                            systems,
+                            &frequencies,
+                            &mut rng,
+                            SimTimestamp::new(year, day, 0),
+                            &config.subscribers,
+                        );
+                    }
                 }
             }
             TickFrequency::Daily => {
                 for day in 1..=DAYS_PER_YEAR {
-                    dispatch_systems(world, systems, &mut rng, SimTimestamp::new(year, day, 0));
+                    dispatch_observed(
+                        world,
+                        systems,
+                        &frequencies,
+                        &mut rng,
+                        SimTimestamp::new(year, day, 0),
+                        &config.subscribers,
+                    );
                 }
             }
             TickFrequency::Hourly => {
                 for day in 1..=DAYS_PER_YEAR {
                     for hour in 0..HOURS_PER_DAY {
-                        dispatch_systems(
+                        dispatch_observed(
                             world,
                             systems,
+                            &frequencies,
                             &mut rng,
                             SimTimestamp::new(year, day, hour),
+                            &config.subscribers,
                         );
                     }
                 }
             }
         }
 
-        // Flush checkpoint at configured interval
+        if config.event_retention != EventRetention::All {
+            world.prune_events(config.event_retention);
+        }
+
+        let stop = config
+            .stop_conditions
+            .iter()
+            .find_map(|condition| condition.check(world));
+
+        // Flush checkpoint at configured interval, and always for the final year
         if let (Some(interval), Some(dir)) = (config.flush_interval, &config.output_dir) {
-            let is_last_year = year_offset == config.num_years - 1;
-            if is_last_year || (year_offset > 0 && (year_offset + 1) % interval == 0) {
+            let is_last_year = year_offset == config.num_years - 1 || stop.is_some();
+            if is_last_year || (year_offset > 0 && (year_offset + 1).is_multiple_of(interval)) {
                 let checkpoint_dir = dir.join(format!("year_{year:06}"));
                 flush_to_jsonl(world, &checkpoint_dir)?;
             }
         }
+
+        // Resumable checkpoint at configured interval
+        if let (Some(interval), Some(dir)) = (config.checkpoint_interval, &config.output_dir)
+            && (year_offset + 1).is_multiple_of(interval)
+        {
+            write_checkpoint(world, &dir.join(format!("checkpoint_{year:06}")))?;
+        }
+
+        if let Some(reason) = stop {
+            return Ok(RunOutcome {
+                final_year: year,
+                reason,
+            });
+        }
     }
-    Ok(())
+    Ok(RunOutcome {
+        final_year: end_year - 1,
+        reason: StopReason::Completed,
+    })
 }
 
 #[cfg(test)]
@@ -260,8 +599,8 @@ mod tests {
     }
 
     #[test]
-    fn should_fire_weekly_every_seven_days() {
-        // Day 1, 8, 15, ... should fire
+    fn should_fire_weekly_at_month_aligned_week_starts() {
+        // Days 1, 8, 15, 22 of each month should fire
         assert!(should_fire(
             TickFrequency::Weekly,
             SimTimestamp::new(1, 1, 0)
@@ -272,13 +611,18 @@ mod tests {
         ));
         assert!(should_fire(
             TickFrequency::Weekly,
-            SimTimestamp::new(1, 358, 0)
+            SimTimestamp::new(1, 352, 0)
         ));
         // Non-week-start days should not fire
         assert!(!should_fire(
             TickFrequency::Weekly,
             SimTimestamp::new(1, 2, 0)
         ));
+        // Day 29 sits in the month's long fourth week
+        assert!(!should_fire(
+            TickFrequency::Weekly,
+            SimTimestamp::new(1, 29, 0)
+        ));
         assert!(!should_fire(
             TickFrequency::Weekly,
             SimTimestamp::new(1, 7, 0)
@@ -391,8 +735,41 @@ mod tests {
         ))];
         let mut world = World::new();
         let _ = run(&mut world, &mut systems, SimConfig::new(0, 1, 0));
-        // 360 days, weeks start at day 1,8,15,...,358 → (360-1)/7 + 1 = 52
-        assert_eq!(count.get(), 52);
+        // 12 months × 4 month-aligned weeks
+        assert_eq!(count.get(), 48);
+    }
+
+    #[test]
+    fn weekly_fires_four_times_per_month_alongside_coarser_systems() {
+        let yearly_count = Rc::new(Cell::new(0));
+        let monthly_count = Rc::new(Cell::new(0));
+        let weekly_count = Rc::new(Cell::new(0));
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![
+            Box::new(CountingSystem::new(
+                "yearly",
+                TickFrequency::Yearly,
+                yearly_count.clone(),
+            )),
+            Box::new(CountingSystem::new(
+                "monthly",
+                TickFrequency::Monthly,
+                monthly_count.clone(),
+            )),
+            Box::new(CountingSystem::new(
+                "weekly",
+                TickFrequency::Weekly,
+                weekly_count.clone(),
+            )),
+        ];
+        let mut world = World::new();
+        let _ = run(&mut world, &mut systems, SimConfig::new(0, 3, 0));
+        assert_eq!(yearly_count.get(), 3, "yearly fires exactly once per year");
+        assert_eq!(
+            monthly_count.get(),
+            36,
+            "monthly fires on every month start"
+        );
+        assert_eq!(weekly_count.get(), 4 * monthly_count.get());
     }
 
     #[test]
@@ -528,6 +905,63 @@ mod tests {
         assert_eq!(*log.borrow(), vec!["A", "B", "A", "B"]);
     }
 
+    #[test]
+    fn adding_a_system_does_not_shift_other_systems_draws() {
+        use rand::Rng;
+
+        /// Records a random roll as an event every month.
+        struct RollingSystem;
+
+        impl SimSystem for RollingSystem {
+            fn name(&self) -> &str {
+                "roller"
+            }
+            fn frequency(&self) -> TickFrequency {
+                TickFrequency::Monthly
+            }
+            fn tick(&mut self, ctx: &mut TickContext) {
+                let roll: u32 = ctx.rng.random_range(0..1_000_000);
+                let time = ctx.world.current_time;
+                ctx.world
+                    .add_event(EventKind::Birth, time, format!("rolled {roll}"));
+            }
+        }
+
+        /// Touches nothing but its own RNG.
+        struct NoiseSystem;
+
+        impl SimSystem for NoiseSystem {
+            fn name(&self) -> &str {
+                "noise"
+            }
+            fn frequency(&self) -> TickFrequency {
+                TickFrequency::Monthly
+            }
+            fn tick(&mut self, ctx: &mut TickContext) {
+                for _ in 0..3 {
+                    ctx.rng.next_u64();
+                }
+            }
+        }
+
+        let rolls = |mut systems: Vec<Box<dyn SimSystem>>| {
+            let mut world = World::new();
+            let _ = run(&mut world, &mut systems, SimConfig::new(0, 3, 99));
+            world
+                .events
+                .values()
+                .map(|e| e.description.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let alone = rolls(vec![Box::new(RollingSystem)]);
+        let after_noise = rolls(vec![Box::new(NoiseSystem), Box::new(RollingSystem)]);
+        let before_noise = rolls(vec![Box::new(RollingSystem), Box::new(NoiseSystem)]);
+        assert_eq!(alone.len(), 36);
+        assert_eq!(alone, after_noise);
+        assert_eq!(alone, before_noise);
+    }
+
     // -- Signal bus tests --
 
     #[test]
@@ -645,4 +1079,136 @@ mod tests {
         // Each tick should only see 1 signal (from that tick), not accumulated
         assert_eq!(max_inbox_len.get(), 1);
     }
+
+    #[test]
+    fn run_stops_early_on_hegemony() {
+        /// Hands one rival settlement to the conqueror each year.
+        struct ConquestSystem {
+            conqueror: u64,
+            victim: u64,
+        }
+
+        impl SimSystem for ConquestSystem {
+            fn name(&self) -> &str {
+                "conquest"
+            }
+            fn frequency(&self) -> TickFrequency {
+                TickFrequency::Yearly
+            }
+            fn tick(&mut self, ctx: &mut TickContext) {
+                let Some(settlement) = ctx
+                    .world
+                    .living(EntityKind::Settlement)
+                    .find(|e| e.has_active_rel(RelationshipKind::MemberOf, self.victim))
+                    .map(|e| e.id)
+                else {
+                    return;
+                };
+                let time = ctx.world.current_time;
+                let ev = ctx
+                    .world
+                    .add_event(EventKind::Conquest, time, "conquered".to_string());
+                let member_of = RelationshipKind::MemberOf;
+                ctx.world
+                    .end_relationship(settlement, self.victim, member_of.clone(), time, ev);
+                ctx.world
+                    .add_relationship(settlement, self.conqueror, member_of, time, ev);
+            }
+        }
+
+        let mut s = crate::scenario::Scenario::at_year(100);
+        let aldor = s.add_kingdom("Aldor");
+        let brell = s.add_rival_kingdom("Brell", aldor.region);
+        s.settlement("Aldor Keep", aldor.faction, aldor.region).id();
+        s.settlement("Brell Hold", brell.faction, brell.region).id();
+        s.settlement("Brell Ford", brell.faction, brell.region).id();
+        let mut world = s.build();
+
+        // Aldor holds 2 of 5 settlements and needs 4 to reach 80%
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(ConquestSystem {
+            conqueror: aldor.faction,
+            victim: brell.faction,
+        })];
+        let mut config = SimConfig::new(100, 50, 0);
+        config.stop_conditions = vec![StopCondition::AllFactionsDead, StopCondition::Hegemony(0.8)];
+        let outcome = run(&mut world, &mut systems, config).unwrap();
+
+        assert_eq!(
+            outcome,
+            RunOutcome {
+                final_year: 101,
+                reason: StopReason::Hegemony {
+                    faction_id: aldor.faction
+                },
+            }
+        );
+        assert_eq!(world.current_time.year(), 101);
+    }
+
+    #[test]
+    fn tick_frequency_override_replaces_system_default() {
+        use crate::sim::EconomySystem;
+
+        fn economy_ticks(config: SimConfig) -> usize {
+            let mut s = crate::scenario::Scenario::at_year(100);
+            s.add_kingdom("Aldor");
+            let mut world = s.build();
+            let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(EconomySystem)];
+            run(&mut world, &mut systems, config).unwrap();
+            world
+                .events
+                .values()
+                .filter(|e| e.kind == EventKind::Custom("economy_tick".to_string()))
+                .count()
+        }
+
+        let monthly = economy_ticks(SimConfig::new(100, 5, 0));
+        let mut config = SimConfig::new(100, 5, 0);
+        config
+            .tick_frequencies
+            .insert("economy".to_string(), TickFrequency::Yearly);
+        let yearly = economy_ticks(config);
+
+        assert_eq!(monthly, 60);
+        assert_eq!(yearly, 5);
+    }
+
+    #[test]
+    fn tick_frequency_override_for_unknown_system_is_rejected() {
+        let count = Rc::new(Cell::new(0));
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(CountingSystem::new(
+            "yearly",
+            TickFrequency::Yearly,
+            count.clone(),
+        ))];
+        let mut config = SimConfig::new(0, 3, 0);
+        config
+            .tick_frequencies
+            .insert("ecnomy".to_string(), TickFrequency::Monthly);
+
+        let err = run(&mut World::new(), &mut systems, config).unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(count.get(), 0);
+    }
+
+    #[test]
+    fn run_records_its_rng_and_resume_rejects_another() {
+        let count = Rc::new(Cell::new(0));
+        let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(CountingSystem::new(
+            "yearly",
+            TickFrequency::Yearly,
+            count.clone(),
+        ))];
+        let mut world = World::new();
+        let mut config = SimConfig::new(0, 2, 0);
+        config.rng = RngBackend::Pcg32V1;
+        run(&mut world, &mut systems, config).unwrap();
+        assert_eq!(world.rng, Some(RngBackend::Pcg32V1));
+
+        let err = resume(&mut world, &mut systems, SimConfig::new(0, 4, 0)).unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(count.get(), 2);
+    }
 }
@@ -83,6 +83,13 @@ pub enum SignalKind {
     /// A faction's treasury hit zero.
     TreasuryDepleted { faction_id: u64 },
 
+    /// A faction's treasury has stayed empty long enough to declare bankruptcy;
+    /// its armies go unpaid.
+    FactionBankrupt {
+        faction_id: u64,
+        insolvent_years: u32,
+    },
+
     /// Refugees arrived at a settlement from another settlement.
     RefugeesArrived {
         settlement_id: u64,
@@ -134,6 +141,8 @@ pub enum SignalKind {
         attacker_faction_id: u64,
         defender_faction_id: u64,
         outcome: SiegeOutcome,
+        /// Civilians the settlement lost over the course of the siege.
+        civilian_deaths: u32,
     },
 
     /// A building was constructed in a settlement.
@@ -334,6 +343,41 @@ pub enum SignalKind {
         employer_faction_id: u64,
     },
 
+    /// An ally honored a defensive pact and joined a war on the defender's side.
+    AllyJoinedWar {
+        ally_id: u64,
+        defender_id: u64,
+        attacker_id: u64,
+    },
+
+    /// Factions banded together against a dominant hegemon.
+    CoalitionFormed {
+        hegemon_id: u64,
+        member_ids: Vec<u64>,
+    },
+
+    /// Two warring factions agreed to a ceasefire lasting until `until_year`.
+    CeasefireAgreed {
+        faction_a: u64,
+        faction_b: u64,
+        until_year: u32,
+    },
+
+    /// A faction cut off trade with another short of war.
+    EmbargoImposed { faction_id: u64, target_id: u64 },
+
+    /// A faction at war began interdicting strategic goods bound for its enemy.
+    StrategicEmbargoImposed { faction_id: u64, target_id: u64 },
+
+    /// An adventurer led settlers out of `origin_settlement_id` to found a new
+    /// settlement and faction in an unclaimed region.
+    FrontierSettlementFounded {
+        settlement_id: u64,
+        faction_id: u64,
+        founder_id: u64,
+        origin_settlement_id: u64,
+    },
+
     /// Extensible: any system can emit a custom signal.
     Custom {
         name: String,
@@ -1,4 +1,5 @@
 use super::context::TickContext;
+use super::runner::SimConfig;
 
 /// How often a simulation system should tick.
 ///
@@ -8,7 +9,7 @@ use super::context::TickContext;
 pub enum TickFrequency {
     Yearly,  // 1 tick/year
     Monthly, // 12 ticks/year
-    Weekly,  // ~51 ticks/year (360/7 = 51.4, we use 51 weeks + partial)
+    Weekly,  // 48 ticks/year (4 month-aligned weeks per month)
     Daily,   // 360 ticks/year
     Hourly,  // 8,640 ticks/year
 }
@@ -29,6 +30,13 @@ pub trait SimSystem {
     fn handle_signals(&mut self, ctx: &mut TickContext) {
         let _ = ctx;
     }
+
+    /// Pick up run-wide settings before the first tick. Called once per
+    /// [`run`](super::run) or [`resume`](super::resume).
+    /// Default: no-op.
+    fn configure(&mut self, config: &SimConfig) {
+        let _ = config;
+    }
 }
 
 #[cfg(test)]
@@ -106,9 +106,9 @@ pub fn generate_and_run(seed: u64, num_years: u32, mut systems: Vec<Box<dyn SimS
 /// Core systems: Demographics + Economy + Politics.
 pub fn core_systems() -> Vec<Box<dyn SimSystem>> {
     vec![
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ]
 }
 
@@ -116,10 +116,10 @@ pub fn core_systems() -> Vec<Box<dyn SimSystem>> {
 pub fn combat_systems() -> Vec<Box<dyn SimSystem>> {
     vec![
         Box::new(EnvironmentSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
         Box::new(ConflictSystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ]
 }
 
@@ -127,7 +127,7 @@ pub fn combat_systems() -> Vec<Box<dyn SimSystem>> {
 pub fn all_systems() -> Vec<Box<dyn SimSystem>> {
     vec![
         Box::new(EnvironmentSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(BuildingSystem),
         Box::new(EducationSystem),
         Box::new(EconomySystem),
@@ -137,7 +137,7 @@ pub fn all_systems() -> Vec<Box<dyn SimSystem>> {
         Box::new(DiseaseSystem),
         Box::new(CultureSystem),
         Box::new(ReligionSystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
         Box::new(ReputationSystem),
         Box::new(ItemSystem),
         Box::new(AgencySystem::new()),
@@ -232,7 +232,7 @@ pub fn living_entities(world: &World, kind: &EntityKind) -> Vec<u64> {
 
 /// Count living entities of a given kind.
 pub fn count_living(world: &World, kind: &EntityKind) -> usize {
-    world.count_living(kind)
+    world.count_living(*kind)
 }
 
 // ---------------------------------------------------------------------------
@@ -404,6 +404,20 @@ pub fn assert_deterministic(world1: &World, world2: &World) {
         "entity kind distribution mismatch:\n  run1: {:?}\n  run2: {:?}",
         counts1, counts2
     );
+
+    // Serialized entities must match byte-for-byte, including map ordering
+    for (id, e1) in &world1.entities {
+        let e2 = world2
+            .entities
+            .get(id)
+            .unwrap_or_else(|| panic!("entity {id} missing from second run"));
+        assert_eq!(
+            serde_json::to_string(e1).unwrap(),
+            serde_json::to_string(e2).unwrap(),
+            "entity {id} ({}) serializes differently between runs",
+            e1.name
+        );
+    }
 }
 
 /// Assert that an entity is alive (exists and has no end timestamp).
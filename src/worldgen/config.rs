@@ -3,9 +3,40 @@
 pub struct WorldGenConfig {
     /// RNG seed for deterministic generation.
     pub seed: u64,
+    /// Overall land/sea layout of the map.
+    pub topology: TopologyKind,
+    /// Whether the map's edges join up.
+    pub wrap: WrapMode,
     pub map: MapConfig,
     pub terrain: TerrainConfig,
     pub rivers: RiverConfig,
+    pub factions: FactionConfig,
+    pub religions: ReligionConfig,
+}
+
+/// How land is divided into landmasses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TopologyKind {
+    /// Land and water follow biome clustering, shaped by `TerrainConfig::water_fraction`.
+    #[default]
+    Continent,
+    /// Many small islands with long coastlines.
+    Archipelago,
+    /// A single supercontinent ringed by ocean.
+    Pangaea,
+    /// A handful of large islands separated by sea.
+    Islands,
+}
+
+/// Which edges of the map meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrapMode {
+    /// The map edges are the edges of the world.
+    #[default]
+    None,
+    /// The east and west edges meet, as on a cylinder or globe, so regions
+    /// on either side of the seam are neighbors.
+    Horizontal,
 }
 
 #[derive(Debug, Clone)]
@@ -34,13 +65,55 @@ pub struct RiverConfig {
     pub num_rivers: u32,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct FactionConfig {
+    /// Number of starting factions. `None` founds one per inhabited region.
+    pub num_factions: Option<u32>,
+    /// How settlements are apportioned when `num_factions` is set.
+    pub size_distribution: FactionSizeDistribution,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReligionConfig {
+    /// How many gods each starting religion worships.
+    pub pantheon: PantheonStyle,
+}
+
+/// Shape of the starting religions' pantheons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PantheonStyle {
+    /// Each faith worships one to three gods.
+    #[default]
+    Mixed,
+    /// Each faith worships a single god.
+    Monotheistic,
+    /// Each faith worships three to five gods, each of a different domain.
+    Polytheistic,
+}
+
+/// Relative sizes of the starting factions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FactionSizeDistribution {
+    /// Every faction starts with roughly the same number of settlements.
+    #[default]
+    Equal,
+    /// The k-th largest faction is about 1/k the size of the largest.
+    PowerLaw,
+    /// One empire holds most settlements; the rest are minor states.
+    OneDominant,
+}
+
 impl Default for WorldGenConfig {
     fn default() -> Self {
         Self {
             seed: 42,
+            topology: TopologyKind::default(),
+            wrap: WrapMode::default(),
             map: MapConfig::default(),
             terrain: TerrainConfig::default(),
             rivers: RiverConfig::default(),
+            factions: FactionConfig::default(),
+            religions: ReligionConfig::default(),
         }
     }
 }
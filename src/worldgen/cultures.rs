@@ -6,7 +6,7 @@ use rand::seq::SliceRandom;
 
 use crate::model::cultural_value::{CulturalValue, NamingStyle, generate_cultural_values};
 use crate::model::entity_data::CultureData;
-use crate::model::{EntityData, EntityKind, RelationshipKind, World};
+use crate::model::{EntityData, EntityKind, Heraldry, RelationshipKind, World};
 use crate::sim::culture_names::generate_culture_entity_name;
 use crate::worldgen::config::WorldGenConfig;
 
@@ -60,6 +60,22 @@ pub fn generate_cultures(
         }
         resistance = resistance.clamp(0.0, 1.0);
 
+        // Compute militarism: martial cultures are warlike, traders and scholars less so
+        let mut militarism: f64 = 0.5;
+        if values.contains(&CulturalValue::Martial) {
+            militarism += 0.3;
+        }
+        if values.contains(&CulturalValue::Mercantile) {
+            militarism -= 0.2;
+        }
+        if values.contains(&CulturalValue::Scholarly) {
+            militarism -= 0.1;
+        }
+        if values.contains(&CulturalValue::Isolationist) {
+            militarism -= 0.1;
+        }
+        militarism = militarism.clamp(0.0, 1.0);
+
         // Create Culture entity
         let name = generate_culture_entity_name(rng);
         let ev = world.add_event(
@@ -76,6 +92,7 @@ pub fn generate_cultures(
                 values,
                 naming_style: style,
                 resistance,
+                militarism,
             }),
             ev,
         );
@@ -85,6 +102,7 @@ pub fn generate_cultures(
             && let Some(fd) = faction.data.as_faction_mut()
         {
             fd.primary_culture = Some(culture_id);
+            fd.heraldry = Heraldry::for_faction(faction_id, Some(culture_id));
         }
 
         // For each settlement in this faction: set dominant_culture and culture_makeup
@@ -2,11 +2,12 @@ use rand::Rng;
 use rand::RngCore;
 
 use crate::model::{
-    EntityData, EntityKind, EventKind, GovernmentType, RelationshipKind, SimTimestamp, World,
+    EntityData, EntityKind, EventKind, GovernmentType, Heraldry, MilitaryDoctrine,
+    RelationshipKind, SimTimestamp, SuccessionLaw, Terrain, World,
 };
 
 use crate::sim::faction_names::generate_faction_name;
-use crate::worldgen::config::WorldGenConfig;
+use crate::worldgen::config::{FactionSizeDistribution, WorldGenConfig};
 
 /// Pipeline-compatible step that creates initial factions from settlement clusters.
 const GOVERNMENT_TYPES: &[GovernmentType] = &[
@@ -15,10 +16,33 @@ const GOVERNMENT_TYPES: &[GovernmentType] = &[
     GovernmentType::Chieftain,
 ];
 
-/// Group settlements by region and create one faction per inhabited region.
+/// Share of all settlements the dominant faction holds under `OneDominant`.
+const DOMINANT_FACTION_SHARE: f64 = 0.6;
+
+/// The doctrine a faction's homeland breeds: horsemen on open ground,
+/// archers in broken or wooded country, foot soldiers elsewhere.
+fn home_doctrine(terrain: Terrain) -> MilitaryDoctrine {
+    match terrain {
+        Terrain::Plains | Terrain::Desert | Terrain::Tundra => MilitaryDoctrine::Cavalry,
+        Terrain::Mountains | Terrain::Hills | Terrain::Forest | Terrain::Jungle => {
+            MilitaryDoctrine::Ranged
+        }
+        _ => MilitaryDoctrine::Infantry,
+    }
+}
+
+struct SettlementInfo {
+    id: u64,
+    region_id: u64,
+    x: f64,
+    y: f64,
+}
+
+/// Group settlements into starting factions: one per inhabited region by
+/// default, or `num_factions` of them sized by the configured distribution.
 pub fn generate_factions(
     world: &mut World,
-    _config: &WorldGenConfig,
+    config: &WorldGenConfig,
     rng: &mut dyn RngCore,
     _genesis_event: u64,
 ) {
@@ -29,12 +53,6 @@ pub fn generate_factions(
             .any(|e| e.kind == EntityKind::Settlement),
         "factions step requires settlements to exist"
     );
-    // Collect settlements grouped by their region (via LocatedIn relationship)
-    struct SettlementInfo {
-        id: u64,
-        region_id: u64,
-    }
-
     let settlements: Vec<SettlementInfo> = world
         .entities
         .values()
@@ -45,24 +63,43 @@ pub fn generate_factions(
                 .iter()
                 .find(|r| r.kind == RelationshipKind::LocatedIn && r.end.is_none())
                 .map(|r| r.target_entity_id)?;
+            let sd = e.data.as_settlement()?;
             Some(SettlementInfo {
                 id: e.id,
                 region_id,
+                x: sd.x,
+                y: sd.y,
             })
         })
         .collect();
 
-    // Group by region — use BTreeMap for deterministic iteration
-    let mut by_region: std::collections::BTreeMap<u64, Vec<u64>> =
-        std::collections::BTreeMap::new();
-    for s in &settlements {
-        by_region.entry(s.region_id).or_default().push(s.id);
-    }
+    let groups = match config.factions.num_factions {
+        Some(num_factions) => apportion_settlements(
+            &settlements,
+            num_factions,
+            config.factions.size_distribution,
+            rng,
+        ),
+        None => {
+            // Group by region — use BTreeMap for deterministic iteration
+            let mut by_region: std::collections::BTreeMap<u64, Vec<u64>> =
+                std::collections::BTreeMap::new();
+            for s in &settlements {
+                by_region.entry(s.region_id).or_default().push(s.id);
+            }
+            by_region.into_values().collect()
+        }
+    };
 
-    // Create one faction per inhabited region
-    for settlement_ids in by_region.values() {
+    // Create one faction per settlement group
+    for settlement_ids in &groups {
         let name = generate_faction_name(rng);
         let gov_type = GOVERNMENT_TYPES[rng.random_range(0..GOVERNMENT_TYPES.len())];
+        let succession_law = if gov_type == GovernmentType::Hereditary {
+            SuccessionLaw::ALL[rng.random_range(0..SuccessionLaw::ALL.len())]
+        } else {
+            SuccessionLaw::default()
+        };
         let stability: f64 = rng.random_range(0.6..1.0);
 
         let ev = world.add_event(
@@ -74,15 +111,30 @@ pub fn generate_factions(
         let happiness: f64 = rng.random_range(0.55..0.85);
         let treasury = settlement_ids.len() as f64 * 50.0;
         let prestige = (settlement_ids.len() as f64 * 0.05).clamp(0.05, 0.20);
+        let expansionism: f64 = rng.random_range(0.2..0.8);
+        let mercantilism: f64 = rng.random_range(0.2..0.8);
+        let isolationism: f64 = rng.random_range(0.2..0.8);
+        let seat_region = settlements
+            .iter()
+            .find(|s| s.id == settlement_ids[0])
+            .map(|s| s.region_id);
+        let doctrine = seat_region
+            .and_then(|r| world.entities.get(&r)?.data.as_region())
+            .map_or(MilitaryDoctrine::default(), |rd| home_doctrine(rd.terrain));
 
         let mut data = EntityData::default_for_kind(EntityKind::Faction);
         if let EntityData::Faction(ref mut fd) = data {
             fd.government_type = gov_type;
+            fd.succession_law = succession_law;
             fd.stability = stability;
             fd.happiness = happiness;
             fd.legitimacy = 1.0;
             fd.treasury = treasury;
             fd.prestige = prestige;
+            fd.expansionism = expansionism;
+            fd.mercantilism = mercantilism;
+            fd.isolationism = isolationism;
+            fd.doctrine = doctrine;
         }
 
         let faction_id = world.add_entity(
@@ -92,6 +144,7 @@ pub fn generate_factions(
             data,
             ev,
         );
+        world.faction_mut(faction_id).heraldry = Heraldry::for_faction(faction_id, None);
 
         // Each settlement in this region joins the faction
         for &settlement_id in settlement_ids {
@@ -103,24 +156,126 @@ pub fn generate_factions(
                 ev,
             );
         }
+        // The group's first settlement is the seat the faction was founded around
+        world.add_relationship(
+            settlement_ids[0],
+            faction_id,
+            RelationshipKind::CapitalOf,
+            SimTimestamp::from_year(0),
+            ev,
+        );
+    }
+}
+
+/// Relative size of each of `n` factions, largest first.
+fn faction_weights(n: usize, distribution: FactionSizeDistribution) -> Vec<f64> {
+    match distribution {
+        FactionSizeDistribution::Equal => vec![1.0; n],
+        FactionSizeDistribution::PowerLaw => (1..=n).map(|k| 1.0 / k as f64).collect(),
+        FactionSizeDistribution::OneDominant if n > 1 => {
+            let minor = (1.0 - DOMINANT_FACTION_SHARE) / (n - 1) as f64;
+            std::iter::once(DOMINANT_FACTION_SHARE)
+                .chain(std::iter::repeat_n(minor, n - 1))
+                .collect()
+        }
+        FactionSizeDistribution::OneDominant => vec![1.0],
     }
 }
 
+/// Split `total` settlements by `weights`, at least one each, handing out
+/// the remainder by largest fractional share.
+fn settlement_quotas(total: usize, weights: &[f64]) -> Vec<usize> {
+    let n = weights.len();
+    let spare = total - n;
+    let weight_sum: f64 = weights.iter().sum();
+    let shares: Vec<f64> = weights
+        .iter()
+        .map(|w| w / weight_sum * spare as f64)
+        .collect();
+    let mut quotas: Vec<usize> = shares.iter().map(|s| 1 + s.floor() as usize).collect();
+
+    let mut by_remainder: Vec<usize> = (0..n).collect();
+    by_remainder.sort_by(|&a, &b| {
+        (shares[b] - shares[b].floor()).total_cmp(&(shares[a] - shares[a].floor()))
+    });
+    let assigned: usize = quotas.iter().sum();
+    for &i in by_remainder.iter().take(total - assigned) {
+        quotas[i] += 1;
+    }
+    quotas
+}
+
+/// Found `num_factions` factions around capitals spread across the map, each
+/// taking its quota of the nearest unclaimed settlements, largest first.
+fn apportion_settlements(
+    settlements: &[SettlementInfo],
+    num_factions: u32,
+    distribution: FactionSizeDistribution,
+    rng: &mut dyn RngCore,
+) -> Vec<Vec<u64>> {
+    if settlements.is_empty() {
+        return Vec::new();
+    }
+    let n = (num_factions as usize).clamp(1, settlements.len());
+    let quotas = settlement_quotas(settlements.len(), &faction_weights(n, distribution));
+    let dist = |a: usize, b: usize| {
+        let (sa, sb) = (&settlements[a], &settlements[b]);
+        (sa.x - sb.x).hypot(sa.y - sb.y)
+    };
+
+    // Each further capital is the settlement farthest from those chosen so far
+    let mut capitals = vec![rng.random_range(0..settlements.len())];
+    while capitals.len() < n {
+        let nearest_capital = |i: usize| {
+            capitals
+                .iter()
+                .map(|&c| dist(c, i))
+                .fold(f64::INFINITY, f64::min)
+        };
+        let next = (0..settlements.len())
+            .filter(|i| !capitals.contains(i))
+            .max_by(|&a, &b| nearest_capital(a).total_cmp(&nearest_capital(b)))
+            .expect("fewer capitals than settlements");
+        capitals.push(next);
+    }
+
+    let mut claimed = vec![false; settlements.len()];
+    for &c in &capitals {
+        claimed[c] = true;
+    }
+    let mut groups = Vec::with_capacity(n);
+    for (&capital, &quota) in capitals.iter().zip(&quotas) {
+        let mut unclaimed: Vec<usize> = (0..settlements.len()).filter(|&i| !claimed[i]).collect();
+        unclaimed.sort_by(|&a, &b| dist(capital, a).total_cmp(&dist(capital, b)));
+        let mut group = vec![settlements[capital].id];
+        for i in unclaimed.into_iter().take(quota - 1) {
+            claimed[i] = true;
+            group.push(settlements[i].id);
+        }
+        groups.push(group);
+    }
+    groups
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
 
-    use crate::worldgen::config::{MapConfig, WorldGenConfig};
+    use crate::worldgen::config::{FactionConfig, MapConfig, WorldGenConfig};
     use crate::worldgen::geography::generate_regions;
     use crate::worldgen::settlements::generate_settlements;
 
     fn make_world_with_settlements() -> (World, u64) {
+        make_world_with_regions(15)
+    }
+
+    fn make_world_with_regions(num_regions: u32) -> (World, u64) {
         let config = WorldGenConfig {
             seed: 12345,
             map: MapConfig {
-                num_regions: 15,
+                num_regions,
                 width: 500.0,
                 height: 500.0,
                 num_biome_centers: 4,
@@ -240,4 +395,103 @@ mod tests {
             .collect();
         assert_eq!(names1, names2);
     }
+
+    #[test]
+    fn same_seed_gives_same_heraldry() {
+        let arms = || {
+            let (mut world, ev) = make_world_with_settlements();
+            let mut rng = SmallRng::seed_from_u64(99);
+            generate_factions(&mut world, &WorldGenConfig::default(), &mut rng, ev);
+            world
+                .entities
+                .values()
+                .filter_map(|e| e.data.as_faction())
+                .map(|fd| fd.heraldry)
+                .collect::<Vec<_>>()
+        };
+        let first = arms();
+        assert!(!first.is_empty());
+        assert_eq!(first, arms());
+    }
+
+    fn settlement_counts(world: &World) -> Vec<usize> {
+        let mut counts: Vec<usize> = world
+            .entities
+            .values()
+            .filter(|e| e.kind == EntityKind::Faction)
+            .map(|f| {
+                world
+                    .entities
+                    .values()
+                    .filter(|e| {
+                        e.kind == EntityKind::Settlement
+                            && e.has_active_rel(RelationshipKind::MemberOf, f.id)
+                    })
+                    .count()
+            })
+            .collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts
+    }
+
+    fn config_with_factions(
+        num_factions: u32,
+        size_distribution: FactionSizeDistribution,
+    ) -> WorldGenConfig {
+        WorldGenConfig {
+            factions: FactionConfig {
+                num_factions: Some(num_factions),
+                size_distribution,
+            },
+            ..WorldGenConfig::default()
+        }
+    }
+
+    #[test]
+    fn one_dominant_faction_dwarfs_the_rest() {
+        let (mut world, ev) = make_world_with_regions(150);
+        let mut rng = SmallRng::seed_from_u64(99);
+        let config = config_with_factions(4, FactionSizeDistribution::OneDominant);
+        generate_factions(&mut world, &config, &mut rng, ev);
+
+        let counts = settlement_counts(&world);
+        assert_eq!(counts.len(), 4);
+        let rest: usize = counts[1..].iter().sum();
+        assert!(
+            counts[0] > rest && counts[0] >= 3 * counts[1],
+            "dominant faction should far outsize the rest: {counts:?}"
+        );
+    }
+
+    #[test]
+    fn equal_distribution_splits_settlements_evenly() {
+        let (mut world, ev) = make_world_with_regions(150);
+        let mut rng = SmallRng::seed_from_u64(99);
+        let config = config_with_factions(4, FactionSizeDistribution::Equal);
+        generate_factions(&mut world, &config, &mut rng, ev);
+
+        let counts = settlement_counts(&world);
+        assert_eq!(counts.len(), 4);
+        assert!(
+            counts[0] - counts[3] <= 1,
+            "factions should be within one settlement of each other: {counts:?}"
+        );
+    }
+
+    #[test]
+    fn num_factions_capped_at_settlement_count() {
+        let (mut world, ev) = make_world_with_settlements();
+        let settlements = world
+            .entities
+            .values()
+            .filter(|e| e.kind == EntityKind::Settlement)
+            .count();
+        let mut rng = SmallRng::seed_from_u64(99);
+        let config = config_with_factions(1000, FactionSizeDistribution::PowerLaw);
+        generate_factions(&mut world, &config, &mut rng, ev);
+
+        let counts = settlement_counts(&world);
+        assert_eq!(counts.len(), settlements);
+        assert!(counts.iter().all(|&c| c == 1));
+    }
 }
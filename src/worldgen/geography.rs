@@ -5,8 +5,8 @@ use rand::RngCore;
 
 use crate::model::{EntityData, EntityKind, RegionData, RelationshipKind, SimTimestamp, World};
 
-use super::terrain::{Terrain, TerrainProfile, TerrainTag};
-use crate::worldgen::config::WorldGenConfig;
+use super::terrain::{Climate, Terrain, TerrainProfile, TerrainTag};
+use crate::worldgen::config::{TopologyKind, WorldGenConfig, WrapMode};
 
 /// Minimum distance between region seed points (fraction of map diagonal).
 const MIN_DISTANCE_FRACTION: f64 = 0.08;
@@ -14,6 +14,40 @@ const MIN_DISTANCE_FRACTION: f64 = 0.08;
 /// Chance that a region's terrain differs from its nearest biome center.
 const PERTURBATION_CHANCE: f64 = 0.15;
 
+/// Land/sea layout parameters for the landmass-based topologies.
+struct LandmassShape {
+    /// Number of separate landmasses.
+    landmasses: usize,
+    /// Fraction of regions that are land.
+    land_fraction: f64,
+    /// Chance a shoreline land region becomes `Coast` terrain.
+    coast_chance: f64,
+}
+
+impl LandmassShape {
+    /// `None` for `Continent`, which keeps biome-driven water placement.
+    fn for_topology(topology: TopologyKind, num_regions: usize) -> Option<Self> {
+        match topology {
+            TopologyKind::Continent => None,
+            TopologyKind::Pangaea => Some(Self {
+                landmasses: 1,
+                land_fraction: 0.75,
+                coast_chance: 0.25,
+            }),
+            TopologyKind::Islands => Some(Self {
+                landmasses: 3,
+                land_fraction: 0.6,
+                coast_chance: 0.5,
+            }),
+            TopologyKind::Archipelago => Some(Self {
+                landmasses: (num_regions / 4).max(4),
+                land_fraction: 0.5,
+                coast_chance: 0.85,
+            }),
+        }
+    }
+}
+
 /// Generate regions with terrain, coordinates, and adjacency relationships.
 pub fn generate_regions(
     world: &mut World,
@@ -58,7 +92,7 @@ pub fn generate_regions(
         .collect();
 
     // 3. Assign terrain to each region based on nearest biome center
-    let terrains: Vec<Terrain> = points
+    let mut terrains: Vec<Terrain> = points
         .iter()
         .map(|&(x, y)| {
             let nearest_terrain = nearest_biome_terrain(x, y, &biome_centers, &biome_terrains);
@@ -79,34 +113,16 @@ pub fn generate_regions(
         })
         .collect();
 
-    // 4. Create Region entities (without tags yet — need adjacency first)
-    let mut region_ids: Vec<u64> = Vec::with_capacity(points.len());
-    for (i, (&(x, y), &terrain)) in points.iter().zip(terrains.iter()).enumerate() {
-        let name = generate_region_name(terrain, i, rng);
-        let id = world.add_entity(
-            EntityKind::Region,
-            name,
-            Some(SimTimestamp::from_year(0)),
-            EntityData::Region(RegionData {
-                terrain,
-                terrain_tags: vec![],
-                x,
-                y,
-                resources: vec![],
-            }),
-            genesis_event,
-        );
-        region_ids.push(id);
-    }
-
-    // 5. Compute K-nearest-neighbor adjacency
+    // 4. Compute K-nearest-neighbor adjacency, measuring across the seam of a
+    // wrapped map
+    let seam_width = (config.wrap == WrapMode::Horizontal).then_some(config.map.width);
     let k = config.map.adjacency_k as usize;
     let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); points.len()];
 
     for i in 0..points.len() {
         let mut distances: Vec<(usize, f64)> = (0..points.len())
             .filter(|&j| j != i)
-            .map(|j| (j, dist(points[i], points[j])))
+            .map(|j| (j, map_dist(points[i], points[j], seam_width)))
             .collect();
         distances.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
 
@@ -120,10 +136,40 @@ pub fn generate_regions(
         }
     }
 
+    if let Some(width) = seam_width {
+        stitch_seam(&points, width, &mut adjacency);
+    }
+
+    // 5. Carve landmasses out of the sea for non-continental topologies
+    if let Some(shape) = LandmassShape::for_topology(config.topology, points.len()) {
+        shape_landmasses(&shape, config, &points, &mut terrains, &mut adjacency, rng);
+    }
+
     // 6. Ensure connectivity via BFS; add edges if disconnected
-    ensure_connected(&points, &mut adjacency);
+    ensure_connected(&points, seam_width, &mut adjacency);
+
+    // 7. Create Region entities (without tags yet — need adjacency first)
+    let mut region_ids: Vec<u64> = Vec::with_capacity(points.len());
+    for (i, (&(x, y), &terrain)) in points.iter().zip(terrains.iter()).enumerate() {
+        let name = generate_region_name(terrain, i, rng);
+        let id = world.add_entity(
+            EntityKind::Region,
+            name,
+            Some(SimTimestamp::from_year(0)),
+            EntityData::Region(RegionData {
+                terrain,
+                terrain_tags: vec![],
+                climate: Climate::from_latitude(y / config.map.height, terrain),
+                x,
+                y,
+                resources: vec![],
+            }),
+            genesis_event,
+        );
+        region_ids.push(id);
+    }
 
-    // 7. Create bidirectional AdjacentTo relationships
+    // 8. Create bidirectional AdjacentTo relationships
     for i in 0..adjacency.len() {
         for &j in &adjacency[i] {
             if i < j {
@@ -145,13 +191,90 @@ pub fn generate_regions(
         }
     }
 
-    // 8. Assign terrain tags (after adjacency is computed for Coastal derivation)
+    // 9. Assign terrain tags (after adjacency is computed for Coastal derivation)
     assign_terrain_tags(world, &region_ids, &terrains, &adjacency, rng);
 
-    // 9. Set resources based on TerrainProfile (base + tags)
+    // 10. Set resources based on TerrainProfile (base + tags)
     set_region_resources(world, &region_ids);
 }
 
+/// Turn the regions nearest each landmass center into land and the rest into sea,
+/// cut land bridges between separate landmasses, and line shores with `Coast`.
+fn shape_landmasses(
+    shape: &LandmassShape,
+    config: &WorldGenConfig,
+    points: &[(f64, f64)],
+    terrains: &mut [Terrain],
+    adjacency: &mut [Vec<usize>],
+    rng: &mut dyn RngCore,
+) {
+    let (width, height) = (config.map.width, config.map.height);
+    let centers = if shape.landmasses == 1 {
+        vec![(width / 2.0, height / 2.0)]
+    } else {
+        let min_dist = (width * width + height * height).sqrt() / (shape.landmasses as f64 + 1.0);
+        scatter_points(shape.landmasses, width, height, min_dist, rng)
+    };
+
+    // Land is the share of regions closest to any landmass center
+    let nearest: Vec<(usize, f64)> = points
+        .iter()
+        .map(|&p| {
+            centers
+                .iter()
+                .enumerate()
+                .map(|(c, &center)| (c, dist(p, center)))
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                .unwrap()
+        })
+        .collect();
+    let mut by_distance: Vec<usize> = (0..points.len()).collect();
+    by_distance.sort_by(|&a, &b| nearest[a].1.partial_cmp(&nearest[b].1).unwrap());
+    let land_count = ((points.len() as f64 * shape.land_fraction).round() as usize).max(1);
+    let mut landmass: Vec<Option<usize>> = vec![None; points.len()];
+    for &i in by_distance.iter().take(land_count) {
+        landmass[i] = Some(nearest[i].0);
+    }
+
+    for (i, terrain) in terrains.iter_mut().enumerate() {
+        if landmass[i].is_some() {
+            if terrain.is_water() {
+                *terrain = rng.random();
+            }
+        } else {
+            *terrain = Terrain::DeepWater;
+        }
+    }
+
+    // Separate landmasses only touch through water
+    for i in 0..adjacency.len() {
+        adjacency[i].retain(|&j| match (landmass[i], landmass[j]) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        });
+    }
+
+    for i in 0..terrains.len() {
+        let borders = |want_water: bool| {
+            adjacency[i]
+                .iter()
+                .any(|&j| landmass[j].is_none() == want_water)
+        };
+        if landmass[i].is_none() {
+            if borders(false) {
+                terrains[i] = Terrain::ShallowWater;
+            }
+        } else if borders(true) {
+            if rng.random_range(0.0..1.0) < shape.coast_chance {
+                terrains[i] = Terrain::Coast;
+            }
+        } else if terrains[i] == Terrain::Coast {
+            // No inland shores
+            terrains[i] = Terrain::Plains;
+        }
+    }
+}
+
 /// Assign terrain tags to regions based on terrain type and adjacency.
 fn assign_terrain_tags(
     world: &mut World,
@@ -285,6 +408,53 @@ fn dist(a: (f64, f64), b: (f64, f64)) -> f64 {
     (dx * dx + dy * dy).sqrt()
 }
 
+/// Distance between two points; with `seam_width` set, the map wraps east to
+/// west at that width and the shorter way round is taken.
+fn map_dist(a: (f64, f64), b: (f64, f64), seam_width: Option<f64>) -> f64 {
+    let mut dx = (a.0 - b.0).abs();
+    if let Some(width) = seam_width {
+        dx = dx.min(width - dx);
+    }
+    let dy = a.1 - b.1;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// Join the west and east edges of a wrapped map: every region within one
+/// typical spacing of either edge borders its nearest region on the far side.
+fn stitch_seam(points: &[(f64, f64)], width: f64, adjacency: &mut [Vec<usize>]) {
+    if points.len() < 2 {
+        return;
+    }
+    let band = width / (points.len() as f64).sqrt();
+    let west: Vec<usize> = (0..points.len()).filter(|&i| points[i].0 < band).collect();
+    let east: Vec<usize> = (0..points.len())
+        .filter(|&i| points[i].0 > width - band)
+        .collect();
+    for (edge, far_side) in [(&west, &east), (&east, &west)] {
+        for &i in edge {
+            let nearest = far_side
+                .iter()
+                .copied()
+                .filter(|&j| j != i)
+                .min_by(|&a, &b| {
+                    map_dist(points[i], points[a], Some(width)).total_cmp(&map_dist(
+                        points[i],
+                        points[b],
+                        Some(width),
+                    ))
+                });
+            if let Some(j) = nearest {
+                if !adjacency[i].contains(&j) {
+                    adjacency[i].push(j);
+                }
+                if !adjacency[j].contains(&i) {
+                    adjacency[j].push(i);
+                }
+            }
+        }
+    }
+}
+
 fn nearest_biome_terrain(
     x: f64,
     y: f64,
@@ -300,7 +470,7 @@ fn nearest_biome_terrain(
 }
 
 /// BFS connectivity check; connect disconnected components by adding edges.
-fn ensure_connected(points: &[(f64, f64)], adjacency: &mut [Vec<usize>]) {
+fn ensure_connected(points: &[(f64, f64)], seam_width: Option<f64>, adjacency: &mut [Vec<usize>]) {
     if points.is_empty() {
         return;
     }
@@ -338,7 +508,7 @@ fn ensure_connected(points: &[(f64, f64)], adjacency: &mut [Vec<usize>]) {
 
         for &a in &components[i - 1] {
             for &b in &components[i] {
-                let d = dist(points[a], points[b]);
+                let d = map_dist(points[a], points[b], seam_width);
                 if d < best_dist {
                     best_dist = d;
                     best_a = a;
@@ -403,7 +573,7 @@ mod tests {
 
     use crate::model::{EventKind, SimTimestamp, World};
 
-    use crate::worldgen::config::WorldGenConfig;
+    use crate::worldgen::config::{TopologyKind, WorldGenConfig, WrapMode};
 
     fn genesis_event(world: &mut World) -> u64 {
         world.add_event(
@@ -444,6 +614,59 @@ mod tests {
         assert_eq!(region_count, config.map.num_regions as usize);
     }
 
+    /// Regions as (id, x, y), generated with the given wrap mode.
+    fn wrapped_world(wrap: WrapMode) -> (World, Vec<(u64, f64, f64)>) {
+        let config = WorldGenConfig {
+            wrap,
+            ..test_config()
+        };
+        let mut world = World::new();
+        let ev = genesis_event(&mut world);
+        let mut rng = SmallRng::seed_from_u64(config.seed);
+        generate_regions(&mut world, &config, &mut rng, ev);
+        let regions = world
+            .entities
+            .values()
+            .filter_map(|e| e.data.as_region().map(|rd| (e.id, rd.x, rd.y)))
+            .collect();
+        (world, regions)
+    }
+
+    #[test]
+    fn horizontal_wrap_joins_west_and_east_edges() {
+        use crate::sim::helpers::bfs_next_step;
+
+        let width = test_config().map.width;
+        let (world, regions) = wrapped_world(WrapMode::Horizontal);
+        let &(west, wx, wy) = regions.iter().min_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        // Its counterpart: the nearest region on the far side of the seam
+        let &(east, ..) = regions
+            .iter()
+            .filter(|r| r.1 > width / 2.0)
+            .min_by(|a, b| {
+                map_dist((wx, wy), (a.1, a.2), Some(width)).total_cmp(&map_dist(
+                    (wx, wy),
+                    (b.1, b.2),
+                    Some(width),
+                ))
+            })
+            .unwrap();
+
+        assert!(
+            world.entities[&west].has_active_rel(RelationshipKind::AdjacentTo, east),
+            "west edge region should border its east-edge counterpart"
+        );
+        assert_eq!(
+            bfs_next_step(&world, west, east),
+            Some(east),
+            "paths should cross the seam in one step"
+        );
+
+        let (flat, _) = wrapped_world(WrapMode::None);
+        assert!(!flat.entities[&west].has_active_rel(RelationshipKind::AdjacentTo, east));
+        assert_ne!(bfs_next_step(&flat, west, east), Some(east));
+    }
+
     #[test]
     fn deterministic_with_same_seed() {
         let config = test_config();
@@ -669,4 +892,80 @@ mod tests {
             }
         }
     }
+
+    fn generate_with_topology(topology: TopologyKind) -> World {
+        let config = WorldGenConfig {
+            topology,
+            map: crate::worldgen::config::MapConfig {
+                num_regions: 60,
+                adjacency_k: 4,
+                ..test_config().map
+            },
+            ..test_config()
+        };
+        let mut world = World::new();
+        let ev = genesis_event(&mut world);
+        let mut rng = SmallRng::seed_from_u64(config.seed);
+        generate_regions(&mut world, &config, &mut rng, ev);
+        world
+    }
+
+    fn count_terrain(world: &World, terrain: Terrain) -> usize {
+        world
+            .entities
+            .values()
+            .filter_map(|e| e.data.as_region())
+            .filter(|r| r.terrain == terrain)
+            .count()
+    }
+
+    #[test]
+    fn archipelago_has_far_more_coast_than_pangaea() {
+        let archipelago = count_terrain(
+            &generate_with_topology(TopologyKind::Archipelago),
+            Terrain::Coast,
+        );
+        let pangaea = count_terrain(
+            &generate_with_topology(TopologyKind::Pangaea),
+            Terrain::Coast,
+        );
+        assert!(archipelago > 0);
+        assert!(
+            archipelago >= pangaea * 2,
+            "archipelago coast {archipelago} should dwarf pangaea coast {pangaea}"
+        );
+    }
+
+    #[test]
+    fn every_topology_stays_connected() {
+        for topology in [
+            TopologyKind::Continent,
+            TopologyKind::Archipelago,
+            TopologyKind::Pangaea,
+            TopologyKind::Islands,
+        ] {
+            let world = generate_with_topology(topology);
+            let region_ids: Vec<u64> = world
+                .entities
+                .values()
+                .filter(|e| e.kind == EntityKind::Region)
+                .map(|e| e.id)
+                .collect();
+
+            let mut visited = std::collections::HashSet::from([region_ids[0]]);
+            let mut queue = VecDeque::from([region_ids[0]]);
+            while let Some(current) = queue.pop_front() {
+                for target in world.entities[&current].active_rels(RelationshipKind::AdjacentTo) {
+                    if visited.insert(target) {
+                        queue.push_back(target);
+                    }
+                }
+            }
+            assert_eq!(
+                visited.len(),
+                region_ids.len(),
+                "{topology:?} is disconnected"
+            );
+        }
+    }
 }
@@ -320,6 +320,8 @@ mod tests {
             widowed_at: None,
             loyalty: std::collections::BTreeMap::new(),
             education: 0.0,
+            health: 1.0,
+            dynasty_id: None,
         });
         let leader_id = world.add_entity(
             EntityKind::Person,
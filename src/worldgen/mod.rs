@@ -18,7 +18,10 @@ use rand::rngs::SmallRng;
 
 use crate::model::{EventKind, SimTimestamp, World};
 
-pub use config::{MapConfig, RiverConfig, TerrainConfig, WorldGenConfig};
+pub use config::{
+    FactionConfig, FactionSizeDistribution, MapConfig, PantheonStyle, ReligionConfig, RiverConfig,
+    TerrainConfig, WorldGenConfig, WrapMode,
+};
 pub use terrain::Terrain;
 
 /// Capitalize the first character of a string.
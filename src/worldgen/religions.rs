@@ -6,8 +6,9 @@ use rand::RngCore;
 use crate::model::cultural_value::CulturalValue;
 use crate::model::entity_data::{DeityData, DeityDomain, ReligionData, ReligiousTenet};
 use crate::model::{EntityData, EntityKind, RelationshipKind, World};
+use crate::sim::holy_sites;
 use crate::sim::religion_names::{generate_deity_name, generate_religion_name};
-use crate::worldgen::config::WorldGenConfig;
+use crate::worldgen::config::{PantheonStyle, WorldGenConfig};
 
 /// All tenet variants for random selection.
 const ALL_TENETS: [ReligiousTenet; 8] = [
@@ -185,7 +186,7 @@ fn domain_weight(domain: DeityDomain, tenets: &[ReligiousTenet]) -> u32 {
 /// Pipeline-compatible step that creates initial religions, one per faction.
 pub fn generate_religions(
     world: &mut World,
-    _config: &WorldGenConfig,
+    config: &WorldGenConfig,
     rng: &mut dyn RngCore,
     _genesis_event: u64,
 ) {
@@ -241,10 +242,22 @@ pub fn generate_religions(
             ev,
         );
 
-        // Create 1-3 Deity entities linked to this religion
-        let deity_count = rng.random_range(1..=3);
+        // Create the religion's Deity entities, linked via MemberOf
+        let deity_count = match config.religions.pantheon {
+            PantheonStyle::Mixed => rng.random_range(1..=3),
+            PantheonStyle::Monotheistic => 1,
+            PantheonStyle::Polytheistic => rng.random_range(3..=5),
+        };
+        let mut domains: Vec<DeityDomain> = Vec::with_capacity(deity_count);
         for _ in 0..deity_count {
-            let domain = pick_domain(rng, &tenets);
+            let mut domain = pick_domain(rng, &tenets);
+            // A pantheon gives each god a sphere of its own
+            while config.religions.pantheon == PantheonStyle::Polytheistic
+                && domains.contains(&domain)
+            {
+                domain = pick_domain(rng, &tenets);
+            }
+            domains.push(domain);
             let worship_strength = 0.4 + rng.random_range(0..=50) as f64 / 100.0; // 0.4-0.9
             let deity_name = generate_deity_name(rng);
             let deity_id = world.add_entity(
@@ -293,6 +306,21 @@ pub fn generate_religions(
                 sd.religion_makeup = BTreeMap::from([(religion_id, 1.0)]);
             }
         }
+
+        // The faith's holy site rises in the faction's greatest settlement
+        let holiest = settlement_ids
+            .iter()
+            .copied()
+            .max_by_key(|&sid| (world.settlement(sid).population, std::cmp::Reverse(sid)));
+        if let Some(sid) = holiest {
+            holy_sites::found_holy_site(
+                world,
+                religion_id,
+                sid,
+                crate::model::SimTimestamp::from_year(0),
+                ev,
+            );
+        }
     }
 }
 
@@ -317,7 +345,7 @@ mod tests {
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
 
-    use crate::worldgen::config::{MapConfig, WorldGenConfig};
+    use crate::worldgen::config::{MapConfig, ReligionConfig, WorldGenConfig};
     use crate::worldgen::cultures::generate_cultures;
     use crate::worldgen::factions::generate_factions;
     use crate::worldgen::geography::generate_regions;
@@ -484,4 +512,41 @@ mod tests {
             );
         }
     }
+
+    fn pantheons(style: PantheonStyle) -> Vec<Vec<DeityDomain>> {
+        let (mut world, ev) = make_world_with_cultures();
+        let mut rng = SmallRng::seed_from_u64(42);
+        let config = WorldGenConfig {
+            religions: ReligionConfig { pantheon: style },
+            ..WorldGenConfig::default()
+        };
+        generate_religions(&mut world, &config, &mut rng, ev);
+
+        world
+            .living(EntityKind::Religion)
+            .map(|r| {
+                world
+                    .active_sources(RelationshipKind::MemberOf, r.id)
+                    .filter_map(|d| d.data.as_deity())
+                    .map(|dd| dd.domain)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pantheon_style_shapes_deity_count() {
+        let mono = pantheons(PantheonStyle::Monotheistic);
+        assert!(!mono.is_empty());
+        assert!(mono.iter().all(|gods| gods.len() == 1));
+
+        let poly = pantheons(PantheonStyle::Polytheistic);
+        assert!(!poly.is_empty());
+        for gods in &poly {
+            assert!(gods.len() >= 3, "polytheistic faiths need a pantheon");
+            for (i, domain) in gods.iter().enumerate() {
+                assert!(!gods[..i].contains(domain), "each god keeps its own domain");
+            }
+        }
+    }
 }
@@ -198,6 +198,7 @@ mod tests {
             terrain: TerrainConfig {
                 water_fraction: 0.2,
             },
+            ..WorldGenConfig::default()
         };
         let (world, ev) = crate::worldgen::make_test_world(&config, &[generate_regions]);
         (world, config, ev)
@@ -2,7 +2,7 @@ use rand::Rng;
 use rand::distr::Distribution;
 
 use crate::model::entity_data::ResourceType;
-pub use crate::model::terrain::{Terrain, TerrainTag};
+pub use crate::model::terrain::{Climate, Terrain, TerrainTag};
 
 impl Distribution<Terrain> for rand::distr::StandardUniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Terrain {
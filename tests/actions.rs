@@ -1,10 +1,13 @@
 use history_gen::model::action::{Action, ActionKind, ActionOutcome, ActionSource};
-use history_gen::model::{EntityKind, EventKind, ParticipantRole, RelationshipKind, Role};
+use history_gen::model::{
+    EntityKind, EventKind, EventPayload, ParticipantRole, RelationshipKind, Role,
+};
 use history_gen::scenario::Scenario;
 use history_gen::sim::{
     ActionSystem, ConflictSystem, DemographicsSystem, EconomySystem, PoliticsSystem, SimConfig,
     SimSystem, run,
 };
+use history_gen::testutil;
 
 #[test]
 fn scenario_assassination_triggers_succession() {
@@ -45,9 +48,9 @@ fn scenario_assassination_triggers_succession() {
 
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
         Box::new(ActionSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
     let _ = run(&mut world, &mut systems, SimConfig::new(100, 3, 42));
 
@@ -152,9 +155,9 @@ fn scenario_undermining_destabilizes_faction() {
 
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
         Box::new(ActionSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
     let _ = run(&mut world, &mut systems, SimConfig::new(100, 1, 42));
 
@@ -210,10 +213,10 @@ fn scenario_declare_war_action() {
 
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
         Box::new(ActionSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
         Box::new(ConflictSystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
     let _ = run(&mut world, &mut systems, SimConfig::new(100, 1, 42));
 
@@ -223,6 +226,12 @@ fn scenario_declare_war_action() {
         .values()
         .find(|e| e.kind == EventKind::WarDeclared)
         .expect("should have WarDeclared event");
+    assert!(matches!(
+        EventPayload::from_data(&war_declared.data),
+        Some(EventPayload::WarDeclared { attacker_id, defender_id, .. })
+            if attacker_id == player_faction && defender_id == target_faction
+    ));
+    assert_eq!(war_declared.source, Some(ActionSource::Player));
 
     // Verify player is Instigator
     assert!(world.event_participants.iter().any(|p| {
@@ -251,3 +260,68 @@ fn scenario_declare_war_action() {
         "DeclareWar action should produce a success result"
     );
 }
+
+#[test]
+fn scenario_declare_war_requires_first_contact() {
+    let mut s = Scenario::at_year(100);
+    let pk = s.add_kingdom("Island Kingdom");
+    let tk = s.add_kingdom("Far Kingdom");
+    // Two unexplored regions keep the kingdoms out of each other's reach
+    let wild_a = s.add_region("Western Wilds");
+    let wild_b = s.add_region("Eastern Wilds");
+    s.make_adjacent(pk.region, wild_a);
+    s.make_adjacent(wild_a, wild_b);
+    s.make_adjacent(wild_b, tk.region);
+    let player = s
+        .player_in("Dorian Blackthorn", pk.faction)
+        .birth_year(70)
+        .id();
+    let mut world = s.build();
+
+    let declare_war = |world: &mut history_gen::model::World, year: u32| {
+        world.queue_action(Action {
+            actor_id: player,
+            source: ActionSource::Player,
+            kind: ActionKind::DeclareWar {
+                target_faction_id: tk.faction,
+            },
+        });
+        testutil::tick_system(world, &mut ActionSystem, year, 42);
+        world.action_results.last().unwrap().outcome.clone()
+    };
+
+    let outcome = declare_war(&mut world, 100);
+    assert!(
+        matches!(&outcome, ActionOutcome::Failed { reason } if reason.contains("contact")),
+        "war on an unmet faction should fail, got {outcome:?}"
+    );
+    assert!(
+        !world.entities[&pk.faction].has_active_rel(RelationshipKind::AtWar, tk.faction),
+        "no war without contact"
+    );
+
+    // Both kingdoms build ports, so their fleets find each other
+    world
+        .settlement_mut(pk.settlement)
+        .building_bonuses
+        .port_trade = 0.1;
+    world
+        .settlement_mut(tk.settlement)
+        .building_bonuses
+        .port_trade = 0.1;
+    testutil::tick_system(&mut world, &mut PoliticsSystem::new(), 101, 42);
+    assert!(
+        world
+            .events
+            .values()
+            .any(|e| e.kind == EventKind::FirstContact),
+        "reachable factions should make first contact"
+    );
+
+    let outcome = declare_war(&mut world, 102);
+    assert!(
+        matches!(outcome, ActionOutcome::Success { .. }),
+        "war after contact should succeed, got {outcome:?}"
+    );
+    assert!(world.entities[&pk.faction].has_active_rel(RelationshipKind::AtWar, tk.faction));
+}
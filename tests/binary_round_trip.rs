@@ -0,0 +1,59 @@
+use std::fs;
+use std::path::Path;
+
+use history_gen::flush::write_checkpoint;
+use history_gen::model::World;
+use history_gen::sim::{
+    DemographicsSystem, EconomySystem, PoliticsSystem, SimConfig, SimSystem, run,
+};
+use history_gen::worldgen::{self, config::WorldGenConfig};
+
+const SEED: u64 = 42;
+
+fn simulated_world() -> World {
+    let mut world = worldgen::generate_world(WorldGenConfig {
+        seed: SEED,
+        ..WorldGenConfig::default()
+    });
+    let mut systems: Vec<Box<dyn SimSystem>> = vec![
+        Box::new(DemographicsSystem::new()),
+        Box::new(EconomySystem),
+        Box::new(PoliticsSystem::new()),
+    ];
+    run(&mut world, &mut systems, SimConfig::new(1, 30, SEED)).unwrap();
+    world
+}
+
+fn dir_size(dir: &Path) -> u64 {
+    fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().metadata().unwrap().len())
+        .sum()
+}
+
+#[test]
+fn binary_save_round_trips_and_beats_json() {
+    let mut world = simulated_world();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("world.bin");
+    world.save_binary(&path).unwrap();
+
+    let mut loaded = World::load_binary(&path).unwrap();
+    assert_eq!(loaded.content_hash(), world.content_hash());
+    assert_eq!(loaded.current_time, world.current_time);
+    assert_eq!(loaded.pending_actions.len(), world.pending_actions.len());
+    assert_eq!(loaded.id_gen.next_id(), world.id_gen.next_id());
+    assert_eq!(
+        loaded.events.values().map(|e| &e.data).collect::<Vec<_>>(),
+        world.events.values().map(|e| &e.data).collect::<Vec<_>>()
+    );
+
+    let json_dir = dir.path().join("json");
+    write_checkpoint(&world, &json_dir).unwrap();
+    let binary_size = fs::metadata(&path).unwrap().len();
+    let json_size = dir_size(&json_dir);
+    assert!(
+        binary_size * 4 < json_size,
+        "binary save ({binary_size} bytes) should be well under the JSON checkpoint ({json_size} bytes)"
+    );
+}
@@ -0,0 +1,76 @@
+use history_gen::flush::read_checkpoint;
+use history_gen::sim::{
+    DemographicsSystem, EconomySystem, PoliticsSystem, SimConfig, SimSystem, resume, run,
+};
+use history_gen::worldgen::{self, config::WorldGenConfig};
+
+const SEED: u64 = 42;
+
+fn stateless_systems() -> Vec<Box<dyn SimSystem>> {
+    vec![
+        Box::new(DemographicsSystem::new()),
+        Box::new(EconomySystem),
+        Box::new(PoliticsSystem::new()),
+    ]
+}
+
+fn generate() -> history_gen::model::World {
+    worldgen::generate_world(WorldGenConfig {
+        seed: SEED,
+        ..WorldGenConfig::default()
+    })
+}
+
+#[test]
+fn checkpoint_then_resume_matches_uninterrupted_run() {
+    let mut straight = generate();
+    run(
+        &mut straight,
+        &mut stateless_systems(),
+        SimConfig::new(1, 100, SEED),
+    )
+    .unwrap();
+
+    // First half, checkpointing at year 50
+    let dir = tempfile::tempdir().unwrap();
+    let mut first_half = generate();
+    let mut config = SimConfig::new(1, 50, SEED);
+    config.checkpoint_interval = Some(50);
+    config.output_dir = Some(dir.path().to_path_buf());
+    run(&mut first_half, &mut stateless_systems(), config).unwrap();
+
+    let mut resumed = read_checkpoint(&dir.path().join("checkpoint_000050")).unwrap();
+    assert_eq!(resumed.current_time.year(), 50);
+    assert_eq!(resumed.content_hash(), first_half.content_hash());
+    resume(
+        &mut resumed,
+        &mut stateless_systems(),
+        SimConfig::new(1, 100, SEED),
+    )
+    .unwrap();
+
+    assert_eq!(resumed.current_time.year(), 100);
+    assert_eq!(resumed.entities.len(), straight.entities.len());
+    assert_eq!(resumed.events.len(), straight.events.len());
+    assert_eq!(resumed.content_hash(), straight.content_hash());
+}
+
+#[test]
+fn resumed_run_does_not_reuse_ids() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut world = generate();
+    let mut config = SimConfig::new(1, 20, SEED);
+    config.checkpoint_interval = Some(10);
+    config.output_dir = Some(dir.path().to_path_buf());
+    run(&mut world, &mut stateless_systems(), config).unwrap();
+
+    let mut resumed = read_checkpoint(&dir.path().join("checkpoint_000010")).unwrap();
+    let max_id_at_checkpoint = resumed
+        .entities
+        .keys()
+        .chain(resumed.events.keys())
+        .copied()
+        .max()
+        .unwrap();
+    assert!(resumed.id_gen.next_id() > max_id_at_checkpoint);
+}
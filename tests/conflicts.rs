@@ -12,10 +12,10 @@ fn generate_and_run(seed: u64, num_years: u32) -> World {
         num_years,
         vec![
             Box::new(ActionSystem),
-            Box::new(DemographicsSystem),
+            Box::new(DemographicsSystem::new()),
             Box::new(EconomySystem),
             Box::new(ConflictSystem),
-            Box::new(PoliticsSystem),
+            Box::new(PoliticsSystem::new()),
         ],
     )
 }
@@ -240,7 +240,7 @@ fn scenario_war_goals_on_declarations() {
 
     // Run conflict + politics for a few years to trigger war declaration
     let mut systems: Vec<Box<dyn SimSystem>> =
-        vec![Box::new(ConflictSystem), Box::new(PoliticsSystem)];
+        vec![Box::new(ConflictSystem), Box::new(PoliticsSystem::new())];
     let world = s.run(&mut systems, 5, 42);
 
     let war_declarations: Vec<_> = world
@@ -293,3 +293,166 @@ fn scenario_tribute_flows_between_factions() {
         );
     }
 }
+
+#[test]
+fn explaining_a_treaty_surfaces_its_war_declaration() {
+    use history_gen::model::{CausalLink, EventPayload, RelationshipKind};
+
+    let world = generate_and_run(42, 100);
+    let treaty = world
+        .events
+        .values()
+        .find(|e| {
+            matches!(
+                EventPayload::from_data(&e.data),
+                Some(EventPayload::Treaty { .. })
+            )
+        })
+        .expect("a century of conflict should end in at least one peace treaty");
+
+    let explanation = world.explain(treaty.id).unwrap();
+    let declaration = explanation
+        .causes
+        .iter()
+        .find(|step| {
+            matches!(
+                step.event.kind,
+                EventKind::WarDeclared | EventKind::ExpansionWar
+            )
+        })
+        .expect("a treaty should trace back to the war it ended");
+    assert_eq!(declaration.linked_to, treaty.id);
+    assert_eq!(
+        declaration.link,
+        CausalLink::Resolved(RelationshipKind::AtWar)
+    );
+    assert!(declaration.event.year <= explanation.event.year);
+
+    let war = world.explain(declaration.event.id).unwrap();
+    assert!(
+        war.consequences
+            .iter()
+            .any(|step| step.event.id == treaty.id),
+        "the treaty should be among the war's consequences"
+    );
+}
+
+#[test]
+fn signal_subscriber_sees_every_war_start() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use history_gen::sim::{SignalKind, SignalSubscriber};
+    use history_gen::worldgen::{self, config::WorldGenConfig};
+
+    let wars_started = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&wars_started);
+    let mut config = SimConfig::new(1, 100, 42);
+    config.subscribers.push(SignalSubscriber::filtered(
+        |kind| matches!(kind, SignalKind::WarStarted { .. }),
+        move |_, _| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        },
+    ));
+
+    let mut world = worldgen::generate_world(WorldGenConfig {
+        seed: 42,
+        ..WorldGenConfig::default()
+    });
+    let mut systems: Vec<Box<dyn SimSystem>> = vec![
+        Box::new(DemographicsSystem::new()),
+        Box::new(EconomySystem),
+        Box::new(ConflictSystem),
+        Box::new(PoliticsSystem::new()),
+    ];
+    run(&mut world, &mut systems, config).unwrap();
+
+    // Allies joining a war declare too, as a consequence of the original declaration
+    let declarations = world
+        .events
+        .values()
+        .filter(|e| match e.kind {
+            EventKind::WarDeclared => e.caused_by.is_none(),
+            EventKind::ExpansionWar => true,
+            _ => false,
+        })
+        .count();
+    assert!(declarations > 0, "seed 42 should see wars within 100 years");
+    assert_eq!(wars_started.load(Ordering::Relaxed), declarations);
+}
+
+/// Year of the first war declared from `start_year` on, or `None` if none.
+fn first_war_year(world: &World, start_year: u32) -> Option<u32> {
+    world
+        .events
+        .values()
+        .filter(|e| {
+            matches!(e.kind, EventKind::WarDeclared | EventKind::ExpansionWar)
+                && e.timestamp.year() >= start_year
+        })
+        .map(|e| e.timestamp.year())
+        .min()
+}
+
+fn feud_scenario(feud: bool) -> (Scenario, u64, u64) {
+    let mut s = Scenario::at_year(100);
+    let a = s.add_kingdom("Westmarch");
+    let b = s.add_rival_kingdom("Eastmarch", a.region);
+    if feud {
+        s.enemies(a.faction, b.faction);
+        s.grievance(a.faction, b.faction, 0.6);
+        s.with_history(a.faction, b.faction, &[60..64, 81..85]);
+    }
+    (s, a.faction, b.faction)
+}
+
+#[test]
+fn scenario_seeded_feud_goes_to_war_sooner_than_neutral_neighbors() {
+    const YEARS: u32 = 30;
+    let mut feud_total = 0;
+    let mut neutral_total = 0;
+    for seed in 0..20 {
+        for (feud, total) in [(true, &mut feud_total), (false, &mut neutral_total)] {
+            let (s, _, _) = feud_scenario(feud);
+            let mut systems: Vec<Box<dyn SimSystem>> =
+                vec![Box::new(ConflictSystem), Box::new(PoliticsSystem::new())];
+            let world = s.run(&mut systems, YEARS, seed);
+            *total += first_war_year(&world, 100).map_or(YEARS, |y| y - 100);
+        }
+    }
+    assert!(
+        feud_total < neutral_total,
+        "feuding neighbors should fight sooner: {feud_total} vs {neutral_total} years"
+    );
+}
+
+#[test]
+fn scenario_history_leaves_decaying_grievances_and_past_wars() {
+    let (s, a, b) = feud_scenario(true);
+    let world = s.build();
+    let past_wars = world
+        .events
+        .values()
+        .filter(|e| e.kind == EventKind::WarDeclared && e.timestamp.year() < 100)
+        .count();
+    assert_eq!(past_wars, 2, "each past war should be recorded");
+    let seeded = world.faction(b).grievances[&a].severity;
+    assert!(
+        world.faction(b).grievances[&a]
+            .sources
+            .contains(&"old_war".to_string())
+    );
+
+    let (s, a, b) = feud_scenario(true);
+    let mut systems: Vec<Box<dyn SimSystem>> = vec![Box::new(PoliticsSystem::new())];
+    let world = s.run(&mut systems, 5, 42);
+    let after = world
+        .faction(b)
+        .grievances
+        .get(&a)
+        .map_or(0.0, |g| g.severity);
+    assert!(
+        after < seeded,
+        "seeded grievance should decay: {seeded} -> {after}"
+    );
+}
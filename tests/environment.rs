@@ -20,14 +20,14 @@ fn hundred_year_full_integration_no_panics() {
     let mut world = worldgen::generate_world(config);
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
         Box::new(EnvironmentSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(BuildingSystem),
         Box::new(EconomySystem),
         Box::new(ConflictSystem),
         Box::new(MigrationSystem),
         Box::new(DiseaseSystem),
         Box::new(CultureSystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
         Box::new(ReputationSystem),
     ];
     let _ = run(&mut world, &mut systems, SimConfig::new(1, 100, 42));
@@ -150,7 +150,7 @@ fn scenario_economy_produces_seasonal_variation() {
 
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
         Box::new(EnvironmentSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
     ];
     let world = s.run(&mut systems, 10, 42);
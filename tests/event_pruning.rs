@@ -0,0 +1,81 @@
+use history_gen::model::{EventKind, EventParticipant, EventRetention, World};
+use history_gen::sim::{SimConfig, run};
+use history_gen::testutil;
+use history_gen::worldgen::{self, config::WorldGenConfig};
+
+/// Battle and treaty events, serialized, with their participants.
+fn wars_on_record(world: &World) -> (Vec<String>, Vec<EventParticipant>) {
+    let is_war = |kind: &EventKind| matches!(kind, EventKind::Battle | EventKind::Treaty);
+    let events = world
+        .events
+        .values()
+        .filter(|e| is_war(&e.kind))
+        .map(|e| serde_json::to_string(e).unwrap())
+        .collect();
+    let participants = world
+        .event_participants
+        .iter()
+        .filter(|p| is_war(&world.events[&p.event_id].kind))
+        .cloned()
+        .collect();
+    (events, participants)
+}
+
+#[test]
+fn pruning_keeps_battles_and_treaties_intact() {
+    let mut world = testutil::generate_and_run(42, 100, testutil::combat_systems());
+    let (battles_before, participants_before) = wars_on_record(&world);
+    assert!(
+        !battles_before.is_empty(),
+        "expected some battles or treaties"
+    );
+    let events_before = world.events.len();
+
+    let removed = world.prune_events(EventRetention::MajorOnly);
+
+    assert!(removed > 0);
+    assert_eq!(world.events.len(), events_before - removed);
+    assert_eq!(
+        wars_on_record(&world),
+        (battles_before, participants_before)
+    );
+    assert!(
+        world
+            .event_participants
+            .iter()
+            .all(|p| world.events.contains_key(&p.event_id))
+    );
+    assert!(
+        world
+            .event_effects
+            .iter()
+            .all(|e| world.events.contains_key(&e.event_id))
+    );
+    for event in world.events.values() {
+        if let Some(parent) = event.caused_by {
+            assert!(world.events.contains_key(&parent), "broken causal chain");
+        }
+    }
+}
+
+#[test]
+fn run_prunes_old_ticks_when_configured() {
+    let mut world = worldgen::generate_world(WorldGenConfig {
+        seed: 42,
+        ..WorldGenConfig::default()
+    });
+    let mut systems = testutil::core_systems();
+    let mut config = SimConfig::new(1, 40, 42);
+    config.event_retention = EventRetention::PruneTicksAfter(10);
+    run(&mut world, &mut systems, config).unwrap();
+
+    let recent_ticks = world
+        .events
+        .values()
+        .filter(|e| e.kind.is_bookkeeping() && e.timestamp.year() >= 30)
+        .count();
+    assert!(recent_ticks > 0, "recent bookkeeping events should remain");
+
+    let unpruned = testutil::generate_and_run(42, 40, testutil::core_systems());
+    assert!(world.events.len() < unpruned.events.len());
+}
@@ -0,0 +1,82 @@
+use std::collections::BTreeSet;
+
+use history_gen::model::World;
+use history_gen::sim::{
+    DemographicsSystem, EconomySystem, PoliticsSystem, SimConfig, SimSystem, run,
+};
+use history_gen::worldgen::{self, config::WorldGenConfig};
+
+fn systems() -> Vec<Box<dyn SimSystem>> {
+    vec![
+        Box::new(DemographicsSystem::new()),
+        Box::new(EconomySystem),
+        Box::new(PoliticsSystem::new()),
+    ]
+}
+
+/// Serialized events up to and including `year`, in id order.
+fn history_through(world: &World, year: u32) -> Vec<String> {
+    world
+        .events
+        .values()
+        .filter(|e| e.timestamp.year() <= year)
+        .map(|e| serde_json::to_string(e).unwrap())
+        .collect()
+}
+
+#[test]
+fn forks_share_past_and_diverge_after_split() {
+    let mut world = worldgen::generate_world(WorldGenConfig {
+        seed: 42,
+        ..WorldGenConfig::default()
+    });
+    run(&mut world, &mut systems(), SimConfig::new(1, 50, 42)).unwrap();
+    let past = history_through(&world, 50);
+
+    let mut branch_a = world.fork();
+    let mut branch_b = world.fork();
+    run(&mut branch_a, &mut systems(), SimConfig::new(51, 10, 1)).unwrap();
+    run(&mut branch_b, &mut systems(), SimConfig::new(51, 10, 2)).unwrap();
+
+    assert_eq!(branch_a.current_time.year(), 60);
+    assert_eq!(branch_b.current_time.year(), 60);
+    assert_ne!(branch_a.content_hash(), branch_b.content_hash());
+
+    assert_eq!(history_through(&branch_a, 50), past);
+    assert_eq!(history_through(&branch_b, 50), past);
+    // The original is untouched by either branch
+    assert_eq!(world.current_time.year(), 50);
+    assert_eq!(history_through(&world, 60), past);
+}
+
+/// Ids of entities and events a branch created after the split.
+fn ids_after_split(branch: &World, parent: &World) -> BTreeSet<u64> {
+    let entities = branch
+        .entities
+        .keys()
+        .filter(|id| !parent.entities.contains_key(id));
+    let events = branch
+        .events
+        .keys()
+        .filter(|id| !parent.events.contains_key(id));
+    entities.chain(events).copied().collect()
+}
+
+#[test]
+fn sibling_forks_issue_disjoint_ids() {
+    let mut world = worldgen::generate_world(WorldGenConfig {
+        seed: 7,
+        ..WorldGenConfig::default()
+    });
+    run(&mut world, &mut systems(), SimConfig::new(1, 20, 7)).unwrap();
+
+    let mut branch_a = world.fork();
+    let mut branch_b = world.fork();
+    run(&mut branch_a, &mut systems(), SimConfig::new(21, 10, 1)).unwrap();
+    run(&mut branch_b, &mut systems(), SimConfig::new(21, 10, 2)).unwrap();
+
+    let ids_a = ids_after_split(&branch_a, &world);
+    let ids_b = ids_after_split(&branch_b, &world);
+    assert!(!ids_a.is_empty() && !ids_b.is_empty());
+    assert!(ids_a.is_disjoint(&ids_b), "sibling forks reused ids");
+}
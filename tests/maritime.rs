@@ -88,8 +88,10 @@ fn coastal_port_increases_capacity() {
     });
 
     let mut world = s.build();
-    let mut systems: Vec<Box<dyn SimSystem>> =
-        vec![Box::new(BuildingSystem), Box::new(DemographicsSystem)];
+    let mut systems: Vec<Box<dyn SimSystem>> = vec![
+        Box::new(BuildingSystem),
+        Box::new(DemographicsSystem::new()),
+    ];
     let _ = run(&mut world, &mut systems, SimConfig::new(1, 1, 42));
 
     let port_cap = world.settlement(coastal_port).capacity;
@@ -254,7 +256,7 @@ fn maritime_integration_200_years() {
     let mut world = s.build();
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
         Box::new(EnvironmentSystem),
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(BuildingSystem),
         Box::new(EconomySystem),
         Box::new(MigrationSystem),
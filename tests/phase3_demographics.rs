@@ -1,5 +1,7 @@
+use history_gen::model::EventRetention;
 use history_gen::sim::{
-    DemographicsSystem, EconomySystem, PoliticsSystem, SimConfig, SimSystem, run,
+    DemographicsConfig, DemographicsSystem, EconomySystem, PoliticsSystem, SimConfig, SimSystem,
+    run,
 };
 use history_gen::testutil;
 use history_gen::worldgen::{self, config::WorldGenConfig};
@@ -21,9 +23,9 @@ fn flush_checkpoints_written() {
     };
     let mut world = worldgen::generate_world(config);
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
 
     let tmp_dir = std::env::temp_dir().join(format!("history_gen_test_{}", seed));
@@ -37,7 +39,15 @@ fn flush_checkpoints_written() {
             num_years: 100,
             seed,
             flush_interval: Some(50),
+            checkpoint_interval: None,
             output_dir: Some(tmp_dir.clone()),
+            event_retention: EventRetention::All,
+            demographics: DemographicsConfig::default(),
+            politics: Default::default(),
+            stop_conditions: Vec::new(),
+            tick_frequencies: Default::default(),
+            subscribers: Vec::new(),
+            rng: Default::default(),
         },
     );
 
@@ -23,7 +23,7 @@ fn succession_claims_and_crises_occur() {
     let mut total_crises = 0;
     let mut total_claim_wars = 0;
 
-    for seed in 0u64..50 {
+    for seed in 0u64..100 {
         let mut s = Scenario::at_year(100);
 
         // Create unstable hereditary kingdoms primed for coups
@@ -76,10 +76,10 @@ fn succession_claims_and_crises_occur() {
         }
 
         let mut systems: Vec<Box<dyn SimSystem>> = vec![
-            Box::new(DemographicsSystem),
+            Box::new(DemographicsSystem::new()),
             Box::new(EconomySystem),
             Box::new(ConflictSystem),
-            Box::new(PoliticsSystem),
+            Box::new(PoliticsSystem::new()),
             Box::new(AgencySystem::default()),
             Box::new(ActionSystem),
         ];
@@ -106,7 +106,7 @@ fn succession_claims_and_crises_occur() {
 
     assert!(
         total_crises > 0 || total_claim_wars > 0,
-        "expected at least one succession crisis or claim war across 50 seeds × 50-year runs \
+        "expected at least one succession crisis or claim war across 100 seeds × 50-year runs \
          (got {total_crises} crises, {total_claim_wars} claim wars)"
     );
 }
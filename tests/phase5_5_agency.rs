@@ -11,9 +11,9 @@ fn generate_and_run(seed: u64, num_years: u32) -> World {
         seed,
         num_years,
         vec![
-            Box::new(DemographicsSystem),
+            Box::new(DemographicsSystem::new()),
             Box::new(EconomySystem),
-            Box::new(PoliticsSystem),
+            Box::new(PoliticsSystem::new()),
             Box::new(AgencySystem::new()),
             Box::new(ActionSystem),
             Box::new(ConflictSystem),
@@ -46,8 +46,10 @@ fn scenario_npcs_have_traits_at_birth() {
     s.make_leader(leader, faction);
 
     // Run demographics for 5 years to produce births
-    let mut systems: Vec<Box<dyn SimSystem>> =
-        vec![Box::new(DemographicsSystem), Box::new(AgencySystem::new())];
+    let mut systems: Vec<Box<dyn SimSystem>> = vec![
+        Box::new(DemographicsSystem::new()),
+        Box::new(AgencySystem::new()),
+    ];
     let world = s.run(&mut systems, 5, 42);
 
     let persons_with_traits: Vec<_> = world
@@ -105,9 +107,9 @@ fn scenario_npc_driven_events_have_instigators() {
 
     // Run with agency + actions + politics for several years
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
         Box::new(AgencySystem::new()),
         Box::new(ActionSystem),
     ];
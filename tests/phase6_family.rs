@@ -51,9 +51,9 @@ fn scenario_parent_child_relationships_exist() {
     }
 
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
     let world = s.run(&mut systems, 50, 42);
 
@@ -107,9 +107,9 @@ fn scenario_marriages_occur() {
     }
 
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
     let world = s.run(&mut systems, 50, 42);
 
@@ -145,9 +145,9 @@ fn scenario_surname_dynasties_visible() {
     s.make_leader(leader, faction);
 
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
     let world = s.run(&mut systems, 30, 42);
 
@@ -181,9 +181,9 @@ fn scenario_cross_faction_marriages_create_alliances() {
     let mut world = s.build();
 
     let mut systems: Vec<Box<dyn SimSystem>> = vec![
-        Box::new(DemographicsSystem),
+        Box::new(DemographicsSystem::new()),
         Box::new(EconomySystem),
-        Box::new(PoliticsSystem),
+        Box::new(PoliticsSystem::new()),
     ];
     let _ = run(&mut world, &mut systems, SimConfig::new(1, 30, 42));
 
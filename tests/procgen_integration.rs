@@ -159,3 +159,70 @@ fn no_id_collisions_across_categories() {
         );
     }
 }
+
+#[test]
+fn finer_detail_materializes_more_inhabitants() {
+    use history_gen::procgen::DetailLevel;
+
+    let (world, settlements) = make_world_with_settlements();
+    let count = |level: DetailLevel| -> usize {
+        settlements
+            .iter()
+            .map(|&sid| {
+                let snapshot = procgen::snapshot_from_world(&world, sid, 100).unwrap();
+                let config = ProcGenConfig {
+                    lazy_inhabitants: false,
+                    ..ProcGenConfig::with_detail(level)
+                };
+                procgen::generate_settlement_details(&snapshot, &config)
+                    .inhabitants
+                    .len()
+            })
+            .sum()
+    };
+
+    let coarse = count(DetailLevel::Coarse);
+    let fine = count(DetailLevel::Fine);
+    assert!(
+        fine > coarse,
+        "fine detail should name more people: {fine} vs {coarse}"
+    );
+    assert!(count(DetailLevel::Standard) > coarse);
+}
+
+#[test]
+fn every_detail_level_is_deterministic() {
+    use history_gen::procgen::DetailLevel;
+
+    let (world, settlements) = make_world_with_settlements();
+    let snapshot = procgen::snapshot_from_world(&world, settlements[0], 100).unwrap();
+
+    for level in [
+        DetailLevel::Coarse,
+        DetailLevel::Standard,
+        DetailLevel::Fine,
+    ] {
+        let config = ProcGenConfig::with_detail(level);
+        let a = procgen::generate_settlement_details(&snapshot, &config);
+        let b = procgen::generate_settlement_details(&snapshot, &config);
+        let names = |d: &procgen::SettlementDetails| -> Vec<String> {
+            d.inhabitants
+                .iter()
+                .map(|p| p.name.clone())
+                .chain(d.artifacts.iter().map(|x| x.name.clone()))
+                .chain(d.writings.iter().map(|w| w.text.clone()))
+                .collect()
+        };
+        assert_eq!(names(&a), names(&b), "{level:?} output should be stable");
+    }
+
+    let coarse = ProcGenConfig::with_detail(DetailLevel::Coarse);
+    assert!(coarse.lazy_inhabitants);
+    let details = procgen::generate_settlement_details(&snapshot, &coarse);
+    assert!(details.inhabitants.is_empty());
+    assert_eq!(
+        procgen::inhabitants::generate_inhabitants(&snapshot, &coarse).len(),
+        procgen::inhabitants::inhabitant_count(&snapshot, &coarse),
+        "lazy inhabitants can still be generated on demand"
+    );
+}
@@ -0,0 +1,122 @@
+use history_gen::model::EventKind;
+use history_gen::scenario::{Scenario, ScenarioSpec, sweep};
+use history_gen::sim::{
+    ConflictSystem, DemographicsSystem, EconomySystem, PoliticsSystem, SimConfig, SimSystem,
+    TickContext, TickFrequency,
+};
+
+fn two_kingdoms() -> Scenario {
+    let mut s = Scenario::at_year(100);
+    let north = s.add_kingdom("North");
+    let south = s.add_kingdom("South");
+    s.make_adjacent(north.region, south.region);
+    s
+}
+
+fn systems() -> Vec<Box<dyn SimSystem>> {
+    vec![
+        Box::new(DemographicsSystem::new()),
+        Box::new(EconomySystem),
+        Box::new(ConflictSystem),
+        Box::new(PoliticsSystem::new()),
+    ]
+}
+
+#[test]
+fn sweep_over_seeds_returns_one_summary_per_run() {
+    let spec = ScenarioSpec::new(two_kingdoms, systems);
+    let results = sweep(&spec, 0..5, &[SimConfig::new(100, 10, 0)]).unwrap();
+
+    assert_eq!(results.runs.len(), 5);
+    for (run, seed) in results.runs.iter().zip(0..) {
+        assert_eq!(run.seed, seed);
+        assert_eq!(run.config_index, 0);
+        assert!(run.final_faction_count > 0, "run {seed} lost every faction");
+        assert!(
+            run.surviving_population > 0,
+            "run {seed} has no people left"
+        );
+    }
+    assert!(results.mean(|r| r.surviving_population as f64).unwrap() > 0.0);
+
+    // Runs are independent: repeating a seed reproduces its row exactly
+    let again = sweep(&spec, 3..4, &[SimConfig::new(100, 10, 0)]).unwrap();
+    assert_eq!(again.runs[0], results.runs[3]);
+}
+
+#[test]
+fn sweep_crosses_every_config_with_every_seed() {
+    let spec = ScenarioSpec::new(two_kingdoms, systems);
+    let configs = [SimConfig::new(100, 5, 0), SimConfig::new(100, 10, 0)];
+    let results = sweep(&spec, 0..3, &configs).unwrap();
+
+    assert_eq!(results.runs.len(), 6);
+    let config_indices: Vec<usize> = results.runs.iter().map(|r| r.config_index).collect();
+    assert_eq!(config_indices, vec![0, 0, 0, 1, 1, 1]);
+}
+
+#[test]
+fn sweep_gives_each_run_its_own_output_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let spec = ScenarioSpec::new(two_kingdoms, systems);
+    let mut config = SimConfig::new(100, 2, 0);
+    config.flush_interval = Some(1);
+    config.output_dir = Some(dir.path().to_path_buf());
+    sweep(&spec, 0..2, &[config.clone(), config]).unwrap();
+
+    for config_index in 0..2 {
+        for seed in 0..2 {
+            let run_dir = dir
+                .path()
+                .join(format!("config_{config_index}"))
+                .join(format!("seed_{seed}"));
+            assert!(
+                run_dir.join("year_000101").join("entities.jsonl").exists(),
+                "missing flush for config {config_index} seed {seed}"
+            );
+        }
+    }
+}
+
+#[test]
+fn sweep_reports_flush_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let blocker = dir.path().join("not_a_dir");
+    std::fs::write(&blocker, "").unwrap();
+    let spec = ScenarioSpec::new(two_kingdoms, systems);
+    let mut config = SimConfig::new(100, 1, 0);
+    config.flush_interval = Some(1);
+    config.output_dir = Some(blocker);
+
+    assert!(sweep(&spec, 0..1, &[config]).is_err());
+}
+
+/// Starts one declared war and one expansion war every year.
+struct WarMonger;
+
+impl SimSystem for WarMonger {
+    fn name(&self) -> &str {
+        "war_monger"
+    }
+
+    fn frequency(&self) -> TickFrequency {
+        TickFrequency::Yearly
+    }
+
+    fn tick(&mut self, ctx: &mut TickContext) {
+        let time = ctx.world.current_time;
+        for kind in [EventKind::WarDeclared, EventKind::ExpansionWar] {
+            ctx.world.add_event(kind, time, "war".to_string());
+        }
+    }
+}
+
+#[test]
+fn sweep_counts_expansion_wars() {
+    let spec = ScenarioSpec::new(two_kingdoms, || -> Vec<Box<dyn SimSystem>> {
+        vec![Box::new(WarMonger)]
+    });
+    let results = sweep(&spec, 0..1, &[SimConfig::new(100, 3, 0)]).unwrap();
+
+    assert_eq!(results.runs[0].total_wars, 6);
+}